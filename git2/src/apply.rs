@@ -0,0 +1,545 @@
+use std::fs::{self, File};
+use std::io::prelude::*;
+use std::path::Path;
+
+use {raw, Delta, Diff, DiffDelta, DiffHunk, Error, ErrorClass, ErrorCode};
+use {Index, IndexEntry, IndexTime, Patch, Repository, Tree};
+use util::{self, Binding};
+
+/// Where a diff is applied with `Repository::apply`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ApplyLocation {
+    /// Apply the diff to the files of the working directory.
+    WorkDir,
+    /// Apply the diff to the index, leaving the working directory alone.
+    Index,
+    /// Apply the diff to both the working directory and the index, which must
+    /// agree on the contents of the files being patched.
+    Both,
+}
+
+/// Options to control how a diff is applied.
+pub struct ApplyOptions<'cb> {
+    delta_cb: Option<Box<ApplyDeltaCb<'cb>>>,
+    hunk_cb: Option<Box<ApplyHunkCb<'cb>>>,
+    check: bool,
+}
+
+/// Callback invoked for each delta before it is applied. Returning `false`
+/// skips the delta.
+pub type ApplyDeltaCb<'a> = FnMut(DiffDelta) -> bool + 'a;
+
+/// Callback invoked for each hunk before it is applied. Returning `false`
+/// skips the hunk.
+pub type ApplyHunkCb<'a> = FnMut(DiffHunk) -> bool + 'a;
+
+impl<'cb> Default for ApplyOptions<'cb> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'cb> ApplyOptions<'cb> {
+    /// Creates a default set of options, applying every delta and hunk.
+    pub fn new() -> ApplyOptions<'cb> {
+        ApplyOptions {
+            delta_cb: None,
+            hunk_cb: None,
+            check: false,
+        }
+    }
+
+    /// Sets a callback deciding whether each delta is applied.
+    pub fn delta_callback<F>(&mut self, cb: F) -> &mut ApplyOptions<'cb>
+        where F: FnMut(DiffDelta) -> bool + 'cb
+    {
+        self.delta_cb = Some(Box::new(cb) as Box<ApplyDeltaCb<'cb>>);
+        self
+    }
+
+    /// Sets a callback deciding whether each hunk is applied.
+    pub fn hunk_callback<F>(&mut self, cb: F) -> &mut ApplyOptions<'cb>
+        where F: FnMut(DiffHunk) -> bool + 'cb
+    {
+        self.hunk_cb = Some(Box::new(cb) as Box<ApplyHunkCb<'cb>>);
+        self
+    }
+
+    /// Only checks whether the diff applies cleanly, without modifying
+    /// anything.
+    pub fn check(&mut self, check: bool) -> &mut ApplyOptions<'cb> {
+        self.check = check;
+        self
+    }
+}
+
+/// The result of applying the patch of one delta.
+struct Change {
+    // path which is removed, for deletions and renames
+    removed: Option<Vec<u8>>,
+    // path, mode and contents which are written, unless the file is deleted
+    written: Option<(Vec<u8>, u32, Vec<u8>)>,
+}
+
+pub fn apply(repo: &Repository, diff: &Diff, location: ApplyLocation,
+             options: Option<&mut ApplyOptions>) -> Result<(), Error> {
+    let mut default = ApplyOptions::new();
+    let options = options.unwrap_or(&mut default);
+    let workdir = match (location, repo.workdir()) {
+        (ApplyLocation::Index, _) => None,
+        (_, Some(workdir)) => Some(workdir),
+        (_, None) => {
+            return Err(Error::new(ErrorCode::BareRepo, ErrorClass::Repository,
+                                  "cannot apply a diff to the working \
+                                   directory of a bare repository"))
+        }
+    };
+    let mut index = try!(repo.index());
+
+    let changes = try!(patch_all(diff, options, &mut |path| {
+        let file = workdir.map(|workdir| read_file(&workdir.join(util::bytes2path(path))));
+        match location {
+            ApplyLocation::WorkDir => file.unwrap(),
+            ApplyLocation::Index => read_blob(repo, &index, path),
+            ApplyLocation::Both => {
+                let contents = try!(read_blob(repo, &index, path));
+                if try!(file.unwrap()) != contents {
+                    return Err(Error::from_str(&format!(
+                        "{}: does not match index",
+                        String::from_utf8_lossy(path))))
+                }
+                Ok(contents)
+            }
+        }
+    }, &mut |path| {
+        if let Some(workdir) = workdir {
+            try!(check_not_in_workdir(workdir, path));
+        }
+        if location != ApplyLocation::WorkDir {
+            try!(check_not_in_index(&index, path));
+        }
+        Ok(())
+    }));
+    if options.check {
+        return Ok(())
+    }
+
+    if let Some(workdir) = workdir {
+        for change in &changes {
+            try!(update_workdir(workdir, change));
+        }
+    }
+    if location != ApplyLocation::WorkDir {
+        for change in &changes {
+            try!(update_index(repo, &mut index, change));
+        }
+        try!(index.write());
+    }
+    Ok(())
+}
+
+pub fn apply_to_tree(repo: &Repository, tree: &Tree, diff: &Diff,
+                     options: Option<&mut ApplyOptions>)
+                     -> Result<Index, Error> {
+    let mut default = ApplyOptions::new();
+    let options = options.unwrap_or(&mut default);
+    let mut index = try!(Index::new());
+    try!(index.read_tree(tree));
+    let changes = try!(patch_all(diff, options, &mut |path| {
+        read_blob(repo, &index, path)
+    }, &mut |path| check_not_in_index(&index, path)));
+    if !options.check {
+        for change in &changes {
+            try!(update_index(repo, &mut index, change));
+        }
+    }
+    Ok(index)
+}
+
+// reads the current contents of a file, by path
+type ReadCb<'a> = FnMut(&[u8]) -> Result<Vec<u8>, Error> + 'a;
+
+// checks that a file which is added by the diff doesn't exist yet, by path
+type CheckNewCb<'a> = FnMut(&[u8]) -> Result<(), Error> + 'a;
+
+// Computes the changes of all deltas of the diff before anything is modified,
+// so that nothing is changed when any of them fails to apply.
+fn patch_all(diff: &Diff, options: &mut ApplyOptions,
+             read: &mut ReadCb, check_new: &mut CheckNewCb)
+             -> Result<Vec<Change>, Error> {
+    let mut changes = Vec::new();
+    for idx in 0..diff.deltas().len() {
+        let delta = diff.get_delta(idx).unwrap();
+        if let Some(ref mut cb) = options.delta_cb {
+            if !cb(diff.get_delta(idx).unwrap()) {
+                continue
+            }
+        }
+        let flags = unsafe { (*delta.raw()).flags };
+        if flags & raw::GIT_DIFF_FLAG_BINARY != 0 {
+            return Err(Error::from_str("binary patches cannot be applied"))
+        }
+        let old_path = delta.old_file().path_bytes().unwrap_or(b"").to_vec();
+        let new_path = delta.new_file().path_bytes().unwrap_or(b"").to_vec();
+        let source = match delta.status() {
+            Delta::Added => {
+                try!(check_new(&new_path));
+                Vec::new()
+            }
+            _ => try!(read(&old_path)),
+        };
+
+        let mut patch = match try!(Patch::from_diff(diff, idx)) {
+            Some(patch) => patch,
+            None => continue,
+        };
+        let contents = try!(patch_contents(&mut patch, &source, options));
+
+        let change = match delta.status() {
+            Delta::Deleted => {
+                if !contents.is_empty() {
+                    return Err(Error::from_str("removal patch leaves file \
+                                                contents"))
+                }
+                Change { removed: Some(old_path), written: None }
+            }
+            status => {
+                let mode = match unsafe { (*delta.raw()).new_file.mode } {
+                    0 => raw::GIT_FILEMODE_BLOB as u32,
+                    mode => mode as u32,
+                };
+                let removed = if status == Delta::Renamed {
+                    Some(old_path)
+                } else {
+                    None
+                };
+                Change {
+                    removed: removed,
+                    written: Some((new_path, mode, contents)),
+                }
+            }
+        };
+        changes.push(change);
+    }
+    Ok(changes)
+}
+
+// Applies the hunks of a patch to the lines of `source` in the same way as
+// libgit2, where each hunk must match exactly at the line it starts at in the
+// new file.
+fn patch_contents(patch: &mut Patch, source: &[u8],
+                  options: &mut ApplyOptions) -> Result<Vec<u8>, Error> {
+    let mut image = source.split(|b| *b == b'\n').map(|l| l.to_vec())
+                          .collect::<Vec<_>>();
+    // lines keep their newline, and the piece after the last newline is only
+    // a line if it isn't empty
+    let last = image.pop().unwrap_or_default();
+    for line in &mut image {
+        line.push(b'\n');
+    }
+    if !last.is_empty() {
+        image.push(last);
+    }
+
+    // line offset caused by the hunks which were skipped
+    let mut skipped = 0isize;
+    for hunk_idx in 0..patch.num_hunks() {
+        let (start, nlines) = {
+            let (hunk, nlines) = try!(patch.hunk(hunk_idx));
+            let start = hunk.new_start();
+            let offset = hunk.old_lines() as isize - hunk.new_lines() as isize;
+            if let Some(ref mut cb) = options.hunk_cb {
+                if !cb(hunk) {
+                    skipped += offset;
+                    continue
+                }
+            }
+            (start, nlines)
+        };
+
+        let mut preimage = Vec::new();
+        let mut postimage = Vec::new();
+        for line_idx in 0..nlines {
+            let line = try!(patch.line_in_hunk(hunk_idx, line_idx));
+            match line.origin() {
+                ' ' => {
+                    preimage.push(line.content().to_vec());
+                    postimage.push(line.content().to_vec());
+                }
+                '-' => preimage.push(line.content().to_vec()),
+                '+' => postimage.push(line.content().to_vec()),
+                _ => {}
+            }
+        }
+
+        let start = if start == 0 { 0 } else { start as isize - 1 };
+        let start = ::std::cmp::max(start + skipped, 0) as usize;
+        let start = ::std::cmp::min(start, image.len());
+        if start + preimage.len() > image.len() ||
+           image[start..start + preimage.len()] != preimage[..] {
+            return Err(Error::from_str(&format!("hunk at line {} did not \
+                                                 apply", start + 1)))
+        }
+        image.splice(start..start + preimage.len(), postimage);
+    }
+    Ok(image.concat())
+}
+
+fn read_file(path: &Path) -> Result<Vec<u8>, Error> {
+    let mut contents = Vec::new();
+    try!(File::open(path).and_then(|mut f| f.read_to_end(&mut contents))
+             .map_err(|e| io_error(path, e)));
+    Ok(contents)
+}
+
+fn read_blob(repo: &Repository, index: &Index, path: &[u8])
+             -> Result<Vec<u8>, Error> {
+    match index.get_path(util::bytes2path(path), 0) {
+        Some(entry) => Ok(try!(repo.find_blob(entry.id)).content().to_vec()),
+        None => {
+            Err(Error::new(ErrorCode::NotFound, ErrorClass::Index,
+                           &format!("{}: does not exist in index",
+                                    String::from_utf8_lossy(path))))
+        }
+    }
+}
+
+fn check_not_in_workdir(workdir: &Path, path: &[u8]) -> Result<(), Error> {
+    // `symlink_metadata` so that dangling symlinks are found too
+    if fs::symlink_metadata(workdir.join(util::bytes2path(path))).is_ok() {
+        return Err(Error::new(ErrorCode::Exists, ErrorClass::Filesystem,
+                              &format!("{}: already exists in working \
+                                        directory",
+                                       String::from_utf8_lossy(path))))
+    }
+    Ok(())
+}
+
+fn check_not_in_index(index: &Index, path: &[u8]) -> Result<(), Error> {
+    if index.get_path(util::bytes2path(path), 0).is_some() {
+        return Err(Error::new(ErrorCode::Exists, ErrorClass::Index,
+                              &format!("{}: already exists in index",
+                                       String::from_utf8_lossy(path))))
+    }
+    Ok(())
+}
+
+fn io_error(path: &Path, e: ::std::io::Error) -> Error {
+    Error::from_str(&format!("{}: {}", path.display(), e))
+}
+
+fn update_workdir(workdir: &Path, change: &Change) -> Result<(), Error> {
+    if let Some(ref path) = change.removed {
+        let path = workdir.join(util::bytes2path(path));
+        try!(fs::remove_file(&path).map_err(|e| io_error(&path, e)));
+    }
+    if let Some((ref path, mode, ref contents)) = change.written {
+        let path = workdir.join(util::bytes2path(path));
+        if let Some(parent) = path.parent() {
+            try!(fs::create_dir_all(parent).map_err(|e| io_error(parent, e)));
+        }
+        try!(File::create(&path).and_then(|mut f| f.write_all(contents))
+                 .map_err(|e| io_error(&path, e)));
+        try!(set_executable(&path, mode == raw::GIT_FILEMODE_BLOB_EXECUTABLE as u32)
+                 .map_err(|e| io_error(&path, e)));
+    }
+    Ok(())
+}
+
+#[cfg(unix)]
+fn set_executable(path: &Path, executable: bool) -> ::std::io::Result<()> {
+    use std::os::unix::prelude::*;
+    let mut perms = try!(fs::metadata(path)).permissions();
+    let mode = perms.mode() & !0o111;
+    // files are executable by whoever may read them
+    perms.set_mode(if executable { mode | (mode & 0o444) >> 2 } else { mode });
+    fs::set_permissions(path, perms)
+}
+
+#[cfg(windows)]
+fn set_executable(_path: &Path, _executable: bool) -> ::std::io::Result<()> {
+    Ok(())
+}
+
+fn update_index(repo: &Repository, index: &mut Index, change: &Change)
+                -> Result<(), Error> {
+    if let Some(ref path) = change.removed {
+        try!(index.remove_path(util::bytes2path(path)));
+    }
+    if let Some((ref path, mode, ref contents)) = change.written {
+        let entry = IndexEntry {
+            ctime: IndexTime::new(0, 0),
+            mtime: IndexTime::new(0, 0),
+            dev: 0,
+            ino: 0,
+            mode: mode,
+            uid: 0,
+            gid: 0,
+            file_size: contents.len() as u32,
+            id: try!(repo.blob(contents)),
+            flags: 0,
+            flags_extended: 0,
+            path: path.clone(),
+        };
+        try!(index.add(&entry));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs::File;
+    use std::io::prelude::*;
+    use std::path::Path;
+
+    use {ApplyLocation, ApplyOptions, Diff, ErrorCode, Repository};
+
+    const PATCH: &[u8] = b"\
+diff --git a/foo b/foo
+index 1191247..e4ba3d5 100644
+--- a/foo
++++ b/foo
+@@ -1,3 +1,3 @@
+ 1
+-2
++two
+ 3
+@@ -8,3 +8,4 @@
+ 8
+ 9
+ 10
++11
+diff --git a/bar b/bar
+new file mode 100644
+index 0000000..5716ca5
+--- /dev/null
++++ b/bar
+@@ -0,0 +1 @@
++bar
+";
+
+    fn commit_foo(repo: &Repository) {
+        let contents = (1..11).map(|i| format!("{}\n", i)).collect::<String>();
+        t!(t!(File::create(repo.workdir().unwrap().join("foo")))
+               .write_all(contents.as_bytes()));
+        let mut index = t!(repo.index());
+        t!(index.add_path(Path::new("foo")));
+        t!(index.write());
+        let tree = t!(repo.find_tree(t!(index.write_tree())));
+        let sig = t!(repo.signature());
+        let parent = t!(t!(repo.head()).peel_to_commit());
+        t!(repo.commit(Some("HEAD"), &sig, &sig, "foo", &tree, &[&parent]));
+    }
+
+    fn read(path: &Path) -> String {
+        let mut s = String::new();
+        t!(t!(File::open(path)).read_to_string(&mut s));
+        s
+    }
+
+    #[test]
+    fn workdir_and_index() {
+        let (td, repo) = ::test::repo_init();
+        commit_foo(&repo);
+        let diff = t!(Diff::from_buffer(PATCH));
+
+        t!(repo.apply(&diff, ApplyLocation::WorkDir, None));
+        assert_eq!(read(&td.path().join("foo")),
+                   "1\ntwo\n3\n4\n5\n6\n7\n8\n9\n10\n11\n");
+        assert_eq!(read(&td.path().join("bar")), "bar\n");
+        let index = t!(repo.index());
+        assert!(index.get_path(Path::new("bar"), 0).is_none());
+
+        // the index still has the old contents, which don't match the files
+        assert!(repo.apply(&diff, ApplyLocation::Both, None).is_err());
+        t!(repo.apply(&diff, ApplyLocation::Index, None));
+        let index = t!(repo.index());
+        let entry = index.get_path(Path::new("foo"), 0).unwrap();
+        assert_eq!(t!(repo.find_blob(entry.id)).content(),
+                   &b"1\ntwo\n3\n4\n5\n6\n7\n8\n9\n10\n11\n"[..]);
+        assert!(index.get_path(Path::new("bar"), 0).is_some());
+    }
+
+    #[test]
+    fn failed_hunk_changes_nothing() {
+        let (td, repo) = ::test::repo_init();
+        commit_foo(&repo);
+        t!(t!(File::create(td.path().join("foo"))).write_all(b"1\n"));
+        let diff = t!(Diff::from_buffer(PATCH));
+
+        let mut opts = ApplyOptions::new();
+        opts.check(true);
+        assert!(repo.apply(&diff, ApplyLocation::WorkDir, Some(&mut opts))
+                    .is_err());
+        assert!(repo.apply(&diff, ApplyLocation::WorkDir, None).is_err());
+        assert_eq!(read(&td.path().join("foo")), "1\n");
+        assert!(!td.path().join("bar").exists());
+    }
+
+    #[test]
+    fn added_file_must_not_exist() {
+        let (td, repo) = ::test::repo_init();
+        commit_foo(&repo);
+        let diff = t!(Diff::from_buffer(PATCH));
+
+        t!(t!(File::create(td.path().join("bar"))).write_all(b"precious\n"));
+        let err = repo.apply(&diff, ApplyLocation::WorkDir, None).err().unwrap();
+        assert_eq!(err.code(), ErrorCode::Exists);
+        let err = repo.apply(&diff, ApplyLocation::Both, None).err().unwrap();
+        assert_eq!(err.code(), ErrorCode::Exists);
+        assert_eq!(read(&td.path().join("bar")), "precious\n");
+        assert_eq!(read(&td.path().join("foo")),
+                   "1\n2\n3\n4\n5\n6\n7\n8\n9\n10\n");
+
+        // the index doesn't know about the untracked file
+        t!(repo.apply(&diff, ApplyLocation::Index, None));
+        assert_eq!(read(&td.path().join("bar")), "precious\n");
+
+        let mut opts = ApplyOptions::new();
+        opts.delta_callback(|delta| {
+            delta.new_file().path() == Some(Path::new("bar"))
+        });
+        let err = repo.apply(&diff, ApplyLocation::Index, Some(&mut opts))
+                      .err().unwrap();
+        assert_eq!(err.code(), ErrorCode::Exists);
+        let mut index = t!(repo.index());
+        let tree = t!(repo.find_tree(t!(index.write_tree_to(&repo))));
+        let err = repo.apply_to_tree(&tree, &diff, Some(&mut opts))
+                      .err().unwrap();
+        assert_eq!(err.code(), ErrorCode::Exists);
+    }
+
+    #[test]
+    fn callbacks_and_tree() {
+        let (td, repo) = ::test::repo_init();
+        commit_foo(&repo);
+        let diff = t!(Diff::from_buffer(PATCH));
+        let tree = t!(t!(repo.head()).peel_to_tree());
+
+        let mut hunks = 0;
+        {
+            let mut opts = ApplyOptions::new();
+            opts.delta_callback(|delta| {
+                delta.new_file().path() == Some(Path::new("foo"))
+            });
+            opts.hunk_callback(|hunk| {
+                hunks += 1;
+                hunk.new_start() != 1
+            });
+            let mut index = t!(repo.apply_to_tree(&tree, &diff,
+                                                  Some(&mut opts)));
+            let tree = t!(repo.find_tree(t!(index.write_tree_to(&repo))));
+            assert!(tree.get_name("bar").is_none());
+            let blob = t!(repo.find_blob(tree.get_name("foo").unwrap().id()));
+            assert_eq!(blob.content(),
+                       &b"1\n2\n3\n4\n5\n6\n7\n8\n9\n10\n11\n"[..]);
+        }
+        assert_eq!(hunks, 2);
+
+        // nothing else was touched
+        assert_eq!(read(&td.path().join("foo")),
+                   "1\n2\n3\n4\n5\n6\n7\n8\n9\n10\n");
+        let statuses = t!(repo.statuses(None));
+        assert_eq!(statuses.len(), 0);
+    }
+}
@@ -1,5 +1,6 @@
+use std::collections::HashMap;
 use std::marker;
-use {raw, Repository, Oid, signature, Signature};
+use {raw, Error, Repository, Oid, signature, Signature};
 use util::{self, Binding};
 use std::path::Path;
 use std::ops::Range;
@@ -8,18 +9,28 @@ use std::mem;
 /// Opaque structure to hold blame results.
 pub struct Blame<'repo> {
     raw: *mut raw::git_blame,
+    mailmap: HashMap<*const raw::git_blame_hunk, MailmapSignatures>,
     _marker: marker::PhantomData<&'repo Repository>,
 }
 
 /// Structure that represents a blame hunk.
 pub struct BlameHunk<'blame> {
     raw: *mut raw::git_blame_hunk,
+    mailmap: Option<&'blame MailmapSignatures>,
     _marker: marker::PhantomData<&'blame raw::git_blame>,
 }
 
 /// Blame options
 pub struct BlameOptions {
     raw: raw::git_blame_options,
+    use_mailmap: bool,
+}
+
+// The signatures of a hunk once mapped through the mailmap. libgit2 owns the
+// hunks, so they are kept next to them instead of replacing theirs.
+struct MailmapSignatures {
+    final_signature: Option<Signature<'static>>,
+    orig_signature: Option<Signature<'static>>,
 }
 
 /// An iterator over the hunks in a blame.
@@ -47,7 +58,7 @@ impl<'repo> Blame<'repo> {
             if ptr.is_null() {
                 None
             } else {
-                Some(BlameHunk::from_raw_const(ptr))
+                Some(BlameHunk::from_raw_const(ptr, self.mailmap.get(&ptr)))
             }
         }
     }
@@ -60,7 +71,7 @@ impl<'repo> Blame<'repo> {
             if ptr.is_null() {
                 None
             } else {
-                Some(BlameHunk::from_raw_const(ptr))
+                Some(BlameHunk::from_raw_const(ptr, self.mailmap.get(&ptr)))
             }
         }
     }
@@ -74,10 +85,12 @@ impl<'repo> Blame<'repo> {
 
 impl<'blame> BlameHunk<'blame> {
 
-    unsafe fn from_raw_const(raw: *const raw::git_blame_hunk)
-                                 -> BlameHunk<'blame> {
+    unsafe fn from_raw_const(raw: *const raw::git_blame_hunk,
+                             mailmap: Option<&'blame MailmapSignatures>)
+                             -> BlameHunk<'blame> {
         BlameHunk {
             raw: raw as *mut raw::git_blame_hunk,
+            mailmap: mailmap,
             _marker: marker::PhantomData,
         }
     }
@@ -89,7 +102,12 @@ impl<'blame> BlameHunk<'blame> {
 
     /// Returns signature of the commit.
     pub fn final_signature(&self) -> Signature {
-        unsafe { signature::from_raw_const(self, (*self.raw).final_signature) }
+        let resolved = self.mailmap.and_then(|m| m.final_signature.as_ref());
+        unsafe {
+            let raw = resolved.map(|s| s.raw())
+                              .unwrap_or((*self.raw).final_signature);
+            signature::from_raw_const(self, raw)
+        }
     }
 
     /// Returns line number where this hunk begins.
@@ -110,7 +128,12 @@ impl<'blame> BlameHunk<'blame> {
 
     /// Returns signature of the commit.
     pub fn orig_signature(&self) -> Signature {
-        unsafe { signature::from_raw_const(self, (*self.raw).orig_signature) }
+        let resolved = self.mailmap.and_then(|m| m.orig_signature.as_ref());
+        unsafe {
+            let raw = resolved.map(|s| s.raw())
+                              .unwrap_or((*self.raw).orig_signature);
+            signature::from_raw_const(self, raw)
+        }
     }
 
     /// Returns line number where this hunk begins.
@@ -216,13 +239,24 @@ impl BlameOptions {
         self
     }
 
+    /// Map the signatures of the hunks to their canonical names and emails
+    /// with the mailmap of the repository.
+    pub fn use_mailmap(&mut self, opt: bool) -> &mut BlameOptions {
+        self.use_mailmap = opt;
+        self
+    }
+
 }
 
 impl<'repo> Binding for Blame<'repo> {
     type Raw = *mut raw::git_blame;
 
     unsafe fn from_raw(raw: *mut raw::git_blame) -> Blame<'repo> {
-        Blame { raw: raw, _marker: marker::PhantomData }
+        Blame {
+            raw: raw,
+            mailmap: HashMap::new(),
+            _marker: marker::PhantomData,
+        }
     }
 
     fn raw(&self) -> *mut raw::git_blame { self.raw }
@@ -238,7 +272,7 @@ impl<'blame> Binding for BlameHunk<'blame> {
     type Raw = *mut raw::git_blame_hunk;
 
     unsafe fn from_raw(raw: *mut raw::git_blame_hunk) -> BlameHunk<'blame> {
-        BlameHunk { raw: raw, _marker: marker::PhantomData }
+        BlameHunk { raw: raw, mailmap: None, _marker: marker::PhantomData }
     }
 
     fn raw(&self) -> *mut raw::git_blame_hunk { self.raw }
@@ -248,7 +282,7 @@ impl Binding for BlameOptions {
     type Raw = *mut raw::git_blame_options;
 
     unsafe fn from_raw(opts: *mut raw::git_blame_options) -> BlameOptions {
-        BlameOptions { raw: *opts }
+        BlameOptions { raw: *opts, use_mailmap: false }
     }
 
     fn raw(&self) -> *mut raw::git_blame_options {
@@ -256,6 +290,37 @@ impl Binding for BlameOptions {
     }
 }
 
+/// Maps the signatures of the hunks of `blame` through the mailmap of `repo`
+/// if `opts` asks for it.
+pub fn resolve_mailmap(repo: &Repository, blame: &mut Blame,
+                       opts: &BlameOptions) -> Result<(), Error> {
+    if !opts.use_mailmap {
+        return Ok(())
+    }
+    let mailmap = try!(repo.mailmap());
+    for i in 0..blame.len() {
+        unsafe {
+            let hunk = raw::git_blame_get_hunk_byindex(blame.raw, i as u32);
+            if hunk.is_null() {
+                continue
+            }
+            let resolve = |sig: *const raw::git_signature| {
+                if sig.is_null() {
+                    return Ok(None)
+                }
+                let sig = signature::from_raw_const(&mailmap, sig);
+                mailmap.resolve_signature(&sig).map(Some)
+            };
+            let signatures = MailmapSignatures {
+                final_signature: try!(resolve((*hunk).final_signature)),
+                orig_signature: try!(resolve((*hunk).orig_signature)),
+            };
+            blame.mailmap.insert(hunk, signatures);
+        }
+    }
+    Ok(())
+}
+
 impl<'blame> Iterator for BlameIter<'blame> {
     type Item = BlameHunk<'blame>;
     fn next(&mut self) -> Option<BlameHunk<'blame>> {
@@ -276,7 +341,9 @@ impl<'blame> ExactSizeIterator for BlameIter<'blame> {}
 #[cfg(test)]
 mod tests {
     use std::fs::{self, File};
+    use std::io::Write;
     use std::path::Path;
+    use BlameOptions;
 
     #[test]
     fn smoke() {
@@ -311,5 +378,39 @@ mod tests {
         assert!(!hunk.is_boundary())
     }
 
+    #[test]
+    fn use_mailmap() {
+        let (_td, repo) = ::test::repo_init();
+        let mut index = repo.index().unwrap();
+
+        let root = repo.path().parent().unwrap();
+        let mut file = File::create(root.join("foo")).unwrap();
+        file.write_all(b"foo\n").unwrap();
+        index.add_path(Path::new("foo")).unwrap();
+
+        let id = index.write_tree().unwrap();
+        let tree = repo.find_tree(id).unwrap();
+        let sig = repo.signature().unwrap();
+        let id = repo.refname_to_id("HEAD").unwrap();
+        let parent = repo.find_commit(id).unwrap();
+        repo.commit(Some("HEAD"), &sig, &sig, "commit",
+                    &tree, &[&parent]).unwrap();
+
+        let mut file = File::create(root.join(".mailmap")).unwrap();
+        file.write_all(b"Real Name <real@example.com> <email>\n").unwrap();
+
+        let mut opts = BlameOptions::new();
+        opts.use_mailmap(true);
+        let blame = repo.blame_file(Path::new("foo"), Some(&mut opts)).unwrap();
+        let hunk = blame.get_index(0).unwrap();
+        assert_eq!(hunk.final_signature().name(), Some("Real Name"));
+        assert_eq!(hunk.final_signature().email(), Some("real@example.com"));
+        assert_eq!(hunk.orig_signature().name(), Some("Real Name"));
+
+        let blame = repo.blame_file(Path::new("foo"), None).unwrap();
+        let hunk = blame.get_index(0).unwrap();
+        assert_eq!(hunk.final_signature().name(), sig.name());
+    }
+
 }
 
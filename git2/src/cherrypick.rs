@@ -0,0 +1,105 @@
+use std::mem;
+use std::ptr;
+use libc::c_uint;
+
+use {raw, MergeOptions};
+use build::CheckoutBuilder;
+
+/// Options which can be used to customize how a commit is cherry-picked.
+pub struct CherrypickOptions<'cb> {
+    mainline: u32,
+    merge_options: Option<MergeOptions>,
+    checkout_options: Option<CheckoutBuilder<'cb>>,
+}
+
+impl<'cb> Default for CherrypickOptions<'cb> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'cb> CherrypickOptions<'cb> {
+    /// Creates a default set of cherry-pick options.
+    pub fn new() -> CherrypickOptions<'cb> {
+        CherrypickOptions {
+            mainline: 0,
+            merge_options: None,
+            checkout_options: None,
+        }
+    }
+
+    /// Sets the parent, starting from 1, whose changes are compared with the
+    /// cherry-picked commit when it is a merge commit.
+    pub fn mainline(&mut self, mainline: u32) -> &mut CherrypickOptions<'cb> {
+        self.mainline = mainline;
+        self
+    }
+
+    /// Options to use when merging the changes of the commit.
+    pub fn merge_options(&mut self, opts: MergeOptions) -> &mut CherrypickOptions<'cb> {
+        self.merge_options = Some(opts);
+        self
+    }
+
+    /// Options to use when writing files to the working directory.
+    pub fn checkout_options(&mut self, opts: CheckoutBuilder<'cb>)
+                            -> &mut CherrypickOptions<'cb> {
+        self.checkout_options = Some(opts);
+        self
+    }
+
+    /// Obtain the raw options for this cherry-pick.
+    ///
+    /// # Safety
+    ///
+    /// The returned options borrow the checkout options of this structure,
+    /// which must outlive them.
+    pub unsafe fn raw(&mut self) -> raw::git_cherrypick_options {
+        let mut opts = mem::zeroed();
+        assert_eq!(raw::git_cherrypick_init_options(
+            &mut opts, raw::GIT_CHERRYPICK_OPTIONS_VERSION), 0);
+        opts.mainline = self.mainline as c_uint;
+        if let Some(merge) = self.merge_options.as_ref() {
+            ptr::copy_nonoverlapping(merge.raw(), &mut opts.merge_opts, 1);
+        }
+        if let Some(checkout) = self.checkout_options.as_mut() {
+            checkout.configure(&mut opts.checkout_opts);
+        }
+        opts
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use {CherrypickOptions, RepositoryState};
+
+    #[test]
+    fn smoke() {
+        let (td, repo) = ::test::repo_init();
+        let head = t!(repo.head()).target().unwrap();
+        let head = t!(repo.find_commit(head));
+        t!(repo.branch("feature", &head, false));
+
+        // commit a file on the feature branch only
+        let blob = t!(repo.blob(b"picked"));
+        let mut builder = t!(repo.treebuilder(Some(&t!(head.tree()))));
+        t!(builder.insert(Path::new("file"), blob, 0o100644));
+        let tree = t!(repo.find_tree(t!(builder.write())));
+        let sig = t!(repo.signature());
+        let id = t!(repo.commit(Some("refs/heads/feature"), &sig, &sig, "pick",
+                                &tree, &[&head]));
+        let commit = t!(repo.find_commit(id));
+
+        let mut index = t!(repo.cherrypick_commit(&commit, &head, 0, None));
+        assert!(!index.has_conflicts());
+        assert_eq!(t!(index.write_tree_to(&repo)), tree.id());
+
+        t!(repo.cherrypick(&commit, Some(&mut CherrypickOptions::new())));
+        assert_eq!(repo.state(), RepositoryState::CherryPick);
+        assert!(t!(repo.index()).get_path(Path::new("file"), 0).is_some());
+        assert!(td.path().join("file").exists());
+        t!(repo.cleanup_state());
+    }
+}
@@ -5,7 +5,7 @@ use std::ptr;
 use std::str;
 use libc;
 
-use {raw, signature, Oid, Error, Signature, Tree, Time, Object};
+use {raw, signature, Oid, Error, Mailmap, Signature, Tree, Time, Object};
 use util::Binding;
 
 /// A structure to represent a git [commit][1]
@@ -173,6 +173,20 @@ impl<'repo> Commit<'repo> {
         }
     }
 
+    /// Get the author of this commit, mapped to its canonical name and email
+    /// by `mailmap`.
+    pub fn author_with_mailmap(&self, mailmap: &Mailmap)
+                               -> Result<Signature<'static>, Error> {
+        mailmap.resolve_signature(&self.author())
+    }
+
+    /// Get the committer of this commit, mapped to its canonical name and
+    /// email by `mailmap`.
+    pub fn committer_with_mailmap(&self, mailmap: &Mailmap)
+                                  -> Result<Signature<'static>, Error> {
+        mailmap.resolve_signature(&self.committer())
+    }
+
     /// Amend this existing commit with all non-`None` values
     ///
     /// This creates a new commit that is exactly the same as the old commit,
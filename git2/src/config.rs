@@ -1,12 +1,15 @@
-use std::ffi::CString;
+use std::ffi::{CStr, CString};
 use std::marker;
+use std::mem;
 use std::path::{Path, PathBuf};
 use std::ptr;
 use std::str;
-use libc;
+use std::sync::{Arc, Mutex};
+use libc::{self, c_char, c_int, c_uint};
 
-use {raw, Error, ConfigLevel, Buf, IntoCString};
-use util::{self, Binding};
+use {raw, Error, ErrorClass, ErrorCode, ConfigLevel, Buf, IntoCString};
+use panic;
+use util::{self, Binding, set_err};
 
 /// A structure representing a git configuration key/value store
 pub struct Config {
@@ -125,6 +128,29 @@ impl Config {
         }
     }
 
+    /// Adds a custom backend to this configuration at the given level.
+    ///
+    /// Backends with a higher level are queried first, and values are written
+    /// to the backend with the highest level. If a backend already exists at
+    /// this level it is only replaced when `force` is set.
+    pub fn add_backend<B: ConfigBackend>(&mut self, backend: B,
+                                         level: ConfigLevel, force: bool)
+                                         -> Result<(), Error> {
+        unsafe {
+            let ptr = try!(new_backend(Box::new(backend), false));
+            let rc = raw::git_config_add_backend(self.raw,
+                                                 ptr as *mut _,
+                                                 ::call::convert(&level),
+                                                 ptr::null(),
+                                                 force as c_int);
+            if rc < 0 {
+                drop(Box::from_raw(ptr));
+                return Err(Error::last_error(rc).unwrap())
+            }
+            Ok(())
+        }
+    }
+
     /// Delete a config variable from the config file with the highest level
     /// (usually the local one).
     pub fn remove(&mut self, name: &str) -> Result<(), Error> {
@@ -135,6 +161,19 @@ impl Config {
         }
     }
 
+    /// Remove the values of a multivar config variable which match the
+    /// regular expression `regexp` from the config file with the highest
+    /// level (usually the local one).
+    pub fn remove_multivar(&mut self, name: &str, regexp: &str)
+                           -> Result<(), Error> {
+        let name = try!(CString::new(name));
+        let regexp = try!(CString::new(regexp));
+        unsafe {
+            try_call!(raw::git_config_delete_multivar(self.raw, name, regexp));
+        }
+        Ok(())
+    }
+
     /// Get the value of a boolean config variable.
     ///
     /// All config files will be looked into, in the order of their defined
@@ -225,6 +264,46 @@ impl Config {
         Ok(::util::bytes2path(&ret).to_path_buf())
     }
 
+    /// Get the values of a multivar config variable, in the order they were
+    /// set.
+    ///
+    /// If `regexp` is `Some`, only the values matching the regular expression
+    /// are returned.
+    pub fn get_multivar(&self, name: &str, regexp: Option<&str>)
+                        -> Result<Vec<String>, Error> {
+        let entries = try!(self.multivar(name, regexp));
+        let mut values = Vec::new();
+        for entry in &entries {
+            let entry = try!(entry);
+            match entry.value() {
+                Some(value) => values.push(value.to_string()),
+                None => {
+                    return Err(Error::from_str("configuration value is not \
+                                                valid utf8"))
+                }
+            }
+        }
+        Ok(values)
+    }
+
+    /// Iterate over the values of a multivar config variable.
+    ///
+    /// If `regexp` is `Some`, then the iterator will only iterate over the
+    /// values matching the regular expression.
+    pub fn multivar(&self, name: &str, regexp: Option<&str>)
+                    -> Result<ConfigEntries, Error> {
+        let mut ret = ptr::null_mut();
+        let name = try!(CString::new(name));
+        let regexp = try!(::opt_cstr(regexp));
+        unsafe {
+            try_call!(raw::git_config_multivar_iterator_new(&mut ret,
+                                                            self.raw,
+                                                            name,
+                                                            regexp));
+            Ok(Binding::from_raw(ret))
+        }
+    }
+
     /// Get the ConfigEntry for a config variable.
     pub fn get_entry(&self, name: &str) -> Result<ConfigEntry, Error> {
         let mut ret = ptr::null_mut();
@@ -238,7 +317,8 @@ impl Config {
     /// Iterate over all the config variables
     ///
     /// If `glob` is `Some`, then the iterator will only iterate over all
-    /// variables whose name matches the pattern.
+    /// variables whose name matches the pattern, which is a regular
+    /// expression.
     ///
     /// # Example
     ///
@@ -260,7 +340,7 @@ impl Config {
                 Some(s) => {
                     let s = try!(CString::new(s));
                     try_call!(raw::git_config_iterator_glob_new(&mut ret,
-                                                                &*self.raw,
+                                                                self.raw,
                                                                 s));
                 }
                 None => {
@@ -503,12 +583,566 @@ impl<'cfg> Drop for ConfigEntry<'cfg> {
     }
 }
 
+/// A custom backend for a configuration, added with `Config::add_backend`.
+///
+/// Variable names given to a backend are normalized: the section and the
+/// variable name are lowercased, while a subsection is kept as is. A
+/// variable may have several values, in which case it is a multivar.
+pub trait ConfigBackend: Send + 'static {
+    /// Lists all the variables of this backend along with their values, in
+    /// the order they were set.
+    fn entries(&self) -> Result<Vec<(String, String)>, Error>;
+
+    /// Gets the value of a variable, returning `None` if it isn't set.
+    ///
+    /// By default this is the last value of the variable listed by
+    /// `entries`.
+    fn get(&self, name: &str) -> Result<Option<String>, Error> {
+        let entries = try!(self.entries());
+        Ok(entries.into_iter().rev().find(|e| e.0 == name).map(|e| e.1))
+    }
+
+    /// Sets the value of a variable, failing if the variable is a multivar.
+    fn set(&self, name: &str, value: &str) -> Result<(), Error>;
+
+    /// Replaces the values of a multivar which match the regular expression
+    /// `regexp` with `value`, or adds `value` if none of them match.
+    fn set_multivar(&self, name: &str, regexp: &MultivarRegexp, value: &str)
+                    -> Result<(), Error>;
+
+    /// Deletes a variable, failing if the variable is a multivar.
+    fn remove(&self, name: &str) -> Result<(), Error>;
+
+    /// Deletes the values of a multivar which match the regular expression
+    /// `regexp`.
+    fn remove_multivar(&self, name: &str, regexp: &MultivarRegexp)
+                       -> Result<(), Error>;
+}
+
+/// The regular expression selecting the values of a multivar which are
+/// replaced or deleted by a `ConfigBackend`.
+///
+/// The values are matched by libgit2 when the backend is called, in the
+/// same way as for the builtin backends.
+pub struct MultivarRegexp {
+    regexp: String,
+    matched: Vec<String>,
+}
+
+impl MultivarRegexp {
+    /// Returns the regular expression.
+    pub fn as_str(&self) -> &str {
+        &self.regexp
+    }
+
+    /// Checks whether a value of the multivar matches the regular
+    /// expression.
+    pub fn is_match(&self, value: &str) -> bool {
+        self.matched.iter().any(|v| v == value)
+    }
+}
+
+/// A `ConfigBackend` which keeps variables in memory.
+///
+/// Clones of this backend share the same variables, so a clone may be kept
+/// around to inspect the variables written through a `Config`.
+#[derive(Clone, Default)]
+pub struct MemoryConfigBackend {
+    inner: Arc<Mutex<Vec<(String, String)>>>,
+}
+
+impl MemoryConfigBackend {
+    /// Creates a backend without any variables.
+    pub fn new() -> MemoryConfigBackend {
+        MemoryConfigBackend::default()
+    }
+}
+
+impl ConfigBackend for MemoryConfigBackend {
+    fn entries(&self) -> Result<Vec<(String, String)>, Error> {
+        Ok(self.inner.lock().unwrap().clone())
+    }
+
+    fn set(&self, name: &str, value: &str) -> Result<(), Error> {
+        let mut entries = self.inner.lock().unwrap();
+        let found = positions(&entries, name);
+        match found.len() {
+            0 => entries.push((name.to_string(), value.to_string())),
+            1 => entries[found[0]].1 = value.to_string(),
+            _ => return Err(multivar_error()),
+        }
+        Ok(())
+    }
+
+    fn set_multivar(&self, name: &str, regexp: &MultivarRegexp, value: &str)
+                    -> Result<(), Error> {
+        let mut entries = self.inner.lock().unwrap();
+        let entry = (name.to_string(), value.to_string());
+        match remove_matching(&mut entries, name, regexp) {
+            Some(pos) => entries.insert(pos, entry),
+            None => entries.push(entry),
+        }
+        Ok(())
+    }
+
+    fn remove(&self, name: &str) -> Result<(), Error> {
+        let mut entries = self.inner.lock().unwrap();
+        let found = positions(&entries, name);
+        match found.len() {
+            0 => return Err(not_found(name)),
+            1 => { entries.remove(found[0]); }
+            _ => return Err(multivar_error()),
+        }
+        Ok(())
+    }
+
+    fn remove_multivar(&self, name: &str, regexp: &MultivarRegexp)
+                       -> Result<(), Error> {
+        let mut entries = self.inner.lock().unwrap();
+        match remove_matching(&mut entries, name, regexp) {
+            Some(_) => Ok(()),
+            None => Err(not_found(name)),
+        }
+    }
+}
+
+fn positions(entries: &[(String, String)], name: &str) -> Vec<usize> {
+    entries.iter().enumerate()
+           .filter(|&(_, e)| e.0 == name)
+           .map(|(i, _)| i)
+           .collect()
+}
+
+// Removes the values of `name` matching `regexp`, returning the position the
+// first of them had.
+fn remove_matching(entries: &mut Vec<(String, String)>, name: &str,
+                   regexp: &MultivarRegexp) -> Option<usize> {
+    let mut first = None;
+    let mut kept = 0;
+    entries.retain(|e| {
+        if e.0 == name && regexp.is_match(&e.1) {
+            if first.is_none() {
+                first = Some(kept);
+            }
+            false
+        } else {
+            kept += 1;
+            true
+        }
+    });
+    first
+}
+
+fn not_found(name: &str) -> Error {
+    Error::new(ErrorCode::NotFound, ErrorClass::Config,
+               &format!("could not find key '{}' to delete", name))
+}
+
+fn multivar_error() -> Error {
+    Error::new(ErrorCode::GenericError, ErrorClass::Config,
+               "entry is not unique due to being a multivar")
+}
+
+// The frozen backend handed out by `backend_snapshot`.
+struct SnapshotConfigBackend {
+    entries: Vec<(String, String)>,
+}
+
+impl SnapshotConfigBackend {
+    fn readonly(&self) -> Result<(), Error> {
+        Err(Error::new(ErrorCode::GenericError, ErrorClass::Config,
+                       "this backend is read-only"))
+    }
+}
+
+impl ConfigBackend for SnapshotConfigBackend {
+    fn entries(&self) -> Result<Vec<(String, String)>, Error> {
+        Ok(self.entries.clone())
+    }
+    fn set(&self, _name: &str, _value: &str) -> Result<(), Error> {
+        self.readonly()
+    }
+    fn set_multivar(&self, _name: &str, _regexp: &MultivarRegexp,
+                    _value: &str)
+                    -> Result<(), Error> {
+        self.readonly()
+    }
+    fn remove(&self, _name: &str) -> Result<(), Error> {
+        self.readonly()
+    }
+    fn remove_multivar(&self, _name: &str, _regexp: &MultivarRegexp)
+                       -> Result<(), Error> {
+        self.readonly()
+    }
+}
+
+/// Instance of a `git_config_backend`, must use `#[repr(C)]` to ensure that
+/// the C fields come first.
+#[repr(C)]
+struct RawConfigBackend {
+    raw: git_config_backend,
+    obj: Box<ConfigBackend>,
+    level: raw::git_config_level_t,
+}
+
+/// Instance of a `git_config_iterator` over the variables listed by a
+/// `ConfigBackend`, which owns the entries it returns.
+#[repr(C)]
+struct RawConfigIterator {
+    raw: git_config_iterator,
+    entries: Vec<RawConfigEntry>,
+    pos: usize,
+}
+
+#[repr(C)]
+struct RawConfigEntry {
+    raw: raw::git_config_entry,
+    name: CString,
+    value: CString,
+}
+
+unsafe fn new_backend(obj: Box<ConfigBackend>, readonly: bool)
+                      -> Result<*mut RawConfigBackend, Error> {
+    let mut raw = Box::new(RawConfigBackend {
+        raw: mem::zeroed(),
+        obj: obj,
+        level: raw::GIT_CONFIG_LEVEL_APP,
+    });
+    try_call!(raw::git_config_init_backend(
+        &mut raw.raw as *mut _ as *mut raw::git_config_backend,
+        raw::GIT_CONFIG_BACKEND_VERSION));
+    raw.raw.readonly = readonly as c_int;
+    raw.raw.open = Some(backend_open);
+    raw.raw.get = Some(backend_get);
+    raw.raw.set = Some(backend_set);
+    raw.raw.set_multivar = Some(backend_set_multivar);
+    raw.raw.del = Some(backend_delete);
+    raw.raw.del_multivar = Some(backend_delete_multivar);
+    raw.raw.iterator = Some(backend_iterator);
+    raw.raw.snapshot = Some(backend_snapshot);
+    raw.raw.lock = Some(backend_lock);
+    raw.raw.unlock = Some(backend_unlock);
+    raw.raw.free = Some(backend_free);
+    Ok(Box::into_raw(raw))
+}
+
+/// Layout of a `git_config_backend`, which `libgit2-sys` leaves opaque.
+#[repr(C)]
+#[allow(non_camel_case_types)]
+struct git_config_backend {
+    version: c_uint,
+    readonly: c_int,
+    cfg: *mut raw::git_config,
+    open: Option<extern fn(*mut raw::git_config_backend,
+                           raw::git_config_level_t,
+                           *const raw::git_repository) -> c_int>,
+    get: Option<extern fn(*mut raw::git_config_backend,
+                          *const c_char,
+                          *mut *mut raw::git_config_entry) -> c_int>,
+    set: Option<extern fn(*mut raw::git_config_backend,
+                          *const c_char,
+                          *const c_char) -> c_int>,
+    set_multivar: Option<extern fn(*mut raw::git_config_backend,
+                                   *const c_char,
+                                   *const c_char,
+                                   *const c_char) -> c_int>,
+    del: Option<extern fn(*mut raw::git_config_backend,
+                          *const c_char) -> c_int>,
+    del_multivar: Option<extern fn(*mut raw::git_config_backend,
+                                   *const c_char,
+                                   *const c_char) -> c_int>,
+    iterator: Option<extern fn(*mut *mut raw::git_config_iterator,
+                               *mut raw::git_config_backend) -> c_int>,
+    snapshot: Option<extern fn(*mut *mut raw::git_config_backend,
+                               *mut raw::git_config_backend) -> c_int>,
+    lock: Option<extern fn(*mut raw::git_config_backend) -> c_int>,
+    unlock: Option<extern fn(*mut raw::git_config_backend, c_int) -> c_int>,
+    free: Option<extern fn(*mut raw::git_config_backend)>,
+}
+
+/// Layout of a `git_config_iterator`, which `libgit2-sys` leaves opaque.
+#[repr(C)]
+#[allow(non_camel_case_types)]
+struct git_config_iterator {
+    backend: *mut raw::git_config_backend,
+    flags: c_uint,
+    next: Option<extern fn(*mut *mut raw::git_config_entry,
+                           *mut raw::git_config_iterator) -> c_int>,
+    free: Option<extern fn(*mut raw::git_config_iterator)>,
+}
+
+unsafe fn backend_obj<'a>(backend: *mut raw::git_config_backend)
+                          -> &'a RawConfigBackend {
+    &*(backend as *mut RawConfigBackend)
+}
+
+// Lists the values of `name` which match `regexp` with the multivar iterator
+// of the configuration the backend belongs to, so that libgit2 does the
+// matching.
+unsafe fn multivar_regexp(backend: *mut raw::git_config_backend, name: &str,
+                          regexp: *const c_char)
+                          -> Result<MultivarRegexp, Error> {
+    let mut iter = ptr::null_mut();
+    let c_name = try!(CString::new(name));
+    let cfg = backend_obj(backend).raw.cfg;
+    try_call!(raw::git_config_multivar_iterator_new(&mut iter,
+                                                    cfg,
+                                                    c_name,
+                                                    regexp));
+    let entries: ConfigEntries = Binding::from_raw(iter);
+    let mut matched = Vec::new();
+    for entry in &entries {
+        if let Some(value) = try!(entry).value() {
+            matched.push(value.to_string());
+        }
+    }
+    Ok(MultivarRegexp {
+        regexp: try!(to_str(regexp)).to_string(),
+        matched: matched,
+    })
+}
+
+unsafe fn to_str<'a>(s: *const c_char) -> Result<&'a str, Error> {
+    str::from_utf8(CStr::from_ptr(s).to_bytes()).map_err(|_| {
+        Error::from_str("configuration name or value is not valid utf-8")
+    })
+}
+
+// libgit2 only normalizes names before looking them up, so names are
+// normalized here before being written, like the builtin backends do.
+unsafe fn to_name(s: *const c_char) -> Result<String, Error> {
+    let name = try!(to_str(s));
+    let invalid = || {
+        Error::new(ErrorCode::InvalidSpec, ErrorClass::Config,
+                   &format!("invalid config item name '{}'", name))
+    };
+    let valid = |s: &str| {
+        !s.is_empty() &&
+            s.bytes().all(|b| b.is_ascii_alphanumeric() || b == b'-')
+    };
+    let (first, last) = match (name.find('.'), name.rfind('.')) {
+        (Some(first), Some(last)) => (first, last),
+        _ => return Err(invalid()),
+    };
+    if !valid(&name[..first]) || !valid(&name[last + 1..]) ||
+       name[first..last].contains('\n') {
+        return Err(invalid())
+    }
+    Ok(format!("{}{}{}", name[..first].to_lowercase(),
+               &name[first..last + 1], name[last + 1..].to_lowercase()))
+}
+
+fn new_entry(name: &str, value: &str, level: raw::git_config_level_t,
+             free: extern fn(*mut raw::git_config_entry))
+             -> Result<RawConfigEntry, Error> {
+    let name = try!(CString::new(name));
+    let value = try!(CString::new(value));
+    Ok(RawConfigEntry {
+        raw: raw::git_config_entry {
+            name: name.as_ptr(),
+            value: value.as_ptr(),
+            level: level,
+            free: free,
+            payload: ptr::null_mut(),
+        },
+        name: name,
+        value: value,
+    })
+}
+
+extern fn entry_free(entry: *mut raw::git_config_entry) {
+    let _ = panic::wrap(|| unsafe {
+        drop(Box::from_raw(entry as *mut RawConfigEntry));
+    });
+}
+
+// entries returned by an iterator are freed along with the iterator
+extern fn entry_free_noop(_entry: *mut raw::git_config_entry) {}
+
+extern fn backend_open(backend: *mut raw::git_config_backend,
+                       level: raw::git_config_level_t,
+                       _repo: *const raw::git_repository) -> c_int {
+    panic::wrap(|| unsafe {
+        (*(backend as *mut RawConfigBackend)).level = level;
+        0
+    }).unwrap_or(-1)
+}
+
+extern fn backend_get(backend: *mut raw::git_config_backend,
+                      name: *const c_char,
+                      out: *mut *mut raw::git_config_entry) -> c_int {
+    panic::wrap(|| unsafe {
+        let backend = backend_obj(backend);
+        let res = to_str(name).and_then(|name| {
+            match try!(backend.obj.get(name)) {
+                Some(value) => new_entry(name, &value, backend.level,
+                                         entry_free).map(Some),
+                None => Ok(None),
+            }
+        });
+        match res {
+            Ok(Some(entry)) => {
+                let entry = Box::into_raw(Box::new(entry));
+                *out = entry as *mut raw::git_config_entry;
+                0
+            }
+            Ok(None) => raw::GIT_ENOTFOUND as c_int,
+            Err(e) => set_err(&e),
+        }
+    }).unwrap_or(-1)
+}
+
+extern fn backend_set(backend: *mut raw::git_config_backend,
+                      name: *const c_char,
+                      value: *const c_char) -> c_int {
+    panic::wrap(|| unsafe {
+        let res = to_name(name).and_then(|name| {
+            let value = try!(to_str(value));
+            backend_obj(backend).obj.set(&name, value)
+        });
+        match res {
+            Ok(()) => 0,
+            Err(e) => set_err(&e),
+        }
+    }).unwrap_or(-1)
+}
+
+extern fn backend_set_multivar(backend: *mut raw::git_config_backend,
+                               name: *const c_char,
+                               regexp: *const c_char,
+                               value: *const c_char) -> c_int {
+    panic::wrap(|| unsafe {
+        let res = to_name(name).and_then(|name| {
+            let regexp = try!(multivar_regexp(backend, &name, regexp));
+            let value = try!(to_str(value));
+            backend_obj(backend).obj.set_multivar(&name, &regexp, value)
+        });
+        match res {
+            Ok(()) => 0,
+            Err(e) => set_err(&e),
+        }
+    }).unwrap_or(-1)
+}
+
+extern fn backend_delete(backend: *mut raw::git_config_backend,
+                         name: *const c_char) -> c_int {
+    panic::wrap(|| unsafe {
+        let res = to_name(name).and_then(|name| {
+            backend_obj(backend).obj.remove(&name)
+        });
+        match res {
+            Ok(()) => 0,
+            Err(e) => set_err(&e),
+        }
+    }).unwrap_or(-1)
+}
+
+extern fn backend_delete_multivar(backend: *mut raw::git_config_backend,
+                                  name: *const c_char,
+                                  regexp: *const c_char) -> c_int {
+    panic::wrap(|| unsafe {
+        let res = to_name(name).and_then(|name| {
+            let regexp = try!(multivar_regexp(backend, &name, regexp));
+            backend_obj(backend).obj.remove_multivar(&name, &regexp)
+        });
+        match res {
+            Ok(()) => 0,
+            Err(e) => set_err(&e),
+        }
+    }).unwrap_or(-1)
+}
+
+extern fn backend_iterator(out: *mut *mut raw::git_config_iterator,
+                           backend: *mut raw::git_config_backend) -> c_int {
+    panic::wrap(|| unsafe {
+        let obj = backend_obj(backend);
+        let res = obj.obj.entries().and_then(|entries| {
+            entries.iter().map(|e| {
+                new_entry(&e.0, &e.1, obj.level, entry_free_noop)
+            }).collect::<Result<Vec<_>, Error>>()
+        });
+        match res {
+            Ok(entries) => {
+                let iter = Box::new(RawConfigIterator {
+                    raw: git_config_iterator {
+                        backend: backend,
+                        flags: 0,
+                        next: Some(iterator_next),
+                        free: Some(iterator_free),
+                    },
+                    entries: entries,
+                    pos: 0,
+                });
+                *out = Box::into_raw(iter) as *mut raw::git_config_iterator;
+                0
+            }
+            Err(e) => set_err(&e),
+        }
+    }).unwrap_or(-1)
+}
+
+extern fn backend_snapshot(out: *mut *mut raw::git_config_backend,
+                           backend: *mut raw::git_config_backend) -> c_int {
+    panic::wrap(|| unsafe {
+        let res = backend_obj(backend).obj.entries().and_then(|entries| {
+            new_backend(Box::new(SnapshotConfigBackend { entries: entries }),
+                        true)
+        });
+        match res {
+            Ok(snapshot) => {
+                *out = snapshot as *mut raw::git_config_backend;
+                0
+            }
+            Err(e) => set_err(&e),
+        }
+    }).unwrap_or(-1)
+}
+
+extern fn backend_lock(_backend: *mut raw::git_config_backend) -> c_int {
+    panic::wrap(|| unsafe {
+        set_err(&Error::new(ErrorCode::GenericError, ErrorClass::Config,
+                            "custom config backends cannot be locked"))
+    }).unwrap_or(-1)
+}
+
+extern fn backend_unlock(_backend: *mut raw::git_config_backend,
+                         _success: c_int) -> c_int {
+    0
+}
+
+extern fn backend_free(backend: *mut raw::git_config_backend) {
+    let _ = panic::wrap(|| unsafe {
+        drop(Box::from_raw(backend as *mut RawConfigBackend));
+    });
+}
+
+extern fn iterator_next(out: *mut *mut raw::git_config_entry,
+                        iter: *mut raw::git_config_iterator) -> c_int {
+    panic::wrap(|| unsafe {
+        let iter = &mut *(iter as *mut RawConfigIterator);
+        match iter.entries.get_mut(iter.pos) {
+            Some(entry) => {
+                *out = &mut entry.raw;
+                iter.pos += 1;
+                0
+            }
+            None => raw::GIT_ITEROVER as c_int,
+        }
+    }).unwrap_or(-1)
+}
+
+extern fn iterator_free(iter: *mut raw::git_config_iterator) {
+    let _ = panic::wrap(|| unsafe {
+        drop(Box::from_raw(iter as *mut RawConfigIterator));
+    });
+}
+
 #[cfg(test)]
 mod tests {
     use std::fs::File;
     use tempdir::TempDir;
 
-    use Config;
+    use {Config, ConfigBackend, ConfigLevel, ErrorCode, MemoryConfigBackend};
 
     #[test]
     fn smoke() {
@@ -564,6 +1198,82 @@ mod tests {
             .collect();
         values.sort();
         assert_eq!(values, ["baz", "qux"]);
+
+        assert_eq!(cfg.get_multivar("foo.bar", None).unwrap(), ["baz", "qux"]);
+        assert_eq!(cfg.get_multivar("foo.bar", Some("^q")).unwrap(), ["qux"]);
+        assert_eq!(cfg.multivar("foo.bar", Some("z$")).unwrap()
+                      .into_iter().count(), 1);
+
+        cfg.remove_multivar("foo.bar", "^b").unwrap();
+        assert_eq!(cfg.get_multivar("foo.bar", None).unwrap(), ["qux"]);
+        cfg.remove_multivar("foo.bar", ".*").unwrap();
+        assert!(cfg.get_multivar("foo.bar", None).unwrap().is_empty());
+    }
+
+    #[test]
+    fn entries_regex() {
+        let td = TempDir::new("test").unwrap();
+        let path = td.path().join("foo");
+        File::create(&path).unwrap();
+
+        let mut cfg = Config::open(&path).unwrap();
+        cfg.set_str("foo.k1", "a").unwrap();
+        cfg.set_str("foo.k2", "b").unwrap();
+        cfg.set_str("bar.k1", "c").unwrap();
+
+        let names: Vec<String> = cfg.entries(Some("^foo\\.k[0-9]$"))
+            .unwrap()
+            .into_iter()
+            .map(|entry| entry.unwrap().name().unwrap().into())
+            .collect();
+        assert_eq!(names, ["foo.k1", "foo.k2"]);
+    }
+
+    #[test]
+    fn memory_backend() {
+        let backend = MemoryConfigBackend::new();
+        let mut cfg = Config::new().unwrap();
+        cfg.add_backend(backend.clone(), ConfigLevel::Local, false).unwrap();
+        assert!(cfg.add_backend(MemoryConfigBackend::new(), ConfigLevel::Local,
+                                false).is_err());
+
+        cfg.set_str("Core.Sub.Name", "value").unwrap();
+        cfg.set_bool("core.bare", true).unwrap();
+        assert_eq!(cfg.get_string("core.Sub.name").unwrap(), "value");
+        assert!(cfg.get_bool("CORE.bare").unwrap());
+        assert_eq!(cfg.get_entry("core.bare").unwrap().level(),
+                   ConfigLevel::Local);
+        assert!(cfg.set_str("core", "value").is_err());
+        assert_eq!(cfg.get_string("core.missing").unwrap_err().code(),
+                   ErrorCode::NotFound);
+
+        cfg.set_multivar("remote.origin.fetch", "^$", "a").unwrap();
+        cfg.set_multivar("remote.origin.fetch", "^$", "b").unwrap();
+        cfg.set_multivar("remote.origin.fetch", "^$", "c").unwrap();
+        cfg.set_multivar("remote.origin.fetch", "^b$", "d").unwrap();
+        assert_eq!(cfg.get_multivar("remote.origin.fetch", None).unwrap(),
+                   ["a", "d", "c"]);
+        assert_eq!(cfg.get_string("remote.origin.fetch").unwrap(), "c");
+        assert!(cfg.set_str("remote.origin.fetch", "e").is_err());
+        assert!(cfg.set_multivar("remote.origin.fetch", "[", "e").is_err());
+        assert!(cfg.remove("remote.origin.fetch").is_err());
+
+        let snapshot = cfg.snapshot().unwrap();
+        cfg.remove_multivar("remote.origin.fetch", "[ac]").unwrap();
+        assert_eq!(cfg.get_multivar("remote.origin.fetch", None).unwrap(),
+                   ["d"]);
+        assert_eq!(cfg.remove_multivar("remote.origin.fetch", "a")
+                      .unwrap_err().code(),
+                   ErrorCode::NotFound);
+        assert_eq!(snapshot.get_multivar("remote.origin.fetch", None).unwrap(),
+                   ["a", "d", "c"]);
+
+        cfg.remove("core.bare").unwrap();
+        assert!(cfg.remove("core.bare").is_err());
+        assert_eq!(backend.entries().unwrap(), [
+            ("core.Sub.name".to_string(), "value".to_string()),
+            ("remote.origin.fetch".to_string(), "d".to_string()),
+        ]);
     }
 
     #[test]
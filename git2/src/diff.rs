@@ -116,6 +116,28 @@ struct ForeachCallbacks<'a, 'b: 'a, 'c, 'd: 'c, 'e, 'f: 'e, 'g, 'h: 'g> {
     line: Option<&'g mut LineCb<'h>>,
 }
 
+impl Diff<'static> {
+    /// Reads the contents of a git patch file into a diff.
+    ///
+    /// The diff is similar to one produced by comparing two trees, but only
+    /// contains what is described by the patch. Only patches created by git
+    /// can be read, not the unified diffs produced by the `diff` program.
+    ///
+    /// The parser of libgit2 0.27 rejects patches containing
+    /// `\ No newline at end of file`, so files which don't end with a newline
+    /// can't be patched this way.
+    pub fn from_buffer(buffer: &[u8]) -> Result<Diff<'static>, Error> {
+        ::init();
+        let mut diff = ptr::null_mut();
+        unsafe {
+            try_call!(raw::git_diff_from_buffer(&mut diff,
+                                                buffer.as_ptr() as *const c_char,
+                                                buffer.len() as size_t));
+            Ok(Diff::from_raw(diff))
+        }
+    }
+}
+
 impl<'repo> Diff<'repo> {
     /// Merge one diff into another.
     ///
@@ -1255,4 +1277,22 @@ mod tests {
         assert_eq!(new_lines, 1);
         assert_eq!(line_content, Some("bar\n".to_string()));
     }
+
+    #[test]
+    fn from_buffer() {
+        let patch = b"diff --git a/foo b/foo
+index 257cc56..3bd1f0e 100644
+--- a/foo
++++ b/foo
+@@ -1 +1,2 @@
+ foo
++bar
+";
+        let diff = ::Diff::from_buffer(patch).unwrap();
+        assert_eq!(diff.deltas().len(), 1);
+        let delta = diff.get_delta(0).unwrap();
+        assert_eq!(delta.status(), ::Delta::Modified);
+        assert_eq!(delta.new_file().path(), Some(Path::new("foo")));
+        assert!(::Diff::from_buffer(b"diff --git a/foo\n@@ garbage").is_err());
+    }
 }
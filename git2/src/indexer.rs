@@ -0,0 +1,179 @@
+use std::io;
+use std::marker;
+use std::mem;
+use std::path::Path;
+use std::ptr;
+use libc::{c_int, c_void, size_t};
+
+use {raw, panic, Error, IntoCString, Odb, Oid, Progress};
+use util::Binding;
+
+/// A callback reporting the progress of an `Indexer`.
+pub type IndexerProgress<'a> = FnMut(Progress) -> bool + 'a;
+
+/// A stream indexing a packfile as its bytes are appended, which writes the
+/// pack along with its index into a directory.
+///
+/// This is the receiving end of a `PackBuilder`, for packs which were
+/// transferred by other means than a git remote.
+pub struct Indexer<'odb> {
+    raw: *mut raw::git_indexer,
+    stats: raw::git_transfer_progress,
+    progress: Box<Option<Box<IndexerProgress<'odb>>>>,
+    _marker: marker::PhantomData<&'odb Odb<'odb>>,
+}
+
+impl<'odb> Indexer<'odb> {
+    /// Creates an indexer writing the `.pack` and `.idx` files into the
+    /// directory `path`, usually the `objects/pack` directory of a
+    /// repository.
+    ///
+    /// The objects of `odb` are used as the bases of the deltas of a thin
+    /// pack, and are added to the pack. `mode` is the permissions of the
+    /// files written, or 0 for the default ones.
+    pub fn new(odb: Option<&'odb Odb<'odb>>, path: &Path, mode: u32)
+               -> Result<Indexer<'odb>, Error> {
+        let path = try!(path.into_c_string());
+        let odb = odb.map(|odb| odb.raw()).unwrap_or(ptr::null_mut());
+        let mut progress = Box::new(None);
+        let payload = &mut *progress as *mut _ as *mut c_void;
+        let progress_cb = Some(progress_cb as raw::git_transfer_progress_cb);
+        let mut raw = ptr::null_mut();
+        unsafe {
+            try_call!(raw::git_indexer_new(&mut raw, path, mode, odb,
+                                           progress_cb, payload));
+            Ok(Indexer {
+                raw: raw,
+                stats: mem::zeroed(),
+                progress: progress,
+                _marker: marker::PhantomData,
+            })
+        }
+    }
+
+    /// Sets a callback which is called with the progress of the indexer as
+    /// objects are indexed and deltas are resolved.
+    ///
+    /// Returning `false` from the callback aborts the indexing.
+    pub fn progress<F>(&mut self, cb: F) -> &mut Indexer<'odb>
+                       where F: FnMut(Progress) -> bool + 'odb {
+        *self.progress = Some(Box::new(cb) as Box<IndexerProgress<'odb>>);
+        self
+    }
+
+    /// Appends the next bytes of the packfile, indexing the objects they
+    /// complete.
+    pub fn append(&mut self, data: &[u8]) -> Result<(), Error> {
+        unsafe {
+            try_call!(raw::git_indexer_append(self.raw,
+                                              data.as_ptr() as *const c_void,
+                                              data.len() as size_t,
+                                              &mut self.stats));
+        }
+        Ok(())
+    }
+
+    /// Returns the progress made indexing the pack so far.
+    pub fn stats(&self) -> Progress {
+        unsafe { Binding::from_raw(&self.stats as *const _) }
+    }
+
+    /// Finishes indexing the pack, resolving its deltas and writing the
+    /// `.pack` and `.idx` files, and returns the hash of the pack.
+    ///
+    /// The files are named after this hash. An `Odb` reading from the
+    /// directory they were written into needs to be refreshed with
+    /// `Odb::refresh` to find the new objects.
+    pub fn commit(mut self) -> Result<Oid, Error> {
+        unsafe {
+            try_call!(raw::git_indexer_commit(self.raw, &mut self.stats));
+            Ok(Binding::from_raw(raw::git_indexer_hash(self.raw)))
+        }
+    }
+}
+
+impl<'odb> Drop for Indexer<'odb> {
+    fn drop(&mut self) {
+        unsafe { raw::git_indexer_free(self.raw) }
+    }
+}
+
+impl<'odb> io::Write for Indexer<'odb> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        match self.append(buf) {
+            Ok(()) => Ok(buf.len()),
+            Err(e) => Err(io::Error::new(io::ErrorKind::Other, e)),
+        }
+    }
+    fn flush(&mut self) -> io::Result<()> { Ok(()) }
+}
+
+extern fn progress_cb(stats: *const raw::git_transfer_progress,
+                      payload: *mut c_void) -> c_int {
+    let ok = panic::wrap(|| unsafe {
+        let payload = &mut *(payload as *mut Option<Box<IndexerProgress>>);
+        let callback = match *payload {
+            Some(ref mut c) => c,
+            None => return true,
+        };
+        callback(Binding::from_raw(stats))
+    });
+    if ok == Some(true) {0} else {-1}
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+    use tempdir::TempDir;
+    use {Buf, Indexer, Repository};
+
+    fn pack(repo: &Repository) -> Buf {
+        let head = t!(repo.head()).target().unwrap();
+        let mut builder = t!(repo.packbuilder());
+        t!(builder.insert_commit(head));
+        let mut buf = Buf::new();
+        t!(builder.write_buf(&mut buf));
+        buf
+    }
+
+    #[test]
+    fn smoke() {
+        let (_td, repo) = ::test::repo_init();
+        let buf = pack(&repo);
+        let head = t!(repo.head()).target().unwrap();
+
+        let td = TempDir::new("test").unwrap();
+        let dst = t!(Repository::init(td.path()));
+        let odb = t!(dst.odb());
+        let dir = dst.path().join("objects/pack");
+        let mut calls = 0;
+        let id = {
+            let mut indexer = t!(Indexer::new(Some(&odb), &dir, 0));
+            indexer.progress(|_| { calls += 1; true });
+            for chunk in buf.chunks(7) {
+                t!(indexer.append(chunk));
+            }
+            let stats = indexer.stats();
+            assert_eq!(stats.total_objects(), 2);
+            assert_eq!(stats.indexed_objects(), 2);
+            t!(indexer.commit())
+        };
+        assert!(calls > 0);
+        assert!(dir.join(format!("pack-{}.pack", id)).exists());
+        assert!(dir.join(format!("pack-{}.idx", id)).exists());
+
+        t!(odb.refresh());
+        t!(dst.find_commit(head));
+    }
+
+    #[test]
+    fn abort() {
+        let (_td, repo) = ::test::repo_init();
+        let buf = pack(&repo);
+
+        let td = TempDir::new("test").unwrap();
+        let mut indexer = t!(Indexer::new(None, td.path(), 0));
+        indexer.progress(|_| false);
+        assert!(indexer.write_all(&buf).is_err());
+    }
+}
@@ -80,19 +80,25 @@ use std::fmt;
 use std::str;
 use std::sync::{Once, ONCE_INIT};
 
+pub use apply::{ApplyDeltaCb, ApplyHunkCb, ApplyLocation, ApplyOptions};
 pub use blame::{Blame, BlameHunk, BlameIter, BlameOptions};
 pub use blob::{Blob, BlobWriter};
 pub use branch::{Branch, Branches};
 pub use buf::Buf;
+pub use cherrypick::CherrypickOptions;
 pub use commit::{Commit, Parents};
-pub use config::{Config, ConfigEntry, ConfigEntries};
-pub use cred::{Cred, CredentialHelper};
+pub use config::{Config, ConfigBackend, ConfigEntry, ConfigEntries};
+pub use config::{MemoryConfigBackend, MultivarRegexp};
+pub use cred::{Cred, CredentialHelper, CredentialStore};
+pub use cred::{FileCredentialStore, MemoryCredentialStore};
 pub use describe::{Describe, DescribeFormatOptions, DescribeOptions};
 pub use diff::{Diff, DiffDelta, DiffFile, DiffOptions, Deltas};
 pub use diff::{DiffBinary, DiffBinaryFile, DiffBinaryKind};
 pub use diff::{DiffLine, DiffHunk, DiffStats, DiffFindOptions};
 pub use error::Error;
 pub use index::{Index, IndexEntry, IndexEntries, IndexMatchedPath};
+pub use indexer::{Indexer, IndexerProgress};
+pub use mailmap::Mailmap;
 pub use merge::{AnnotatedCommit, MergeOptions};
 pub use message::{message_prettify, DEFAULT_COMMENT_CHAR};
 pub use note::{Note, Notes};
@@ -104,12 +110,15 @@ pub use pathspec::{PathspecDiffEntries, PathspecEntries};
 pub use patch::Patch;
 pub use proxy_options::ProxyOptions;
 pub use reference::{Reference, References, ReferenceNames};
+pub use refdb::{Refdb, RefdbBackend, RefdbLogEntry, RefdbTarget, MemoryRefdbBackend};
+pub use rebase::{Rebase, RebaseOptions, RebaseOperation, RebaseOperationType};
 pub use reflog::{Reflog, ReflogEntry, ReflogIter};
 pub use refspec::Refspec;
 pub use remote::{Remote, RemoteConnection, Refspecs, RemoteHead, FetchOptions, PushOptions};
 pub use remote_callbacks::{RemoteCallbacks, Credentials, TransferProgress};
 pub use remote_callbacks::{TransportMessage, Progress, UpdateTips};
 pub use repo::{Repository, RepositoryInitOptions};
+pub use revert::RevertOptions;
 pub use revspec::Revspec;
 pub use revwalk::Revwalk;
 pub use signature::Signature;
@@ -120,8 +129,9 @@ pub use tag::Tag;
 pub use time::{Time, IndexTime};
 pub use tree::{Tree, TreeEntry, TreeIter};
 pub use treebuilder::TreeBuilder;
-pub use odb::{Odb, OdbObject, OdbReader, OdbWriter};
+pub use odb::{Odb, OdbBackend, OdbObject, OdbReader, OdbWriter};
 pub use util::IntoCString;
+pub use worktree::{Worktree, WorktreeAddOptions, WorktreeLockStatus, WorktreePruneOptions};
 
 /// An enumeration of possible errors that can happen when working with a git
 /// repository.
@@ -541,10 +551,12 @@ pub mod string_array;
 pub mod oid_array;
 pub mod transport;
 
+mod apply;
 mod blame;
 mod blob;
 mod branch;
 mod buf;
+mod cherrypick;
 mod commit;
 mod config;
 mod cred;
@@ -552,6 +564,8 @@ mod describe;
 mod diff;
 mod error;
 mod index;
+mod indexer;
+mod mailmap;
 mod merge;
 mod message;
 mod note;
@@ -562,12 +576,15 @@ mod packbuilder;
 mod pathspec;
 mod patch;
 mod proxy_options;
+mod rebase;
+mod refdb;
 mod reference;
 mod reflog;
 mod refspec;
 mod remote;
 mod remote_callbacks;
 mod repo;
+mod revert;
 mod revspec;
 mod revwalk;
 mod signature;
@@ -576,8 +593,10 @@ mod submodule;
 mod stash;
 mod tag;
 mod time;
+mod transport_memory;
 mod tree;
 mod treebuilder;
+mod worktree;
 
 fn init() {
     static INIT: Once = ONCE_INIT;
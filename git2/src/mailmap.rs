@@ -0,0 +1,267 @@
+use std::collections::BTreeMap;
+use std::fs::File;
+use std::io::Read;
+use std::path::Path;
+
+use {Blob, Error, ErrorCode, Repository, Signature};
+
+/// A mailmap, which maps the names and emails contributors used over time to
+/// their canonical ones.
+///
+/// Mailmaps are read from the `.mailmap` format documented in
+/// git-shortlog(1). libgit2 doesn't support them yet, so they are parsed and
+/// resolved here the same way git does.
+#[derive(Clone, Default)]
+pub struct Mailmap {
+    // keyed by the lowercased email and name to replace, without a name for
+    // the entries replacing any name used with the email
+    entries: BTreeMap<(String, Option<String>), MailmapEntry>,
+}
+
+#[derive(Clone, Default)]
+struct MailmapEntry {
+    real_name: Option<String>,
+    real_email: Option<String>,
+}
+
+impl Mailmap {
+    /// Creates an empty mailmap.
+    pub fn new() -> Mailmap {
+        Mailmap::default()
+    }
+
+    /// Parses a mailmap from the contents of a `.mailmap` file.
+    pub fn from_buffer(buf: &str) -> Mailmap {
+        let mut mailmap = Mailmap::new();
+        mailmap.add_buffer(buf);
+        mailmap
+    }
+
+    /// Parses a mailmap from a blob holding the contents of a `.mailmap`
+    /// file.
+    pub fn from_blob(blob: &Blob) -> Mailmap {
+        Mailmap::from_buffer(&String::from_utf8_lossy(blob.content()))
+    }
+
+    /// Adds the entries of the contents of a `.mailmap` file to this
+    /// mailmap, overriding the entries it has for the same identities.
+    ///
+    /// Lines which aren't valid entries are ignored.
+    pub fn add_buffer(&mut self, buf: &str) {
+        for line in buf.lines() {
+            if line.starts_with('#') {
+                continue
+            }
+            let (real_name, real_email, rest) = match parse_ident(line, false) {
+                Some(ident) => ident,
+                None => continue,
+            };
+            let (replace_name, replace_email) = match parse_ident(rest, true) {
+                Some((name, email, _)) => (name, Some(email)),
+                None => (None, None),
+            };
+            match replace_email {
+                Some(email) => self.add_entry(real_name, Some(real_email),
+                                              replace_name, email),
+                None => self.add_entry(real_name, None, None, real_email),
+            }
+        }
+    }
+
+    /// Adds an entry mapping the identities using `replace_email` to
+    /// `real_name` and `real_email`.
+    ///
+    /// When `replace_name` is given, only the identities also using that
+    /// name are mapped. Names and emails are compared case-insensitively.
+    pub fn add_entry(&mut self, real_name: Option<&str>,
+                     real_email: Option<&str>, replace_name: Option<&str>,
+                     replace_email: &str) {
+        let key = (replace_email.to_lowercase(),
+                   replace_name.map(|name| name.to_lowercase()));
+        let entry = self.entries.entry(key).or_insert(MailmapEntry::default());
+        if replace_name.is_some() {
+            entry.real_name = real_name.map(|s| s.to_string());
+            entry.real_email = real_email.map(|s| s.to_string());
+        } else {
+            if let Some(name) = real_name {
+                entry.real_name = Some(name.to_string());
+            }
+            if let Some(email) = real_email {
+                entry.real_email = Some(email.to_string());
+            }
+        }
+    }
+
+    /// Resolves a name and email to the canonical ones, returning them
+    /// unchanged if no entry of the mailmap applies.
+    pub fn resolve<'a>(&'a self, name: &'a str, email: &'a str)
+                       -> (&'a str, &'a str) {
+        let email_key = email.to_lowercase();
+        let named = (email_key, Some(name.to_lowercase()));
+        let entry = self.entries.get(&named).or_else(|| {
+            self.entries.get(&(named.0.clone(), None))
+        });
+        match entry {
+            Some(entry) => {
+                (entry.real_name.as_ref().map(|s| &s[..]).unwrap_or(name),
+                 entry.real_email.as_ref().map(|s| &s[..]).unwrap_or(email))
+            }
+            None => (name, email),
+        }
+    }
+
+    /// Resolves the name and email of a signature to the canonical ones,
+    /// keeping its time.
+    pub fn resolve_signature(&self, sig: &Signature)
+                             -> Result<Signature<'static>, Error> {
+        let name = String::from_utf8_lossy(sig.name_bytes());
+        let email = String::from_utf8_lossy(sig.email_bytes());
+        let (real_name, real_email) = self.resolve(&name, &email);
+        if real_name == name && real_email == email {
+            return Ok(sig.to_owned())
+        }
+        Signature::new(real_name, real_email, &sig.when())
+    }
+}
+
+// Parses a `Name <email>` identity at the start of `s`, returning the
+// trimmed name if it isn't empty, the email and the rest of `s`.
+fn parse_ident(s: &str, allow_empty_email: bool)
+               -> Option<(Option<&str>, &str, &str)> {
+    let left = s.find('<');
+    let right = left.and_then(|left| {
+        s[left + 1..].find('>').map(|right| left + 1 + right)
+    });
+    let (left, right) = match (left, right) {
+        (Some(left), Some(right)) => (left, right),
+        _ => return None,
+    };
+    if !allow_empty_email && right == left + 1 {
+        return None
+    }
+    let name = s[..left].trim();
+    let name = if name.is_empty() {None} else {Some(name)};
+    Some((name, &s[left + 1..right], &s[right + 1..]))
+}
+
+/// Loads the mailmap of a repository, see `Repository::mailmap`.
+pub fn from_repository(repo: &Repository) -> Result<Mailmap, Error> {
+    let mut mailmap = Mailmap::new();
+    let config = try!(repo.config());
+
+    if let Some(workdir) = repo.workdir() {
+        add_file(&mut mailmap, &workdir.join(".mailmap"));
+    }
+
+    let blob = match config.get_string("mailmap.blob") {
+        Ok(spec) => Some(spec),
+        Err(ref e) if e.code() == ErrorCode::NotFound => {
+            if repo.is_bare() {
+                Some("HEAD:.mailmap".to_string())
+            } else {
+                None
+            }
+        }
+        Err(e) => return Err(e),
+    };
+    if let Some(spec) = blob {
+        if let Ok(blob) = repo.revparse_single(&spec)
+                              .and_then(|obj| obj.peel_to_blob()) {
+            mailmap.add_buffer(&String::from_utf8_lossy(blob.content()));
+        }
+    }
+
+    match config.get_path("mailmap.file") {
+        Ok(path) => {
+            let path = match repo.workdir() {
+                Some(workdir) => workdir.join(path),
+                None => path,
+            };
+            add_file(&mut mailmap, &path);
+        }
+        Err(ref e) if e.code() == ErrorCode::NotFound => {}
+        Err(e) => return Err(e),
+    }
+    Ok(mailmap)
+}
+
+// Adds the entries of a `.mailmap` file, which like in git is skipped if it
+// can't be read.
+fn add_file(mailmap: &mut Mailmap, path: &Path) {
+    let mut contents = Vec::new();
+    if let Ok(mut file) = File::open(path) {
+        if file.read_to_end(&mut contents).is_ok() {
+            mailmap.add_buffer(&String::from_utf8_lossy(&contents));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs::File;
+    use std::io::Write;
+    use {Mailmap, Signature, Time};
+
+    #[test]
+    fn parse_and_resolve() {
+        let mailmap = Mailmap::from_buffer("\
+# comment
+Proper Name <commit@example.com>
+<proper@example.com> <Other@Example.com>
+Joe <joe@example.com> Joseph <old@example.com>
+Jane Doe <jane@example.com> <jane@laptop>
+Not An Entry
+");
+        assert_eq!(mailmap.resolve("nick", "commit@example.com"),
+                   ("Proper Name", "commit@example.com"));
+        assert_eq!(mailmap.resolve("nick", "other@example.COM"),
+                   ("nick", "proper@example.com"));
+        assert_eq!(mailmap.resolve("joseph", "old@example.com"),
+                   ("Joe", "joe@example.com"));
+        assert_eq!(mailmap.resolve("Jo", "old@example.com"),
+                   ("Jo", "old@example.com"));
+        assert_eq!(mailmap.resolve("jd", "jane@laptop"),
+                   ("Jane Doe", "jane@example.com"));
+        assert_eq!(mailmap.resolve("Someone", "someone@example.com"),
+                   ("Someone", "someone@example.com"));
+
+        let time = Time::new(1234567890, 60);
+        let sig = t!(Signature::new("Joseph", "old@example.com", &time));
+        let sig = t!(mailmap.resolve_signature(&sig));
+        assert_eq!(sig.name(), Some("Joe"));
+        assert_eq!(sig.email(), Some("joe@example.com"));
+        assert!(sig.when() == time);
+    }
+
+    #[test]
+    fn add_entry() {
+        let mut mailmap = Mailmap::new();
+        mailmap.add_entry(Some("Name"), None, None, "a@example.com");
+        mailmap.add_entry(None, Some("b@example.com"), None, "a@example.com");
+        assert_eq!(mailmap.resolve("x", "a@example.com"),
+                   ("Name", "b@example.com"));
+    }
+
+    #[test]
+    fn repository() {
+        let (_td, repo) = ::test::repo_init();
+        let root = repo.path().parent().unwrap();
+        t!(t!(File::create(root.join(".mailmap")))
+            .write_all(b"Real Name <real@example.com> <email>\n"));
+
+        let mailmap = t!(repo.mailmap());
+        let head = t!(repo.head()).target().unwrap();
+        let commit = t!(repo.find_commit(head));
+        let author = t!(commit.author_with_mailmap(&mailmap));
+        assert_eq!(author.name(), Some("Real Name"));
+        assert_eq!(author.email(), Some("real@example.com"));
+
+        let blob = t!(repo.blob(b"Other <other@example.com> <email>\n"));
+        let mut config = t!(repo.config());
+        t!(config.set_str("mailmap.blob", &blob.to_string()));
+        let mailmap = t!(repo.mailmap());
+        let committer = t!(commit.committer_with_mailmap(&mailmap));
+        assert_eq!(committer.name(), Some("Other"));
+        assert_eq!(committer.email(), Some("other@example.com"));
+    }
+}
@@ -1,15 +1,16 @@
 use std::marker;
+use std::mem;
 use std::io;
 use std::ptr;
 use std::slice;
 
 use std::ffi::CString;
 
-use libc::{c_char, c_int, c_void, size_t};
+use libc::{c_char, c_int, c_uint, c_void, size_t};
 
 use {raw, Oid, Object, ObjectType, Error};
 use panic;
-use util::Binding;
+use util::{Binding, set_err};
 
 /// A structure to represent a git object database
 pub struct Odb<'repo> {
@@ -127,7 +128,7 @@ impl<'repo> Odb<'repo> {
 
     /// Checks if the object database has an object.
     pub fn exists(&self, oid: Oid) -> bool {
-        unsafe { raw::git_odb_exists(self.raw, oid.raw()) != -1 }
+        unsafe { raw::git_odb_exists(self.raw, oid.raw()) == 1 }
     }
 
     /// Potentially finds an object that starts with the given prefix.
@@ -163,6 +164,82 @@ impl<'repo> Odb<'repo> {
             Ok(())
         }
     }
+
+    /// Adds a custom backend to the object database.
+    ///
+    /// Backends with a higher priority are queried first when reading
+    /// objects, and objects are written to the first backend which supports
+    /// writing.
+    pub fn add_backend<B: OdbBackend>(&self, backend: B, priority: i32)
+                                      -> Result<(), Error> {
+        unsafe {
+            let mut raw = Box::new(RawOdbBackend {
+                raw: mem::zeroed(),
+                obj: Box::new(backend),
+            });
+            try_call!(raw::git_odb_init_backend(
+                &mut raw.raw as *mut _ as *mut raw::git_odb_backend,
+                raw::GIT_ODB_BACKEND_VERSION));
+            raw.raw.read = Some(backend_read);
+            raw.raw.read_header = Some(backend_read_header);
+            raw.raw.write = Some(backend_write);
+            raw.raw.exists = Some(backend_exists);
+            raw.raw.refresh = Some(backend_refresh);
+            raw.raw.foreach = Some(backend_foreach);
+            raw.raw.free = Some(backend_free);
+            let ptr = Box::into_raw(raw);
+            let rc = raw::git_odb_add_backend(self.raw,
+                                              ptr as *mut raw::git_odb_backend,
+                                              priority as c_int);
+            if rc < 0 {
+                drop(Box::from_raw(ptr));
+                return Err(Error::last_error(rc).unwrap())
+            }
+            Ok(())
+        }
+    }
+}
+
+/// A custom backend for an object database, added with `Odb::add_backend`.
+///
+/// Objects are identified by their id, which is computed by libgit2 before
+/// they are written, and are returned as their kind and raw contents.
+pub trait OdbBackend: Send + 'static {
+    /// Reads an object, returning `None` if it isn't in this backend.
+    fn read(&self, oid: Oid) -> Result<Option<(ObjectType, Vec<u8>)>, Error>;
+
+    /// Reads the size and the kind of an object, returning `None` if it
+    /// isn't in this backend.
+    ///
+    /// By default the whole object is read.
+    fn read_header(&self, oid: Oid) -> Result<Option<(usize, ObjectType)>, Error> {
+        Ok(try!(self.read(oid)).map(|(kind, data)| (data.len(), kind)))
+    }
+
+    /// Stores an object.
+    fn write(&self, oid: Oid, kind: ObjectType, data: &[u8]) -> Result<(), Error>;
+
+    /// Checks whether an object is in this backend.
+    ///
+    /// By default the header of the object is read.
+    fn exists(&self, oid: Oid) -> bool {
+        match self.read_header(oid) {
+            Ok(header) => header.is_some(),
+            Err(_) => false,
+        }
+    }
+
+    /// Calls `callback` with the id of each object of this backend, stopping
+    /// early if it returns `false`.
+    fn foreach(&self, callback: &mut FnMut(Oid) -> bool) -> Result<(), Error>;
+
+    /// Reloads any state cached by this backend.
+    ///
+    /// This is called when an object isn't found, before looking for it
+    /// again.
+    fn refresh(&self) -> Result<(), Error> {
+        Ok(())
+    }
 }
 
 /// An object from the Object Database.
@@ -333,11 +410,185 @@ extern fn foreach_cb(id: *const raw::git_oid,
     }).unwrap_or(1)
 }
 
+/// Instance of a `git_odb_backend`, must use `#[repr(C)]` to ensure that the
+/// C fields come first.
+#[repr(C)]
+struct RawOdbBackend {
+    raw: git_odb_backend,
+    obj: Box<OdbBackend>,
+}
+
+/// Same layout as `raw::git_odb_backend`, whose callbacks can't be null,
+/// while `git_odb_init_backend` leaves the unsupported ones null.
+#[repr(C)]
+#[allow(non_camel_case_types)]
+struct git_odb_backend {
+    version: c_uint,
+    odb: *mut raw::git_odb,
+    read: Option<extern fn(*mut *mut c_void,
+                           *mut size_t,
+                           *mut raw::git_otype,
+                           *mut raw::git_odb_backend,
+                           *const raw::git_oid) -> c_int>,
+    read_prefix: Option<extern fn(*mut raw::git_oid,
+                                  *mut *mut c_void,
+                                  *mut size_t,
+                                  *mut raw::git_otype,
+                                  *mut raw::git_odb_backend,
+                                  *const raw::git_oid,
+                                  size_t) -> c_int>,
+    read_header: Option<extern fn(*mut size_t,
+                                  *mut raw::git_otype,
+                                  *mut raw::git_odb_backend,
+                                  *const raw::git_oid) -> c_int>,
+    write: Option<extern fn(*mut raw::git_odb_backend,
+                            *const raw::git_oid,
+                            *const c_void,
+                            size_t,
+                            raw::git_otype) -> c_int>,
+    writestream: Option<extern fn(*mut *mut raw::git_odb_stream,
+                                  *mut raw::git_odb_backend,
+                                  raw::git_off_t,
+                                  raw::git_otype) -> c_int>,
+    readstream: Option<extern fn(*mut *mut raw::git_odb_stream,
+                                 *mut size_t,
+                                 *mut raw::git_otype,
+                                 *mut raw::git_odb_backend,
+                                 *const raw::git_oid) -> c_int>,
+    exists: Option<extern fn(*mut raw::git_odb_backend,
+                             *const raw::git_oid) -> c_int>,
+    exists_prefix: Option<extern fn(*mut raw::git_oid,
+                                    *mut raw::git_odb_backend,
+                                    *const raw::git_oid,
+                                    size_t) -> c_int>,
+    refresh: Option<extern fn(*mut raw::git_odb_backend) -> c_int>,
+    foreach: Option<extern fn(*mut raw::git_odb_backend,
+                              raw::git_odb_foreach_cb,
+                              *mut c_void) -> c_int>,
+    writepack: Option<extern fn(*mut *mut raw::git_odb_writepack,
+                                *mut raw::git_odb_backend,
+                                *mut raw::git_odb,
+                                raw::git_transfer_progress_cb,
+                                *mut c_void) -> c_int>,
+    freshen: Option<extern fn(*mut raw::git_odb_backend,
+                              *const raw::git_oid) -> c_int>,
+    free: Option<extern fn(*mut raw::git_odb_backend)>,
+}
+
+unsafe fn backend_obj<'a>(backend: *mut raw::git_odb_backend) -> &'a OdbBackend {
+    &*(*(backend as *mut RawOdbBackend)).obj
+}
+
+// callback used by the object database to read an object from an
+// `OdbBackend`, the data being copied to a buffer allocated by libgit2
+extern fn backend_read(data: *mut *mut c_void,
+                       len: *mut size_t,
+                       kind: *mut raw::git_otype,
+                       backend: *mut raw::git_odb_backend,
+                       oid: *const raw::git_oid) -> c_int {
+    panic::wrap(|| unsafe {
+        match backend_obj(backend).read(Binding::from_raw(oid)) {
+            Ok(Some((obj_kind, obj_data))) => {
+                let buf = raw::git_odb_backend_malloc(backend,
+                                                      obj_data.len() as size_t);
+                if buf.is_null() {
+                    return -1
+                }
+                ptr::copy_nonoverlapping(obj_data.as_ptr(), buf as *mut u8,
+                                         obj_data.len());
+                *data = buf;
+                *len = obj_data.len() as size_t;
+                *kind = obj_kind.raw();
+                0
+            }
+            Ok(None) => raw::GIT_ENOTFOUND as c_int,
+            Err(e) => set_err(&e),
+        }
+    }).unwrap_or(-1)
+}
+
+extern fn backend_read_header(len: *mut size_t,
+                              kind: *mut raw::git_otype,
+                              backend: *mut raw::git_odb_backend,
+                              oid: *const raw::git_oid) -> c_int {
+    panic::wrap(|| unsafe {
+        match backend_obj(backend).read_header(Binding::from_raw(oid)) {
+            Ok(Some((obj_len, obj_kind))) => {
+                *len = obj_len as size_t;
+                *kind = obj_kind.raw();
+                0
+            }
+            Ok(None) => raw::GIT_ENOTFOUND as c_int,
+            Err(e) => set_err(&e),
+        }
+    }).unwrap_or(-1)
+}
+
+extern fn backend_write(backend: *mut raw::git_odb_backend,
+                        oid: *const raw::git_oid,
+                        data: *const c_void,
+                        len: size_t,
+                        kind: raw::git_otype) -> c_int {
+    panic::wrap(|| unsafe {
+        let kind = match ObjectType::from_raw(kind) {
+            Some(kind) => kind,
+            None => return -1,
+        };
+        let data = slice::from_raw_parts(data as *const u8, len as usize);
+        match backend_obj(backend).write(Binding::from_raw(oid), kind, data) {
+            Ok(()) => 0,
+            Err(e) => set_err(&e),
+        }
+    }).unwrap_or(-1)
+}
+
+extern fn backend_exists(backend: *mut raw::git_odb_backend,
+                         oid: *const raw::git_oid) -> c_int {
+    panic::wrap(|| unsafe {
+        backend_obj(backend).exists(Binding::from_raw(oid)) as c_int
+    }).unwrap_or(0)
+}
+
+extern fn backend_refresh(backend: *mut raw::git_odb_backend) -> c_int {
+    panic::wrap(|| unsafe {
+        match backend_obj(backend).refresh() {
+            Ok(()) => 0,
+            Err(e) => set_err(&e),
+        }
+    }).unwrap_or(-1)
+}
+
+// callback used by the object database to list the objects of an
+// `OdbBackend`, stopping with the value returned by `cb` if it isn't zero
+extern fn backend_foreach(backend: *mut raw::git_odb_backend,
+                          cb: raw::git_odb_foreach_cb,
+                          payload: *mut c_void) -> c_int {
+    panic::wrap(|| unsafe {
+        let mut stopped = 0;
+        let res = backend_obj(backend).foreach(&mut |oid| {
+            stopped = cb(oid.raw(), payload);
+            stopped == 0
+        });
+        match res {
+            Ok(()) => stopped,
+            Err(e) => set_err(&e),
+        }
+    }).unwrap_or(-1)
+}
+
+extern fn backend_free(backend: *mut raw::git_odb_backend) {
+    let _ = panic::wrap(|| unsafe {
+        drop(Box::from_raw(backend as *mut RawOdbBackend));
+    });
+}
+
 #[cfg(test)]
 mod tests {
+    use std::collections::HashMap;
     use std::io::prelude::*;
+    use std::sync::{Arc, Mutex};
     use tempdir::TempDir;
-    use {Repository, ObjectType, Oid};
+    use {Repository, ObjectType, Oid, Odb, OdbBackend, Error, ErrorCode};
 
     #[test]
     fn read() {
@@ -402,6 +653,9 @@ mod tests {
         let db = repo.odb().unwrap();
         let id = db.write(ObjectType::Blob, &dat).unwrap();
         assert!(db.exists(id));
+
+        let missing = Oid::hash_object(ObjectType::Blob, b"missing").unwrap();
+        assert!(!db.exists(missing));
     }
 
     #[test]
@@ -416,4 +670,68 @@ mod tests {
         let found_oid = db.exists_prefix(id_prefix, 10).unwrap();
         assert_eq!(found_oid, id);
     }
+
+    #[derive(Clone, Default)]
+    struct MemoryBackend {
+        objects: Arc<Mutex<HashMap<Oid, (ObjectType, Vec<u8>)>>>,
+    }
+
+    impl OdbBackend for MemoryBackend {
+        fn read(&self, oid: Oid) -> Result<Option<(ObjectType, Vec<u8>)>, Error> {
+            match self.objects.lock().unwrap().get(&oid) {
+                Some(&(_, ref data)) if data == b"corrupt" => {
+                    Err(Error::from_str("this object is corrupt\0"))
+                }
+                object => Ok(object.cloned()),
+            }
+        }
+
+        fn write(&self, oid: Oid, kind: ObjectType, data: &[u8]) -> Result<(), Error> {
+            self.objects.lock().unwrap().insert(oid, (kind, data.to_vec()));
+            Ok(())
+        }
+
+        fn foreach(&self, callback: &mut FnMut(Oid) -> bool) -> Result<(), Error> {
+            let ids = self.objects.lock().unwrap().keys().cloned().collect::<Vec<_>>();
+            for id in ids {
+                if !callback(id) {
+                    break
+                }
+            }
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn custom_backend() {
+        let backend = MemoryBackend::default();
+        let db = Odb::new().unwrap();
+        db.add_backend(backend.clone(), 1).unwrap();
+
+        let id = db.write(ObjectType::Blob, b"stored in memory").unwrap();
+        assert_eq!(id, Oid::hash_object(ObjectType::Blob, b"stored in memory").unwrap());
+        assert!(backend.objects.lock().unwrap().contains_key(&id));
+        assert!(db.exists(id));
+        assert_eq!(db.read_header(id).unwrap(), (16, ObjectType::Blob));
+        assert_eq!(db.read(id).unwrap().data(), b"stored in memory");
+
+        let missing = Oid::hash_object(ObjectType::Blob, b"missing").unwrap();
+        assert!(!db.exists(missing));
+        assert_eq!(db.read(missing).err().unwrap().code(), ErrorCode::NotFound);
+
+        let corrupt = db.write(ObjectType::Blob, b"corrupt").unwrap();
+        let err = db.read(corrupt).err().unwrap();
+        assert_eq!(err.message(), "this object is corrupt");
+
+        let mut count = 0;
+        db.foreach(|_| { count += 1; true }).unwrap();
+        assert_eq!(count, 2);
+        count = 0;
+        db.foreach(|_| { count += 1; false }).unwrap();
+        assert_eq!(count, 1);
+
+        // a repository can be served entirely from the backend
+        let repo = Repository::from_odb(db).unwrap();
+        assert_eq!(repo.find_blob(id).unwrap().content(), b"stored in memory");
+    }
 }
@@ -0,0 +1,407 @@
+use std::ffi::{CStr, CString};
+use std::marker;
+use std::mem;
+use std::ptr;
+use libc::{c_int, size_t};
+
+use {raw, Error, Index, MergeOptions, Oid, Repository, Signature};
+use build::CheckoutBuilder;
+use util::Binding;
+
+/// A rebase in progress, created with `Repository::rebase` or resumed with
+/// `Repository::open_rebase`.
+///
+/// Iterating over a rebase applies its operations one after the other. Each
+/// applied operation is then committed with `commit`, and the rebase is
+/// completed with `finish` or abandoned with `abort`.
+pub struct Rebase<'repo> {
+    raw: *mut raw::git_rebase,
+    _marker: marker::PhantomData<&'repo Repository>,
+}
+
+/// Options which can be used to customize how a rebase is performed.
+pub struct RebaseOptions<'cb> {
+    raw: raw::git_rebase_options,
+    rewrite_notes_ref: Option<CString>,
+    merge_options: Option<MergeOptions>,
+    checkout_options: Option<CheckoutBuilder<'cb>>,
+}
+
+/// A single operation of a rebase.
+#[derive(Debug, Clone)]
+pub struct RebaseOperation {
+    kind: Option<RebaseOperationType>,
+    id: Oid,
+    exec: Option<String>,
+}
+
+/// The kinds of operations performed during a rebase.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum RebaseOperationType {
+    /// The commit is cherry-picked onto the new base.
+    Pick,
+    /// The commit is cherry-picked, and its message is expected to be edited
+    /// before it is committed.
+    Reword,
+    /// The commit is cherry-picked, and the rebase pauses so that it can be
+    /// amended before it is committed.
+    Edit,
+    /// The commit is squashed into the previous one.
+    Squash,
+    /// The commit is squashed into the previous one, discarding its message.
+    Fixup,
+    /// No commit is cherry-picked, a command is run instead.
+    Exec,
+}
+
+impl<'repo> Rebase<'repo> {
+    /// Returns the number of operations of this rebase.
+    pub fn len(&self) -> usize {
+        unsafe { raw::git_rebase_operation_entrycount(self.raw) as usize }
+    }
+
+    /// Returns `true` if this rebase has no operations.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the index of the operation which is being applied, or `None`
+    /// if iteration has not started yet.
+    pub fn operation_current(&self) -> Option<usize> {
+        match unsafe { raw::git_rebase_operation_current(self.raw) } {
+            raw::GIT_REBASE_NO_OPERATION => None,
+            n => Some(n),
+        }
+    }
+
+    /// Returns the operation at the given index.
+    pub fn operation(&self, idx: usize) -> Option<RebaseOperation> {
+        unsafe {
+            let ptr = raw::git_rebase_operation_byindex(self.raw,
+                                                        idx as size_t);
+            if ptr.is_null() {
+                None
+            } else {
+                Some(RebaseOperation::from_raw(ptr))
+            }
+        }
+    }
+
+    /// Returns the index produced by the last applied operation of an
+    /// in-memory rebase.
+    ///
+    /// This index can be inspected for conflicts, which must be resolved and
+    /// written back to it before the operation is committed.
+    pub fn inmemory_index(&mut self) -> Result<Index, Error> {
+        let mut raw = ptr::null_mut();
+        unsafe {
+            try_call!(raw::git_rebase_inmemory_index(&mut raw, self.raw));
+            Ok(Binding::from_raw(raw))
+        }
+    }
+
+    /// Commits the changes of the operation which was last applied.
+    ///
+    /// The author and the message of the original commit are kept unless
+    /// given. Fails with `ErrorCode::Applied` if the changes are already
+    /// present in the new base, in which case the operation can be skipped.
+    pub fn commit(&mut self,
+                  author: Option<&Signature>,
+                  committer: &Signature,
+                  message: Option<&str>) -> Result<Oid, Error> {
+        let message = try!(::opt_cstr(message));
+        let mut id = raw::git_oid { id: [0; raw::GIT_OID_RAWSZ] };
+        unsafe {
+            try_call!(raw::git_rebase_commit(&mut id, self.raw,
+                                             author.map(|a| a.raw()),
+                                             committer.raw(),
+                                             ptr::null(),
+                                             message));
+            Ok(Binding::from_raw(&id as *const _))
+        }
+    }
+
+    /// Aborts this rebase, resetting the repository and the working directory
+    /// to their state before the rebase began.
+    pub fn abort(&mut self) -> Result<(), Error> {
+        unsafe {
+            try_call!(raw::git_rebase_abort(self.raw));
+        }
+        Ok(())
+    }
+
+    /// Finishes this rebase once all of its operations have been applied
+    /// and committed, updating the rebased branch.
+    ///
+    /// The signature is used for the reflog entries, the default signature
+    /// of the repository being used if none is given.
+    pub fn finish(&mut self, signature: Option<&Signature>) -> Result<(), Error> {
+        unsafe {
+            try_call!(raw::git_rebase_finish(self.raw,
+                                             signature.map(|s| s.raw())));
+        }
+        Ok(())
+    }
+}
+
+impl<'repo> Iterator for Rebase<'repo> {
+    type Item = Result<RebaseOperation, Error>;
+
+    /// Applies the next operation of this rebase, returning it.
+    ///
+    /// For a rebase in the working directory, the changes are checked out
+    /// and written to the index of the repository.
+    fn next(&mut self) -> Option<Result<RebaseOperation, Error>> {
+        let mut out = ptr::null_mut();
+        unsafe {
+            try_call_iter!(raw::git_rebase_next(&mut out, self.raw));
+            Some(Ok(RebaseOperation::from_raw(out)))
+        }
+    }
+}
+
+impl<'repo> Binding for Rebase<'repo> {
+    type Raw = *mut raw::git_rebase;
+
+    unsafe fn from_raw(raw: *mut raw::git_rebase) -> Rebase<'repo> {
+        Rebase { raw: raw, _marker: marker::PhantomData }
+    }
+    fn raw(&self) -> *mut raw::git_rebase { self.raw }
+}
+
+impl<'repo> Drop for Rebase<'repo> {
+    fn drop(&mut self) {
+        unsafe { raw::git_rebase_free(self.raw) }
+    }
+}
+
+impl RebaseOperation {
+    unsafe fn from_raw(raw: *const raw::git_rebase_operation) -> RebaseOperation {
+        let exec = if (*raw).exec.is_null() {
+            None
+        } else {
+            Some(String::from_utf8_lossy(CStr::from_ptr((*raw).exec).to_bytes())
+                     .into_owned())
+        };
+        RebaseOperation {
+            kind: RebaseOperationType::from_raw((*raw).kind),
+            id: Binding::from_raw(&(*raw).id as *const _),
+            exec: exec,
+        }
+    }
+
+    /// Returns the kind of this operation, or `None` if it is unknown.
+    pub fn kind(&self) -> Option<RebaseOperationType> {
+        self.kind
+    }
+
+    /// Returns the id of the commit being applied by this operation.
+    ///
+    /// This is zero for `Exec` operations.
+    pub fn id(&self) -> Oid {
+        self.id
+    }
+
+    /// Returns the command run by an `Exec` operation.
+    pub fn exec(&self) -> Option<&str> {
+        self.exec.as_ref().map(|s| &s[..])
+    }
+}
+
+impl RebaseOperationType {
+    /// Convert a raw git_rebase_operation_t to a RebaseOperationType.
+    pub fn from_raw(raw: raw::git_rebase_operation_t) -> Option<RebaseOperationType> {
+        match raw {
+            raw::GIT_REBASE_OPERATION_PICK => Some(RebaseOperationType::Pick),
+            raw::GIT_REBASE_OPERATION_REWORD => Some(RebaseOperationType::Reword),
+            raw::GIT_REBASE_OPERATION_EDIT => Some(RebaseOperationType::Edit),
+            raw::GIT_REBASE_OPERATION_SQUASH => Some(RebaseOperationType::Squash),
+            raw::GIT_REBASE_OPERATION_FIXUP => Some(RebaseOperationType::Fixup),
+            raw::GIT_REBASE_OPERATION_EXEC => Some(RebaseOperationType::Exec),
+            _ => None,
+        }
+    }
+}
+
+impl<'cb> Default for RebaseOptions<'cb> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'cb> RebaseOptions<'cb> {
+    /// Creates a default set of rebase options.
+    pub fn new() -> RebaseOptions<'cb> {
+        let mut opts = RebaseOptions {
+            raw: unsafe { mem::zeroed() },
+            rewrite_notes_ref: None,
+            merge_options: None,
+            checkout_options: None,
+        };
+        assert_eq!(unsafe {
+            raw::git_rebase_init_options(&mut opts.raw,
+                                         raw::GIT_REBASE_OPTIONS_VERSION)
+        }, 0);
+        opts
+    }
+
+    /// Asks other git clients which may resume this rebase not to print
+    /// progress information.
+    pub fn quiet(&mut self, quiet: bool) -> &mut RebaseOptions<'cb> {
+        self.raw.quiet = quiet as c_int;
+        self
+    }
+
+    /// Performs the rebase in memory, without touching the working directory,
+    /// the index or the references of the repository.
+    ///
+    /// The result of each operation is available with
+    /// `Rebase::inmemory_index`, and `Rebase::finish` does not update the
+    /// rebased branch. An in-memory rebase can't be resumed with
+    /// `Repository::open_rebase`.
+    pub fn inmemory(&mut self, inmemory: bool) -> &mut RebaseOptions<'cb> {
+        self.raw.inmemory = inmemory as c_int;
+        self
+    }
+
+    /// Sets the notes reference whose notes are copied to the rewritten
+    /// commits when the rebase finishes.
+    ///
+    /// By default the `notes.rewriteRef` configuration is used.
+    pub fn rewrite_notes_ref(&mut self, reference: &str) -> &mut RebaseOptions<'cb> {
+        self.rewrite_notes_ref = Some(CString::new(reference).unwrap());
+        self
+    }
+
+    /// Options to use when merging the changes of each operation.
+    pub fn merge_options(&mut self, opts: MergeOptions) -> &mut RebaseOptions<'cb> {
+        self.merge_options = Some(opts);
+        self
+    }
+
+    /// Options to use when writing files to the working directory.
+    pub fn checkout_options(&mut self, opts: CheckoutBuilder<'cb>)
+                            -> &mut RebaseOptions<'cb> {
+        self.checkout_options = Some(opts);
+        self
+    }
+
+    /// Pointer to a raw git_rebase_options
+    pub fn raw(&mut self) -> &raw::git_rebase_options {
+        unsafe {
+            if let Some(opts) = self.merge_options.as_ref() {
+                ptr::copy_nonoverlapping(opts.raw(),
+                                         &mut self.raw.merge_options, 1);
+            }
+            if let Some(opts) = self.checkout_options.as_mut() {
+                opts.configure(&mut self.raw.checkout_options);
+            }
+        }
+        self.raw.rewrite_notes_ref = self.rewrite_notes_ref.as_ref()
+                                         .map(|s| s.as_ptr())
+                                         .unwrap_or(ptr::null());
+        &self.raw
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use {Oid, RebaseOptions, RebaseOperationType, Repository, RepositoryState};
+
+    // commits a file on top of a branch, without touching the working
+    // directory
+    fn commit(repo: &Repository, branch: &str, file: &str) -> Oid {
+        let refname = format!("refs/heads/{}", branch);
+        let parent = t!(repo.find_reference(&refname)).target().unwrap();
+        let parent = t!(repo.find_commit(parent));
+        let blob = t!(repo.blob(file.as_bytes()));
+        let mut builder = t!(repo.treebuilder(Some(&t!(parent.tree()))));
+        t!(builder.insert(Path::new(file), blob, 0o100644));
+        let tree = t!(repo.find_tree(t!(builder.write())));
+        let sig = t!(repo.signature());
+        t!(repo.commit(Some(&refname), &sig, &sig, file, &tree, &[&parent]))
+    }
+
+    #[test]
+    fn inmemory() {
+        let (_td, repo) = ::test::repo_init();
+        let head = t!(repo.head()).target().unwrap();
+        t!(repo.branch("feature", &t!(repo.find_commit(head)), false));
+        let first = commit(&repo, "feature", "first");
+        let second = commit(&repo, "feature", "second");
+        let master = commit(&repo, "master", "master");
+
+        let branch = t!(repo.find_annotated_commit(second));
+        let upstream = t!(repo.find_annotated_commit(master));
+        let mut opts = RebaseOptions::new();
+        opts.inmemory(true);
+        let mut rebase = t!(repo.rebase(Some(&branch), Some(&upstream), None,
+                                        Some(&mut opts)));
+        assert_eq!(rebase.len(), 2);
+        assert_eq!(rebase.operation_current(), None);
+        assert_eq!(rebase.operation(0).unwrap().id(), first);
+        assert!(rebase.operation(2).is_none());
+
+        let sig = t!(repo.signature());
+        let mut ids = Vec::new();
+        for _ in 0..2 {
+            let op = t!(rebase.next().unwrap());
+            assert_eq!(op.kind(), Some(RebaseOperationType::Pick));
+            assert!(!t!(rebase.inmemory_index()).has_conflicts());
+            ids.push(t!(rebase.commit(None, &sig, None)));
+        }
+        assert!(rebase.next().is_none());
+        assert_eq!(rebase.operation_current(), Some(1));
+        t!(rebase.finish(None));
+
+        let rebased = t!(repo.find_commit(ids[1]));
+        assert_eq!(rebased.message(), Some("second"));
+        assert_eq!(rebased.parent_id(0).unwrap(), ids[0]);
+        assert_eq!(t!(repo.find_commit(ids[0])).parent_id(0).unwrap(), master);
+        assert!(t!(rebased.tree()).get_name("master").is_some());
+
+        // nothing was changed in the repository
+        assert_eq!(repo.state(), RepositoryState::Clean);
+        assert_eq!(t!(repo.refname_to_id("refs/heads/feature")), second);
+    }
+
+    #[test]
+    fn abort_and_finish() {
+        let (_td, repo) = ::test::repo_init();
+        let head = t!(repo.head()).target().unwrap();
+        t!(repo.branch("feature", &t!(repo.find_commit(head)), false));
+        commit(&repo, "feature", "feature");
+        let master = commit(&repo, "master", "master");
+        t!(repo.reset(&t!(repo.find_object(master, None)), ::ResetType::Hard,
+                      None));
+
+        let feature = t!(repo.find_reference("refs/heads/feature"));
+        let branch = t!(repo.reference_to_annotated_commit(&feature));
+        let upstream = t!(repo.find_annotated_commit(master));
+        {
+            let mut rebase = t!(repo.rebase(Some(&branch), Some(&upstream),
+                                            None, None));
+            assert_eq!(repo.state(), RepositoryState::RebaseMerge);
+            t!(rebase.abort());
+        }
+        assert_eq!(repo.state(), RepositoryState::Clean);
+        assert!(repo.open_rebase(None).is_err());
+
+        t!(repo.rebase(Some(&branch), Some(&upstream), None, None));
+        let mut rebase = t!(repo.open_rebase(None));
+        let sig = t!(repo.signature());
+        let op = t!(rebase.next().unwrap());
+        assert!(rebase.next().is_none());
+        let id = t!(rebase.commit(None, &sig, Some("rebased")));
+        t!(rebase.finish(None));
+
+        assert_eq!(repo.state(), RepositoryState::Clean);
+        assert_eq!(t!(repo.refname_to_id("refs/heads/feature")), id);
+        let commit = t!(repo.find_commit(id));
+        assert_eq!(commit.message(), Some("rebased"));
+        assert_eq!(commit.parent_id(0).unwrap(), master);
+        assert!(op.exec().is_none());
+    }
+}
@@ -0,0 +1,931 @@
+use std::collections::BTreeMap;
+use std::ffi::{CStr, CString};
+use std::marker;
+use std::mem;
+use std::ptr;
+use std::str;
+use std::sync::{Arc, Mutex};
+use std::vec;
+
+use libc::{c_char, c_int, c_uint, c_void};
+
+use {raw, signature, Error, ErrorClass, ErrorCode, Oid};
+use {Repository, Signature, Time};
+use panic;
+use util::{Binding, set_err};
+
+/// A reference database of a repository, which stores references through a
+/// backend.
+pub struct Refdb<'repo> {
+    raw: *mut raw::git_refdb,
+    _marker: marker::PhantomData<&'repo Repository>,
+}
+
+/// What a reference stored in a `RefdbBackend` points to.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum RefdbTarget {
+    /// A direct reference to an object.
+    Direct(Oid),
+    /// A symbolic reference to another reference, by name.
+    Symbolic(String),
+}
+
+/// An entry of a reflog kept by a `RefdbBackend`.
+#[derive(Clone, PartialEq, Eq)]
+pub struct RefdbLogEntry {
+    /// The id the reference pointed to before the update.
+    pub id_old: Oid,
+    /// The id the reference points to after the update.
+    pub id_new: Oid,
+    /// The name of whoever made the update.
+    pub committer_name: String,
+    /// The email of whoever made the update.
+    pub committer_email: String,
+    /// When the update was made.
+    pub when: Time,
+    /// The message of the update, if any.
+    pub message: Option<String>,
+}
+
+impl<'repo> Refdb<'repo> {
+    /// Creates a reference database for a repository without any backend.
+    ///
+    /// A backend must be set with `set_backend` before the database can be
+    /// used.
+    pub fn new(repo: &Repository) -> Result<Refdb, Error> {
+        let mut raw = ptr::null_mut();
+        unsafe {
+            try_call!(raw::git_refdb_new(&mut raw, repo.raw()));
+            Ok(Binding::from_raw(raw))
+        }
+    }
+
+    /// Creates a reference database for a repository which uses the default
+    /// on-disk backend.
+    pub fn open(repo: &Repository) -> Result<Refdb, Error> {
+        let mut raw = ptr::null_mut();
+        unsafe {
+            try_call!(raw::git_refdb_open(&mut raw, repo.raw()));
+            Ok(Binding::from_raw(raw))
+        }
+    }
+
+    /// Sets a custom backend for this reference database, replacing the
+    /// previous one.
+    pub fn set_backend<B: RefdbBackend>(&self, backend: B) -> Result<(), Error> {
+        unsafe {
+            let mut raw = Box::new(RawRefdbBackend {
+                raw: mem::zeroed(),
+                obj: Box::new(backend),
+            });
+            try_call!(raw::git_refdb_init_backend(
+                &mut raw.raw as *mut _ as *mut raw::git_refdb_backend,
+                raw::GIT_REFDB_BACKEND_VERSION));
+            raw.raw.exists = Some(backend_exists);
+            raw.raw.lookup = Some(backend_lookup);
+            raw.raw.iterator = Some(backend_iterator);
+            raw.raw.write = Some(backend_write);
+            raw.raw.rename = Some(backend_rename);
+            raw.raw.del = Some(backend_delete);
+            raw.raw.compress = Some(backend_compress);
+            raw.raw.has_log = Some(backend_has_log);
+            raw.raw.ensure_log = Some(backend_ensure_log);
+            raw.raw.free = Some(backend_free);
+            raw.raw.reflog_read = Some(backend_reflog_read);
+            raw.raw.reflog_write = Some(backend_reflog_write);
+            raw.raw.reflog_rename = Some(backend_reflog_rename);
+            raw.raw.reflog_delete = Some(backend_reflog_delete);
+            let ptr = Box::into_raw(raw);
+            let rc = raw::git_refdb_set_backend(self.raw,
+                                                ptr as *mut raw::git_refdb_backend);
+            if rc < 0 {
+                drop(Box::from_raw(ptr));
+                return Err(Error::last_error(rc).unwrap())
+            }
+            Ok(())
+        }
+    }
+
+    /// Suggests that the backend compresses or optimizes its references.
+    pub fn compress(&self) -> Result<(), Error> {
+        unsafe {
+            try_call!(raw::git_refdb_compress(self.raw));
+        }
+        Ok(())
+    }
+}
+
+impl<'repo> Binding for Refdb<'repo> {
+    type Raw = *mut raw::git_refdb;
+
+    unsafe fn from_raw(raw: *mut raw::git_refdb) -> Refdb<'repo> {
+        Refdb {
+            raw: raw,
+            _marker: marker::PhantomData,
+        }
+    }
+    fn raw(&self) -> *mut raw::git_refdb { self.raw }
+}
+
+impl<'repo> Drop for Refdb<'repo> {
+    fn drop(&mut self) {
+        unsafe { raw::git_refdb_free(self.raw) }
+    }
+}
+
+impl RefdbLogEntry {
+    /// Creates a reflog entry for an update made by `committer`.
+    pub fn new(id_old: Oid, id_new: Oid, committer: &Signature,
+               message: Option<&str>) -> RefdbLogEntry {
+        RefdbLogEntry {
+            id_old: id_old,
+            id_new: id_new,
+            committer_name: String::from_utf8_lossy(committer.name_bytes())
+                                   .into_owned(),
+            committer_email: String::from_utf8_lossy(committer.email_bytes())
+                                    .into_owned(),
+            when: committer.when(),
+            message: message.map(|s| s.to_string()),
+        }
+    }
+}
+
+/// A custom backend for a reference database, set with
+/// `Refdb::set_backend`.
+///
+/// Updates are made with compare-and-swap semantics: when an expected target
+/// is given, the backend must only apply the update if the reference still
+/// has that target, and fail with `ErrorCode::Modified` otherwise. References
+/// which already exist must only be overwritten when `force` is set, failing
+/// with `ErrorCode::Exists` otherwise.
+///
+/// Backends keep reflogs themselves, by recording the `who` and `message` of
+/// the updates passed to `write`, and return them from `reflog`. libgit2 0.27
+/// has no public way of creating a `git_reflog`, so `Repository::reflog`
+/// fails for references stored in a custom backend and the reflog must be
+/// read from the backend instead.
+pub trait RefdbBackend: Send + 'static {
+    /// Checks whether a reference exists.
+    ///
+    /// By default the reference is looked up.
+    fn exists(&self, name: &str) -> Result<bool, Error> {
+        Ok(try!(self.lookup(name)).is_some())
+    }
+
+    /// Looks up a reference, returning `None` if it doesn't exist.
+    fn lookup(&self, name: &str) -> Result<Option<RefdbTarget>, Error>;
+
+    /// Lists the references of this backend, along with their targets.
+    ///
+    /// When `glob` is given, only the references whose name matches it are
+    /// returned.
+    fn iterate(&self, glob: Option<&str>)
+               -> Result<Vec<(String, RefdbTarget)>, Error>;
+
+    /// Writes a reference, optionally only if it currently has the
+    /// `expected` target.
+    ///
+    /// `who` and `message` describe the update for the reflog, which the
+    /// backend is responsible for recording.
+    fn write(&self, name: &str, target: &RefdbTarget, force: bool,
+             expected: Option<&RefdbTarget>, who: Option<&Signature>,
+             message: Option<&str>) -> Result<(), Error>;
+
+    /// Renames a reference along with its reflog, returning its target.
+    fn rename(&self, old_name: &str, new_name: &str, force: bool,
+              who: Option<&Signature>, message: Option<&str>)
+              -> Result<RefdbTarget, Error>;
+
+    /// Deletes a reference along with its reflog, optionally only if it
+    /// currently has the `expected` target.
+    fn delete(&self, name: &str, expected: Option<&RefdbTarget>)
+              -> Result<(), Error>;
+
+    /// Compresses or optimizes the storage of references.
+    ///
+    /// By default nothing is done.
+    fn compress(&self) -> Result<(), Error> {
+        Ok(())
+    }
+
+    /// Checks whether a reference has a reflog.
+    ///
+    /// By default references never have one.
+    fn has_log(&self, _name: &str) -> Result<bool, Error> {
+        Ok(false)
+    }
+
+    /// Makes sure that a reference has a reflog, creating an empty one if
+    /// needed.
+    ///
+    /// By default this fails, as reflogs aren't kept.
+    fn ensure_log(&self, name: &str) -> Result<(), Error> {
+        Err(Error::new(ErrorCode::GenericError, ErrorClass::Reference,
+                       &format!("cannot create a reflog for '{}': reflogs \
+                                 are not kept by this backend", name)))
+    }
+
+    /// Reads the reflog of a reference, oldest entry first.
+    ///
+    /// By default references never have any reflog entries.
+    fn reflog(&self, _name: &str) -> Result<Vec<RefdbLogEntry>, Error> {
+        Ok(Vec::new())
+    }
+
+    /// Renames the reflog of a reference.
+    ///
+    /// By default nothing is done.
+    fn reflog_rename(&self, _old_name: &str, _new_name: &str)
+                     -> Result<(), Error> {
+        Ok(())
+    }
+
+    /// Deletes the reflog of a reference.
+    ///
+    /// By default nothing is done.
+    fn reflog_delete(&self, _name: &str) -> Result<(), Error> {
+        Ok(())
+    }
+}
+
+/// A `RefdbBackend` which keeps references and reflogs in memory.
+///
+/// Clones of this backend share the same references, so a clone may be kept
+/// around to inspect the references and reflogs of a repository using it.
+///
+/// Every update of a direct reference which has a committer is logged, and
+/// so is the update of `HEAD` when it points to the updated branch.
+#[derive(Clone, Default)]
+pub struct MemoryRefdbBackend {
+    inner: Arc<Mutex<MemoryRefs>>,
+}
+
+#[derive(Default)]
+struct MemoryRefs {
+    refs: BTreeMap<String, RefdbTarget>,
+    logs: BTreeMap<String, Vec<RefdbLogEntry>>,
+}
+
+impl MemoryRefdbBackend {
+    /// Creates a backend without any references.
+    pub fn new() -> MemoryRefdbBackend {
+        MemoryRefdbBackend::default()
+    }
+}
+
+fn not_found(name: &str) -> Error {
+    Error::new(ErrorCode::NotFound, ErrorClass::Reference,
+               &format!("reference '{}' not found", name))
+}
+
+fn check_expected(current: Option<&RefdbTarget>,
+                  expected: Option<&RefdbTarget>,
+                  name: &str) -> Result<(), Error> {
+    match expected {
+        Some(expected) if current != Some(expected) => {
+            Err(Error::new(ErrorCode::Modified, ErrorClass::Reference,
+                           &format!("old reference value does not match \
+                                     for '{}'", name)))
+        }
+        _ => Ok(()),
+    }
+}
+
+impl RefdbBackend for MemoryRefdbBackend {
+    fn lookup(&self, name: &str) -> Result<Option<RefdbTarget>, Error> {
+        Ok(self.inner.lock().unwrap().refs.get(name).cloned())
+    }
+
+    fn iterate(&self, glob: Option<&str>)
+               -> Result<Vec<(String, RefdbTarget)>, Error> {
+        let inner = self.inner.lock().unwrap();
+        Ok(inner.refs.iter().filter(|&(name, _)| {
+            glob.map(|g| glob_matches(g.as_bytes(), name.as_bytes()))
+                .unwrap_or(true)
+        }).map(|(name, target)| (name.clone(), target.clone())).collect())
+    }
+
+    fn write(&self, name: &str, target: &RefdbTarget, force: bool,
+             expected: Option<&RefdbTarget>, who: Option<&Signature>,
+             message: Option<&str>) -> Result<(), Error> {
+        let mut inner = self.inner.lock().unwrap();
+        let old = inner.refs.get(name).cloned();
+        try!(check_expected(old.as_ref(), expected, name));
+        if old.is_some() && !force && expected.is_none() {
+            return Err(Error::new(ErrorCode::Exists, ErrorClass::Reference,
+                                  &format!("failed to write reference '{}': \
+                                            a reference with that name \
+                                            already exists.", name)))
+        }
+        if let (&RefdbTarget::Direct(id_new), Some(who)) = (target, who) {
+            let id_old = match old {
+                Some(RefdbTarget::Direct(id)) => id,
+                _ => Oid::zero(),
+            };
+            let entry = RefdbLogEntry::new(id_old, id_new, who, message);
+            let head = RefdbTarget::Symbolic(name.to_string());
+            if name != "HEAD" && inner.refs.get("HEAD") == Some(&head) {
+                inner.logs.entry("HEAD".to_string()).or_insert(Vec::new())
+                     .push(entry.clone());
+            }
+            inner.logs.entry(name.to_string()).or_insert(Vec::new())
+                 .push(entry);
+        }
+        inner.refs.insert(name.to_string(), target.clone());
+        Ok(())
+    }
+
+    fn rename(&self, old_name: &str, new_name: &str, force: bool,
+              _who: Option<&Signature>, _message: Option<&str>)
+              -> Result<RefdbTarget, Error> {
+        let mut inner = self.inner.lock().unwrap();
+        if !inner.refs.contains_key(old_name) {
+            return Err(not_found(old_name))
+        }
+        if inner.refs.contains_key(new_name) && !force {
+            return Err(Error::new(ErrorCode::Exists, ErrorClass::Reference,
+                                  &format!("a reference with the name '{}' \
+                                            already exists", new_name)))
+        }
+        let target = inner.refs.remove(old_name).unwrap();
+        inner.refs.insert(new_name.to_string(), target.clone());
+        if let Some(log) = inner.logs.remove(old_name) {
+            inner.logs.insert(new_name.to_string(), log);
+        }
+        Ok(target)
+    }
+
+    fn delete(&self, name: &str, expected: Option<&RefdbTarget>)
+              -> Result<(), Error> {
+        let mut inner = self.inner.lock().unwrap();
+        if !inner.refs.contains_key(name) {
+            return Err(not_found(name))
+        }
+        try!(check_expected(inner.refs.get(name), expected, name));
+        inner.refs.remove(name);
+        inner.logs.remove(name);
+        Ok(())
+    }
+
+    fn has_log(&self, name: &str) -> Result<bool, Error> {
+        Ok(self.inner.lock().unwrap().logs.contains_key(name))
+    }
+
+    fn ensure_log(&self, name: &str) -> Result<(), Error> {
+        let mut inner = self.inner.lock().unwrap();
+        inner.logs.entry(name.to_string()).or_insert(Vec::new());
+        Ok(())
+    }
+
+    fn reflog(&self, name: &str) -> Result<Vec<RefdbLogEntry>, Error> {
+        let inner = self.inner.lock().unwrap();
+        Ok(inner.logs.get(name).cloned().unwrap_or(Vec::new()))
+    }
+
+    fn reflog_rename(&self, old_name: &str, new_name: &str)
+                     -> Result<(), Error> {
+        let mut inner = self.inner.lock().unwrap();
+        if let Some(log) = inner.logs.remove(old_name) {
+            inner.logs.insert(new_name.to_string(), log);
+        }
+        Ok(())
+    }
+
+    fn reflog_delete(&self, name: &str) -> Result<(), Error> {
+        self.inner.lock().unwrap().logs.remove(name);
+        Ok(())
+    }
+}
+
+// Matches a reference name against a glob in the same way as libgit2's
+// on-disk backend, where `*` may also match slashes.
+fn glob_matches(glob: &[u8], name: &[u8]) -> bool {
+    match glob.first() {
+        None => name.is_empty(),
+        Some(&b'*') => {
+            (0..name.len() + 1).any(|i| glob_matches(&glob[1..], &name[i..]))
+        }
+        Some(&b'?') => !name.is_empty() && glob_matches(&glob[1..], &name[1..]),
+        Some(&c) => {
+            name.first() == Some(&c) && glob_matches(&glob[1..], &name[1..])
+        }
+    }
+}
+
+/// Instance of a `git_refdb_backend`, must use `#[repr(C)]` to ensure that
+/// the C fields come first.
+#[repr(C)]
+struct RawRefdbBackend {
+    raw: git_refdb_backend,
+    obj: Box<RefdbBackend>,
+}
+
+/// Instance of a `git_reference_iterator` over the references listed by a
+/// `RefdbBackend`.
+#[repr(C)]
+struct RawRefdbIterator {
+    raw: git_reference_iterator,
+    refs: vec::IntoIter<(String, RefdbTarget)>,
+    // the name last returned by `next_name`, which must outlive the call
+    name: Option<CString>,
+}
+
+/// Same layout as `raw::git_refdb_backend`, whose callbacks can't be null,
+/// while `git_refdb_init_backend` leaves the unsupported ones null.
+#[repr(C)]
+#[allow(non_camel_case_types)]
+struct git_refdb_backend {
+    version: c_uint,
+    exists: Option<extern fn(*mut c_int,
+                             *mut raw::git_refdb_backend,
+                             *const c_char) -> c_int>,
+    lookup: Option<extern fn(*mut *mut raw::git_reference,
+                             *mut raw::git_refdb_backend,
+                             *const c_char) -> c_int>,
+    iterator: Option<extern fn(*mut *mut raw::git_reference_iterator,
+                               *mut raw::git_refdb_backend,
+                               *const c_char) -> c_int>,
+    write: Option<extern fn(*mut raw::git_refdb_backend,
+                            *const raw::git_reference,
+                            c_int,
+                            *const raw::git_signature,
+                            *const c_char,
+                            *const raw::git_oid,
+                            *const c_char) -> c_int>,
+    rename: Option<extern fn(*mut *mut raw::git_reference,
+                             *mut raw::git_refdb_backend,
+                             *const c_char,
+                             *const c_char,
+                             c_int,
+                             *const raw::git_signature,
+                             *const c_char) -> c_int>,
+    del: Option<extern fn(*mut raw::git_refdb_backend,
+                          *const c_char,
+                          *const raw::git_oid,
+                          *const c_char) -> c_int>,
+    compress: Option<extern fn(*mut raw::git_refdb_backend) -> c_int>,
+    has_log: Option<extern fn(*mut raw::git_refdb_backend,
+                              *const c_char) -> c_int>,
+    ensure_log: Option<extern fn(*mut raw::git_refdb_backend,
+                                 *const c_char) -> c_int>,
+    free: Option<extern fn(*mut raw::git_refdb_backend)>,
+    reflog_read: Option<extern fn(*mut *mut raw::git_reflog,
+                                  *mut raw::git_refdb_backend,
+                                  *const c_char) -> c_int>,
+    reflog_write: Option<extern fn(*mut raw::git_refdb_backend,
+                                   *mut raw::git_reflog) -> c_int>,
+    reflog_rename: Option<extern fn(*mut raw::git_refdb_backend,
+                                    *const c_char,
+                                    *const c_char) -> c_int>,
+    reflog_delete: Option<extern fn(*mut raw::git_refdb_backend,
+                                    *const c_char) -> c_int>,
+    lock: Option<extern fn(*mut *mut c_void,
+                           *mut raw::git_refdb_backend,
+                           *const c_char) -> c_int>,
+    unlock: Option<extern fn(*mut raw::git_refdb_backend,
+                             *mut c_void,
+                             c_int,
+                             c_int,
+                             *const raw::git_reference,
+                             *const raw::git_signature,
+                             *const c_char) -> c_int>,
+}
+
+/// Layout of a `git_reference_iterator`, which `libgit2-sys` leaves opaque.
+#[repr(C)]
+#[allow(non_camel_case_types)]
+struct git_reference_iterator {
+    db: *mut raw::git_refdb,
+    next: Option<extern fn(*mut *mut raw::git_reference,
+                           *mut raw::git_reference_iterator) -> c_int>,
+    next_name: Option<extern fn(*mut *const c_char,
+                                *mut raw::git_reference_iterator) -> c_int>,
+    free: Option<extern fn(*mut raw::git_reference_iterator)>,
+}
+
+unsafe fn backend_obj<'a>(backend: *mut raw::git_refdb_backend)
+                          -> &'a RefdbBackend {
+    &*(*(backend as *mut RawRefdbBackend)).obj
+}
+
+unsafe fn to_str<'a>(s: *const c_char) -> Result<&'a str, Error> {
+    str::from_utf8(CStr::from_ptr(s).to_bytes()).map_err(|_| {
+        Error::from_str("reference name is not valid utf-8")
+    })
+}
+
+unsafe fn opt_str<'a>(s: *const c_char) -> Result<Option<&'a str>, Error> {
+    if s.is_null() {
+        Ok(None)
+    } else {
+        to_str(s).map(Some)
+    }
+}
+
+unsafe fn expected_target(old_id: *const raw::git_oid,
+                          old_target: *const c_char)
+                          -> Result<Option<RefdbTarget>, Error> {
+    if !old_id.is_null() {
+        Ok(Some(RefdbTarget::Direct(Binding::from_raw(old_id))))
+    } else {
+        Ok(try!(opt_str(old_target)).map(|s| {
+            RefdbTarget::Symbolic(s.to_string())
+        }))
+    }
+}
+
+unsafe fn new_reference(name: &str, target: &RefdbTarget)
+                        -> Result<*mut raw::git_reference, Error> {
+    let name = try!(CString::new(name));
+    let reference = match *target {
+        RefdbTarget::Direct(ref id) => {
+            raw::git_reference__alloc(name.as_ptr(), id.raw(), ptr::null())
+        }
+        RefdbTarget::Symbolic(ref target) => {
+            let target = try!(CString::new(&target[..]));
+            raw::git_reference__alloc_symbolic(name.as_ptr(), target.as_ptr())
+        }
+    };
+    if reference.is_null() {
+        Err(Error::from_str("failed to allocate reference"))
+    } else {
+        Ok(reference)
+    }
+}
+
+extern fn backend_exists(exists: *mut c_int,
+                         backend: *mut raw::git_refdb_backend,
+                         name: *const c_char) -> c_int {
+    panic::wrap(|| unsafe {
+        let res = to_str(name).and_then(|name| {
+            backend_obj(backend).exists(name)
+        });
+        match res {
+            Ok(found) => {
+                *exists = found as c_int;
+                0
+            }
+            Err(e) => set_err(&e),
+        }
+    }).unwrap_or(-1)
+}
+
+extern fn backend_lookup(out: *mut *mut raw::git_reference,
+                         backend: *mut raw::git_refdb_backend,
+                         name: *const c_char) -> c_int {
+    panic::wrap(|| unsafe {
+        let res = to_str(name).and_then(|name| {
+            match try!(backend_obj(backend).lookup(name)) {
+                Some(target) => new_reference(name, &target),
+                None => Err(not_found(name)),
+            }
+        });
+        match res {
+            Ok(reference) => {
+                *out = reference;
+                0
+            }
+            Err(e) => set_err(&e),
+        }
+    }).unwrap_or(-1)
+}
+
+extern fn backend_iterator(out: *mut *mut raw::git_reference_iterator,
+                           backend: *mut raw::git_refdb_backend,
+                           glob: *const c_char) -> c_int {
+    panic::wrap(|| unsafe {
+        let res = opt_str(glob).and_then(|glob| {
+            backend_obj(backend).iterate(glob)
+        });
+        let refs = match res {
+            Ok(refs) => refs,
+            Err(e) => return set_err(&e),
+        };
+        let iter = Box::new(RawRefdbIterator {
+            raw: git_reference_iterator {
+                db: ptr::null_mut(),
+                next: Some(iterator_next),
+                next_name: Some(iterator_next_name),
+                free: Some(iterator_free),
+            },
+            refs: refs.into_iter(),
+            name: None,
+        });
+        *out = Box::into_raw(iter) as *mut raw::git_reference_iterator;
+        0
+    }).unwrap_or(-1)
+}
+
+extern fn backend_write(backend: *mut raw::git_refdb_backend,
+                        reference: *const raw::git_reference,
+                        force: c_int,
+                        who: *const raw::git_signature,
+                        message: *const c_char,
+                        old_id: *const raw::git_oid,
+                        old_target: *const c_char) -> c_int {
+    panic::wrap(|| unsafe {
+        let res = (|| {
+            let name = try!(to_str(raw::git_reference_name(reference)));
+            let target = match raw::git_reference_type(reference) {
+                raw::GIT_REF_OID => {
+                    let id = raw::git_reference_target(reference);
+                    RefdbTarget::Direct(Binding::from_raw(id))
+                }
+                _ => {
+                    let target = raw::git_reference_symbolic_target(reference);
+                    RefdbTarget::Symbolic(try!(to_str(target)).to_string())
+                }
+            };
+            let expected = try!(expected_target(old_id, old_target));
+            let who = if who.is_null() {
+                None
+            } else {
+                Some(signature::from_raw_const(&reference, who))
+            };
+            let message = try!(opt_str(message));
+            backend_obj(backend).write(name, &target, force != 0,
+                                       expected.as_ref(), who.as_ref(),
+                                       message)
+        })();
+        match res {
+            Ok(()) => 0,
+            Err(e) => set_err(&e),
+        }
+    }).unwrap_or(-1)
+}
+
+extern fn backend_rename(out: *mut *mut raw::git_reference,
+                         backend: *mut raw::git_refdb_backend,
+                         old_name: *const c_char,
+                         new_name: *const c_char,
+                         force: c_int,
+                         who: *const raw::git_signature,
+                         message: *const c_char) -> c_int {
+    panic::wrap(|| unsafe {
+        let res = (|| {
+            let old_name = try!(to_str(old_name));
+            let new_name = try!(to_str(new_name));
+            let who = if who.is_null() {
+                None
+            } else {
+                Some(signature::from_raw_const(&backend, who))
+            };
+            let message = try!(opt_str(message));
+            let target = try!(backend_obj(backend).rename(old_name, new_name,
+                                                          force != 0,
+                                                          who.as_ref(),
+                                                          message));
+            new_reference(new_name, &target)
+        })();
+        match res {
+            Ok(reference) => {
+                *out = reference;
+                0
+            }
+            Err(e) => set_err(&e),
+        }
+    }).unwrap_or(-1)
+}
+
+extern fn backend_delete(backend: *mut raw::git_refdb_backend,
+                         name: *const c_char,
+                         old_id: *const raw::git_oid,
+                         old_target: *const c_char) -> c_int {
+    panic::wrap(|| unsafe {
+        let res = (|| {
+            let name = try!(to_str(name));
+            let expected = try!(expected_target(old_id, old_target));
+            backend_obj(backend).delete(name, expected.as_ref())
+        })();
+        match res {
+            Ok(()) => 0,
+            Err(e) => set_err(&e),
+        }
+    }).unwrap_or(-1)
+}
+
+extern fn backend_compress(backend: *mut raw::git_refdb_backend) -> c_int {
+    panic::wrap(|| unsafe {
+        match backend_obj(backend).compress() {
+            Ok(()) => 0,
+            Err(e) => set_err(&e),
+        }
+    }).unwrap_or(-1)
+}
+
+extern fn backend_has_log(backend: *mut raw::git_refdb_backend,
+                          name: *const c_char) -> c_int {
+    panic::wrap(|| unsafe {
+        let res = to_str(name).and_then(|name| {
+            backend_obj(backend).has_log(name)
+        });
+        match res {
+            Ok(has_log) => has_log as c_int,
+            Err(e) => set_err(&e),
+        }
+    }).unwrap_or(-1)
+}
+
+extern fn backend_ensure_log(backend: *mut raw::git_refdb_backend,
+                             name: *const c_char) -> c_int {
+    panic::wrap(|| unsafe {
+        let res = to_str(name).and_then(|name| {
+            backend_obj(backend).ensure_log(name)
+        });
+        match res {
+            Ok(()) => 0,
+            Err(e) => set_err(&e),
+        }
+    }).unwrap_or(-1)
+}
+
+extern fn backend_free(backend: *mut raw::git_refdb_backend) {
+    let _ = panic::wrap(|| unsafe {
+        drop(Box::from_raw(backend as *mut RawRefdbBackend));
+    });
+}
+
+// libgit2 has no public way of creating a `git_reflog`, so reflogs can only
+// be read and written through `RefdbBackend`
+extern fn backend_reflog_read(_out: *mut *mut raw::git_reflog,
+                              _backend: *mut raw::git_refdb_backend,
+                              _name: *const c_char) -> c_int {
+    reflog_unsupported()
+}
+
+extern fn backend_reflog_write(_backend: *mut raw::git_refdb_backend,
+                               _reflog: *mut raw::git_reflog) -> c_int {
+    reflog_unsupported()
+}
+
+extern fn backend_reflog_rename(backend: *mut raw::git_refdb_backend,
+                                old_name: *const c_char,
+                                new_name: *const c_char) -> c_int {
+    panic::wrap(|| unsafe {
+        let res = (|| {
+            let old_name = try!(to_str(old_name));
+            let new_name = try!(to_str(new_name));
+            backend_obj(backend).reflog_rename(old_name, new_name)
+        })();
+        match res {
+            Ok(()) => 0,
+            Err(e) => set_err(&e),
+        }
+    }).unwrap_or(-1)
+}
+
+extern fn backend_reflog_delete(backend: *mut raw::git_refdb_backend,
+                                name: *const c_char) -> c_int {
+    panic::wrap(|| unsafe {
+        let res = to_str(name).and_then(|name| {
+            backend_obj(backend).reflog_delete(name)
+        });
+        match res {
+            Ok(()) => 0,
+            Err(e) => set_err(&e),
+        }
+    }).unwrap_or(-1)
+}
+
+fn reflog_unsupported() -> c_int {
+    unsafe {
+        set_err(&Error::from_str("the reflogs of custom reference backends \
+                                  must be accessed through the backend"))
+    }
+}
+
+unsafe fn iterator_obj<'a>(iter: *mut raw::git_reference_iterator)
+                           -> &'a mut RawRefdbIterator {
+    &mut *(iter as *mut RawRefdbIterator)
+}
+
+extern fn iterator_next(out: *mut *mut raw::git_reference,
+                        iter: *mut raw::git_reference_iterator) -> c_int {
+    panic::wrap(|| unsafe {
+        let (name, target) = match iterator_obj(iter).refs.next() {
+            Some(next) => next,
+            None => return raw::GIT_ITEROVER as c_int,
+        };
+        match new_reference(&name, &target) {
+            Ok(reference) => {
+                *out = reference;
+                0
+            }
+            Err(e) => set_err(&e),
+        }
+    }).unwrap_or(-1)
+}
+
+extern fn iterator_next_name(out: *mut *const c_char,
+                             iter: *mut raw::git_reference_iterator) -> c_int {
+    panic::wrap(|| unsafe {
+        let iter = iterator_obj(iter);
+        let name = match iter.refs.next() {
+            Some((name, _)) => name,
+            None => return raw::GIT_ITEROVER as c_int,
+        };
+        match CString::new(name) {
+            Ok(name) => {
+                *out = name.as_ptr();
+                iter.name = Some(name);
+                0
+            }
+            Err(e) => set_err(&Error::from(e)),
+        }
+    }).unwrap_or(-1)
+}
+
+extern fn iterator_free(iter: *mut raw::git_reference_iterator) {
+    let _ = panic::wrap(|| unsafe {
+        drop(Box::from_raw(iter as *mut RawRefdbIterator));
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use {ErrorCode, MemoryRefdbBackend, Oid, Refdb, RefdbBackend, RefdbTarget};
+
+    #[test]
+    fn smoke() {
+        let (_td, repo) = ::test::repo_init();
+        let refdb = repo.refdb().unwrap();
+        refdb.compress().unwrap();
+        let refdb = Refdb::open(&repo).unwrap();
+        repo.set_refdb(&refdb);
+        assert!(repo.find_reference("refs/heads/master").is_ok());
+    }
+
+    #[test]
+    fn memory_backend() {
+        let (_td, repo) = ::test::repo_init();
+        let backend = MemoryRefdbBackend::new();
+        {
+            let refdb = Refdb::new(&repo).unwrap();
+            refdb.set_backend(backend.clone()).unwrap();
+            repo.set_refdb(&refdb);
+        }
+        assert_eq!(repo.head().err().unwrap().code(), ErrorCode::NotFound);
+        repo.reference_symbolic("HEAD", "refs/heads/master", true, "init")
+            .unwrap();
+
+        let sig = repo.signature().unwrap();
+        let tree_id = repo.index().unwrap().write_tree().unwrap();
+        let tree = repo.find_tree(tree_id).unwrap();
+        let first = repo.commit(Some("HEAD"), &sig, &sig, "first", &tree, &[])
+                        .unwrap();
+        let parent = repo.find_commit(first).unwrap();
+        let second = repo.commit(Some("HEAD"), &sig, &sig, "second", &tree,
+                                 &[&parent]).unwrap();
+        assert_eq!(repo.head().unwrap().target(), Some(second));
+        assert_eq!(backend.lookup("refs/heads/master").unwrap(),
+                   Some(RefdbTarget::Direct(second)));
+        assert_eq!(backend.lookup("HEAD").unwrap(),
+                   Some(RefdbTarget::Symbolic("refs/heads/master".to_string())));
+
+        // updates are compare-and-swap
+        let err = repo.reference_matching("refs/heads/master", first, true,
+                                          first, "stale").err().unwrap();
+        assert_eq!(err.code(), ErrorCode::Modified);
+        let err = repo.reference("refs/heads/master", first, false, "exists")
+                      .err().unwrap();
+        assert_eq!(err.code(), ErrorCode::Exists);
+        repo.reference_matching("refs/heads/master", first, true, second,
+                                "reset").unwrap();
+        repo.reference("refs/heads/topic", second, false, "topic").unwrap();
+        repo.reference("refs/tags/v1", first, false, "tag").unwrap();
+
+        let names = repo.references_glob("refs/heads/*").unwrap()
+                        .names().map(|n| n.unwrap().to_string())
+                        .collect::<Vec<_>>();
+        assert_eq!(names, ["refs/heads/master", "refs/heads/topic"]);
+        assert_eq!(repo.references().unwrap().count(), 4);
+
+        // reflogs are kept by the backend
+        let log = backend.reflog("refs/heads/master").unwrap();
+        assert_eq!(log.iter().map(|e| (e.id_old, e.id_new))
+                      .collect::<Vec<_>>(),
+                   [(Oid::zero(), first), (first, second), (second, first)]);
+        assert_eq!(log[1].message.as_ref().map(|s| &s[..]),
+                   Some("commit: second"));
+        assert_eq!(log[2].committer_name, sig.name().unwrap());
+        assert_eq!(backend.reflog("HEAD").unwrap().len(), 3);
+        assert!(repo.reference_has_log("refs/heads/master").unwrap());
+        assert!(!repo.reference_has_log("refs/tags/v2").unwrap());
+        repo.reference_ensure_log("refs/tags/v2").unwrap();
+        assert!(repo.reference_has_log("refs/tags/v2").unwrap());
+        assert!(repo.reflog("refs/heads/master").is_err());
+
+        let mut topic = repo.find_reference("refs/heads/topic").unwrap();
+        let mut renamed = topic.rename("refs/heads/renamed", false, "rename")
+                               .unwrap();
+        assert_eq!(renamed.target(), Some(second));
+        assert!(repo.find_reference("refs/heads/topic").is_err());
+        assert_eq!(backend.lookup("refs/heads/renamed").unwrap(),
+                   Some(RefdbTarget::Direct(second)));
+        assert!(!backend.has_log("refs/heads/topic").unwrap());
+        assert_eq!(backend.reflog("refs/heads/renamed").unwrap().len(), 1);
+        renamed.delete().unwrap();
+        assert_eq!(backend.lookup("refs/heads/renamed").unwrap(), None);
+        assert!(!backend.has_log("refs/heads/renamed").unwrap());
+    }
+}
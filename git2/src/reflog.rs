@@ -115,7 +115,7 @@ impl<'reflog> ReflogEntry<'reflog> {
 
     /// Get the old oid
     pub fn id_old(&self) -> Oid {
-        unsafe { Binding::from_raw(raw::git_reflog_entry_id_new(self.raw)) }
+        unsafe { Binding::from_raw(raw::git_reflog_entry_id_old(self.raw)) }
     }
 
     /// Get the log message, returning `None` on invalid UTF-8.
@@ -169,4 +169,23 @@ mod tests {
         repo.reflog_rename("HEAD", "refs/heads/foo").unwrap();
         repo.reflog_delete("refs/heads/foo").unwrap();
     }
+
+    #[test]
+    fn entry_ids() {
+        let (_td, repo) = ::test::repo_init();
+        let first = repo.head().unwrap().target().unwrap();
+        let sig = repo.signature().unwrap();
+        let tree = repo.find_commit(first).unwrap().tree().unwrap();
+        let parent = repo.find_commit(first).unwrap();
+        let second = repo.commit(Some("HEAD"), &sig, &sig, "second", &tree,
+                                 &[&parent]).unwrap();
+
+        let reflog = repo.reflog("HEAD").unwrap();
+        let entry = reflog.get(0).unwrap();
+        assert_eq!(entry.id_old(), first);
+        assert_eq!(entry.id_new(), second);
+        let entry = reflog.get(1).unwrap();
+        assert!(entry.id_old().is_zero());
+        assert_eq!(entry.id_new(), first);
+    }
 }
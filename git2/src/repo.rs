@@ -14,6 +14,9 @@ use {AnnotatedCommit, MergeOptions, SubmoduleIgnore, SubmoduleStatus, MergeAnaly
 use {ObjectType, Tag, Note, Notes, StatusOptions, Statuses, Status, Revwalk};
 use {RevparseMode, RepositoryInitMode, Reflog, IntoCString, Describe};
 use {DescribeOptions, TreeBuilder, Diff, DiffOptions, PackBuilder, Odb};
+use {Worktree, WorktreeAddOptions, Rebase, RebaseOptions, CherrypickOptions};
+use {ApplyLocation, ApplyOptions, Mailmap, Refdb, RevertOptions};
+use {apply, blame, mailmap};
 use build::{RepoBuilder, CheckoutBuilder};
 use stash::{StashApplyOptions, StashCbData, stash_cb};
 use string_array::StringArray;
@@ -155,6 +158,20 @@ impl Repository {
         Repository::open(util::bytes2path(&*buf))
     }
 
+    /// Opens the repository of a linked worktree.
+    ///
+    /// The working directory of the returned repository is the one of the
+    /// worktree, while its objects and references are shared with the parent
+    /// repository.
+    pub fn open_from_worktree(worktree: &Worktree) -> Result<Repository, Error> {
+        let mut ret = ptr::null_mut();
+        unsafe {
+            try_call!(raw::git_repository_open_from_worktree(&mut ret,
+                                                             worktree.raw()));
+            Ok(Binding::from_raw(ret))
+        }
+    }
+
     /// Creates a new repository in the specified folder.
     ///
     /// This by default will create any necessary directories to create the
@@ -929,6 +946,23 @@ impl Repository {
         }
     }
 
+    /// Get the reference database for this repository
+    pub fn refdb(&self) -> Result<Refdb, Error> {
+        let mut refdb = ptr::null_mut();
+        unsafe {
+            try_call!(raw::git_repository_refdb(&mut refdb, self.raw()));
+            Ok(Refdb::from_raw(refdb))
+        }
+    }
+
+    /// Sets the reference database of this repository, through which all
+    /// references and reflogs are read and written from now on.
+    pub fn set_refdb(&self, refdb: &Refdb) {
+        unsafe {
+            raw::git_repository_set_refdb(self.raw(), refdb.raw());
+        }
+    }
+
     /// Create a new branch pointing at a target commit
     ///
     /// A new direct reference will be created pointing to this target commit.
@@ -1568,6 +1602,129 @@ impl Repository {
         }
     }
 
+    /// Starts a rebase of the commits of `branch` which are not in `upstream`
+    /// onto `onto`.
+    ///
+    /// `branch` defaults to `HEAD` and `onto` to `upstream`, while a missing
+    /// `upstream` rebases all of the commits reachable from `branch`. Unless
+    /// an in-memory rebase is requested, `onto` is checked out and the
+    /// repository enters the rebase state.
+    ///
+    /// The options stay borrowed by the returned rebase, which uses them for
+    /// each of its operations.
+    pub fn rebase<'a, 'cb>(&'a self,
+                           branch: Option<&AnnotatedCommit>,
+                           upstream: Option<&AnnotatedCommit>,
+                           onto: Option<&AnnotatedCommit>,
+                           opts: Option<&'a mut RebaseOptions<'cb>>)
+                           -> Result<Rebase<'a>, Error> {
+        let mut rebase = ptr::null_mut();
+        unsafe {
+            let opts = opts.map(|o| o.raw() as *const _);
+            try_call!(raw::git_rebase_init(&mut rebase, self.raw(),
+                                           branch.map(|c| c.raw()),
+                                           upstream.map(|c| c.raw()),
+                                           onto.map(|c| c.raw()),
+                                           opts));
+            Ok(Binding::from_raw(rebase))
+        }
+    }
+
+    /// Resumes the rebase which is in progress in this repository.
+    ///
+    /// The options stay borrowed by the returned rebase, which uses them for
+    /// each of its operations.
+    pub fn open_rebase<'a, 'cb>(&'a self,
+                                opts: Option<&'a mut RebaseOptions<'cb>>)
+                                -> Result<Rebase<'a>, Error> {
+        let mut rebase = ptr::null_mut();
+        unsafe {
+            let opts = opts.map(|o| o.raw() as *const _);
+            try_call!(raw::git_rebase_open(&mut rebase, self.raw(), opts));
+            Ok(Binding::from_raw(rebase))
+        }
+    }
+
+    /// Applies the changes of a commit to the index and the working
+    /// directory, leaving the repository in the cherry-pick state.
+    ///
+    /// The changes are not committed, and any conflicts are recorded in the
+    /// index.
+    pub fn cherrypick(&self,
+                      commit: &Commit,
+                      opts: Option<&mut CherrypickOptions>)
+                      -> Result<(), Error> {
+        unsafe {
+            let raw_opts = opts.map(|o| o.raw());
+            try_call!(raw::git_cherrypick(self.raw, commit.raw(),
+                                          raw_opts.as_ref()));
+        }
+        Ok(())
+    }
+
+    /// Applies the changes of `cherrypick_commit` on top of `our_commit`,
+    /// producing an index that reflects the result, without touching the
+    /// working directory.
+    ///
+    /// For a merge commit, `mainline` is the parent, starting from 1, whose
+    /// changes are compared with it; it is 0 otherwise.
+    pub fn cherrypick_commit(&self,
+                             cherrypick_commit: &Commit,
+                             our_commit: &Commit,
+                             mainline: u32,
+                             opts: Option<&MergeOptions>)
+                             -> Result<Index, Error> {
+        let mut ret = ptr::null_mut();
+        unsafe {
+            try_call!(raw::git_cherrypick_commit(&mut ret, self.raw,
+                                                 cherrypick_commit.raw(),
+                                                 our_commit.raw(),
+                                                 mainline as c_uint,
+                                                 opts.map(|o| o.raw())));
+            Ok(Binding::from_raw(ret))
+        }
+    }
+
+    /// Reverts the changes of a commit in the index and the working
+    /// directory, leaving the repository in the revert state.
+    ///
+    /// The changes are not committed, and any conflicts are recorded in the
+    /// index.
+    pub fn revert(&self,
+                  commit: &Commit,
+                  opts: Option<&mut RevertOptions>)
+                  -> Result<(), Error> {
+        unsafe {
+            let raw_opts = opts.map(|o| o.raw());
+            try_call!(raw::git_revert(self.raw, commit.raw(),
+                                      raw_opts.as_ref()));
+        }
+        Ok(())
+    }
+
+    /// Reverts the changes of `revert_commit` on top of `our_commit`,
+    /// producing an index that reflects the result, without touching the
+    /// working directory.
+    ///
+    /// For a merge commit, `mainline` is the parent, starting from 1, whose
+    /// changes are compared with it; it is 0 otherwise.
+    pub fn revert_commit(&self,
+                         revert_commit: &Commit,
+                         our_commit: &Commit,
+                         mainline: u32,
+                         opts: Option<&MergeOptions>)
+                         -> Result<Index, Error> {
+        let mut ret = ptr::null_mut();
+        unsafe {
+            try_call!(raw::git_revert_commit(&mut ret, self.raw,
+                                             revert_commit.raw(),
+                                             our_commit.raw(),
+                                             mainline as c_uint,
+                                             opts.map(|o| o.raw())));
+            Ok(Binding::from_raw(ret))
+        }
+    }
+
     /// Add a note for an object
     ///
     /// The `notes_ref` argument is the canonical name of the reference to use,
@@ -1667,7 +1824,21 @@ impl Repository {
         }
     }
 
+    /// Load the mailmap of this repository.
+    ///
+    /// Like git, this reads the `.mailmap` file of the working directory,
+    /// then the blob named by the `mailmap.blob` configuration, which
+    /// defaults to `HEAD:.mailmap` in bare repositories, and then the file
+    /// named by the `mailmap.file` configuration. Entries read later
+    /// override the earlier ones.
+    pub fn mailmap(&self) -> Result<Mailmap, Error> {
+        mailmap::from_repository(self)
+    }
+
     /// Get the blame for a single file.
+    ///
+    /// When the options ask for it, the signatures of the hunks are mapped
+    /// with the mailmap of this repository, see `Repository::mailmap`.
     pub fn blame_file(&self, path: &Path, opts: Option<&mut BlameOptions>)
                       -> Result<Blame, Error> {
         let path = try!(path.into_c_string());
@@ -1677,8 +1848,12 @@ impl Repository {
             try_call!(raw::git_blame_file(&mut raw,
                                           self.raw(),
                                           path,
-                                          opts.map(|s| s.raw())));
-            Ok(Binding::from_raw(raw))
+                                          opts.as_ref().map(|s| s.raw())));
+            let mut blame = Binding::from_raw(raw);
+            if let Some(opts) = opts {
+                try!(blame::resolve_mailmap(self, &mut blame, opts));
+            }
+            Ok(blame)
         }
     }
 
@@ -1948,6 +2123,31 @@ impl Repository {
         }
     }
 
+    /// Applies a diff to the working directory, the index or both, like
+    /// `git apply`.
+    ///
+    /// Every delta of the diff is patched before anything is written, so
+    /// nothing is modified if any hunk fails to apply. Files which are added
+    /// by the diff must not exist yet, or `ErrorCode::Exists` is returned.
+    pub fn apply(&self,
+                 diff: &Diff,
+                 location: ApplyLocation,
+                 options: Option<&mut ApplyOptions>) -> Result<(), Error> {
+        apply::apply(self, diff, location, options)
+    }
+
+    /// Applies a diff to a tree, returning the resulting index.
+    ///
+    /// Neither the working directory nor the index of the repository are
+    /// modified, the new blobs are only written to the object database.
+    pub fn apply_to_tree(&self,
+                         tree: &Tree,
+                         diff: &Diff,
+                         options: Option<&mut ApplyOptions>)
+                         -> Result<Index, Error> {
+        apply::apply_to_tree(self, tree, diff, options)
+    }
+
     /// Create a PackBuilder
     pub fn packbuilder(&self) -> Result<PackBuilder, Error> {
         let mut ret = ptr::null_mut();
@@ -2051,6 +2251,48 @@ impl Repository {
         }
         Ok(ignored == 1)
     }
+
+    /// List the names of the linked worktrees of this repository.
+    pub fn worktrees(&self) -> Result<StringArray, Error> {
+        let mut arr = raw::git_strarray {
+            strings: 0 as *mut *mut c_char,
+            count: 0,
+        };
+        unsafe {
+            try_call!(raw::git_worktree_list(&mut arr, self.raw));
+            Ok(Binding::from_raw(arr))
+        }
+    }
+
+    /// Lookup a linked worktree of this repository by its name.
+    pub fn find_worktree(&self, name: &str) -> Result<Worktree, Error> {
+        let name = try!(CString::new(name));
+        let mut raw = ptr::null_mut();
+        unsafe {
+            try_call!(raw::git_worktree_lookup(&mut raw, self.raw, name));
+            Ok(Binding::from_raw(raw))
+        }
+    }
+
+    /// Add a new worktree named `name` in the directory `path`.
+    ///
+    /// The worktree checks out a new branch, also called `name`, created at
+    /// the commit pointed to by `HEAD`.
+    pub fn worktree_add<P: AsRef<Path>>(&self,
+                                        name: &str,
+                                        path: P,
+                                        opts: Option<&WorktreeAddOptions>)
+                                        -> Result<Worktree, Error> {
+        let name = try!(CString::new(name));
+        let path = try!(path.as_ref().into_c_string());
+        let mut raw = ptr::null_mut();
+        unsafe {
+            let opts = opts.map(|o| o.raw());
+            try_call!(raw::git_worktree_add(&mut raw, self.raw, name, path,
+                                            opts));
+            Ok(Binding::from_raw(raw))
+        }
+    }
 }
 
 impl Binding for Repository {
@@ -0,0 +1,106 @@
+use std::mem;
+use std::ptr;
+use libc::c_uint;
+
+use {raw, MergeOptions};
+use build::CheckoutBuilder;
+
+/// Options which can be used to customize how a commit is reverted.
+pub struct RevertOptions<'cb> {
+    mainline: u32,
+    merge_options: Option<MergeOptions>,
+    checkout_options: Option<CheckoutBuilder<'cb>>,
+}
+
+impl<'cb> Default for RevertOptions<'cb> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'cb> RevertOptions<'cb> {
+    /// Creates a default set of revert options.
+    pub fn new() -> RevertOptions<'cb> {
+        RevertOptions {
+            mainline: 0,
+            merge_options: None,
+            checkout_options: None,
+        }
+    }
+
+    /// Sets the parent, starting from 1, whose changes are compared with the
+    /// reverted commit when it is a merge commit.
+    pub fn mainline(&mut self, mainline: u32) -> &mut RevertOptions<'cb> {
+        self.mainline = mainline;
+        self
+    }
+
+    /// Options to use when merging the changes of the commit.
+    pub fn merge_options(&mut self, opts: MergeOptions) -> &mut RevertOptions<'cb> {
+        self.merge_options = Some(opts);
+        self
+    }
+
+    /// Options to use when writing files to the working directory.
+    pub fn checkout_options(&mut self, opts: CheckoutBuilder<'cb>)
+                            -> &mut RevertOptions<'cb> {
+        self.checkout_options = Some(opts);
+        self
+    }
+
+    /// Obtain the raw options for this revert.
+    ///
+    /// # Safety
+    ///
+    /// The returned options borrow the checkout options of this structure,
+    /// which must outlive them.
+    pub unsafe fn raw(&mut self) -> raw::git_revert_options {
+        let mut opts = mem::zeroed();
+        assert_eq!(raw::git_revert_init_options(
+            &mut opts, raw::GIT_REVERT_OPTIONS_VERSION), 0);
+        opts.mainline = self.mainline as c_uint;
+        if let Some(merge) = self.merge_options.as_ref() {
+            ptr::copy_nonoverlapping(merge.raw(), &mut opts.merge_opts, 1);
+        }
+        if let Some(checkout) = self.checkout_options.as_mut() {
+            checkout.configure(&mut opts.checkout_opts);
+        }
+        opts
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use {ResetType, RevertOptions, RepositoryState};
+
+    #[test]
+    fn smoke() {
+        let (td, repo) = ::test::repo_init();
+        let head = t!(repo.head()).target().unwrap();
+        let head = t!(repo.find_commit(head));
+
+        // commit a file, then undo it
+        let blob = t!(repo.blob(b"reverted"));
+        let mut builder = t!(repo.treebuilder(Some(&t!(head.tree()))));
+        t!(builder.insert(Path::new("file"), blob, 0o100644));
+        let tree = t!(repo.find_tree(t!(builder.write())));
+        let sig = t!(repo.signature());
+        let id = t!(repo.commit(Some("HEAD"), &sig, &sig, "add", &tree,
+                                &[&head]));
+        let commit = t!(repo.find_commit(id));
+        t!(repo.reset(commit.as_object(), ResetType::Hard, None));
+        assert!(td.path().join("file").exists());
+
+        let mut index = t!(repo.revert_commit(&commit, &commit, 0, None));
+        assert!(!index.has_conflicts());
+        assert_eq!(t!(index.write_tree_to(&repo)), head.tree_id());
+
+        t!(repo.revert(&commit, Some(&mut RevertOptions::new())));
+        assert_eq!(repo.state(), RepositoryState::Revert);
+        assert!(t!(repo.index()).get_path(Path::new("file"), 0).is_none());
+        assert!(!td.path().join("file").exists());
+        t!(repo.cleanup_state());
+    }
+}
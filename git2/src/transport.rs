@@ -12,6 +12,8 @@ use libc::{c_int, c_void, c_uint, c_char, size_t};
 use {raw, panic, Error, Remote};
 use util::Binding;
 
+pub use transport_memory::{memory_url, register_memory};
+
 /// A transport is a structure which knows how to transfer data to and from a
 /// remote.
 ///
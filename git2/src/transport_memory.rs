@@ -0,0 +1,490 @@
+use std::io::prelude::*;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::str;
+use std::sync::atomic::{AtomicBool, Ordering, ATOMIC_BOOL_INIT};
+
+use {Buf, Error, Indexer, ObjectType, Oid, Reference, Repository};
+use transport::{self, Service, SmartSubtransport, SmartSubtransportStream};
+use transport::Transport;
+
+static REGISTERED: AtomicBool = ATOMIC_BOOL_INIT;
+
+/// Registers the `mem` transport, which serves the repository at the path of
+/// a `mem://` url through an in-process implementation of the server side of
+/// the smart protocol (`git-upload-pack` and `git-receive-pack`).
+///
+/// This allows `Remote::fetch` and `Remote::push` between two local
+/// repositories to go through the smart protocol without spawning processes
+/// or opening sockets, which is mostly useful in tests. Urls for this
+/// transport are created with `memory_url`.
+///
+/// Registering the transport more than once has no effect.
+///
+/// # Safety
+///
+/// This function is unsafe for the same reasons as `register`.
+pub unsafe fn register_memory() -> Result<(), Error> {
+    if REGISTERED.swap(true, Ordering::SeqCst) {
+        return Ok(())
+    }
+    let ret = transport::register("mem", |remote| {
+        Transport::smart(remote, true, MemorySubtransport)
+    });
+    if ret.is_err() {
+        REGISTERED.store(false, Ordering::SeqCst);
+    }
+    ret
+}
+
+/// Returns the `mem://` url of the repository at `path`, see
+/// `register_memory`.
+pub fn memory_url(path: &Path) -> String {
+    format!("mem://{}", path.display())
+}
+
+struct MemorySubtransport;
+
+impl SmartSubtransport for MemorySubtransport {
+    fn action(&self, url: &str, action: Service)
+              -> Result<Box<SmartSubtransportStream>, Error> {
+        if !url.starts_with("mem://") {
+            return Err(Error::from_str(&format!("invalid memory url: {}",
+                                                url)))
+        }
+        Ok(Box::new(MemoryStream {
+            path: PathBuf::from(&url["mem://".len()..]),
+            service: action,
+            request: Vec::new(),
+            response: None,
+        }))
+    }
+
+    fn close(&self) -> Result<(), Error> {
+        Ok(())
+    }
+}
+
+// The transport is stateless like http: each action sends a whole request,
+// which is served once the client starts reading the response.
+struct MemoryStream {
+    path: PathBuf,
+    service: Service,
+    request: Vec<u8>,
+    response: Option<io::Cursor<Vec<u8>>>,
+}
+
+impl Read for MemoryStream {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if self.response.is_none() {
+            let response = match serve(&self.path, self.service,
+                                       &self.request) {
+                Ok(response) => response,
+                Err(e) => return Err(io::Error::new(io::ErrorKind::Other, e)),
+            };
+            self.response = Some(io::Cursor::new(response));
+        }
+        self.response.as_mut().unwrap().read(buf)
+    }
+}
+
+impl Write for MemoryStream {
+    fn write(&mut self, data: &[u8]) -> io::Result<usize> {
+        if self.response.is_some() {
+            return Err(io::Error::new(io::ErrorKind::Other,
+                                      "request already served"))
+        }
+        self.request.extend_from_slice(data);
+        Ok(data.len())
+    }
+    fn flush(&mut self) -> io::Result<()> { Ok(()) }
+}
+
+fn serve(path: &Path, service: Service, request: &[u8])
+         -> Result<Vec<u8>, Error> {
+    let repo = try!(Repository::open(path));
+    match service {
+        Service::UploadPackLs => advertise(&repo, "git-upload-pack"),
+        Service::ReceivePackLs => advertise(&repo, "git-receive-pack"),
+        Service::UploadPack => upload_pack(&repo, request),
+        Service::ReceivePack => receive_pack(&repo, request),
+    }
+}
+
+// Writes the reference advertisement, preceded by the service announcement
+// of the stateless protocol.
+fn advertise(repo: &Repository, service: &str) -> Result<Vec<u8>, Error> {
+    let upload = service == "git-upload-pack";
+    let mut out = Vec::new();
+    write_pkt(&mut out, format!("# service={}\n", service).as_bytes());
+    write_flush(&mut out);
+
+    let mut refs = Vec::new();
+    for reference in try!(repo.references()) {
+        let reference = try!(reference);
+        let name = match reference.name() {
+            Some(name) => name.to_string(),
+            None => continue,
+        };
+        let id = match reference.resolve().ok().and_then(|r| r.target()) {
+            Some(id) => id,
+            None => continue,
+        };
+        refs.push((name, id));
+    }
+    refs.sort();
+
+    let mut caps = if upload {
+        "multi_ack_detailed".to_string()
+    } else {
+        "report-status delete-refs".to_string()
+    };
+    if upload {
+        if let Ok(head) = repo.find_reference("HEAD") {
+            if let Some(target) = head.symbolic_target() {
+                caps.push_str(&format!(" symref=HEAD:{}", target));
+            }
+        }
+        if let Ok(id) = repo.refname_to_id("HEAD") {
+            refs.insert(0, ("HEAD".to_string(), id));
+        }
+    }
+
+    if refs.is_empty() {
+        let line = format!("{} capabilities^{{}}\0{}\n", Oid::zero(), caps);
+        write_pkt(&mut out, line.as_bytes());
+    }
+    for (i, &(ref name, id)) in refs.iter().enumerate() {
+        let line = if i == 0 {
+            format!("{} {}\0{}\n", id, name, caps)
+        } else {
+            format!("{} {}\n", id, name)
+        };
+        write_pkt(&mut out, line.as_bytes());
+        if upload {
+            if let Some(peeled) = peel_tag(repo, id) {
+                write_pkt(&mut out,
+                          format!("{} {}^{{}}\n", peeled, name).as_bytes());
+            }
+        }
+    }
+    write_flush(&mut out);
+    Ok(out)
+}
+
+fn peel_tag(repo: &Repository, id: Oid) -> Option<Oid> {
+    let mut peeled = None;
+    let mut id = id;
+    while let Ok(tag) = repo.find_tag(id) {
+        id = tag.target_id();
+        peeled = Some(id);
+    }
+    peeled
+}
+
+// Serves a round of the negotiation, or the pack once the client is done.
+fn upload_pack(repo: &Repository, request: &[u8]) -> Result<Vec<u8>, Error> {
+    let mut wants = Vec::new();
+    let mut haves = Vec::new();
+    let mut done = false;
+    let mut pos = 0;
+    while pos < request.len() && !done {
+        let line = match try!(read_pkt(request, &mut pos)) {
+            Some(line) => try!(pkt_str(line)),
+            None => continue,
+        };
+        let line = line.trim_right_matches('\n');
+        let mut parts = line.splitn(2, ' ');
+        match (parts.next(), parts.next()) {
+            (Some("want"), Some(id)) => wants.push(try!(parse_oid(id))),
+            (Some("have"), Some(id)) => haves.push(try!(parse_oid(id))),
+            (Some("done"), None) => done = true,
+            _ => {
+                return Err(Error::from_str(&format!("unexpected upload-pack \
+                                                     line: {}", line)))
+            }
+        }
+    }
+
+    let mut out = Vec::new();
+    let odb = try!(repo.odb());
+    let common = haves.into_iter()
+                      .filter(|have| odb.exists(*have))
+                      .collect::<Vec<_>>();
+    for have in common.iter() {
+        write_pkt(&mut out, format!("ACK {} common\n", have).as_bytes());
+    }
+    if !done {
+        write_pkt(&mut out, b"NAK\n");
+        return Ok(out)
+    }
+    match common.last() {
+        Some(last) => write_pkt(&mut out, format!("ACK {}\n", last).as_bytes()),
+        None => write_pkt(&mut out, b"NAK\n"),
+    }
+
+    let mut builder = try!(repo.packbuilder());
+    let mut walk = try!(repo.revwalk());
+    for want in wants {
+        let mut obj = try!(repo.find_object(want, None));
+        while obj.kind() == Some(ObjectType::Tag) {
+            try!(builder.insert_object(obj.id(), None));
+            obj = try!(obj.peel(ObjectType::Any));
+        }
+        if obj.kind() == Some(ObjectType::Commit) {
+            try!(walk.push(obj.id()));
+        } else {
+            try!(builder.insert_recursive(obj.id(), None));
+        }
+    }
+    for have in common {
+        if repo.find_commit(have).is_ok() {
+            try!(walk.hide(have));
+        }
+    }
+    try!(builder.insert_walk(&mut walk));
+    let mut buf = Buf::new();
+    try!(builder.write_buf(&mut buf));
+    out.extend_from_slice(&buf);
+    Ok(out)
+}
+
+// Indexes the pushed pack and updates the references, reporting the status
+// of each update if the client asked for it.
+fn receive_pack(repo: &Repository, request: &[u8]) -> Result<Vec<u8>, Error> {
+    let mut commands = Vec::new();
+    let mut report_status = false;
+    let mut pos = 0;
+    while pos < request.len() {
+        let line = match try!(read_pkt(request, &mut pos)) {
+            Some(line) => line,
+            None => break,
+        };
+        let (line, caps) = match line.iter().position(|b| *b == 0) {
+            Some(i) => (&line[..i], &line[i + 1..]),
+            None => (line, &[][..]),
+        };
+        if commands.is_empty() {
+            report_status = try!(pkt_str(caps)).split(' ')
+                                               .any(|c| c == "report-status");
+        }
+        let line = try!(pkt_str(line)).trim_right_matches('\n');
+        let mut parts = line.splitn(3, ' ');
+        match (parts.next(), parts.next(), parts.next()) {
+            (Some(old), Some(new), Some(name)) => {
+                commands.push((try!(parse_oid(old)), try!(parse_oid(new)),
+                               name.to_string()));
+            }
+            _ => {
+                return Err(Error::from_str(&format!("invalid receive-pack \
+                                                     command: {}", line)))
+            }
+        }
+    }
+
+    let pack = &request[pos..];
+    let unpack = if pack.is_empty() {
+        Ok(())
+    } else {
+        index_pack(repo, pack)
+    };
+    let mut out = Vec::new();
+    if !report_status {
+        if unpack.is_ok() {
+            for &(old, new, ref name) in commands.iter() {
+                let _ = update_ref(repo, old, new, name);
+            }
+        }
+        return Ok(out)
+    }
+    match unpack {
+        Ok(()) => write_pkt(&mut out, b"unpack ok\n"),
+        Err(ref e) => {
+            write_pkt(&mut out, format!("unpack {}\n", e.message()).as_bytes())
+        }
+    }
+    for &(old, new, ref name) in commands.iter() {
+        let status = if unpack.is_err() {
+            Err("unpacker error".to_string())
+        } else {
+            update_ref(repo, old, new, name)
+        };
+        let line = match status {
+            Ok(()) => format!("ok {}\n", name),
+            Err(reason) => format!("ng {} {}\n", name, reason),
+        };
+        write_pkt(&mut out, line.as_bytes());
+    }
+    write_flush(&mut out);
+    Ok(out)
+}
+
+fn index_pack(repo: &Repository, pack: &[u8]) -> Result<(), Error> {
+    let odb = try!(repo.odb());
+    let dir = repo.path().join("objects/pack");
+    {
+        let mut indexer = try!(Indexer::new(Some(&odb), &dir, 0));
+        try!(indexer.append(pack));
+        try!(indexer.commit());
+    }
+    odb.refresh()
+}
+
+// Applies a pushed update of a reference, returning the reason it was
+// rejected on failure like git-receive-pack does.
+fn update_ref(repo: &Repository, old: Oid, new: Oid, name: &str)
+              -> Result<(), String> {
+    if !name.starts_with("refs/") || !Reference::is_valid_name(name) {
+        return Err("funny refname".to_string())
+    }
+    if !repo.is_bare() {
+        if let Ok(head) = repo.find_reference("HEAD") {
+            if head.symbolic_target() == Some(name) {
+                return Err("branch is currently checked out".to_string())
+            }
+        }
+    }
+    let current = repo.find_reference(name).ok();
+    let current_id = match current {
+        Some(ref reference) => reference.target().unwrap_or(Oid::zero()),
+        None => Oid::zero(),
+    };
+    if current_id != old {
+        return Err("failed to lock".to_string())
+    }
+    if new.is_zero() {
+        return match current {
+            Some(mut reference) => {
+                reference.delete().map_err(|e| e.message().to_string())
+            }
+            None => Ok(()),
+        }
+    }
+    if repo.find_object(new, None).is_err() {
+        return Err("missing necessary objects".to_string())
+    }
+    repo.reference(name, new, true, "push").map(|_| ())
+        .map_err(|e| e.message().to_string())
+}
+
+fn write_pkt(out: &mut Vec<u8>, data: &[u8]) {
+    out.extend_from_slice(format!("{:04x}", data.len() + 4).as_bytes());
+    out.extend_from_slice(data);
+}
+
+fn write_flush(out: &mut Vec<u8>) {
+    out.extend_from_slice(b"0000");
+}
+
+// Reads the pkt-line at `pos`, returning `None` for a flush.
+fn read_pkt<'a>(data: &'a [u8], pos: &mut usize)
+                -> Result<Option<&'a [u8]>, Error> {
+    let invalid = || Error::from_str("invalid pkt-line");
+    if data.len() < *pos + 4 {
+        return Err(invalid())
+    }
+    let len = try!(str::from_utf8(&data[*pos..*pos + 4]).ok().and_then(|s| {
+        usize::from_str_radix(s, 16).ok()
+    }).ok_or_else(&invalid));
+    if len == 0 {
+        *pos += 4;
+        return Ok(None)
+    }
+    if len < 4 || data.len() < *pos + len {
+        return Err(invalid())
+    }
+    let line = &data[*pos + 4..*pos + len];
+    *pos += len;
+    Ok(Some(line))
+}
+
+fn pkt_str(line: &[u8]) -> Result<&str, Error> {
+    str::from_utf8(line).map_err(|_| Error::from_str("invalid pkt-line"))
+}
+
+fn parse_oid(s: &str) -> Result<Oid, Error> {
+    if s.len() < 40 {
+        return Err(Error::from_str(&format!("invalid object id: {}", s)))
+    }
+    Oid::from_str(&s[..40])
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use tempdir::TempDir;
+    use {PushOptions, RemoteCallbacks, Repository, Signature};
+    use transport::{memory_url, register_memory};
+
+    fn commit(repo: &Repository, refname: &str, parent: Option<&str>)
+              -> ::Oid {
+        let sig = t!(Signature::now("foo", "bar"));
+        let tree = t!(repo.treebuilder(None)).write().unwrap();
+        let tree = t!(repo.find_tree(tree));
+        let parents = parent.map(|p| t!(t!(repo.revparse_single(p))
+                                            .peel_to_commit()));
+        let parents = parents.iter().collect::<Vec<_>>();
+        t!(repo.commit(Some(refname), &sig, &sig, "commit", &tree, &parents))
+    }
+
+    #[test]
+    fn fetch_and_push() {
+        unsafe { t!(register_memory()); }
+        let (_td, server) = ::test::repo_init();
+        let td = TempDir::new("test").unwrap();
+        let client = t!(Repository::init(td.path()));
+        let url = memory_url(server.path());
+        let mut remote = t!(client.remote("origin", &url));
+
+        t!(remote.fetch(&["refs/heads/*:refs/remotes/origin/*"], None, None));
+        let head = t!(server.refname_to_id("refs/heads/master"));
+        assert_eq!(t!(client.refname_to_id("refs/remotes/origin/master")),
+                   head);
+
+        commit(&client, "refs/heads/local", None);
+        for _ in 0..30 {
+            commit(&client, "refs/heads/local", Some("refs/heads/local"));
+        }
+        let new = commit(&server, "refs/heads/master",
+                         Some("refs/heads/master"));
+        t!(remote.fetch(&["refs/heads/*:refs/remotes/origin/*"], None, None));
+        assert_eq!(t!(client.refname_to_id("refs/remotes/origin/master")),
+                   new);
+
+        let pushed = commit(&client, "refs/heads/feature",
+                            Some("refs/remotes/origin/master"));
+        t!(remote.push(&["refs/heads/feature:refs/heads/feature"], None));
+        assert_eq!(t!(server.refname_to_id("refs/heads/feature")), pushed);
+        t!(server.find_commit(pushed));
+
+        t!(remote.push(&[":refs/heads/feature"], None));
+        assert!(server.refname_to_id("refs/heads/feature").is_err());
+    }
+
+    #[test]
+    fn push_rejected() {
+        unsafe { t!(register_memory()); }
+        let (_td, server) = ::test::repo_init();
+        let td = TempDir::new("test").unwrap();
+        let client = t!(Repository::init(td.path()));
+        let mut remote = t!(client.remote_anonymous(&memory_url(server.path())));
+        commit(&client, "refs/heads/master", None);
+
+        let statuses = RefCell::new(Vec::new());
+        {
+            let mut callbacks = RemoteCallbacks::new();
+            callbacks.push_update_reference(|name, status| {
+                statuses.borrow_mut().push((name.to_string(),
+                                            status.map(|s| s.to_string())));
+                Ok(())
+            });
+            let mut opts = PushOptions::new();
+            opts.remote_callbacks(callbacks);
+            t!(remote.push(&["+refs/heads/master:refs/heads/master"],
+                           Some(&mut opts)));
+        }
+        assert_eq!(statuses.into_inner(),
+                   vec![("refs/heads/master".to_string(),
+                         Some("branch is currently checked out".to_string()))]);
+    }
+}
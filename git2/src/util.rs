@@ -1,7 +1,7 @@
 use std::ffi::{CString, OsStr, OsString};
 use std::iter::IntoIterator;
 use std::path::{Path, PathBuf};
-use libc::{c_char, size_t};
+use libc::{c_char, c_int, size_t};
 
 use {raw, Error};
 
@@ -150,3 +150,17 @@ pub fn into_opt_c_string<S>(opt_s: Option<S>) -> Result<Option<CString>, Error>
         Some(s) => Ok(Some(try!(s.into_c_string()))),
     }
 }
+
+/// Stores an error returned by a Rust callback as the last error of libgit2,
+/// returning the code to hand back to libgit2.
+///
+/// NUL bytes can't be passed to libgit2, so they are removed from the
+/// message.
+pub unsafe fn set_err(e: &Error) -> c_int {
+    let s = CString::new(e.message().replace('\0', "")).unwrap();
+    raw::giterr_set_str(e.raw_class() as c_int, s.as_ptr());
+    match e.raw_code() as c_int {
+        0 => -1,
+        code => code,
+    }
+}
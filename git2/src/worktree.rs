@@ -0,0 +1,291 @@
+use std::mem;
+use std::ptr;
+use std::str;
+use libc::{c_char, c_int};
+
+use {raw, Buf, Error, Repository};
+use util::Binding;
+
+/// A linked working tree of a repository.
+///
+/// Worktrees are created with `Repository::worktree_add` and looked up by name
+/// with `Repository::find_worktree`.
+pub struct Worktree {
+    raw: *mut raw::git_worktree,
+}
+
+/// Options which can be used to customize how a worktree is added.
+pub struct WorktreeAddOptions {
+    raw: raw::git_worktree_add_options,
+}
+
+/// Options which indicate which worktrees may be pruned.
+pub struct WorktreePruneOptions {
+    raw: raw::git_worktree_prune_options,
+}
+
+/// Whether a worktree is locked, along with the reason it was locked for.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum WorktreeLockStatus {
+    /// The worktree is not locked.
+    Unlocked,
+    /// The worktree is locked, with the reason given when it was locked if
+    /// any.
+    Locked(Option<String>),
+}
+
+impl Worktree {
+    /// Opens the worktree of a repository which is itself a linked worktree.
+    ///
+    /// This fails if `repo` is the main working tree of its repository.
+    pub fn open_from_repository(repo: &Repository) -> Result<Worktree, Error> {
+        let mut raw = ptr::null_mut();
+        unsafe {
+            try_call!(raw::git_worktree_open_from_repository(&mut raw,
+                                                             repo.raw()));
+            Ok(Binding::from_raw(raw))
+        }
+    }
+
+    /// Checks that the git data structures of this worktree inside the parent
+    /// repository are present.
+    pub fn validate(&self) -> Result<(), Error> {
+        unsafe {
+            try_call!(raw::git_worktree_validate(self.raw));
+        }
+        Ok(())
+    }
+
+    /// Locks this worktree, optionally recording why.
+    ///
+    /// A locked worktree is not pruned unless explicitly requested. It is an
+    /// error to lock a worktree which is already locked.
+    pub fn lock(&self, reason: Option<&str>) -> Result<(), Error> {
+        let reason = try!(::opt_cstr(reason));
+        let reason = reason.as_ref().map(|s| s.as_ptr() as *mut c_char)
+                           .unwrap_or(ptr::null_mut());
+        unsafe {
+            try_call!(raw::git_worktree_lock(self.raw, reason));
+        }
+        Ok(())
+    }
+
+    /// Unlocks this worktree. Nothing happens if it is not locked.
+    pub fn unlock(&self) -> Result<(), Error> {
+        unsafe {
+            try_call!(raw::git_worktree_unlock(self.raw));
+        }
+        Ok(())
+    }
+
+    /// Returns whether this worktree is locked, and the reason if one was
+    /// given.
+    pub fn is_locked(&self) -> Result<WorktreeLockStatus, Error> {
+        let buf = Buf::new();
+        unsafe {
+            match try_call!(raw::git_worktree_is_locked(buf.raw(), self.raw)) {
+                0 => Ok(WorktreeLockStatus::Unlocked),
+                _ => {
+                    let reason = match str::from_utf8(&buf) {
+                        Ok("") | Err(_) => None,
+                        Ok(reason) => Some(reason.to_owned()),
+                    };
+                    Ok(WorktreeLockStatus::Locked(reason))
+                }
+            }
+        }
+    }
+
+    /// Returns whether this worktree may be pruned with the given options.
+    ///
+    /// By default, only worktrees which are neither valid nor locked may be
+    /// pruned.
+    pub fn is_prunable(&self, opts: Option<&mut WorktreePruneOptions>)
+                       -> Result<bool, Error> {
+        let opts = opts.map(|o| o.raw());
+        unsafe {
+            Ok(try_call!(raw::git_worktree_is_prunable(self.raw, opts)) != 0)
+        }
+    }
+
+    /// Prunes this worktree, removing its git data structures from the parent
+    /// repository.
+    ///
+    /// This fails if the worktree is not prunable with the given options. The
+    /// working directory itself is only removed when
+    /// `WorktreePruneOptions::working_tree` is set.
+    pub fn prune(&self, opts: Option<&mut WorktreePruneOptions>)
+                 -> Result<(), Error> {
+        let opts = opts.map(|o| o.raw());
+        unsafe {
+            try_call!(raw::git_worktree_prune(self.raw, opts));
+        }
+        Ok(())
+    }
+}
+
+impl Binding for Worktree {
+    type Raw = *mut raw::git_worktree;
+
+    unsafe fn from_raw(raw: *mut raw::git_worktree) -> Worktree {
+        Worktree { raw: raw }
+    }
+    fn raw(&self) -> *mut raw::git_worktree { self.raw }
+}
+
+impl Drop for Worktree {
+    fn drop(&mut self) {
+        unsafe { raw::git_worktree_free(self.raw) }
+    }
+}
+
+impl Default for WorktreeAddOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl WorktreeAddOptions {
+    /// Creates a default set of options for adding a worktree.
+    pub fn new() -> WorktreeAddOptions {
+        let mut opts = WorktreeAddOptions {
+            raw: unsafe { mem::zeroed() },
+        };
+        opts.raw.version = raw::GIT_WORKTREE_ADD_OPTIONS_VERSION;
+        opts
+    }
+
+    /// Sets whether the new worktree is locked as soon as it is created.
+    pub fn lock(&mut self, lock: bool) -> &mut Self {
+        self.raw.lock = lock as c_int;
+        self
+    }
+}
+
+impl Binding for WorktreeAddOptions {
+    type Raw = *const raw::git_worktree_add_options;
+
+    unsafe fn from_raw(_raw: *const raw::git_worktree_add_options)
+                       -> WorktreeAddOptions {
+        panic!("unimplemened")
+    }
+    fn raw(&self) -> *const raw::git_worktree_add_options {
+        &self.raw
+    }
+}
+
+impl Default for WorktreePruneOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl WorktreePruneOptions {
+    /// Creates a default set of pruning options, which only allow pruning
+    /// worktrees that are invalid and unlocked.
+    pub fn new() -> WorktreePruneOptions {
+        unsafe {
+            let mut raw = mem::zeroed();
+            assert_eq!(raw::git_worktree_prune_init_options(
+                &mut raw, raw::GIT_WORKTREE_PRUNE_OPTIONS_VERSION), 0);
+            WorktreePruneOptions { raw: raw }
+        }
+    }
+
+    fn flag(&mut self, flag: raw::git_worktree_prune_t, on: bool) -> &mut Self {
+        if on {
+            self.raw.flags |= flag as u32;
+        } else {
+            self.raw.flags &= !(flag as u32);
+        }
+        self
+    }
+
+    /// Allows pruning a worktree even if it is still valid.
+    pub fn valid(&mut self, valid: bool) -> &mut Self {
+        self.flag(raw::GIT_WORKTREE_PRUNE_VALID, valid)
+    }
+
+    /// Allows pruning a worktree even if it is locked.
+    pub fn locked(&mut self, locked: bool) -> &mut Self {
+        self.flag(raw::GIT_WORKTREE_PRUNE_LOCKED, locked)
+    }
+
+    /// Also removes the working directory of the worktree when it is pruned.
+    pub fn working_tree(&mut self, working_tree: bool) -> &mut Self {
+        self.flag(raw::GIT_WORKTREE_PRUNE_WORKING_TREE, working_tree)
+    }
+}
+
+impl Binding for WorktreePruneOptions {
+    type Raw = *mut raw::git_worktree_prune_options;
+
+    unsafe fn from_raw(_raw: *mut raw::git_worktree_prune_options)
+                       -> WorktreePruneOptions {
+        panic!("unimplemened")
+    }
+    fn raw(&self) -> *mut raw::git_worktree_prune_options {
+        &self.raw as *const _ as *mut _
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tempdir::TempDir;
+
+    use {Repository, WorktreeAddOptions, WorktreeLockStatus};
+    use WorktreePruneOptions;
+
+    #[test]
+    fn smoke() {
+        let (_td, repo) = ::test::repo_init();
+        let td = TempDir::new("worktree").unwrap();
+        let path = td.path().join("foo");
+        assert_eq!(t!(repo.worktrees()).len(), 0);
+
+        let wt = t!(repo.worktree_add("foo", &path, None));
+        t!(wt.validate());
+        assert_eq!(t!(repo.worktrees()).iter().collect::<Vec<_>>(),
+                   vec![Some("foo")]);
+        assert!(repo.find_worktree("bar").is_err());
+
+        let wt_repo = t!(Repository::open_from_worktree(&wt));
+        assert!(wt_repo.is_worktree());
+        assert!(t!(::test::realpath(wt_repo.workdir().unwrap())) ==
+                t!(::test::realpath(&path)));
+        t!(::Worktree::open_from_repository(&wt_repo));
+        assert!(::Worktree::open_from_repository(&repo).is_err());
+
+        let wt = t!(repo.find_worktree("foo"));
+        assert_eq!(t!(wt.is_locked()), WorktreeLockStatus::Unlocked);
+        t!(wt.lock(Some("on a removable disk")));
+        assert_eq!(t!(wt.is_locked()),
+                   WorktreeLockStatus::Locked(Some("on a removable disk".to_string())));
+        assert!(wt.lock(None).is_err());
+        t!(wt.unlock());
+        assert_eq!(t!(wt.is_locked()), WorktreeLockStatus::Unlocked);
+    }
+
+    #[test]
+    fn prune() {
+        let (_td, repo) = ::test::repo_init();
+        let td = TempDir::new("worktree").unwrap();
+        let path = td.path().join("foo");
+        let wt = t!(repo.worktree_add("foo", &path,
+                                      Some(WorktreeAddOptions::new().lock(true))));
+        assert_eq!(t!(wt.is_locked()), WorktreeLockStatus::Locked(None));
+
+        // valid worktrees are kept by default, and locked ones as well
+        assert!(!t!(wt.is_prunable(None)));
+        let mut opts = WorktreePruneOptions::new();
+        opts.valid(true);
+        assert!(!t!(wt.is_prunable(Some(&mut opts))));
+        assert!(wt.prune(Some(&mut opts)).is_err());
+
+        t!(wt.unlock());
+        assert!(t!(wt.is_prunable(Some(&mut opts))));
+        t!(wt.prune(Some(opts.working_tree(true))));
+        assert_eq!(t!(repo.worktrees()).len(), 0);
+        assert!(!path.exists());
+    }
+}
@@ -12,10 +12,7 @@ use self::spin::Once;
 pub struct Lazy<T: Sync>(Once<T>);
 
 impl<T: Sync> Lazy<T> {
-    #[inline(always)]
-    pub const fn new() -> Self {
-        Lazy(Once::new())
-    }
+    pub const INIT: Self = Lazy(Once::INIT);
 
     #[inline(always)]
     pub fn get<F>(&'static self, builder: F) -> &T
@@ -29,6 +26,6 @@ impl<T: Sync> Lazy<T> {
 #[doc(hidden)]
 macro_rules! __lazy_static_create {
     ($NAME:ident, $T:ty) => {
-        static $NAME: $crate::lazy::Lazy<$T> = $crate::lazy::Lazy::new();
+        static $NAME: $crate::lazy::Lazy<$T> = $crate::lazy::Lazy::INIT;
     }
 }
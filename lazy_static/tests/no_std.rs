@@ -1,5 +1,4 @@
 #![cfg(feature="spin_no_std")]
-#![feature(const_fn)]
 
 #![no_std]
 
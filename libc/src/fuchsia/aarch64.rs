@@ -1,8 +1,8 @@
 pub type c_char = u8;
 pub type __u64 = ::c_ulonglong;
 pub type wchar_t = u32;
-pub type nlink_t = u32;
-pub type blksize_t = ::c_int;
+pub type nlink_t = ::c_ulong;
+pub type blksize_t = ::c_long;
 
 s! {
     pub struct stat {
@@ -1,3 +1,5 @@
+use dox::mem;
+
 pub type c_long = i64;
 pub type c_ulong = u64;
 pub type time_t = i64;
@@ -29,4 +31,7 @@ s! {
     }
 }
 
+// should be pub(crate), but that requires Rust 1.18.0
+#[doc(hidden)]
+pub const _ALIGNBYTES: usize = mem::size_of::<::c_longlong>() - 1;
 pub const MAP_32BIT: ::c_int = 0x00080000;
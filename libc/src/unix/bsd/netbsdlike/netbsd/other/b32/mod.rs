@@ -1,2 +0,0 @@
-pub type c_long = i32;
-pub type c_ulong = u32;
@@ -1,2 +0,0 @@
-pub type c_long = i64;
-pub type c_ulong = u64;
@@ -25,6 +25,13 @@ pub const GIT_PROXY_OPTIONS_VERSION: c_uint = 1;
 pub const GIT_SUBMODULE_UPDATE_OPTIONS_VERSION: c_uint = 1;
 pub const GIT_ODB_BACKEND_VERSION: c_uint = 1;
 pub const GIT_REFDB_BACKEND_VERSION: c_uint = 1;
+pub const GIT_WORKTREE_ADD_OPTIONS_VERSION: c_uint = 1;
+pub const GIT_WORKTREE_PRUNE_OPTIONS_VERSION: c_uint = 1;
+pub const GIT_REBASE_OPTIONS_VERSION: c_uint = 1;
+pub const GIT_CHERRYPICK_OPTIONS_VERSION: c_uint = 1;
+pub const GIT_REVERT_OPTIONS_VERSION: c_uint = 1;
+pub const GIT_CONFIG_BACKEND_VERSION: c_uint = 1;
+pub const GIT_REBASE_NO_OPERATION: size_t = ::std::usize::MAX;
 
 macro_rules! git_enum {
     (pub enum $name:ident { $($variants:tt)* }) => {
@@ -54,6 +61,7 @@ pub enum git_branch_iterator {}
 pub enum git_blame {}
 pub enum git_commit {}
 pub enum git_config {}
+pub enum git_config_backend {}
 pub enum git_config_iterator {}
 pub enum git_index {}
 pub enum git_object {}
@@ -83,11 +91,13 @@ pub enum git_reflog {}
 pub enum git_reflog_entry {}
 pub enum git_describe_result {}
 pub enum git_packbuilder {}
+pub enum git_indexer {}
 pub enum git_odb {}
 pub enum git_odb_stream {}
 pub enum git_odb_object {}
 pub enum git_odb_writepack {}
 pub enum git_worktree {}
+pub enum git_rebase {}
 
 #[repr(C)]
 pub struct git_revspec {
@@ -1080,6 +1090,12 @@ pub type git_diff_progress_cb = extern fn (*const git_diff,
                                            *const c_char,
                                            *mut c_void) -> c_int;
 
+pub type git_diff_flag_t = u32;
+pub const GIT_DIFF_FLAG_BINARY: git_diff_flag_t = 1 << 0;
+pub const GIT_DIFF_FLAG_NOT_BINARY: git_diff_flag_t = 1 << 1;
+pub const GIT_DIFF_FLAG_VALID_ID: git_diff_flag_t = 1 << 2;
+pub const GIT_DIFF_FLAG_EXISTS: git_diff_flag_t = 1 << 3;
+
 pub type git_diff_option_t = i32;
 pub const GIT_DIFF_NORMAL: git_diff_option_t = 0;
 pub const GIT_DIFF_REVERSE: git_diff_option_t = 1 << 0;
@@ -1556,6 +1572,70 @@ pub type git_packbuilder_foreach_cb = extern fn(*const c_void, size_t,
 
 pub type git_odb_foreach_cb = extern fn(id: *const git_oid, payload: *mut c_void) -> c_int;
 
+#[repr(C)]
+pub struct git_rebase_options {
+    pub version: c_uint,
+    pub quiet: c_int,
+    pub inmemory: c_int,
+    pub rewrite_notes_ref: *const c_char,
+    pub merge_options: git_merge_options,
+    pub checkout_options: git_checkout_options,
+}
+
+git_enum! {
+    pub enum git_rebase_operation_t {
+        GIT_REBASE_OPERATION_PICK = 0,
+        GIT_REBASE_OPERATION_REWORD,
+        GIT_REBASE_OPERATION_EDIT,
+        GIT_REBASE_OPERATION_SQUASH,
+        GIT_REBASE_OPERATION_FIXUP,
+        GIT_REBASE_OPERATION_EXEC,
+    }
+}
+
+#[repr(C)]
+pub struct git_rebase_operation {
+    pub kind: git_rebase_operation_t,
+    pub id: git_oid,
+    pub exec: *const c_char,
+}
+
+#[repr(C)]
+pub struct git_cherrypick_options {
+    pub version: c_uint,
+    pub mainline: c_uint,
+    pub merge_opts: git_merge_options,
+    pub checkout_opts: git_checkout_options,
+}
+
+#[repr(C)]
+pub struct git_revert_options {
+    pub version: c_uint,
+    pub mainline: c_uint,
+    pub merge_opts: git_merge_options,
+    pub checkout_opts: git_checkout_options,
+}
+
+#[repr(C)]
+pub struct git_worktree_add_options {
+    pub version: c_uint,
+    pub lock: c_int,
+}
+
+git_enum! {
+    pub enum git_worktree_prune_t {
+        GIT_WORKTREE_PRUNE_VALID = 1 << 0,
+        GIT_WORKTREE_PRUNE_LOCKED = 1 << 1,
+        GIT_WORKTREE_PRUNE_WORKING_TREE = 1 << 2,
+    }
+}
+
+#[repr(C)]
+pub struct git_worktree_prune_options {
+    pub version: c_uint,
+    pub flags: u32,
+}
+
 extern {
     // threads
     pub fn git_libgit2_init() -> c_int;
@@ -2246,6 +2326,11 @@ extern {
                                    repo: *mut git_repository) -> c_int;
 
     // config
+    pub fn git_config_add_backend(cfg: *mut git_config,
+                                  file: *mut git_config_backend,
+                                  level: git_config_level_t,
+                                  repo: *const git_repository,
+                                  force: c_int) -> c_int;
     pub fn git_config_add_file_ondisk(cfg: *mut git_config,
                                       path: *const c_char,
                                       level: git_config_level_t,
@@ -2282,12 +2367,18 @@ extern {
     pub fn git_config_get_path(out: *mut git_buf,
                                cfg: *const git_config,
                                name: *const c_char) -> c_int;
+    pub fn git_config_init_backend(backend: *mut git_config_backend,
+                                   version: c_uint) -> c_int;
     pub fn git_config_iterator_free(iter: *mut git_config_iterator);
     pub fn git_config_iterator_glob_new(out: *mut *mut git_config_iterator,
                                         cfg: *const git_config,
                                         regexp: *const c_char) -> c_int;
     pub fn git_config_iterator_new(out: *mut *mut git_config_iterator,
                                    cfg: *const git_config) -> c_int;
+    pub fn git_config_multivar_iterator_new(out: *mut *mut git_config_iterator,
+                                            cfg: *const git_config,
+                                            name: *const c_char,
+                                            regexp: *const c_char) -> c_int;
     pub fn git_config_new(out: *mut *mut git_config) -> c_int;
     pub fn git_config_next(entry: *mut *mut git_config_entry,
                            iter: *mut git_config_iterator) -> c_int;
@@ -2634,6 +2725,9 @@ extern {
                             line_cb: Option<git_diff_line_cb>,
                             payload: *mut c_void) -> c_int;
     pub fn git_diff_free(diff: *mut git_diff);
+    pub fn git_diff_from_buffer(diff: *mut *mut git_diff,
+                                content: *const c_char,
+                                content_len: size_t) -> c_int;
     pub fn git_diff_get_delta(diff: *const git_diff,
                               idx: size_t) -> *const git_diff_delta;
     pub fn git_diff_get_stats(out: *mut *mut git_diff_stats,
@@ -2841,6 +2935,22 @@ extern {
                                          progress_cb_payload: *mut c_void) -> c_int;
     pub fn git_packbuilder_free(pb: *mut git_packbuilder);
 
+    // indexer
+    pub fn git_indexer_new(out: *mut *mut git_indexer,
+                           path: *const c_char,
+                           mode: c_uint,
+                           odb: *mut git_odb,
+                           progress_cb: Option<git_transfer_progress_cb>,
+                           progress_cb_payload: *mut c_void) -> c_int;
+    pub fn git_indexer_append(idx: *mut git_indexer,
+                              data: *const c_void,
+                              size: size_t,
+                              stats: *mut git_transfer_progress) -> c_int;
+    pub fn git_indexer_commit(idx: *mut git_indexer,
+                              stats: *mut git_transfer_progress) -> c_int;
+    pub fn git_indexer_hash(idx: *const git_indexer) -> *const git_oid;
+    pub fn git_indexer_free(idx: *mut git_indexer);
+
     // odb
     pub fn git_repository_odb(out: *mut *mut git_odb,
                               repo: *mut git_repository) -> c_int;
@@ -2958,6 +3068,94 @@ extern {
     pub fn git_refdb_set_backend(refdb: *mut git_refdb, backend: *mut git_refdb_backend) -> c_int;
     pub fn git_refdb_compress(refdb: *mut git_refdb) -> c_int;
     pub fn git_refdb_free(refdb: *mut git_refdb);
+    pub fn git_reference__alloc(name: *const c_char,
+                                oid: *const git_oid,
+                                peel: *const git_oid) -> *mut git_reference;
+    pub fn git_reference__alloc_symbolic(name: *const c_char,
+                                         target: *const c_char) -> *mut git_reference;
+
+    // worktree
+    pub fn git_worktree_list(out: *mut git_strarray,
+                             repo: *mut git_repository) -> c_int;
+    pub fn git_worktree_lookup(out: *mut *mut git_worktree,
+                               repo: *mut git_repository,
+                               name: *const c_char) -> c_int;
+    pub fn git_worktree_open_from_repository(out: *mut *mut git_worktree,
+                                             repo: *mut git_repository) -> c_int;
+    pub fn git_worktree_free(wt: *mut git_worktree);
+    pub fn git_worktree_validate(wt: *const git_worktree) -> c_int;
+    pub fn git_worktree_add(out: *mut *mut git_worktree,
+                            repo: *mut git_repository,
+                            name: *const c_char,
+                            path: *const c_char,
+                            opts: *const git_worktree_add_options) -> c_int;
+    pub fn git_worktree_lock(wt: *mut git_worktree, reason: *mut c_char) -> c_int;
+    pub fn git_worktree_unlock(wt: *mut git_worktree) -> c_int;
+    pub fn git_worktree_is_locked(reason: *mut git_buf,
+                                  wt: *const git_worktree) -> c_int;
+    pub fn git_worktree_prune_init_options(opts: *mut git_worktree_prune_options,
+                                           version: c_uint) -> c_int;
+    pub fn git_worktree_is_prunable(wt: *mut git_worktree,
+                                    opts: *mut git_worktree_prune_options) -> c_int;
+    pub fn git_worktree_prune(wt: *mut git_worktree,
+                              opts: *mut git_worktree_prune_options) -> c_int;
+
+    // rebase
+    pub fn git_rebase_init_options(opts: *mut git_rebase_options,
+                                   version: c_uint) -> c_int;
+    pub fn git_rebase_init(out: *mut *mut git_rebase,
+                           repo: *mut git_repository,
+                           branch: *const git_annotated_commit,
+                           upstream: *const git_annotated_commit,
+                           onto: *const git_annotated_commit,
+                           opts: *const git_rebase_options) -> c_int;
+    pub fn git_rebase_open(out: *mut *mut git_rebase,
+                           repo: *mut git_repository,
+                           opts: *const git_rebase_options) -> c_int;
+    pub fn git_rebase_operation_entrycount(rebase: *mut git_rebase) -> size_t;
+    pub fn git_rebase_operation_current(rebase: *mut git_rebase) -> size_t;
+    pub fn git_rebase_operation_byindex(rebase: *mut git_rebase,
+                                        idx: size_t) -> *mut git_rebase_operation;
+    pub fn git_rebase_next(operation: *mut *mut git_rebase_operation,
+                           rebase: *mut git_rebase) -> c_int;
+    pub fn git_rebase_inmemory_index(index: *mut *mut git_index,
+                                     rebase: *mut git_rebase) -> c_int;
+    pub fn git_rebase_commit(id: *mut git_oid,
+                             rebase: *mut git_rebase,
+                             author: *const git_signature,
+                             committer: *const git_signature,
+                             message_encoding: *const c_char,
+                             message: *const c_char) -> c_int;
+    pub fn git_rebase_abort(rebase: *mut git_rebase) -> c_int;
+    pub fn git_rebase_finish(rebase: *mut git_rebase,
+                             signature: *const git_signature) -> c_int;
+    pub fn git_rebase_free(rebase: *mut git_rebase);
+
+    // cherrypick
+    pub fn git_cherrypick_init_options(opts: *mut git_cherrypick_options,
+                                       version: c_uint) -> c_int;
+    pub fn git_cherrypick(repo: *mut git_repository,
+                          commit: *mut git_commit,
+                          options: *const git_cherrypick_options) -> c_int;
+    pub fn git_cherrypick_commit(out: *mut *mut git_index,
+                                 repo: *mut git_repository,
+                                 cherrypick_commit: *mut git_commit,
+                                 our_commit: *mut git_commit,
+                                 mainline: c_uint,
+                                 merge_options: *const git_merge_options) -> c_int;
+
+    // revert
+    pub fn git_revert_init_options(opts: *mut git_revert_options,
+                                   version: c_uint) -> c_int;
+    pub fn git_revert(repo: *mut git_repository,
+                      commit: *mut git_commit,
+                      given_opts: *const git_revert_options) -> c_int;
+    pub fn git_revert_commit(out: *mut *mut git_index,
+                             repo: *mut git_repository,
+                             revert_commit: *mut git_commit,
+                             our_commit: *mut git_commit,
+                             mainline: c_uint,
+                             merge_options: *const git_merge_options) -> c_int;
 }
 
 pub fn init() {
@@ -0,0 +1,7 @@
+use nix::sys::pthread::*;
+
+#[test]
+fn test_pthread_self() {
+    let tid = pthread_self();
+    assert!(tid != 0);
+}
@@ -0,0 +1,9 @@
+use ring::{signature, test};
+
+#[test]
+fn signature_impl_test() {
+    test::compile_time_assert_clone::<signature::Signature>();
+    test::compile_time_assert_copy::<signature::Signature>();
+    test::compile_time_assert_send::<signature::Signature>();
+    test::compile_time_assert_sync::<signature::Signature>();
+}
@@ -0,0 +1,516 @@
+// Copyright (c) 2016 The Rouille developers
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>,
+// at your option. All files in the project carrying such
+// notice may not be copied, modified, or distributed except
+// according to those terms.
+
+//! Access logs in standard formats.
+//!
+//! Contrary to [the `log` function](../fn.log.html), which writes a human-readable line, the
+//! `AccessLog` struct of this module produces entries in the Apache Common Log Format, the Apache
+//! Combined Log Format, or as JSON objects (one per line). The formatted entries are passed to a
+//! `LogSink`, which can for example write them to a file.
+//!
+//! The entry of a request is recorded once the body of its response has been sent, so that it
+//! contains the number of bytes that were actually sent.
+//!
+//! # Example
+//!
+//! ```
+//! use std::io;
+//! use rouille::Request;
+//! use rouille::Response;
+//! use rouille::access_log::AccessLog;
+//! use rouille::access_log::LogFormat;
+//! use rouille::access_log::WriterSink;
+//!
+//! let access_log = AccessLog::new(LogFormat::Combined, WriterSink::new(io::stdout()));
+//!
+//! fn handle(request: &Request, access_log: &AccessLog<WriterSink<io::Stdout>>) -> Response {
+//!     access_log.log(request, || {
+//!         Response::text("hello world")
+//!     })
+//! }
+//! ```
+//!
+//! `AccessLog` also implements the `Middleware` trait, and can be registered on a `Server` with
+//! `Server::with_middleware`.
+//!
+//! # Writing a sink
+//!
+//! Implement the `LogSink` trait in order to send the entries somewhere else, for example to a
+//! file that is rotated every day or to a logging service.
+//!
+//! ```
+//! use rouille::access_log::LogEntry;
+//! use rouille::access_log::LogSink;
+//!
+//! struct ErrorsOnly;
+//!
+//! impl LogSink for ErrorsOnly {
+//!     fn record(&self, entry: &LogEntry, line: &str) {
+//!         if entry.status_code >= 500 {
+//!             eprintln!("{}", line);
+//!         }
+//!     }
+//! }
+//! ```
+
+// The AsciiExt import is needed for Rust older than 1.23.0. These two lines can
+// be removed when supporting older Rust is no longer needed.
+#[allow(unused_imports)]
+use std::ascii::AsciiExt;
+
+use std::io;
+use std::io::Read;
+use std::io::Write;
+use std::mem;
+use std::net::SocketAddr;
+use std::panic;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::time::Duration;
+use std::time::Instant;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+use chrono;
+use chrono::TimeZone;
+use serde_json;
+
+use Request;
+use Response;
+use ResponseBody;
+use middleware::Middleware;
+
+/// Format of the entries of an `AccessLog`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum LogFormat {
+    /// The Apache Common Log Format.
+    ///
+    /// `127.0.0.1 - - [10/Oct/2000:13:55:36 +0000] "GET /index.html HTTP/1.1" 200 2326`
+    Common,
+
+    /// The Apache Combined Log Format, which is the Common Log Format followed with the referrer
+    /// and the user agent.
+    ///
+    /// `127.0.0.1 - - [10/Oct/2000:13:55:36 +0000] "GET /index.html HTTP/1.1" 200 2326 "-"
+    /// "curl/7.58"`
+    Combined,
+
+    /// One JSON object per line, with the fields `time`, `remote_addr`, `method`, `url`,
+    /// `http_version`, `status`, `size`, `referrer`, `user_agent`, `request_id` and
+    /// `duration_ms`. The fields whose value is unknown are `null`.
+    Json,
+}
+
+/// Information about a request and its response.
+#[derive(Debug, Clone)]
+pub struct LogEntry {
+    /// Time when the request started being processed.
+    pub time: SystemTime,
+    /// Address of the client.
+    pub remote_addr: SocketAddr,
+    /// Method of the request.
+    pub method: String,
+    /// Raw URL of the request, including the query string.
+    pub url: String,
+    /// HTTP version of the request, for example `HTTP/1.1`.
+    pub http_version: String,
+    /// Status code of the response. If the handler panicked, this is `500`, which is what the
+    /// server answers in that situation.
+    pub status_code: u16,
+    /// True if the handler panicked.
+    pub panicked: bool,
+    /// Number of bytes of the body of the response that were sent, or `None` if the handler
+    /// panicked.
+    pub response_size: Option<usize>,
+    /// Value of the `Referer` header of the request.
+    pub referrer: Option<String>,
+    /// Value of the `User-Agent` header of the request.
+    pub user_agent: Option<String>,
+    /// Identifier of the request. See `AccessLog::request_id_header`.
+    pub request_id: Option<String>,
+    /// Time it took to process the request, not including sending the body of the response.
+    pub elapsed: Duration,
+}
+
+impl LogEntry {
+    /// Formats the entry, without a trailing line break.
+    pub fn format(&self, format: LogFormat) -> String {
+        match format {
+            LogFormat::Common => self.format_common(),
+            LogFormat::Combined => {
+                format!("{} \"{}\" \"{}\"", self.format_common(),
+                        escape(self.referrer.as_ref().map(|s| &s[..]).unwrap_or("-")),
+                        escape(self.user_agent.as_ref().map(|s| &s[..]).unwrap_or("-")))
+            },
+            LogFormat::Json => self.format_json(),
+        }
+    }
+
+    fn utc_time(&self) -> chrono::DateTime<chrono::Utc> {
+        let since_epoch = self.time.duration_since(UNIX_EPOCH).unwrap_or(Duration::from_secs(0));
+        chrono::Utc.timestamp(since_epoch.as_secs() as i64, since_epoch.subsec_nanos())
+    }
+
+    fn format_common(&self) -> String {
+        // Like Apache, `-` is written instead of 0 when no byte was sent.
+        let size = match self.response_size {
+            Some(size) if size != 0 => size.to_string(),
+            _ => "-".to_owned(),
+        };
+
+        format!("{} - - [{}] \"{} {} {}\" {} {}", self.remote_addr.ip(),
+                self.utc_time().format("%d/%b/%Y:%H:%M:%S %z"), escape(&self.method),
+                escape(&self.url), escape(&self.http_version), self.status_code, size)
+    }
+
+    fn format_json(&self) -> String {
+        let elapsed = self.elapsed;
+        let entry = JsonEntry {
+            time: self.utc_time().format("%Y-%m-%dT%H:%M:%S%.6fZ").to_string(),
+            remote_addr: self.remote_addr.to_string(),
+            method: &self.method,
+            url: &self.url,
+            http_version: &self.http_version,
+            status: self.status_code,
+            size: self.response_size,
+            referrer: self.referrer.as_ref().map(|s| &s[..]),
+            user_agent: self.user_agent.as_ref().map(|s| &s[..]),
+            request_id: self.request_id.as_ref().map(|s| &s[..]),
+            duration_ms: elapsed.as_secs() as f64 * 1000.0 +
+                         f64::from(elapsed.subsec_nanos()) / 1_000_000.0,
+        };
+
+        serde_json::to_string(&entry).unwrap()
+    }
+}
+
+// Serialized form of an entry with `LogFormat::Json`.
+#[derive(Serialize)]
+struct JsonEntry<'a> {
+    time: String,
+    remote_addr: String,
+    method: &'a str,
+    url: &'a str,
+    http_version: &'a str,
+    status: u16,
+    size: Option<usize>,
+    referrer: Option<&'a str>,
+    user_agent: Option<&'a str>,
+    request_id: Option<&'a str>,
+    duration_ms: f64,
+}
+
+// Escapes a value that is written between double quotes in the Apache formats, the same way as
+// Apache does.
+fn escape(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            c if (c as u32) < 0x20 || c as u32 == 0x7f => {
+                out.push_str(&format!("\\x{:02x}", c as u32));
+            },
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+/// Destination of the entries of an `AccessLog`.
+pub trait LogSink: Send + Sync {
+    /// Records an entry. `line` is the entry formatted with the format of the `AccessLog`,
+    /// without a trailing line break.
+    ///
+    /// This method is called from the threads that process the requests, possibly from multiple
+    /// threads at once.
+    fn record(&self, entry: &LogEntry, line: &str);
+}
+
+impl<F> LogSink for F where F: Fn(&LogEntry, &str) + Send + Sync {
+    #[inline]
+    fn record(&self, entry: &LogEntry, line: &str) {
+        (*self)(entry, line)
+    }
+}
+
+/// Sink that writes each entry followed with a line break to a writer.
+///
+/// Errors while writing are ignored.
+pub struct WriterSink<W> {
+    output: Mutex<W>,
+}
+
+impl<W> WriterSink<W> where W: Write + Send {
+    /// Builds a sink that writes to `output`.
+    #[inline]
+    pub fn new(output: W) -> WriterSink<W> {
+        WriterSink { output: Mutex::new(output) }
+    }
+
+    /// Destroys the sink and returns the underlying writer.
+    #[inline]
+    pub fn into_inner(self) -> W {
+        match self.output.into_inner() {
+            Ok(w) => w,
+            Err(poisoned) => poisoned.into_inner(),
+        }
+    }
+}
+
+impl<W> LogSink for WriterSink<W> where W: Write + Send {
+    fn record(&self, _: &LogEntry, line: &str) {
+        let mut output = match self.output.lock() {
+            Ok(o) => o,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+
+        let _ = writeln!(output, "{}", line);
+        let _ = output.flush();
+    }
+}
+
+/// Writes an entry to a `LogSink` for each request.
+pub struct AccessLog<S> {
+    format: LogFormat,
+    sink: Arc<S>,
+    request_id_header: String,
+}
+
+impl<S> AccessLog<S> where S: LogSink + 'static {
+    /// Builds an `AccessLog` that writes entries with the given format to the given sink.
+    #[inline]
+    pub fn new(format: LogFormat, sink: S) -> AccessLog<S> {
+        AccessLog {
+            format: format,
+            sink: Arc::new(sink),
+            request_id_header: "X-Request-Id".to_owned(),
+        }
+    }
+
+    /// Sets the header that contains the identifier of the request. The default is
+    /// `X-Request-Id`.
+    ///
+    /// The header is first looked for in the response, then in the request. This means that
+    /// the identifiers generated by the `RequestId` middleware are found even if it is
+    /// registered after the `AccessLog`.
+    #[inline]
+    pub fn request_id_header<H>(mut self, header: H) -> AccessLog<S> where H: Into<String> {
+        self.request_id_header = header.into();
+        self
+    }
+
+    /// Returns the sink.
+    #[inline]
+    pub fn sink(&self) -> &S {
+        &self.sink
+    }
+
+    /// Calls `handler` and records an entry for the request.
+    ///
+    /// The entry is recorded once the body of the response has been sent, or more precisely when
+    /// the body is destroyed. If `handler` panics, an entry is recorded right away and the panic
+    /// is resumed.
+    pub fn log<F>(&self, request: &Request, handler: F) -> Response
+        where F: FnOnce() -> Response
+    {
+        let time = SystemTime::now();
+        let start_instant = Instant::now();
+
+        // Note that we always resume unwinding afterwards, so we can ignore the small
+        // panic-safety mechanism of `catch_unwind`.
+        let response = panic::catch_unwind(panic::AssertUnwindSafe(handler));
+        let elapsed = start_instant.elapsed();
+
+        let header = |name: &str| request.header(name).map(|h| h.to_owned());
+        let mut entry = LogEntry {
+            time: time,
+            remote_addr: *request.remote_addr(),
+            method: request.method().to_owned(),
+            url: request.raw_url().to_owned(),
+            http_version: format!("HTTP/{}.{}", request.http_version.0, request.http_version.1),
+            status_code: 500,
+            panicked: true,
+            response_size: None,
+            referrer: header("Referer"),
+            user_agent: header("User-Agent"),
+            request_id: header(&self.request_id_header),
+            elapsed: elapsed,
+        };
+
+        let mut response = match response {
+            Ok(response) => response,
+            Err(payload) => {
+                self.sink.record(&entry, &entry.format(self.format));
+                panic::resume_unwind(payload)
+            },
+        };
+
+        entry.status_code = response.status_code;
+        entry.panicked = false;
+        entry.response_size = Some(0);
+
+        let id = response.headers.iter()
+                         .find(|&&(ref k, _)| k.eq_ignore_ascii_case(&self.request_id_header))
+                         .map(|&(_, ref v)| v.clone().into_owned());
+        if id.is_some() {
+            entry.request_id = id;
+        }
+
+        let data = mem::replace(&mut response.data, ResponseBody::empty());
+        let (reader, size) = data.into_reader_and_size();
+        let body = LoggedBody {
+            reader: reader,
+            entry: entry,
+            format: self.format,
+            sink: self.sink.clone(),
+        };
+        response.data = match size {
+            Some(size) => ResponseBody::from_reader_and_size(body, size),
+            None => ResponseBody::from_reader(body),
+        };
+        response
+    }
+}
+
+impl<S> Middleware for AccessLog<S> where S: LogSink + 'static {
+    #[inline]
+    fn around(&self, request: &Request, next: &Fn(&Request) -> Response) -> Response {
+        self.log(request, || next(request))
+    }
+}
+
+// Body of a response that counts the bytes read from it, and records the entry of the request
+// when it is destroyed.
+struct LoggedBody<S> where S: LogSink {
+    reader: Box<Read + Send>,
+    entry: LogEntry,
+    format: LogFormat,
+    sink: Arc<S>,
+}
+
+impl<S> Read for LoggedBody<S> where S: LogSink {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let len = try!(self.reader.read(buf));
+        self.entry.response_size = Some(self.entry.response_size.unwrap_or(0) + len);
+        Ok(len)
+    }
+}
+
+impl<S> Drop for LoggedBody<S> where S: LogSink {
+    fn drop(&mut self) {
+        self.sink.record(&self.entry, &self.entry.format(self.format));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Read;
+    use std::net::SocketAddr;
+    use std::sync::Arc;
+    use std::sync::Mutex;
+    use std::time::Duration;
+    use std::time::UNIX_EPOCH;
+
+    use Request;
+    use Response;
+    use super::AccessLog;
+    use super::LogEntry;
+    use super::LogFormat;
+    use super::WriterSink;
+
+    fn entry() -> LogEntry {
+        LogEntry {
+            time: UNIX_EPOCH + Duration::from_secs(971186136),
+            remote_addr: "127.0.0.1:5000".parse().unwrap(),
+            method: "GET".to_owned(),
+            url: "/index.html?a=\"b\"".to_owned(),
+            http_version: "HTTP/1.1".to_owned(),
+            status_code: 200,
+            panicked: false,
+            response_size: Some(2326),
+            referrer: None,
+            user_agent: Some("curl/7.58".to_owned()),
+            request_id: Some("abc".to_owned()),
+            elapsed: Duration::from_millis(12),
+        }
+    }
+
+    #[test]
+    fn common() {
+        assert_eq!(entry().format(LogFormat::Common),
+                   "127.0.0.1 - - [10/Oct/2000:13:55:36 +0000] \"GET /index.html?a=\\\"b\\\" \
+                    HTTP/1.1\" 200 2326");
+    }
+
+    #[test]
+    fn combined() {
+        let mut entry = entry();
+        entry.response_size = None;
+        assert_eq!(entry.format(LogFormat::Combined),
+                   "127.0.0.1 - - [10/Oct/2000:13:55:36 +0000] \"GET /index.html?a=\\\"b\\\" \
+                    HTTP/1.1\" 200 - \"-\" \"curl/7.58\"");
+    }
+
+    #[test]
+    fn json() {
+        assert_eq!(entry().format(LogFormat::Json),
+                   "{\"time\":\"2000-10-10T13:55:36.000000Z\",\"remote_addr\":\"127.0.0.1:5000\",\
+                    \"method\":\"GET\",\"url\":\"/index.html?a=\\\"b\\\"\",\"http_version\":\"HTTP/1.1\",\
+                    \"status\":200,\
+                    \"size\":2326,\"referrer\":null,\"user_agent\":\"curl/7.58\",\
+                    \"request_id\":\"abc\",\"duration_ms\":12.0}");
+    }
+
+    #[test]
+    fn access_log() {
+        let access_log = AccessLog::new(LogFormat::Common, WriterSink::new(Vec::new()));
+        let addr: SocketAddr = "10.0.0.1:1234".parse().unwrap();
+        let request = Request::fake_http_from(addr, "POST", "/submit", vec![], vec![]);
+        let response = access_log.log(&request, || Response::text("hello").with_status_code(201));
+        assert!(access_log.sink().output.lock().unwrap().is_empty());
+
+        let (mut body, _) = response.data.into_reader_and_size();
+        let mut sent = Vec::new();
+        body.read_to_end(&mut sent).unwrap();
+        drop(body);
+
+        let sink = Arc::try_unwrap(access_log.sink).ok().unwrap();
+        let output = String::from_utf8(sink.into_inner()).unwrap();
+        assert!(output.starts_with("10.0.0.1 - - ["));
+        assert!(output.ends_with("] \"POST /submit HTTP/1.1\" 201 5\n"));
+    }
+
+    #[test]
+    fn unsent_body() {
+        let access_log = AccessLog::new(LogFormat::Common, WriterSink::new(Vec::new()));
+        let request = Request::fake_http("GET", "/", vec![], vec![]);
+        drop(access_log.log(&request, || Response::text("hello")));
+
+        let sink = Arc::try_unwrap(access_log.sink).ok().unwrap();
+        let output = String::from_utf8(sink.into_inner()).unwrap();
+        assert!(output.ends_with("] \"GET / HTTP/1.1\" 200 -\n"));
+    }
+
+    #[test]
+    fn request_id_from_response() {
+        let entries = Arc::new(Mutex::new(Vec::new()));
+        {
+            let entries = entries.clone();
+            let access_log = AccessLog::new(LogFormat::Json, move |entry: &LogEntry, _: &str| {
+                entries.lock().unwrap().push(entry.request_id.clone());
+            });
+            let request = Request::fake_http("GET", "/", vec![], vec![]);
+            drop(access_log.log(&request, || {
+                Response::text("").with_unique_header("X-Request-Id", "generated")
+            }));
+        }
+        assert_eq!(*entries.lock().unwrap(), vec![Some("generated".to_owned())]);
+    }
+}
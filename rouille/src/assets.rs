@@ -9,12 +9,20 @@
 
 use std::fs;
 use std::path::Path;
+use std::path::PathBuf;
+
+// The AsciiExt import is needed for Rust older than 1.23.0. These two lines can
+// be removed when supporting older Rust is no longer needed.
+#[allow(unused_imports)]
+use std::ascii::AsciiExt;
 
 use filetime;
 use time;
 
 use Request;
 use Response;
+use content_encoding;
+use range;
 
 /// Searches inside `path` for a file that matches the given request. If a file is found,
 /// returns a `Response` that would serve this file if returned. If no file is found, a 404
@@ -24,6 +32,13 @@ use Response;
 /// extension. If you wish so, you can modify that `Content-Type` by modifying the `Response`
 /// object returned by this function.
 ///
+/// Requests with a `Range` header are answered with the requested parts of the file. See
+/// [the `range` module](range/index.html).
+///
+/// If a file named like the requested file followed with `.br` or `.gz` exists, and if the
+/// client accepts the corresponding content encoding, then this pre-compressed file is served
+/// instead with the appropriate `Content-Encoding` header.
+///
 /// # Example
 ///
 /// In this example, a request made for example to `/test.txt` will return the file
@@ -103,7 +118,7 @@ pub fn match_assets<P: ?Sized>(request: &Request, path: &P) -> Response
 
     // Check that we're still within `path`. This should eliminate security issues with
     // requests like `GET /../private_file`.
-    if !potential_file.starts_with(path) {
+    if !potential_file.starts_with(&path) {
         return Response::empty_404();
     }
 
@@ -115,19 +130,73 @@ pub fn match_assets<P: ?Sized>(request: &Request, path: &P) -> Response
 
     let extension = potential_file.extension().and_then(|s| s.to_str());
 
-    let file = match fs::File::open(&potential_file) {
+    // If there are pre-compressed versions of the file next to it, and if the client accepts
+    // one of them, we serve it instead of the file.
+    let precompressed = PRECOMPRESSED_EXTENSIONS.iter()
+        .filter_map(|&(encoding, ext)| {
+            precompressed_sibling(&potential_file, &path, ext).map(|p| (encoding, p))
+        })
+        .collect::<Vec<_>>();
+    let chosen = content_encoding::accepted_encoding_names(request).into_iter()
+        .filter_map(|accepted| {
+            precompressed.iter().find(|&&(encoding, _)| accepted.eq_ignore_ascii_case(encoding))
+        })
+        .next();
+    let (served_file, encoding) = match chosen {
+        Some(&(encoding, ref file)) => (file.clone(), Some(encoding)),
+        None => (potential_file.clone(), None),
+    };
+
+    let file = match fs::File::open(&served_file) {
         Ok(f) => f,
         Err(_) => return Response::empty_404(),
     };
 
-    let etag: String = (fs::metadata(&potential_file)
+    let mut etag: String = (fs::metadata(&served_file)
         .map(|meta| filetime::FileTime::from_last_modification_time(&meta).unix_seconds() as u64)
         .unwrap_or(time::now().tm_nsec as u64)
         ^ 0xd3f4_0305_c9f8_e911_u64).to_string();
+    if let Some(encoding) = encoding {
+        etag.push('-');
+        etag.push_str(encoding);
+    }
 
-    Response::from_file(extension_to_mime_impl(extension), file)
+    let mut response = Response::from_file(extension_to_mime_impl(extension), file)
         .with_etag(request, etag)
-        .with_public_cache(3600)        // TODO: is this a good idea? what if the file is private?
+        .with_public_cache(3600);       // TODO: is this a good idea? what if the file is private?
+
+    if let Some(encoding) = encoding {
+        response.headers.push(("Content-Encoding".into(), encoding.into()));
+    }
+    if !precompressed.is_empty() {
+        response.headers.push(("Vary".into(), "Accept-Encoding".into()));
+    }
+
+    range::apply(request, response)
+}
+
+// Content encodings that can be served from pre-compressed files, and the extension that is
+// appended to the name of the original file.
+const PRECOMPRESSED_EXTENSIONS: &[(&str, &str)] = &[("br", "br"), ("gzip", "gz")];
+
+// Returns the path of `file` with `.ext` appended, if it is a file within `root`.
+fn precompressed_sibling(file: &Path, root: &Path, ext: &str) -> Option<PathBuf> {
+    let mut name = match file.file_name() {
+        Some(n) => n.to_os_string(),
+        None => return None,
+    };
+    name.push(".");
+    name.push(ext);
+
+    let sibling = match file.with_file_name(name).canonicalize() {
+        Ok(f) => f,
+        Err(_) => return None,
+    };
+
+    match fs::metadata(&sibling) {
+        Ok(ref m) if m.is_file() && sibling.starts_with(root) => Some(sibling),
+        _ => None,
+    }
 }
 
 /// Returns the mime type of a file based on its extension, or `application/octet-stream` if the
@@ -711,3 +780,65 @@ fn extension_to_mime_impl(extension: Option<&str>) -> &'static str {
         _ => "application/octet-stream"
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+    use std::fs;
+    use std::fs::File;
+    use std::io::Read;
+    use std::io::Write;
+
+    use Request;
+    use Response;
+    use super::match_assets;
+
+    fn header<'a>(response: &'a Response, name: &str) -> Option<&'a str> {
+        response.headers.iter().find(|&&(ref k, _)| k.eq_ignore_ascii_case(name))
+                .map(|&(_, ref v)| &v[..])
+    }
+
+    fn body(response: Response) -> String {
+        let (mut reader, _) = response.data.into_reader_and_size();
+        let mut out = String::new();
+        reader.read_to_string(&mut out).unwrap();
+        out
+    }
+
+    fn request(url: &str, accept_encoding: &str) -> Request {
+        let headers = vec![("Accept-Encoding".to_owned(), accept_encoding.to_owned())];
+        Request::fake_http("GET", url, headers, vec![])
+    }
+
+    #[test]
+    fn precompressed() {
+        let dir = env::temp_dir().join("rouille-assets-test-precompressed");
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        File::create(dir.join("app.js")).unwrap().write_all(b"plain").unwrap();
+        File::create(dir.join("app.js.gz")).unwrap().write_all(b"gzipped").unwrap();
+        File::create(dir.join("app.js.br")).unwrap().write_all(b"brotli").unwrap();
+        File::create(dir.join("other.js")).unwrap().write_all(b"other").unwrap();
+
+        let response = match_assets(&request("/app.js", "gzip, br"), &dir);
+        assert_eq!(header(&response, "Content-Encoding"), Some("gzip"));
+        assert_eq!(header(&response, "Content-Type"), Some("application/javascript"));
+        assert_eq!(header(&response, "Vary"), Some("Accept-Encoding"));
+        assert_eq!(body(response), "gzipped");
+
+        let response = match_assets(&request("/app.js", "br;q=1.0, gzip"), &dir);
+        assert_eq!(header(&response, "Content-Encoding"), Some("br"));
+        assert_eq!(body(response), "brotli");
+
+        let response = match_assets(&request("/app.js", "br;q=0, identity"), &dir);
+        assert_eq!(header(&response, "Content-Encoding"), None);
+        assert_eq!(header(&response, "Vary"), Some("Accept-Encoding"));
+        assert_eq!(body(response), "plain");
+
+        let response = match_assets(&request("/other.js", "gzip"), &dir);
+        assert_eq!(header(&response, "Content-Encoding"), None);
+        assert_eq!(header(&response, "Vary"), None);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
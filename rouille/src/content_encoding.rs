@@ -25,9 +25,16 @@ use std::ascii::AsciiExt;
 /// If the response already has a `Content-Encoding` header, this function is a no-op.
 /// If the response has a `Content-Type` header that isn't textual content, this function is a
 /// no-op.
+/// If the response isn't a `200 OK`, for example a partial response to a `Range` request, this
+/// function is a no-op as well.
+///
+/// The body is encoded progressively while it is being sent to the client, and is never entirely
+/// loaded in memory.
 ///
 /// The gzip encoding is supported only if you enable the `gzip` feature of rouille (which is
-/// enabled by default).
+/// enabled by default), and the brotli encoding only if you enable the `brotli` feature (which
+/// is enabled by default as well). This function uses the default `EncodingOptions`, which
+/// means that the deflate encoding is never used. See `apply_with_options` for more control.
 ///
 /// # Example
 ///
@@ -40,18 +47,54 @@ use std::ascii::AsciiExt;
 ///     content_encoding::apply(request, Response::text("hello world"))
 /// }
 /// ```
+#[inline]
 pub fn apply(request: &Request, response: Response) -> Response {
+    apply_with_options(request, response, &EncodingOptions::default())
+}
+
+/// Same as `apply`, but with the given options.
+///
+/// # Example
+///
+/// ```rust
+/// use rouille::content_encoding;
+/// use rouille::content_encoding::EncodingOptions;
+/// use rouille::content_encoding::Quality;
+/// use rouille::Request;
+/// use rouille::Response;
+///
+/// fn handle(request: &Request) -> Response {
+///     let options = EncodingOptions::new().quality(Quality::Fast).min_size(1024).deflate(true);
+///     content_encoding::apply_with_options(request, Response::text("hello world"), &options)
+/// }
+/// ```
+pub fn apply_with_options(request: &Request, response: Response, options: &EncodingOptions)
+                          -> Response
+{
     // Only text should be encoded. Otherwise just return.
     if !response_is_text(&response) {
         return response;
     }
 
+    // Encoding a partial body would make the byte offsets of `Content-Range` wrong.
+    if response.status_code != 200 ||
+       response.headers.iter().any(|&(ref key, _)| key.eq_ignore_ascii_case("Content-Range"))
+    {
+        return response;
+    }
+
     // If any of the response's headers is equal to `Content-Encoding`, ignore the function
     // call and return immediately.
     if response.headers.iter().any(|&(ref key, _)| key.eq_ignore_ascii_case("Content-Encoding")) {
         return response;
     }
 
+    // Small bodies are not worth encoding.
+    match response.data.known_length() {
+        Some(len) if len < options.min_size => return response,
+        _ => (),
+    }
+
     // Put the response in an Option for later.
     let mut response = Some(response);
 
@@ -59,13 +102,23 @@ pub fn apply(request: &Request, response: Response) -> Response {
     // The list should be ordered from the most desired to the list desired.
     // TODO: use input::priority_header_preferred instead
     for encoding in accepted_content_encodings(request) {
+        let encoding = match encoding_name(encoding) {
+            Some(e) => e,
+            None => continue,
+        };
+
         // Try the brotli encoding.
-        if brotli(encoding, &mut response) {
+        if options.brotli && brotli(encoding, &mut response, options.quality) {
             return response.take().unwrap();
         }
 
         // Try the gzip encoding.
-        if gzip(encoding, &mut response) {
+        if options.gzip && gzip(encoding, &mut response, options.quality) {
+            return response.take().unwrap();
+        }
+
+        // Try the deflate encoding.
+        if options.deflate && deflate(encoding, &mut response, options.quality) {
             return response.take().unwrap();
         }
 
@@ -79,6 +132,122 @@ pub fn apply(request: &Request, response: Response) -> Response {
     response.take().unwrap()
 }
 
+/// Options for `apply_with_options`.
+///
+/// By default, the brotli and gzip encodings are enabled and the deflate encoding is disabled,
+/// the quality is `Quality::Default`, and there is no minimum size.
+#[derive(Debug, Clone)]
+pub struct EncodingOptions {
+    quality: Quality,
+    min_size: usize,
+    gzip: bool,
+    brotli: bool,
+    deflate: bool,
+}
+
+impl EncodingOptions {
+    /// Builds the default options.
+    #[inline]
+    pub fn new() -> EncodingOptions {
+        EncodingOptions {
+            quality: Quality::Default,
+            min_size: 0,
+            gzip: true,
+            brotli: true,
+            deflate: false,
+        }
+    }
+
+    /// Sets the compression quality.
+    #[inline]
+    pub fn quality(mut self, quality: Quality) -> EncodingOptions {
+        self.quality = quality;
+        self
+    }
+
+    /// Sets the minimum size in bytes of a body for it to be encoded. Bodies whose size is not
+    /// known in advance are always encoded.
+    #[inline]
+    pub fn min_size(mut self, min_size: usize) -> EncodingOptions {
+        self.min_size = min_size;
+        self
+    }
+
+    /// Sets whether the gzip encoding can be used. Has no effect if the `gzip` feature is
+    /// disabled.
+    #[inline]
+    pub fn gzip(mut self, enabled: bool) -> EncodingOptions {
+        self.gzip = enabled;
+        self
+    }
+
+    /// Sets whether the brotli encoding can be used. Has no effect if the `brotli` feature is
+    /// disabled.
+    #[inline]
+    pub fn brotli(mut self, enabled: bool) -> EncodingOptions {
+        self.brotli = enabled;
+        self
+    }
+
+    /// Sets whether the deflate encoding can be used. Has no effect if the `gzip` feature is
+    /// disabled.
+    ///
+    /// This encoding is disabled by default, as some old clients expect raw deflate data instead
+    /// of the zlib format required by the HTTP specification.
+    #[inline]
+    pub fn deflate(mut self, enabled: bool) -> EncodingOptions {
+        self.deflate = enabled;
+        self
+    }
+}
+
+impl Default for EncodingOptions {
+    #[inline]
+    fn default() -> EncodingOptions {
+        EncodingOptions::new()
+    }
+}
+
+/// Compression quality, as a trade-off between speed and size.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Quality {
+    /// Fastest compression.
+    Fast,
+    /// Compromise between speed and size.
+    Default,
+    /// Smallest output. Can be very slow, and should preferably be used for content that is
+    /// compressed once and cached.
+    Best,
+}
+
+// Returns the name of an element of `Accept-Encoding`, or `None` if the client has given it a
+// quality of zero.
+fn encoding_name(element: &str) -> Option<&str> {
+    let mut parts = element.split(';');
+    let name = parts.next().unwrap_or("").trim();
+
+    for param in parts {
+        let mut param = param.splitn(2, '=');
+        let key = param.next().unwrap_or("").trim();
+        let value = param.next().unwrap_or("").trim();
+        if key.eq_ignore_ascii_case("q") {
+            if let Ok(q) = value.parse::<f32>() {
+                if q <= 0.0 {
+                    return None;
+                }
+            }
+        }
+    }
+
+    Some(name)
+}
+
+/// Returns the names of the content encodings accepted by the request, in order of preference,
+/// without the ones that have a quality of zero.
+pub(crate) fn accepted_encoding_names(request: &Request) -> Vec<&str> {
+    accepted_content_encodings(request).filter_map(encoding_name).collect()
+}
+
 // Returns true if the Content-Type of the response is a type that should be encoded.
 // Since encoding is purely an optimisation, it's not a problem if the function sometimes has
 // false positives or false negatives.
@@ -88,6 +257,12 @@ fn response_is_text(response: &Response) -> bool {
             return false;
         }
 
+        // Event streams must reach the client as soon as possible, while compressing them
+        // would buffer them.
+        if value.starts_with("text/event-stream") {
+            return false;
+        }
+
         // TODO: perform case-insensitive comparison
         value.starts_with("text/") || value.contains("javascript") || value.contains("json") ||
             value.contains("xml") || value.contains("font")
@@ -147,38 +322,166 @@ impl<'a> Iterator for AcceptedContentEncodingsIter<'a> {
 }
 
 #[cfg(feature = "gzip")]
-fn gzip(e: &str, response: &mut Option<Response>) -> bool {
-    use ResponseBody;
-    use std::mem;
-    use std::io;
-    use deflate::deflate_bytes_gzip;
-
+fn gzip(e: &str, response: &mut Option<Response>, quality: Quality) -> bool {
     if !e.eq_ignore_ascii_case("gzip") {
         return false;
     }
 
     let response = response.as_mut().unwrap();
     response.headers.push(("Content-Encoding".into(), "gzip".into()));
-    let previous_body = mem::replace(&mut response.data, ResponseBody::empty());
-    let (mut raw_data, size) = previous_body.into_reader_and_size();
-    let mut src = match size {
-        Some(size) => Vec::with_capacity(size),
-        None => Vec::new(),
-    };
-    io::copy(&mut raw_data, &mut src).expect("Failed reading response body while gzipping");
-    let zipped = deflate_bytes_gzip(&src);
-    response.data = ResponseBody::from_data(zipped);
+    deflate_stream::encode(response, deflate_stream::Format::Gzip, quality);
+    true
+}
+
+#[cfg(not(feature = "gzip"))]
+#[inline]
+fn gzip(e: &str, response: &mut Option<Response>, quality: Quality) -> bool {
+    false
+}
+
+#[cfg(feature = "gzip")]
+fn deflate(e: &str, response: &mut Option<Response>, quality: Quality) -> bool {
+    if !e.eq_ignore_ascii_case("deflate") {
+        return false;
+    }
+
+    let response = response.as_mut().unwrap();
+    response.headers.push(("Content-Encoding".into(), "deflate".into()));
+    deflate_stream::encode(response, deflate_stream::Format::Zlib, quality);
     true
 }
 
 #[cfg(not(feature = "gzip"))]
 #[inline]
-fn gzip(e: &str, response: &mut Option<Response>) -> bool {
+fn deflate(e: &str, response: &mut Option<Response>, quality: Quality) -> bool {
     false
 }
 
+// Progressive encoding with the `deflate` crate.
+#[cfg(feature = "gzip")]
+mod deflate_stream {
+    use std::cmp;
+    use std::io;
+    use std::io::Read;
+    use std::io::Write;
+    use std::mem;
+    use std::sync::Arc;
+    use std::sync::Mutex;
+    use deflate::Compression;
+    use deflate::write::GzEncoder;
+    use deflate::write::ZlibEncoder;
+
+    use Response;
+    use ResponseBody;
+    use super::Quality;
+
+    pub enum Format {
+        Gzip,
+        Zlib,
+    }
+
+    // Replaces the body of the response with a reader that encodes it.
+    pub fn encode(response: &mut Response, format: Format, quality: Quality) {
+        let compression = match quality {
+            Quality::Fast => Compression::Fast,
+            Quality::Default => Compression::Default,
+            Quality::Best => Compression::Best,
+        };
+
+        let output = SharedBuffer(Arc::new(Mutex::new(Vec::new())));
+        let encoder = match format {
+            Format::Gzip => Encoder::Gzip(GzEncoder::new(output.clone(), compression)),
+            Format::Zlib => Encoder::Zlib(ZlibEncoder::new(output.clone(), compression)),
+        };
+
+        let previous_body = mem::replace(&mut response.data, ResponseBody::empty());
+        let (raw_data, _) = previous_body.into_reader_and_size();
+        response.data = ResponseBody::from_reader(EncodingReader {
+            source: raw_data,
+            encoder: Some(encoder),
+            output: output,
+        });
+    }
+
+    // The encoders of the `deflate` crate write to a writer that they own. We give them a
+    // `SharedBuffer` and keep a clone of it in order to read what they produce.
+    #[derive(Clone)]
+    struct SharedBuffer(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for SharedBuffer {
+        #[inline]
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.lock().unwrap().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        #[inline]
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    enum Encoder {
+        Gzip(GzEncoder<SharedBuffer>),
+        Zlib(ZlibEncoder<SharedBuffer>),
+    }
+
+    impl Encoder {
+        fn write_all(&mut self, data: &[u8]) -> io::Result<()> {
+            match *self {
+                Encoder::Gzip(ref mut e) => e.write_all(data),
+                Encoder::Zlib(ref mut e) => e.write_all(data),
+            }
+        }
+
+        fn finish(self) -> io::Result<()> {
+            match self {
+                Encoder::Gzip(e) => e.finish().map(|_| ()),
+                Encoder::Zlib(e) => e.finish().map(|_| ()),
+            }
+        }
+    }
+
+    // Reader that reads data from `source` and produces its encoded version.
+    struct EncodingReader {
+        source: Box<Read + Send>,
+        // `None` once the source has been entirely read and the encoder finished.
+        encoder: Option<Encoder>,
+        output: SharedBuffer,
+    }
+
+    impl Read for EncodingReader {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            let mut input = [0; 8192];
+
+            loop {
+                {
+                    let mut output = self.output.0.lock().unwrap();
+                    if !output.is_empty() {
+                        let len = cmp::min(buf.len(), output.len());
+                        buf[..len].copy_from_slice(&output[..len]);
+                        output.drain(..len);
+                        return Ok(len);
+                    }
+                }
+
+                let read = match self.encoder {
+                    Some(_) => try!(self.source.read(&mut input)),
+                    None => return Ok(0),
+                };
+
+                if read == 0 {
+                    try!(self.encoder.take().unwrap().finish());
+                } else {
+                    try!(self.encoder.as_mut().unwrap().write_all(&input[..read]));
+                }
+            }
+        }
+    }
+}
+
 #[cfg(feature = "brotli")]
-fn brotli(e: &str, response: &mut Option<Response>) -> bool {
+fn brotli(e: &str, response: &mut Option<Response>, quality: Quality) -> bool {
     use ResponseBody;
     use std::mem;
     use brotli2::read::BrotliEncoder;
@@ -187,24 +490,40 @@ fn brotli(e: &str, response: &mut Option<Response>) -> bool {
         return false;
     }
 
+    let level = match quality {
+        Quality::Fast => 1,
+        Quality::Default => 6,
+        Quality::Best => 11,
+    };
+
     let response = response.as_mut().unwrap();
     response.headers.push(("Content-Encoding".into(), "br".into()));
     let previous_body = mem::replace(&mut response.data, ResponseBody::empty());
     let (raw_data, _) = previous_body.into_reader_and_size();
-    response.data = ResponseBody::from_reader(BrotliEncoder::new(raw_data, 6));
+    response.data = ResponseBody::from_reader(BrotliEncoder::new(raw_data, level));
     true
 }
 
 #[cfg(not(feature = "brotli"))]
 #[inline]
-fn brotli(e: &str, response: &mut Option<Response>) -> bool {
+fn brotli(e: &str, response: &mut Option<Response>, quality: Quality) -> bool {
     false
 }
 
 #[cfg(test)]
 mod tests {
+    use std::env;
+    use std::fs;
+    use std::fs::File;
+    use std::io::Read;
+    use std::io::Write;
+
     use Request;
+    use Response;
+    use match_assets;
     use content_encoding;
+    use content_encoding::EncodingOptions;
+    use content_encoding::Quality;
 
     #[test]
     fn no_req_encodings() {
@@ -247,5 +566,108 @@ mod tests {
         assert_eq!(list.next(), None);
     }
 
-    // TODO: more tests for encoding stuff
+    fn encoded(accept_encoding: &str, options: &EncodingOptions, data: &str)
+               -> (Option<String>, Vec<u8>)
+    {
+        let request = {
+            let h = vec![("Accept-Encoding".to_owned(), accept_encoding.to_owned())];
+            Request::fake_http("GET", "/", h, vec![])
+        };
+
+        let response = content_encoding::apply_with_options(&request, Response::text(data),
+                                                             options);
+        let encoding = response.headers.iter()
+            .find(|&&(ref k, _)| k.eq_ignore_ascii_case("Content-Encoding"))
+            .map(|&(_, ref v)| v.clone().into_owned());
+        let (mut reader, _) = response.data.into_reader_and_size();
+        let mut body = Vec::new();
+        reader.read_to_end(&mut body).unwrap();
+        (encoding, body)
+    }
+
+    #[test]
+    #[cfg(feature = "gzip")]
+    fn gzip_stream() {
+        let data = "hello world ".repeat(10000);
+        let (encoding, body) = encoded("gzip", &EncodingOptions::new(), &data);
+        assert_eq!(encoding, Some("gzip".to_owned()));
+        assert_eq!(&body[..2], &[0x1f, 0x8b]);
+        assert!(body.len() < data.len());
+
+        // The last four bytes of the gzip trailer are the size of the original data.
+        let len = body.len();
+        let size = body[len - 4] as usize | (body[len - 3] as usize) << 8 |
+                   (body[len - 2] as usize) << 16 | (body[len - 1] as usize) << 24;
+        assert_eq!(size, data.len());
+    }
+
+    #[test]
+    #[cfg(feature = "gzip")]
+    fn deflate_option() {
+        let (encoding, _) = encoded("deflate", &EncodingOptions::new(), "hello");
+        assert_eq!(encoding, None);
+
+        let (encoding, body) = encoded("deflate", &EncodingOptions::new().deflate(true), "hello");
+        assert_eq!(encoding, Some("deflate".to_owned()));
+        // zlib header
+        assert_eq!(body[0] & 0x0f, 8);
+        assert_eq!(((body[0] as u16) << 8 | body[1] as u16) % 31, 0);
+    }
+
+    #[test]
+    #[cfg(feature = "brotli")]
+    fn brotli_quality() {
+        use brotli2::read::BrotliDecoder;
+
+        let data = "hello world ".repeat(1000);
+        for &quality in &[Quality::Fast, Quality::Default, Quality::Best] {
+            let options = EncodingOptions::new().quality(quality);
+            let (encoding, body) = encoded("br", &options, &data);
+            assert_eq!(encoding, Some("br".to_owned()));
+
+            let mut decoded = String::new();
+            BrotliDecoder::new(&body[..]).read_to_string(&mut decoded).unwrap();
+            assert_eq!(decoded, data);
+        }
+    }
+
+    #[test]
+    fn min_size() {
+        let options = EncodingOptions::new().min_size(100);
+        let (encoding, body) = encoded("gzip, br", &options, "hello");
+        assert_eq!(encoding, None);
+        assert_eq!(body, b"hello");
+    }
+
+    #[test]
+    fn zero_quality_is_refused() {
+        let (encoding, _) = encoded("gzip;q=0, br;q=0", &EncodingOptions::new(), "hello");
+        assert_eq!(encoding, None);
+    }
+
+    #[test]
+    fn range_response_is_not_encoded() {
+        let dir = env::temp_dir().join("rouille-content-encoding-test-range");
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        File::create(dir.join("file.txt")).unwrap()
+            .write_all("hello world ".repeat(1000).as_bytes()).unwrap();
+
+        let request = {
+            let h = vec![("Accept-Encoding".to_owned(), "gzip, br".to_owned()),
+                         ("Range".to_owned(), "bytes=6-10".to_owned())];
+            Request::fake_http("GET", "/file.txt", h, vec![])
+        };
+        let response = content_encoding::apply(&request, match_assets(&request, &dir));
+        assert_eq!(response.status_code, 206);
+        assert!(!response.headers.iter()
+                         .any(|&(ref k, _)| k.eq_ignore_ascii_case("Content-Encoding")));
+
+        let (mut reader, _) = response.data.into_reader_and_size();
+        let mut body = String::new();
+        reader.read_to_string(&mut body).unwrap();
+        assert_eq!(body, "world");
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
 }
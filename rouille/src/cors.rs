@@ -0,0 +1,378 @@
+// Copyright (c) 2016 The Rouille developers
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>,
+// at your option. All files in the project carrying such
+// notice may not be copied, modified, or distributed except
+// according to those terms.
+
+//! Cross-origin resource sharing (CORS).
+//!
+//! Browsers forbid a web page from reading the responses of requests it makes to another origin,
+//! unless the server allows it with the `Access-Control-*` headers. Before sending some requests,
+//! for example a `PUT` request or a request with a custom header, browsers also send a
+//! *preflight* `OPTIONS` request in order to ask the server whether the actual request is
+//! allowed.
+//!
+//! The `Cors` struct of this module describes which cross-origin requests are allowed. Its
+//! `handle` method answers preflight requests and adds the appropriate headers to the other
+//! responses. It can also be registered as a middleware with `Server::with_middleware`.
+//!
+//! # Example
+//!
+//! ```
+//! use rouille::Request;
+//! use rouille::Response;
+//! use rouille::cors::Cors;
+//!
+//! fn handle(request: &Request, cors: &Cors) -> Response {
+//!     cors.handle(request, || {
+//!         Response::text("hello world")
+//!     })
+//! }
+//!
+//! let cors = Cors::new()
+//!     .allow_origin("https://example.com")
+//!     .allow_methods(&["GET", "POST", "PUT"])
+//!     .allow_headers(&["Content-Type", "Authorization"])
+//!     .allow_credentials(true)
+//!     .max_age(3600);
+//! ```
+
+// The AsciiExt import is needed for Rust older than 1.23.0. These two lines can
+// be removed when supporting older Rust is no longer needed.
+#[allow(unused_imports)]
+use std::ascii::AsciiExt;
+
+use Request;
+use Response;
+use middleware::Middleware;
+
+/// Describes which cross-origin requests are allowed.
+///
+/// By default no origin is allowed, the allowed methods are `GET`, `HEAD` and `POST`, no header
+/// is allowed or exposed, credentials are not allowed and no max-age is sent.
+#[derive(Debug, Clone)]
+pub struct Cors {
+    any_origin: bool,
+    origins: Vec<String>,
+    methods: Vec<String>,
+    headers: Vec<String>,
+    expose_headers: Vec<String>,
+    credentials: bool,
+    max_age: Option<u64>,
+}
+
+impl Cors {
+    /// Builds a new `Cors` with the default configuration.
+    pub fn new() -> Cors {
+        Cors {
+            any_origin: false,
+            origins: Vec::new(),
+            methods: vec!["GET".to_owned(), "HEAD".to_owned(), "POST".to_owned()],
+            headers: Vec::new(),
+            expose_headers: Vec::new(),
+            credentials: false,
+            max_age: None,
+        }
+    }
+
+    /// Allows requests from any origin.
+    #[inline]
+    pub fn allow_any_origin(mut self) -> Cors {
+        self.any_origin = true;
+        self
+    }
+
+    /// Allows requests from the given origin, for example `https://example.com`.
+    #[inline]
+    pub fn allow_origin<S>(mut self, origin: S) -> Cors where S: Into<String> {
+        self.origins.push(origin.into());
+        self
+    }
+
+    /// Sets the list of methods that are allowed. Replaces the default list.
+    pub fn allow_methods(mut self, methods: &[&str]) -> Cors {
+        self.methods = methods.iter().map(|m| m.to_uppercase()).collect();
+        self
+    }
+
+    /// Sets the list of request headers that are allowed.
+    pub fn allow_headers(mut self, headers: &[&str]) -> Cors {
+        self.headers = headers.iter().map(|h| (*h).to_owned()).collect();
+        self
+    }
+
+    /// Sets the list of response headers that the browser is allowed to expose to the page.
+    pub fn expose_headers(mut self, headers: &[&str]) -> Cors {
+        self.expose_headers = headers.iter().map(|h| (*h).to_owned()).collect();
+        self
+    }
+
+    /// Sets whether the browser is allowed to send cookies and HTTP authentication along with
+    /// the requests.
+    ///
+    /// If `true` and any origin is allowed, the origin of the request is sent back instead of
+    /// `*`, as browsers don't accept `*` in that situation.
+    #[inline]
+    pub fn allow_credentials(mut self, credentials: bool) -> Cors {
+        self.credentials = credentials;
+        self
+    }
+
+    /// Sets the number of seconds during which browsers can cache the response to a preflight
+    /// request.
+    #[inline]
+    pub fn max_age(mut self, seconds: u64) -> Cors {
+        self.max_age = Some(seconds);
+        self
+    }
+
+    /// Returns true if requests from the given origin are allowed.
+    pub fn is_origin_allowed(&self, origin: &str) -> bool {
+        self.any_origin || self.origins.iter().any(|o| o.eq_ignore_ascii_case(origin))
+    }
+
+    /// Answers the request if it is a preflight request, and otherwise calls `inner` and adds the
+    /// CORS headers to its response.
+    pub fn handle<F>(&self, request: &Request, inner: F) -> Response
+        where F: FnOnce() -> Response
+    {
+        if let Some(response) = self.preflight(request) {
+            return response;
+        }
+
+        let response = inner();
+        self.apply(request, response)
+    }
+
+    /// If the request is a preflight request, returns the response to send back.
+    ///
+    /// A preflight request is an `OPTIONS` request with an `Origin` and an
+    /// `Access-Control-Request-Method` header. If the origin, the method or one of the headers
+    /// is not allowed, the response is a `403` error.
+    pub fn preflight(&self, request: &Request) -> Option<Response> {
+        if request.method() != "OPTIONS" {
+            return None;
+        }
+
+        let origin = match request.header("Origin") {
+            Some(o) => o,
+            None => return None,
+        };
+
+        let method = match request.header("Access-Control-Request-Method") {
+            Some(m) => m.trim(),
+            None => return None,
+        };
+
+        if !self.is_origin_allowed(origin) || !self.methods.iter().any(|m| m == method) {
+            return Some(self.add_vary(Response::text("").with_status_code(403)));
+        }
+
+        let requested_headers = request.header("Access-Control-Request-Headers").unwrap_or("");
+        let requested_headers = requested_headers.split(',').map(|h| h.trim())
+                                                 .filter(|h| !h.is_empty());
+        for header in requested_headers {
+            if !self.headers.iter().any(|h| h.eq_ignore_ascii_case(header)) {
+                return Some(self.add_vary(Response::text("").with_status_code(403)));
+            }
+        }
+
+        let mut response = self.add_origin_headers(origin, Response::empty_204());
+        response = response.with_unique_header("Access-Control-Allow-Methods",
+                                               self.methods.join(", "));
+        if !self.headers.is_empty() {
+            response = response.with_unique_header("Access-Control-Allow-Headers",
+                                                   self.headers.join(", "));
+        }
+        if let Some(max_age) = self.max_age {
+            response = response.with_unique_header("Access-Control-Max-Age", max_age.to_string());
+        }
+
+        Some(response)
+    }
+
+    /// Adds the CORS headers to a response, if the request has an `Origin` header with an
+    /// allowed origin.
+    ///
+    /// Unless any origin is allowed with `*`, `Origin` is added to the `Vary` header of the
+    /// response even if the request has no origin or a forbidden one, so that caches don't serve
+    /// this response to requests from other origins.
+    pub fn apply(&self, request: &Request, response: Response) -> Response {
+        let response = self.add_vary(response);
+        let origin = match request.header("Origin") {
+            Some(o) => o,
+            None => return response,
+        };
+
+        if !self.is_origin_allowed(origin) {
+            return response;
+        }
+
+        let mut response = self.add_origin_headers(origin, response);
+        if !self.expose_headers.is_empty() {
+            response = response.with_unique_header("Access-Control-Expose-Headers",
+                                                   self.expose_headers.join(", "));
+        }
+        response
+    }
+
+    fn add_origin_headers(&self, origin: &str, response: Response) -> Response {
+        let mut response = if self.any_origin && !self.credentials {
+            response.with_unique_header("Access-Control-Allow-Origin", "*")
+        } else {
+            self.add_vary(response)
+                .with_unique_header("Access-Control-Allow-Origin", origin.to_owned())
+        };
+
+        if self.credentials {
+            response = response.with_unique_header("Access-Control-Allow-Credentials", "true");
+        }
+
+        response
+    }
+
+    // Adds `Origin` to the `Vary` header of a response, as the response depends on the origin
+    // of the request unless any origin is allowed with `*`.
+    fn add_vary(&self, response: Response) -> Response {
+        if self.any_origin && !self.credentials {
+            return response;
+        }
+
+        let existing = response.headers.iter()
+                               .find(|&&(ref k, _)| k.eq_ignore_ascii_case("Vary"));
+        let vary = match existing {
+            Some(&(_, ref v)) if v.split(',').any(|v| v.trim() == "Origin") => {
+                v.clone().into_owned()
+            },
+            Some(&(_, ref v)) => format!("{}, Origin", v),
+            None => "Origin".to_owned(),
+        };
+
+        response.with_unique_header("Vary", vary)
+    }
+}
+
+impl Default for Cors {
+    #[inline]
+    fn default() -> Cors {
+        Cors::new()
+    }
+}
+
+impl Middleware for Cors {
+    #[inline]
+    fn before(&self, request: &Request) -> Option<Response> {
+        self.preflight(request)
+    }
+
+    #[inline]
+    fn after(&self, request: &Request, response: Response) -> Response {
+        self.apply(request, response)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use Request;
+    use Response;
+    use super::Cors;
+
+    fn header<'a>(response: &'a Response, name: &str) -> Option<&'a str> {
+        response.headers.iter().find(|&&(ref k, _)| k.eq_ignore_ascii_case(name))
+                .map(|&(_, ref v)| &v[..])
+    }
+
+    fn request(method: &str, headers: &[(&str, &str)]) -> Request {
+        let headers = headers.iter().map(|&(k, v)| (k.to_owned(), v.to_owned())).collect();
+        Request::fake_http(method, "/", headers, vec![])
+    }
+
+    fn cors() -> Cors {
+        Cors::new().allow_origin("https://example.com")
+                   .allow_methods(&["GET", "PUT"])
+                   .allow_headers(&["Content-Type", "X-Token"])
+                   .max_age(600)
+    }
+
+    #[test]
+    fn no_origin() {
+        let response = cors().handle(&request("GET", &[]), || Response::text(""));
+        assert_eq!(header(&response, "Access-Control-Allow-Origin"), None);
+        assert_eq!(header(&response, "Vary"), Some("Origin"));
+    }
+
+    #[test]
+    fn allowed_origin() {
+        let rq = request("GET", &[("Origin", "https://example.com")]);
+        let response = cors().handle(&rq, || Response::text(""));
+        assert_eq!(header(&response, "Access-Control-Allow-Origin"), Some("https://example.com"));
+        assert_eq!(header(&response, "Vary"), Some("Origin"));
+    }
+
+    #[test]
+    fn vary_is_merged() {
+        let rq = request("GET", &[("Origin", "https://example.com")]);
+        let response = cors().handle(&rq, || {
+            Response::text("").with_unique_header("Vary", "Accept-Encoding")
+        });
+        assert_eq!(header(&response, "Vary"), Some("Accept-Encoding, Origin"));
+    }
+
+    #[test]
+    fn forbidden_origin() {
+        let rq = request("GET", &[("Origin", "https://evil.com")]);
+        let response = cors().handle(&rq, || Response::text(""));
+        assert_eq!(header(&response, "Access-Control-Allow-Origin"), None);
+        assert_eq!(header(&response, "Vary"), Some("Origin"));
+    }
+
+    #[test]
+    fn any_origin() {
+        let rq = request("GET", &[("Origin", "https://foo.com")]);
+        let response = Cors::new().allow_any_origin().handle(&rq, || Response::text(""));
+        assert_eq!(header(&response, "Access-Control-Allow-Origin"), Some("*"));
+        assert_eq!(header(&response, "Vary"), None);
+
+        let response = Cors::new().allow_any_origin().allow_credentials(true)
+                                  .handle(&rq, || Response::text(""));
+        assert_eq!(header(&response, "Access-Control-Allow-Origin"), Some("https://foo.com"));
+        assert_eq!(header(&response, "Access-Control-Allow-Credentials"), Some("true"));
+        assert_eq!(header(&response, "Vary"), Some("Origin"));
+    }
+
+    #[test]
+    fn preflight() {
+        let rq = request("OPTIONS", &[("Origin", "https://example.com"),
+                                      ("Access-Control-Request-Method", "PUT"),
+                                      ("Access-Control-Request-Headers", "content-type, x-token")]);
+        let response = cors().handle(&rq, || panic!());
+        assert_eq!(response.status_code, 204);
+        assert_eq!(header(&response, "Access-Control-Allow-Origin"), Some("https://example.com"));
+        assert_eq!(header(&response, "Access-Control-Allow-Methods"), Some("GET, PUT"));
+        assert_eq!(header(&response, "Access-Control-Allow-Headers"),
+                   Some("Content-Type, X-Token"));
+        assert_eq!(header(&response, "Access-Control-Max-Age"), Some("600"));
+    }
+
+    #[test]
+    fn preflight_rejected() {
+        let rq = request("OPTIONS", &[("Origin", "https://example.com"),
+                                      ("Access-Control-Request-Method", "DELETE")]);
+        assert_eq!(cors().handle(&rq, || panic!()).status_code, 403);
+
+        let rq = request("OPTIONS", &[("Origin", "https://example.com"),
+                                      ("Access-Control-Request-Method", "GET"),
+                                      ("Access-Control-Request-Headers", "X-Other")]);
+        assert_eq!(cors().handle(&rq, || panic!()).status_code, 403);
+    }
+
+    #[test]
+    fn plain_options() {
+        let rq = request("OPTIONS", &[("Origin", "https://example.com")]);
+        let response = cors().handle(&rq, || Response::text("").with_status_code(200));
+        assert_eq!(response.status_code, 200);
+    }
+}
@@ -0,0 +1,100 @@
+// Copyright (c) 2016 The Rouille developers
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>,
+// at your option. All files in the project carrying such
+// notice may not be copied, modified, or distributed except
+// according to those terms.
+
+// The AsciiExt import is needed for Rust older than 1.23.0. These two lines can
+// be removed when supporting older Rust is no longer needed.
+#[allow(unused_imports)]
+use std::ascii::AsciiExt;
+
+use Request;
+
+/// Attempts to parse a `Authorization` header with a bearer token (RFC 6750).
+///
+/// If such a header is present and valid, the token is returned. Rouille doesn't know how the
+/// tokens are issued, so checking that the token is valid is up to you.
+///
+/// # Example
+///
+/// ```
+/// use rouille::input;
+/// use rouille::Request;
+/// use rouille::Response;
+///
+/// fn handle(request: &Request) -> Response {
+///     let token = match input::bearer_http_auth(request) {
+///         Some(t) => t,
+///         None => return Response::bearer_http_auth_login_required("api")
+///     };
+///
+///     if token == "mF_9.B5f-4.1JqM" {
+///         Response::text("You are in a secret area")
+///     } else {
+///         Response::bearer_http_auth_invalid_token("api")
+///     }
+/// }
+/// ```
+pub fn bearer_http_auth(request: &Request) -> Option<&str> {
+    let header = match request.header("Authorization") {
+        None => return None,
+        Some(h) => h,
+    };
+
+    let mut split = header.splitn(2, |c| c == ' ');
+    let authtype = match split.next() { None => return None, Some(t) => t };
+
+    if !authtype.eq_ignore_ascii_case("Bearer") {
+        return None;
+    }
+
+    let token = match split.next() { None => return None, Some(t) => t.trim() };
+
+    // The token must match the `b64token` rule of RFC 6750.
+    let padding = token.bytes().rev().take_while(|&b| b == b'=').count();
+    let valid = padding < token.len() && token[.. token.len() - padding].chars().all(|c| {
+        (c >= 'a' && c <= 'z') || (c >= 'A' && c <= 'Z') || (c >= '0' && c <= '9') ||
+            c == '-' || c == '.' || c == '_' || c == '~' || c == '+' || c == '/'
+    });
+
+    if valid {
+        Some(token)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use Request;
+    use super::bearer_http_auth;
+
+    fn with_auth(value: &str) -> Request {
+        Request::fake_http("GET", "/", vec![("Authorization".to_owned(), value.to_owned())],
+                           Vec::new())
+    }
+
+    #[test]
+    fn no_header() {
+        let request = Request::fake_http("GET", "/", vec![], Vec::new());
+        assert_eq!(bearer_http_auth(&request), None);
+    }
+
+    #[test]
+    fn wrong_header() {
+        assert_eq!(bearer_http_auth(&with_auth("Basic QWxhZGRpbjpvcGVuIHNlc2FtZQ==")), None);
+        assert_eq!(bearer_http_auth(&with_auth("Bearer")), None);
+        assert_eq!(bearer_http_auth(&with_auth("Bearer a b")), None);
+        assert_eq!(bearer_http_auth(&with_auth("Bearer =abc")), None);
+    }
+
+    #[test]
+    fn ok() {
+        assert_eq!(bearer_http_auth(&with_auth("Bearer mF_9.B5f-4.1JqM")), Some("mF_9.B5f-4.1JqM"));
+        assert_eq!(bearer_http_auth(&with_auth("bearer abc+/==")), Some("abc+/=="));
+    }
+}
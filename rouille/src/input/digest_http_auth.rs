@@ -0,0 +1,642 @@
+// Copyright (c) 2016 The Rouille developers
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>,
+// at your option. All files in the project carrying such
+// notice may not be copied, modified, or distributed except
+// according to those terms.
+
+// The AsciiExt import is needed for Rust older than 1.23.0. These two lines can
+// be removed when supporting older Rust is no longer needed.
+#[allow(unused_imports)]
+use std::ascii::AsciiExt;
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::Duration;
+use std::time::Instant;
+use rand;
+use url::percent_encoding;
+
+use Request;
+use super::hash;
+
+/// Hash algorithm of digest HTTP authentication.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum DigestAlgorithm {
+    /// `MD5`. This is the algorithm that is used if the client doesn't specify one.
+    Md5,
+    /// `MD5-sess`.
+    Md5Sess,
+    /// `SHA-256`.
+    Sha256,
+    /// `SHA-256-sess`.
+    Sha256Sess,
+}
+
+impl DigestAlgorithm {
+    /// Returns the name of the algorithm, as it appears in the headers.
+    #[inline]
+    pub fn name(&self) -> &'static str {
+        match *self {
+            DigestAlgorithm::Md5 => "MD5",
+            DigestAlgorithm::Md5Sess => "MD5-sess",
+            DigestAlgorithm::Sha256 => "SHA-256",
+            DigestAlgorithm::Sha256Sess => "SHA-256-sess",
+        }
+    }
+
+    /// Computes the value named `A1` in RFC 7616, which is the hash of
+    /// `username:realm:password`.
+    ///
+    /// You can store this value instead of the password and pass it to
+    /// `DigestCredentials::verify_ha1`. The value is the same for an algorithm and its `-sess`
+    /// variant.
+    pub fn ha1(&self, username: &str, realm: &str, password: &str) -> String {
+        self.hash(&format!("{}:{}:{}", username, realm, password))
+    }
+
+    fn from_name(name: &str) -> Option<DigestAlgorithm> {
+        let all = [DigestAlgorithm::Md5, DigestAlgorithm::Md5Sess, DigestAlgorithm::Sha256,
+                   DigestAlgorithm::Sha256Sess];
+        all.iter().find(|a| a.name().eq_ignore_ascii_case(name)).cloned()
+    }
+
+    fn is_session(&self) -> bool {
+        match *self {
+            DigestAlgorithm::Md5Sess | DigestAlgorithm::Sha256Sess => true,
+            DigestAlgorithm::Md5 | DigestAlgorithm::Sha256 => false,
+        }
+    }
+
+    fn hash(&self, data: &str) -> String {
+        match *self {
+            DigestAlgorithm::Md5 | DigestAlgorithm::Md5Sess => {
+                hash::to_hex(&hash::md5(data.as_bytes()))
+            },
+            DigestAlgorithm::Sha256 | DigestAlgorithm::Sha256Sess => {
+                hash::to_hex(&hash::sha256(data.as_bytes()))
+            },
+        }
+    }
+}
+
+/// Credentials returned by `digest_http_auth`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DigestCredentials {
+    /// Login provided by the client.
+    pub username: String,
+    /// Realm that the client is authenticating for.
+    pub realm: String,
+    /// Nonce that the server has sent in the challenge.
+    pub nonce: String,
+    /// URI of the request, as sent by the client.
+    pub uri: String,
+    /// Digest computed by the client, in hexadecimal.
+    pub response: String,
+    /// Hash algorithm used by the client.
+    pub algorithm: DigestAlgorithm,
+    /// Quality of protection. Always `auth` if present.
+    pub qop: Option<String>,
+    /// Nonce count, as eight hexadecimal digits. Present if and only if `qop` is present.
+    pub nc: Option<String>,
+    /// Nonce chosen by the client. Present if and only if `qop` is present.
+    pub cnonce: Option<String>,
+    /// Opaque value that the server has sent in the challenge, if any.
+    pub opaque: Option<String>,
+}
+
+impl DigestCredentials {
+    /// Returns true if the credentials have been computed with the given password for the given
+    /// request.
+    ///
+    /// This also checks that the URI of the credentials matches the URL of the request. It
+    /// doesn't check the realm nor the nonce. Use a `DigestNonces` in order to check the nonce.
+    #[inline]
+    pub fn verify(&self, request: &Request, password: &str) -> bool {
+        let ha1 = self.algorithm.ha1(&self.username, &self.realm, password);
+        self.verify_ha1(request, &ha1)
+    }
+
+    /// Same as `verify`, but with the hash returned by `DigestAlgorithm::ha1` instead of the
+    /// password.
+    pub fn verify_ha1(&self, request: &Request, ha1: &str) -> bool {
+        if self.uri != request.raw_url() {
+            return false;
+        }
+
+        let ha1 = if self.algorithm.is_session() {
+            let cnonce = self.cnonce.as_ref().map(|s| &s[..]).unwrap_or("");
+            self.algorithm.hash(&format!("{}:{}:{}", ha1, self.nonce, cnonce))
+        } else {
+            ha1.to_owned()
+        };
+
+        let ha2 = self.algorithm.hash(&format!("{}:{}", request.method(), self.uri));
+
+        let expected = match (&self.qop, &self.nc, &self.cnonce) {
+            (&Some(ref qop), &Some(ref nc), &Some(ref cnonce)) => {
+                self.algorithm.hash(&format!("{}:{}:{}:{}:{}:{}", ha1, self.nonce, nc, cnonce,
+                                             qop, ha2))
+            },
+            _ => self.algorithm.hash(&format!("{}:{}:{}", ha1, self.nonce, ha2)),
+        };
+
+        constant_time_eq(expected.as_bytes(), self.response.to_lowercase().as_bytes())
+    }
+}
+
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+
+    a.iter().zip(b.iter()).fold(0, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
+/// Attempts to parse a `Authorization` header with digest HTTP auth (RFC 7616).
+///
+/// If such a header is present and valid, a `DigestCredentials` is returned. The `auth-int`
+/// quality of protection and the `userhash` parameter are not supported, and credentials that
+/// use them are ignored.
+///
+/// In order to authenticate a client, you need to:
+///
+/// - Send a challenge with `Response::digest_http_auth_login_required` and a nonce produced by
+///   `DigestNonces::generate`.
+/// - Check the credentials sent back by the client with `DigestCredentials::verify`.
+/// - Check the nonce with `DigestNonces::check`. If the nonce is stale, send a new challenge
+///   with `stale` set to `true`, so that the client retries without asking the user again.
+///
+/// # Example
+///
+/// ```
+/// use rouille::input;
+/// use rouille::input::DigestNonces;
+/// use rouille::input::NonceStatus;
+/// use rouille::Request;
+/// use rouille::Response;
+///
+/// fn handle(request: &Request, nonces: &DigestNonces) -> Response {
+///     let login_required = |stale| {
+///         Response::digest_http_auth_login_required("realm", &nonces.generate(), stale)
+///     };
+///
+///     let auth = match input::digest_http_auth(request) {
+///         Some(a) => a,
+///         None => return login_required(false)
+///     };
+///
+///     if auth.realm != "realm" || auth.username != "admin" ||
+///        !auth.verify(request, "GT5GeKyLvKLxuc7mjF5h")
+///     {
+///         return login_required(false);
+///     }
+///
+///     match nonces.check(&auth) {
+///         NonceStatus::Valid => Response::text("You are in a secret area"),
+///         NonceStatus::Stale => login_required(true),
+///         NonceStatus::Replayed => Response::text("Replayed request").with_status_code(403),
+///     }
+/// }
+/// ```
+pub fn digest_http_auth(request: &Request) -> Option<DigestCredentials> {
+    let header = match request.header("Authorization") {
+        None => return None,
+        Some(h) => h,
+    };
+
+    let mut split = header.splitn(2, |c| c == ' ');
+    let authtype = match split.next() { None => return None, Some(t) => t };
+
+    if !authtype.eq_ignore_ascii_case("Digest") {
+        return None;
+    }
+
+    let params = match split.next().and_then(parse_params) {
+        Some(p) => p, None => return None
+    };
+
+    let param = |name: &str| {
+        params.iter().find(|&&(ref k, _)| k == name).map(|&(_, ref v)| v.clone())
+    };
+
+    if param("userhash").map(|v| v.eq_ignore_ascii_case("true")).unwrap_or(false) {
+        return None;
+    }
+
+    let username = match (param("username"), param("username*")) {
+        (Some(u), None) => u,
+        (None, Some(u)) => match decode_ext_value(&u) { Some(u) => u, None => return None },
+        _ => return None,
+    };
+
+    let algorithm = match param("algorithm") {
+        Some(a) => match DigestAlgorithm::from_name(&a) { Some(a) => a, None => return None },
+        None => DigestAlgorithm::Md5,
+    };
+
+    let qop = param("qop");
+    let nc = param("nc");
+    let cnonce = param("cnonce");
+
+    match (&qop, &nc, &cnonce) {
+        (&Some(ref qop), &Some(ref nc), &Some(_)) => {
+            if qop != "auth" || nc.len() != 8 || u32::from_str_radix(nc, 16).is_err() {
+                return None;
+            }
+        },
+        (&None, &None, &None) => (),
+        _ => return None,
+    }
+
+    Some(DigestCredentials {
+        username: username,
+        realm: match param("realm") { Some(r) => r, None => return None },
+        nonce: match param("nonce") { Some(n) => n, None => return None },
+        uri: match param("uri") { Some(u) => u, None => return None },
+        response: match param("response") { Some(r) => r, None => return None },
+        algorithm: algorithm,
+        qop: qop,
+        nc: nc,
+        cnonce: cnonce,
+        opaque: param("opaque"),
+    })
+}
+
+// Parses a comma-separated list of `name=value` or `name="value"`. The names are lowercased.
+fn parse_params(input: &str) -> Option<Vec<(String, String)>> {
+    let mut params = Vec::new();
+    let mut chars = input.chars().peekable();
+
+    loop {
+        while chars.peek().map(|&c| c == ' ' || c == '\t' || c == ',').unwrap_or(false) {
+            chars.next();
+        }
+
+        if chars.peek().is_none() {
+            return Some(params);
+        }
+
+        let mut name = String::new();
+        while let Some(&c) = chars.peek() {
+            if c == '=' || c == ' ' || c == '\t' || c == ',' || c == '"' {
+                break;
+            }
+            name.push(c.to_ascii_lowercase());
+            chars.next();
+        }
+
+        while chars.peek().map(|&c| c == ' ' || c == '\t').unwrap_or(false) {
+            chars.next();
+        }
+        if name.is_empty() || chars.next() != Some('=') {
+            return None;
+        }
+        while chars.peek().map(|&c| c == ' ' || c == '\t').unwrap_or(false) {
+            chars.next();
+        }
+
+        let mut value = String::new();
+        if chars.peek() == Some(&'"') {
+            chars.next();
+            loop {
+                match chars.next() {
+                    Some('"') => break,
+                    Some('\\') => match chars.next() {
+                        Some(c) => value.push(c),
+                        None => return None,
+                    },
+                    Some(c) => value.push(c),
+                    None => return None,
+                }
+            }
+        } else {
+            while let Some(&c) = chars.peek() {
+                if c == ',' || c == ' ' || c == '\t' {
+                    break;
+                }
+                value.push(c);
+                chars.next();
+            }
+        }
+
+        params.push((name, value));
+    }
+}
+
+// Decodes a value of the form `UTF-8''percent-encoded` (RFC 5987).
+fn decode_ext_value(value: &str) -> Option<String> {
+    let mut split = value.splitn(3, '\'');
+    let charset = match split.next() { Some(c) => c, None => return None };
+    let encoded = match (split.next(), split.next()) { (Some(_), Some(e)) => e, _ => return None };
+
+    if !charset.eq_ignore_ascii_case("UTF-8") {
+        return None;
+    }
+
+    percent_encoding::percent_decode(encoded.as_bytes()).decode_utf8().ok()
+                                                         .map(|s| s.into_owned())
+}
+
+/// Result of `DigestNonces::check`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum NonceStatus {
+    /// The nonce is valid.
+    Valid,
+    /// The nonce has expired or is unknown, for example because the server has restarted. You
+    /// should answer with a new challenge where `stale` is `true`.
+    Stale,
+    /// The nonce count has already been used with this nonce. The request is probably a replay of
+    /// an earlier request.
+    Replayed,
+}
+
+/// Generates the nonces of digest HTTP authentication and tracks their use.
+///
+/// A nonce is valid for a limited time after it has been generated. Nonces are signed with a key
+/// that is randomly generated when the `DigestNonces` is built, which means that generating a
+/// nonce doesn't use any memory. Only the nonces that have been used by clients are remembered,
+/// until they expire.
+///
+/// When the client sends a nonce count, each count can only be used once, which prevents
+/// replaying requests. Clients may send requests in parallel, so the counts don't need to
+/// arrive in order, but a count that is more than 64 below the highest count seen with the same
+/// nonce is considered as replayed.
+pub struct DigestNonces {
+    lifetime: Duration,
+    key: [u8; 32],
+    // Origin of the timestamps that are stored in the nonces.
+    start: Instant,
+    used: Mutex<HashMap<String, NonceState>>,
+}
+
+struct NonceState {
+    // Milliseconds between `start` and the generation of the nonce.
+    timestamp: u64,
+    // Highest nonce count that has been seen.
+    highest_count: u32,
+    // Bit `n` is set if the count `highest_count - n` has been seen.
+    seen: u64,
+}
+
+impl DigestNonces {
+    /// Builds a new `DigestNonces`. The nonces that it generates expire after `lifetime`.
+    pub fn new(lifetime: Duration) -> DigestNonces {
+        let mut key = [0; 32];
+        for chunk in key.chunks_mut(8) {
+            let random = rand::random::<u64>();
+            for (i, byte) in chunk.iter_mut().enumerate() {
+                *byte = (random >> (i * 8)) as u8;
+            }
+        }
+
+        DigestNonces {
+            lifetime: lifetime,
+            key: key,
+            start: Instant::now(),
+            used: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Generates a new nonce, to pass to `Response::digest_http_auth_login_required`.
+    pub fn generate(&self) -> String {
+        let payload = format!("{:016x}{:016x}", millis(self.start.elapsed()),
+                              rand::random::<u64>());
+        let signature = hash::to_hex(&hash::hmac_sha256(&self.key, payload.as_bytes()));
+        payload + &signature
+    }
+
+    /// Checks the nonce and the nonce count of credentials.
+    ///
+    /// You should call this method only after the credentials have been verified, as it
+    /// records the nonce count as used.
+    pub fn check(&self, credentials: &DigestCredentials) -> NonceStatus {
+        let timestamp = match self.verify_signature(&credentials.nonce) {
+            Some(t) => t,
+            None => return NonceStatus::Stale,
+        };
+
+        let now = millis(self.start.elapsed());
+        let lifetime = millis(self.lifetime);
+        if now.saturating_sub(timestamp) >= lifetime {
+            return NonceStatus::Stale;
+        }
+
+        let count = match credentials.nc {
+            Some(ref nc) => match u32::from_str_radix(nc, 16) {
+                Ok(c) => c,
+                Err(_) => return NonceStatus::Replayed,
+            },
+            // Without a nonce count, the nonce can be used multiple times until it expires.
+            None => return NonceStatus::Valid,
+        };
+
+        let mut used = self.used.lock().unwrap();
+        used.retain(|_, state| now.saturating_sub(state.timestamp) < lifetime);
+
+        let state = used.entry(credentials.nonce.clone()).or_insert(NonceState {
+            timestamp: timestamp,
+            highest_count: 0,
+            // The count 0 is never valid.
+            seen: 1,
+        });
+
+        if count > state.highest_count {
+            let shift = count - state.highest_count;
+            state.seen = if shift >= 64 { 0 } else { state.seen << shift };
+            state.seen |= 1;
+            state.highest_count = count;
+            return NonceStatus::Valid;
+        }
+
+        let offset = state.highest_count - count;
+        if offset >= 64 || state.seen & (1 << offset) != 0 {
+            return NonceStatus::Replayed;
+        }
+
+        state.seen |= 1 << offset;
+        NonceStatus::Valid
+    }
+
+    // Checks that the nonce has been generated by `self`, and returns its timestamp.
+    fn verify_signature(&self, nonce: &str) -> Option<u64> {
+        if nonce.len() != 96 || !nonce.is_char_boundary(32) {
+            return None;
+        }
+
+        let (payload, signature) = nonce.split_at(32);
+        let expected = hash::to_hex(&hash::hmac_sha256(&self.key, payload.as_bytes()));
+        if !constant_time_eq(signature.as_bytes(), expected.as_bytes()) {
+            return None;
+        }
+
+        u64::from_str_radix(&payload[.. 16], 16).ok()
+    }
+}
+
+// Converts a `Duration` to a number of milliseconds.
+fn millis(duration: Duration) -> u64 {
+    duration.as_secs().saturating_mul(1000) + u64::from(duration.subsec_nanos() / 1_000_000)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+    use std::time::Duration;
+
+    use Request;
+    use super::DigestAlgorithm;
+    use super::DigestCredentials;
+    use super::DigestNonces;
+    use super::NonceStatus;
+    use super::digest_http_auth;
+
+    fn with_auth(value: &str) -> Request {
+        Request::fake_http("GET", "/dir/index.html",
+                           vec![("Authorization".to_owned(), value.to_owned())], Vec::new())
+    }
+
+    // Example of section 3.9.1 of RFC 7616.
+    const RFC_EXAMPLE: &str = "Digest username=\"Mufasa\", \
+        realm=\"http-auth@example.org\", uri=\"/dir/index.html\", algorithm=MD5, \
+        nonce=\"7ypf/xlj9XXwfDPEoM4URrv/xwf94BcCAzFZH4GiTo0v\", nc=00000001, \
+        cnonce=\"f2/wE4q74E6zIJEtWaHKaf5wv/H5QzzpXusqGemxURZJ\", qop=auth, \
+        response=\"8ca523f5e9506fed4657c9700eebdbec\", \
+        opaque=\"FQhe/qaU925kfnzjCev0ciny7QMkPqMAFRtzCUYo5tdS\"";
+
+    #[test]
+    fn no_header() {
+        let request = Request::fake_http("GET", "/", vec![], Vec::new());
+        assert_eq!(digest_http_auth(&request), None);
+    }
+
+    #[test]
+    fn wrong_header() {
+        assert_eq!(digest_http_auth(&with_auth("Basic QWxhZGRpbjpvcGVuIHNlc2FtZQ==")), None);
+        assert_eq!(digest_http_auth(&with_auth("Digest username=\"Mufasa")), None);
+        assert_eq!(digest_http_auth(&with_auth("Digest username=\"Mufasa\"")), None);
+        assert_eq!(digest_http_auth(&with_auth(&RFC_EXAMPLE.replace("qop=auth", "qop=auth-int"))),
+                   None);
+        assert_eq!(digest_http_auth(&with_auth(&RFC_EXAMPLE.replace("MD5", "SHA-512"))), None);
+    }
+
+    #[test]
+    fn rfc_example_md5() {
+        let request = with_auth(RFC_EXAMPLE);
+        let credentials = digest_http_auth(&request).unwrap();
+        assert_eq!(credentials.username, "Mufasa");
+        assert_eq!(credentials.algorithm, DigestAlgorithm::Md5);
+        assert_eq!(credentials.nc, Some("00000001".to_owned()));
+        assert!(credentials.verify(&request, "Circle of Life"));
+        assert!(!credentials.verify(&request, "circle of life"));
+
+        let other_url = Request::fake_http("GET", "/dir/other.html", vec![], Vec::new());
+        assert!(!credentials.verify(&other_url, "Circle of Life"));
+    }
+
+    #[test]
+    fn rfc_example_sha256() {
+        let header = RFC_EXAMPLE.replace("algorithm=MD5", "algorithm=SHA-256")
+            .replace("8ca523f5e9506fed4657c9700eebdbec",
+                     "753927fa0e85d155564e2e272a28d1802ca10daf4496794697cf8db5856cb6c1");
+        let request = with_auth(&header);
+        let credentials = digest_http_auth(&request).unwrap();
+        assert_eq!(credentials.algorithm, DigestAlgorithm::Sha256);
+        assert!(credentials.verify(&request, "Circle of Life"));
+
+        let ha1 = DigestAlgorithm::Sha256.ha1("Mufasa", "http-auth@example.org",
+                                              "Circle of Life");
+        assert!(credentials.verify_ha1(&request, &ha1));
+    }
+
+    #[test]
+    fn extended_username() {
+        let header = RFC_EXAMPLE.replace("username=\"Mufasa\"", "username*=UTF-8''J%C3%A4s%C3%B8n");
+        let credentials = digest_http_auth(&with_auth(&header)).unwrap();
+        assert_eq!(credentials.username, "J\u{e4}s\u{f8}n");
+    }
+
+    fn credentials(nonce: &str, nc: Option<&str>) -> DigestCredentials {
+        DigestCredentials {
+            username: "Mufasa".to_owned(),
+            realm: "realm".to_owned(),
+            nonce: nonce.to_owned(),
+            uri: "/".to_owned(),
+            response: String::new(),
+            algorithm: DigestAlgorithm::Md5,
+            qop: nc.map(|_| "auth".to_owned()),
+            nc: nc.map(|nc| nc.to_owned()),
+            cnonce: nc.map(|_| "abc".to_owned()),
+            opaque: None,
+        }
+    }
+
+    #[test]
+    fn nonce_count() {
+        let nonces = DigestNonces::new(Duration::from_secs(60));
+        let nonce = nonces.generate();
+
+        assert_eq!(nonces.check(&credentials(&nonce, Some("00000000"))), NonceStatus::Replayed);
+        assert_eq!(nonces.check(&credentials(&nonce, Some("00000001"))), NonceStatus::Valid);
+        assert_eq!(nonces.check(&credentials(&nonce, Some("00000001"))), NonceStatus::Replayed);
+        assert_eq!(nonces.check(&credentials(&nonce, Some("0000000a"))), NonceStatus::Valid);
+        assert_eq!(nonces.check(&credentials(&nonce, None)), NonceStatus::Valid);
+    }
+
+    #[test]
+    fn nonce_count_out_of_order() {
+        let nonces = DigestNonces::new(Duration::from_secs(60));
+        let nonce = nonces.generate();
+
+        assert_eq!(nonces.check(&credentials(&nonce, Some("00000003"))), NonceStatus::Valid);
+        assert_eq!(nonces.check(&credentials(&nonce, Some("00000001"))), NonceStatus::Valid);
+        assert_eq!(nonces.check(&credentials(&nonce, Some("00000002"))), NonceStatus::Valid);
+        assert_eq!(nonces.check(&credentials(&nonce, Some("00000002"))), NonceStatus::Replayed);
+        assert_eq!(nonces.check(&credentials(&nonce, Some("00000043"))), NonceStatus::Valid);
+        assert_eq!(nonces.check(&credentials(&nonce, Some("00000004"))), NonceStatus::Valid);
+        assert_eq!(nonces.check(&credentials(&nonce, Some("00000003"))), NonceStatus::Replayed);
+        assert_eq!(nonces.check(&credentials(&nonce, Some("00000100"))), NonceStatus::Valid);
+        assert_eq!(nonces.check(&credentials(&nonce, Some("00000050"))), NonceStatus::Replayed);
+    }
+
+    #[test]
+    fn unknown_nonce() {
+        let nonces = DigestNonces::new(Duration::from_secs(60));
+        let nonce = nonces.generate();
+        let other = DigestNonces::new(Duration::from_secs(60));
+
+        assert_eq!(nonces.check(&credentials("unknown", Some("00000001"))), NonceStatus::Stale);
+        assert_eq!(other.check(&credentials(&nonce, Some("00000001"))), NonceStatus::Stale);
+
+        let forged = format!("ffffffffffffffff{}", &nonce[16 ..]);
+        assert_eq!(nonces.check(&credentials(&forged, Some("00000001"))), NonceStatus::Stale);
+    }
+
+    #[test]
+    fn nonce_expires() {
+        let nonces = DigestNonces::new(Duration::from_millis(10));
+        let nonce = nonces.generate();
+        thread::sleep(Duration::from_millis(20));
+        assert_eq!(nonces.check(&credentials(&nonce, Some("00000001"))), NonceStatus::Stale);
+    }
+
+    #[test]
+    fn used_nonces_are_forgotten() {
+        let nonces = DigestNonces::new(Duration::from_millis(10));
+        for _ in 0 .. 100 {
+            nonces.generate();
+        }
+        assert!(nonces.used.lock().unwrap().is_empty());
+
+        let nonce = nonces.generate();
+        assert_eq!(nonces.check(&credentials(&nonce, Some("00000001"))), NonceStatus::Valid);
+        assert_eq!(nonces.used.lock().unwrap().len(), 1);
+
+        thread::sleep(Duration::from_millis(20));
+        let nonce = nonces.generate();
+        assert_eq!(nonces.check(&credentials(&nonce, Some("00000001"))), NonceStatus::Valid);
+        assert_eq!(nonces.used.lock().unwrap().len(), 1);
+    }
+}
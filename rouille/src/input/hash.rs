@@ -0,0 +1,250 @@
+// Copyright (c) 2016 The Rouille developers
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>,
+// at your option. All files in the project carrying such
+// notice may not be copied, modified, or distributed except
+// according to those terms.
+
+// Hash functions required by digest HTTP authentication (RFC 7616).
+//
+// Only small inputs are hashed, so these implementations favor simplicity over speed. MD5 is
+// broken as a cryptographic hash, but it is still the algorithm that most clients use for digest
+// authentication.
+
+// Padding common to MD5 and SHA-256: a `0x80` byte, zeroes, and the length of the message in
+// bits on 64 bits, so that the total is a multiple of 64 bytes.
+fn pad(data: &[u8], big_endian: bool) -> Vec<u8> {
+    let bit_len = (data.len() as u64).wrapping_mul(8);
+    let mut message = data.to_vec();
+    message.push(0x80);
+    while message.len() % 64 != 56 {
+        message.push(0);
+    }
+    for i in 0 .. 8 {
+        let shift = if big_endian { 56 - i * 8 } else { i * 8 };
+        message.push((bit_len >> shift) as u8);
+    }
+    message
+}
+
+/// Computes the MD5 hash of `data`.
+pub fn md5(data: &[u8]) -> [u8; 16] {
+    const S: [u32; 64] = [
+        7, 12, 17, 22, 7, 12, 17, 22, 7, 12, 17, 22, 7, 12, 17, 22,
+        5, 9, 14, 20, 5, 9, 14, 20, 5, 9, 14, 20, 5, 9, 14, 20,
+        4, 11, 16, 23, 4, 11, 16, 23, 4, 11, 16, 23, 4, 11, 16, 23,
+        6, 10, 15, 21, 6, 10, 15, 21, 6, 10, 15, 21, 6, 10, 15, 21,
+    ];
+
+    const K: [u32; 64] = [
+        0xd76aa478, 0xe8c7b756, 0x242070db, 0xc1bdceee, 0xf57c0faf, 0x4787c62a, 0xa8304613,
+        0xfd469501, 0x698098d8, 0x8b44f7af, 0xffff5bb1, 0x895cd7be, 0x6b901122, 0xfd987193,
+        0xa679438e, 0x49b40821, 0xf61e2562, 0xc040b340, 0x265e5a51, 0xe9b6c7aa, 0xd62f105d,
+        0x02441453, 0xd8a1e681, 0xe7d3fbc8, 0x21e1cde6, 0xc33707d6, 0xf4d50d87, 0x455a14ed,
+        0xa9e3e905, 0xfcefa3f8, 0x676f02d9, 0x8d2a4c8a, 0xfffa3942, 0x8771f681, 0x6d9d6122,
+        0xfde5380c, 0xa4beea44, 0x4bdecfa9, 0xf6bb4b60, 0xbebfbc70, 0x289b7ec6, 0xeaa127fa,
+        0xd4ef3085, 0x04881d05, 0xd9d4d039, 0xe6db99e5, 0x1fa27cf8, 0xc4ac5665, 0xf4292244,
+        0x432aff97, 0xab9423a7, 0xfc93a039, 0x655b59c3, 0x8f0ccc92, 0xffeff47d, 0x85845dd1,
+        0x6fa87e4f, 0xfe2ce6e0, 0xa3014314, 0x4e0811a1, 0xf7537e82, 0xbd3af235, 0x2ad7d2bb,
+        0xeb86d391,
+    ];
+
+    let mut state: [u32; 4] = [0x67452301, 0xefcdab89, 0x98badcfe, 0x10325476];
+
+    for block in pad(data, false).chunks(64) {
+        let mut m = [0u32; 16];
+        for (i, word) in m.iter_mut().enumerate() {
+            *word = u32::from(block[i * 4]) | u32::from(block[i * 4 + 1]) << 8 |
+                    u32::from(block[i * 4 + 2]) << 16 | u32::from(block[i * 4 + 3]) << 24;
+        }
+
+        let (mut a, mut b, mut c, mut d) = (state[0], state[1], state[2], state[3]);
+        for i in 0 .. 64 {
+            let (f, g) = match i / 16 {
+                0 => ((b & c) | (!b & d), i),
+                1 => ((d & b) | (!d & c), (5 * i + 1) % 16),
+                2 => (b ^ c ^ d, (3 * i + 5) % 16),
+                _ => (c ^ (b | !d), (7 * i) % 16),
+            };
+
+            let f = f.wrapping_add(a).wrapping_add(K[i]).wrapping_add(m[g]);
+            a = d;
+            d = c;
+            c = b;
+            b = b.wrapping_add(f.rotate_left(S[i]));
+        }
+
+        state[0] = state[0].wrapping_add(a);
+        state[1] = state[1].wrapping_add(b);
+        state[2] = state[2].wrapping_add(c);
+        state[3] = state[3].wrapping_add(d);
+    }
+
+    let mut out = [0; 16];
+    for (i, word) in state.iter().enumerate() {
+        for j in 0 .. 4 {
+            out[i * 4 + j] = (word >> (j * 8)) as u8;
+        }
+    }
+    out
+}
+
+/// Computes the SHA-256 hash of `data`.
+pub fn sha256(data: &[u8]) -> [u8; 32] {
+    const K: [u32; 64] = [
+        0x428a2f98, 0x71374491, 0xb5c0fbcf, 0xe9b5dba5, 0x3956c25b, 0x59f111f1, 0x923f82a4,
+        0xab1c5ed5, 0xd807aa98, 0x12835b01, 0x243185be, 0x550c7dc3, 0x72be5d74, 0x80deb1fe,
+        0x9bdc06a7, 0xc19bf174, 0xe49b69c1, 0xefbe4786, 0x0fc19dc6, 0x240ca1cc, 0x2de92c6f,
+        0x4a7484aa, 0x5cb0a9dc, 0x76f988da, 0x983e5152, 0xa831c66d, 0xb00327c8, 0xbf597fc7,
+        0xc6e00bf3, 0xd5a79147, 0x06ca6351, 0x14292967, 0x27b70a85, 0x2e1b2138, 0x4d2c6dfc,
+        0x53380d13, 0x650a7354, 0x766a0abb, 0x81c2c92e, 0x92722c85, 0xa2bfe8a1, 0xa81a664b,
+        0xc24b8b70, 0xc76c51a3, 0xd192e819, 0xd6990624, 0xf40e3585, 0x106aa070, 0x19a4c116,
+        0x1e376c08, 0x2748774c, 0x34b0bcb5, 0x391c0cb3, 0x4ed8aa4a, 0x5b9cca4f, 0x682e6ff3,
+        0x748f82ee, 0x78a5636f, 0x84c87814, 0x8cc70208, 0x90befffa, 0xa4506ceb, 0xbef9a3f7,
+        0xc67178f2,
+    ];
+
+    let mut state: [u32; 8] = [
+        0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab,
+        0x5be0cd19,
+    ];
+
+    for block in pad(data, true).chunks(64) {
+        let mut w = [0u32; 64];
+        for i in 0 .. 16 {
+            w[i] = u32::from(block[i * 4]) << 24 | u32::from(block[i * 4 + 1]) << 16 |
+                   u32::from(block[i * 4 + 2]) << 8 | u32::from(block[i * 4 + 3]);
+        }
+        for i in 16 .. 64 {
+            let s0 = w[i - 15].rotate_right(7) ^ w[i - 15].rotate_right(18) ^ (w[i - 15] >> 3);
+            let s1 = w[i - 2].rotate_right(17) ^ w[i - 2].rotate_right(19) ^ (w[i - 2] >> 10);
+            w[i] = w[i - 16].wrapping_add(s0).wrapping_add(w[i - 7]).wrapping_add(s1);
+        }
+
+        let mut v = state;
+        for i in 0 .. 64 {
+            let s1 = v[4].rotate_right(6) ^ v[4].rotate_right(11) ^ v[4].rotate_right(25);
+            let ch = (v[4] & v[5]) ^ (!v[4] & v[6]);
+            let t1 = v[7].wrapping_add(s1).wrapping_add(ch).wrapping_add(K[i]).wrapping_add(w[i]);
+            let s0 = v[0].rotate_right(2) ^ v[0].rotate_right(13) ^ v[0].rotate_right(22);
+            let maj = (v[0] & v[1]) ^ (v[0] & v[2]) ^ (v[1] & v[2]);
+            let t2 = s0.wrapping_add(maj);
+
+            v[7] = v[6];
+            v[6] = v[5];
+            v[5] = v[4];
+            v[4] = v[3].wrapping_add(t1);
+            v[3] = v[2];
+            v[2] = v[1];
+            v[1] = v[0];
+            v[0] = t1.wrapping_add(t2);
+        }
+
+        for (s, v) in state.iter_mut().zip(v.iter()) {
+            *s = s.wrapping_add(*v);
+        }
+    }
+
+    let mut out = [0; 32];
+    for (i, word) in state.iter().enumerate() {
+        for j in 0 .. 4 {
+            out[i * 4 + j] = (word >> (24 - j * 8)) as u8;
+        }
+    }
+    out
+}
+
+/// Computes the HMAC-SHA-256 (RFC 2104) of `data` with `key`.
+pub fn hmac_sha256(key: &[u8], data: &[u8]) -> [u8; 32] {
+    let mut block = [0u8; 64];
+    if key.len() > 64 {
+        block[.. 32].copy_from_slice(&sha256(key));
+    } else {
+        block[.. key.len()].copy_from_slice(key);
+    }
+
+    let mut inner = block.iter().map(|b| b ^ 0x36).collect::<Vec<_>>();
+    inner.extend_from_slice(data);
+    let mut outer = block.iter().map(|b| b ^ 0x5c).collect::<Vec<_>>();
+    outer.extend_from_slice(&sha256(&inner));
+    sha256(&outer)
+}
+
+/// Turns bytes into a lowercase hexadecimal string.
+pub fn to_hex(bytes: &[u8]) -> String {
+    let mut out = String::with_capacity(bytes.len() * 2);
+    for byte in bytes {
+        out.push_str(&format!("{:02x}", byte));
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::hmac_sha256;
+    use super::md5;
+    use super::sha256;
+    use super::to_hex;
+
+    // Test suite of appendix A.5 of RFC 1321.
+    #[test]
+    fn md5_rfc1321() {
+        assert_eq!(to_hex(&md5(b"")), "d41d8cd98f00b204e9800998ecf8427e");
+        assert_eq!(to_hex(&md5(b"a")), "0cc175b9c0f1b6a831c399e269772661");
+        assert_eq!(to_hex(&md5(b"abc")), "900150983cd24fb0d6963f7d28e17f72");
+        assert_eq!(to_hex(&md5(b"message digest")), "f96b697d7cb7938d525a2f31aaf161d0");
+        assert_eq!(to_hex(&md5(b"abcdefghijklmnopqrstuvwxyz")),
+                   "c3fcd3d76192e4007dfb496cca67e13b");
+        assert_eq!(to_hex(&md5(b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789")),
+                   "d174ab98d277d9f5a5611c2c9f419d9f");
+        assert_eq!(to_hex(&md5(b"1234567890123456789012345678901234567890\
+                                 1234567890123456789012345678901234567890")),
+                   "57edf4a22be3c955ac49da2e2107b67a");
+    }
+
+    #[test]
+    fn md5_padding() {
+        // Lengths around the 56 and 64 bytes boundaries of the padding.
+        assert_eq!(to_hex(&md5(&[b'a'; 55])), "ef1772b6dff9a122358552954ad0df65");
+        assert_eq!(to_hex(&md5(&[b'a'; 56])), "3b0c8ac703f828b04c6c197006d17218");
+        assert_eq!(to_hex(&md5(&[b'a'; 64])), "014842d480b571495a4a0363793f7367");
+        assert_eq!(to_hex(&md5(&[b'a'; 100])), "36a92cc94a9e0fa21f625f8bfb007adf");
+    }
+
+    // Examples of FIPS 180-2, appendix B.
+    #[test]
+    fn sha256_fips180() {
+        assert_eq!(to_hex(&sha256(b"abc")),
+                   "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad");
+        assert_eq!(to_hex(&sha256(b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq")),
+                   "248d6a61d20638b8e5c026930c3e6039a33ce45964ff2167f6ecedd419db06c1");
+        assert_eq!(to_hex(&sha256(&vec![b'a'; 1000000])),
+                   "cdc76e5c9914fb9281a1c7e284d73e67f1809a48a497200e046d39ccc7112cd0");
+    }
+
+    #[test]
+    fn sha256_padding() {
+        assert_eq!(to_hex(&sha256(b"")),
+                   "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855");
+        assert_eq!(to_hex(&sha256(&[b'a'; 55])),
+                   "9f4390f8d30c2dd92ec9f095b65e2b9ae9b0a925a5258e241c9f1e910f734318");
+        assert_eq!(to_hex(&sha256(&[b'a'; 56])),
+                   "b35439a4ac6f0948b6d6f9e3c6af0f5f590ce20f1bde7090ef7970686ec6738a");
+        assert_eq!(to_hex(&sha256(&[b'a'; 64])),
+                   "ffe054fe7ae0cb6dc65c3af9b61d5209f439851db43d0ba5997337df154668eb");
+    }
+
+    // Test cases 1, 2 and 6 of RFC 4231.
+    #[test]
+    fn hmac_sha256_rfc4231() {
+        assert_eq!(to_hex(&hmac_sha256(&[0x0b; 20], b"Hi There")),
+                   "b0344c61d8db38535ca8afceaf0bf12b881dc200c9833da726e9376c2e32cff7");
+        assert_eq!(to_hex(&hmac_sha256(b"Jefe", b"what do ya want for nothing?")),
+                   "5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843");
+        assert_eq!(to_hex(&hmac_sha256(&[0xaa; 131],
+                                       b"Test Using Larger Than Block-Size Key - Hash Key First")),
+                   "60e431591ee0b67f0d8a26aacbf5b77f8e0bc6213728c5140546040f0ee37f54");
+    }
+}
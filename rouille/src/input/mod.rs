@@ -19,8 +19,14 @@
 
 pub use self::basic_http_auth::HttpAuthCredentials;
 pub use self::basic_http_auth::basic_http_auth;
+pub use self::bearer_http_auth::bearer_http_auth;
 pub use self::cookies::cookies;
 pub use self::cookies::CookiesIter;
+pub use self::digest_http_auth::DigestAlgorithm;
+pub use self::digest_http_auth::DigestCredentials;
+pub use self::digest_http_auth::DigestNonces;
+pub use self::digest_http_auth::NonceStatus;
+pub use self::digest_http_auth::digest_http_auth;
 pub use self::json::json_input;
 pub use self::plain::plain_text_body;
 pub use self::plain::plain_text_body_with_limit;
@@ -34,6 +40,9 @@ pub mod post;
 
 mod accept;
 mod basic_http_auth;
+mod bearer_http_auth;
 mod cookies;
+mod digest_http_auth;
+mod hash;
 mod plain;
 mod priority_header;
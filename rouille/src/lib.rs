@@ -77,6 +77,7 @@ pub use assets::match_assets;
 pub use log::{log, log_custom};
 pub use response::{Response, ResponseBody};
 pub use tiny_http::ReadWrite;
+pub use tiny_http::{Listener, ListenAddr};
 
 use std::error::Error;
 use std::io::Cursor;
@@ -89,20 +90,33 @@ use std::panic;
 use std::panic::AssertUnwindSafe;
 use std::slice::Iter as SliceIter;
 use std::sync::Arc;
+use std::sync::Condvar;
 use std::sync::Mutex;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
 use std::thread;
+use std::time::Duration;
+use std::time::Instant;
 use std::fmt;
+#[cfg(unix)]
+use std::path::Path;
 
 // The AsciiExt import is needed for Rust older than 1.23.0. These two lines can
 // be removed when supporting older Rust is no longer needed.
 #[allow(unused_imports)]
 use std::ascii::AsciiExt;
 
+pub mod access_log;
 pub mod cgi;
 pub mod content_encoding;
+pub mod cors;
 pub mod input;
+pub mod middleware;
 pub mod proxy;
+pub mod range;
+pub mod routing;
 pub mod session;
+pub mod sse;
 pub mod websocket;
 
 mod assets;
@@ -257,6 +271,69 @@ impl Executor {
 }
 
 
+/// State shared between a `Server`, its `ShutdownHandle`s and the requests being processed.
+struct ServerState {
+    // Set to true once a shutdown has been requested.
+    shutdown: AtomicBool,
+    // Number of requests whose handler hasn't finished yet.
+    active_requests: Mutex<usize>,
+    // Notified every time `active_requests` is decreased.
+    requests_finished: Condvar,
+}
+
+impl ServerState {
+    fn new() -> ServerState {
+        ServerState {
+            shutdown: AtomicBool::new(false),
+            active_requests: Mutex::new(0),
+            requests_finished: Condvar::new(),
+        }
+    }
+
+    /// Blocks until all the active requests are finished or until `timeout` has elapsed.
+    /// Returns `true` if all the requests are finished.
+    fn wait_idle(&self, timeout: Option<Duration>) -> bool {
+        let deadline = timeout.map(|t| Instant::now() + t);
+        let mut active = self.active_requests.lock().unwrap();
+
+        while *active != 0 {
+            active = match deadline {
+                None => self.requests_finished.wait(active).unwrap(),
+                Some(deadline) => {
+                    let now = Instant::now();
+                    if now >= deadline {
+                        return false;
+                    }
+                    self.requests_finished.wait_timeout(active, deadline - now).unwrap().0
+                },
+            };
+        }
+
+        true
+    }
+}
+
+/// Keeps track of a request being processed. Decrements the number of active requests when
+/// destroyed, even if the handler panicked.
+struct ActiveRequestGuard(Arc<ServerState>);
+
+impl ActiveRequestGuard {
+    fn new(state: Arc<ServerState>) -> ActiveRequestGuard {
+        *state.active_requests.lock().unwrap() += 1;
+        ActiveRequestGuard(state)
+    }
+}
+
+impl Drop for ActiveRequestGuard {
+    fn drop(&mut self) {
+        *self.0.active_requests.lock().unwrap() -= 1;
+        self.0.requests_finished.notify_all();
+    }
+}
+
+/// Interval at which `Server::run` checks whether a shutdown has been requested.
+const SHUTDOWN_POLL_INTERVAL_MS: u64 = 50;
+
 /// A listening server.
 ///
 /// This struct is the more manual server creation API of rouille and can be used as an alternative
@@ -274,13 +351,84 @@ impl Executor {
 /// let server = Server::new("localhost:0", |request| {
 ///     Response::text("hello world")
 /// }).unwrap();
-/// println!("Listening on {:?}", server.server_addr());
+/// println!("Listening on {}", server.server_addr());
 /// server.run();
 /// ```
+///
+/// # Graceful shutdown
+///
+/// Call `shutdown_handle()` before `run()` in order to obtain a `ShutdownHandle`. Calling
+/// `shutdown()` on this handle, from any thread, makes `run()` stop accepting new connections,
+/// wait for the requests that are being processed to finish, and return.
+///
+/// ```no_run
+/// use std::thread;
+/// use std::time::Duration;
+/// use rouille::Server;
+/// use rouille::Response;
+///
+/// let server = Server::new("localhost:0", |request| {
+///     Response::text("hello world")
+/// }).unwrap().drain_timeout(Duration::from_secs(10));
+///
+/// let handle = server.shutdown_handle();
+/// let thread = thread::spawn(move || server.run());
+///
+/// // ...
+///
+/// handle.shutdown();
+/// thread.join().unwrap();
+/// ```
 pub struct Server<F> {
     server: tiny_http::Server,
     handler: Arc<AssertUnwindSafe<F>>,
+    middlewares: Arc<Vec<Box<middleware::Middleware>>>,
     executor: Executor,
+    state: Arc<ServerState>,
+    drain_timeout: Option<Duration>,
+}
+
+/// Handle that can be used to stop a `Server` from another thread.
+///
+/// Obtained by calling `Server::shutdown_handle()`. Can be cloned and sent between threads.
+#[derive(Clone)]
+pub struct ShutdownHandle {
+    state: Arc<ServerState>,
+}
+
+impl ShutdownHandle {
+    /// Asks the server to shut down.
+    ///
+    /// The `run()` function of the server will stop accepting new connections, wait for the
+    /// requests that are being processed to finish (within the limit set with `drain_timeout`),
+    /// then return.
+    ///
+    /// This function doesn't block. Calling it multiple times has no additional effect.
+    #[inline]
+    pub fn shutdown(&self) {
+        self.state.shutdown.store(true, Ordering::SeqCst);
+    }
+
+    /// Returns `true` if `shutdown()` has been called on this handle or on one of its clones.
+    #[inline]
+    pub fn is_shutdown(&self) -> bool {
+        self.state.shutdown.load(Ordering::SeqCst)
+    }
+
+    /// Returns the number of requests that are currently being processed by the server.
+    #[inline]
+    pub fn num_active_requests(&self) -> usize {
+        *self.state.active_requests.lock().unwrap()
+    }
+}
+
+impl fmt::Debug for ShutdownHandle {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("ShutdownHandle")
+            .field("shutdown", &self.is_shutdown())
+            .field("active_requests", &self.num_active_requests())
+            .finish()
+    }
 }
 
 
@@ -295,11 +443,62 @@ impl<F> Server<F> where F: Send + Sync + 'static + Fn(&Request) -> Response {
         where A: ToSocketAddrs
     {
         let server = try!(tiny_http::Server::http(addr));
-        Ok(Server {
-            server,
-            executor: Executor::Threaded,
-            handler: Arc::new(AssertUnwindSafe(handler)),   // TODO: using AssertUnwindSafe here is wrong, but unwind safety has some usability problems in Rust in general
-        })
+        Ok(Server::from_tiny_http(server, handler))
+    }
+
+    /// Builds a new `Server` object that listens on a Unix domain socket.
+    ///
+    /// After this function returns, the HTTP server is listening. This is useful for a server
+    /// that sits behind a reverse proxy on the same machine.
+    ///
+    /// Returns an error if there was an error while creating the listening socket, for example if
+    /// a file already exists at `path`. The socket file is not removed when the server stops.
+    ///
+    /// Since the clients don't have an IP address, `Request::remote_addr()` returns `0.0.0.0:0`
+    /// for the requests received on the socket.
+    #[cfg(unix)]
+    pub fn new_unix<P>(path: P, handler: F) -> Result<Server<F>, Box<Error + Send + Sync>>
+        where P: AsRef<Path>
+    {
+        let server = try!(tiny_http::Server::http_unix(path));
+        Ok(Server::from_tiny_http(server, handler))
+    }
+
+    /// Builds a new `Server` object that accepts connections from a socket that is already
+    /// listening.
+    pub fn from_listener<L>(listener: L, handler: F) -> Result<Server<F>, Box<Error + Send + Sync>>
+        where L: Into<Listener>
+    {
+        let server = try!(tiny_http::Server::from_listener(listener, Default::default()));
+        Ok(Server::from_tiny_http(server, handler))
+    }
+
+    /// Builds a new `Server` object that uses the socket passed by the service manager with the
+    /// systemd socket activation protocol.
+    ///
+    /// Returns an error if no socket has been passed. If multiple sockets have been passed, only
+    /// the first one is used. See `Listener::from_listen_fds` if you need the other ones.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use rouille::Server;
+    /// use rouille::Response;
+    ///
+    /// let server = Server::from_listen_fds(|request| {
+    ///     Response::text("hello world")
+    /// }).unwrap();
+    /// println!("Listening on {}", server.server_addr());
+    /// server.run();
+    /// ```
+    #[cfg(unix)]
+    pub fn from_listen_fds(handler: F) -> Result<Server<F>, Box<Error + Send + Sync>> {
+        let listener = match try!(Listener::from_listen_fds()).into_iter().next() {
+            Some(l) => l,
+            None => return Err("No socket has been passed by the service manager".into()),
+        };
+
+        Server::from_listener(listener, handler)
     }
 
     /// Builds a new `Server` object with SSL support.
@@ -308,7 +507,7 @@ impl<F> Server<F> where F: Send + Sync + 'static + Fn(&Request) -> Response {
     ///
     /// Returns an error if there was an error while creating the listening socket, for example if
     /// the port is already in use.
-    #[cfg(feature = "ssl")]
+    #[cfg(any(feature = "ssl", feature = "ssl-rustls"))]
     pub fn new_ssl<A>(
         addr: A,
         handler: F,
@@ -320,11 +519,18 @@ impl<F> Server<F> where F: Send + Sync + 'static + Fn(&Request) -> Response {
             private_key,
         };
         let server = try!(tiny_http::Server::https(addr, ssl_config));
-        Ok(Server {
+        Ok(Server::from_tiny_http(server, handler))
+    }
+
+    fn from_tiny_http(server: tiny_http::Server, handler: F) -> Server<F> {
+        Server {
             server,
             executor: Executor::Threaded,
             handler: Arc::new(AssertUnwindSafe(handler)),   // TODO: using AssertUnwindSafe here is wrong, but unwind safety has some usability problems in Rust in general
-        })
+            middlewares: Arc::new(Vec::new()),
+            state: Arc::new(ServerState::new()),
+            drain_timeout: None,
+        }
     }
 
     /// Use a `ThreadPool` of the given size to process requests
@@ -335,19 +541,87 @@ impl<F> Server<F> where F: Send + Sync + 'static + Fn(&Request) -> Response {
         self
     }
 
+    /// Sets the maximum duration that `run()` waits for the requests being processed to finish
+    /// after a shutdown has been requested.
+    ///
+    /// By default there is no limit and `run()` waits for all the handlers to return. When the
+    /// timeout expires, `run()` returns and the remaining handlers continue running in the
+    /// background.
+    pub fn drain_timeout(mut self, timeout: Duration) -> Self {
+        self.drain_timeout = Some(timeout);
+        self
+    }
+
+    /// Adds a middleware around the handler.
+    ///
+    /// The middlewares are called in the order in which they were added, which means that the
+    /// first middleware is the outermost one. See [the `middleware` module](middleware/index.html).
+    ///
+    /// If the handler panics, the `after` hooks of the middlewares are not called.
+    pub fn with_middleware<M>(mut self, middleware: M) -> Self
+        where M: middleware::Middleware + 'static
+    {
+        Arc::get_mut(&mut self.middlewares)
+            .expect("The middlewares are only shared once the server is running")
+            .push(Box::new(middleware));
+        self
+    }
+
+    /// Returns a handle that can be used to stop the server from another thread.
+    ///
+    /// See the documentation of `ShutdownHandle`.
+    #[inline]
+    pub fn shutdown_handle(&self) -> ShutdownHandle {
+        ShutdownHandle { state: self.state.clone() }
+    }
+
+    /// Returns the number of requests that are currently being processed.
+    #[inline]
+    pub fn num_active_requests(&self) -> usize {
+        *self.state.active_requests.lock().unwrap()
+    }
+
     /// Returns the address of the listening socket.
+    ///
+    /// # Panic
+    ///
+    /// Panics if the server is listening on a Unix domain socket. Use `listen_addr` instead if
+    /// this can happen.
     #[inline]
     pub fn server_addr(&self) -> SocketAddr {
         self.server.server_addr()
     }
 
-    /// Runs the server forever, or until the listening socket is somehow force-closed by the
-    /// operating system.
+    /// Returns the address of the listening socket, which can be a Unix domain socket.
     #[inline]
+    pub fn listen_addr(&self) -> ListenAddr {
+        self.server.listen_addr()
+    }
+
+    /// Runs the server until a shutdown is requested through a `ShutdownHandle`, or until the
+    /// listening socket is somehow force-closed by the operating system.
+    ///
+    /// After a shutdown has been requested, the requests that have already been received are
+    /// still processed, the listening socket is closed, and this function waits for all the
+    /// handlers to finish before returning. See also `drain_timeout`.
     pub fn run(self) {
-        for request in self.server.incoming_requests() {
-            self.process(request);
+        let poll_interval = Duration::from_millis(SHUTDOWN_POLL_INTERVAL_MS);
+
+        while !self.state.shutdown.load(Ordering::SeqCst) {
+            match self.server.recv_timeout(poll_interval) {
+                Ok(Some(request)) => self.process(request),
+                Ok(None) => (),
+                Err(_) => break,
+            }
         }
+
+        // Requests that were received before the shutdown still get an answer.
+        self.poll();
+
+        let Server { server, state, drain_timeout, .. } = self;
+        // Destroying the tiny-http server closes the listening socket.
+        drop(server);
+        state.wait_idle(drain_timeout);
     }
 
     /// Processes all the client requests waiting to be processed, then returns.
@@ -365,7 +639,11 @@ impl<F> Server<F> where F: Send + Sync + 'static + Fn(&Request) -> Response {
     fn process(&self, request: tiny_http::Request) {
         // We spawn a thread so that requests are processed in parallel.
         let handler = self.handler.clone();
+        let middlewares = AssertUnwindSafe(self.middlewares.clone());
+        let active_guard = ActiveRequestGuard::new(self.state.clone());
         self.executor.execute(|| {
+            let _active_guard = active_guard;
+
             // Small helper struct that makes it possible to put
             // a `tiny_http::Request` inside a `Box<Read>`.
             struct RequestRead(Arc<Mutex<Option<tiny_http::Request>>>);
@@ -383,6 +661,7 @@ impl<F> Server<F> where F: Send + Sync + 'static + Fn(&Request) -> Response {
                 let method = request.method().as_str().to_owned();
                 let headers = request.headers().iter().map(|h| (h.field.to_string(), h.value.clone().into())).collect();
                 let remote_addr = *request.remote_addr();
+                let http_version = (request.http_version().0, request.http_version().1);
 
                 tiny_http_request = Arc::new(Mutex::new(Some(request)));
                 let data = Arc::new(Mutex::new(Some(Box::new(RequestRead(tiny_http_request.clone())) as Box<_>)));
@@ -394,6 +673,7 @@ impl<F> Server<F> where F: Send + Sync + 'static + Fn(&Request) -> Response {
                     https: false,
                     data,
                     remote_addr,
+                    http_version,
                 }
             };
 
@@ -405,7 +685,7 @@ impl<F> Server<F> where F: Send + Sync + 'static + Fn(&Request) -> Response {
                 let rouille_request = AssertUnwindSafe(rouille_request);
                 let res = panic::catch_unwind(move || {
                     let rouille_request = rouille_request;
-                    handler(&rouille_request)
+                    middleware::call_chain(&rouille_request, &middlewares.0, |rq| handler(rq))
                 });
 
                 match res {
@@ -475,6 +755,8 @@ pub struct Request {
     https: bool,
     data: Arc<Mutex<Option<Box<Read + Send>>>>,
     remote_addr: SocketAddr,
+    // major and minor version of the HTTP protocol used by the client
+    http_version: (u8, u8),
 }
 
 impl fmt::Debug for Request {
@@ -507,6 +789,7 @@ impl Request {
             data,
             headers,
             remote_addr,
+            http_version: (1, 1),
         }
     }
 
@@ -524,6 +807,7 @@ impl Request {
             data,
             headers,
             remote_addr: from,
+            http_version: (1, 1),
         }
     }
 
@@ -544,6 +828,7 @@ impl Request {
             data,
             headers,
             remote_addr,
+            http_version: (1, 1),
         }
     }
 
@@ -561,6 +846,7 @@ impl Request {
             data,
             headers,
             remote_addr: from,
+            http_version: (1, 1),
         }
     }
 
@@ -595,6 +881,7 @@ impl Request {
             https: self.https,
             data: self.data.clone(),
             remote_addr: self.remote_addr,
+            http_version: self.http_version,
         })
     }
 
@@ -837,7 +1124,15 @@ impl<'a> Read for RequestBody<'a> {
 
 #[cfg(test)]
 mod tests {
+    use std::io::Read;
+    use std::io::Write;
+    use std::net::TcpStream;
+    use std::sync::mpsc;
+    use std::thread;
+    use std::time::Duration;
     use Request;
+    use Response;
+    use Server;
 
     #[test]
     fn header() {
@@ -909,4 +1204,97 @@ mod tests {
         let request = Request::fake_http("GET", "/", vec![("DNT".to_owned(), "malformed".to_owned())], vec![]);
         assert_eq!(request.do_not_track(), None);
     }
+
+    #[test]
+    fn shutdown_stops_run() {
+        let server = Server::new("localhost:0", |_| Response::text("hello")).unwrap();
+        let handle = server.shutdown_handle();
+        assert!(!handle.is_shutdown());
+
+        let thread = thread::spawn(move || server.run());
+        handle.shutdown();
+        assert!(handle.is_shutdown());
+        thread.join().unwrap();
+    }
+
+    #[test]
+    fn shutdown_waits_for_active_requests() {
+        let (started_tx, started_rx) = mpsc::channel();
+        let started_tx = ::std::sync::Mutex::new(started_tx);
+        let server = Server::new("localhost:0", move |_| {
+            started_tx.lock().unwrap().send(()).unwrap();
+            thread::sleep(Duration::from_millis(200));
+            Response::text("hello")
+        }).unwrap();
+        let addr = server.server_addr();
+        let handle = server.shutdown_handle();
+        let thread = thread::spawn(move || server.run());
+
+        let mut stream = TcpStream::connect(addr).unwrap();
+        stream.write_all(b"GET / HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\r\n").unwrap();
+        started_rx.recv().unwrap();
+        assert_eq!(handle.num_active_requests(), 1);
+
+        handle.shutdown();
+        thread.join().unwrap();
+        assert_eq!(handle.num_active_requests(), 0);
+
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        assert!(response.starts_with("HTTP/1.1 200"));
+        assert!(response.ends_with("hello"));
+    }
+
+    #[test]
+    fn drain_timeout() {
+        let server = Server::new("localhost:0", |_| {
+            thread::sleep(Duration::from_secs(5));
+            Response::text("hello")
+        }).unwrap().drain_timeout(Duration::from_millis(100));
+        let addr = server.server_addr();
+        let handle = server.shutdown_handle();
+        let thread = thread::spawn(move || server.run());
+
+        let mut stream = TcpStream::connect(addr).unwrap();
+        stream.write_all(b"GET / HTTP/1.1\r\nHost: localhost\r\n\r\n").unwrap();
+        while handle.num_active_requests() == 0 {
+            thread::sleep(Duration::from_millis(10));
+        }
+
+        handle.shutdown();
+        thread.join().unwrap();
+        assert_eq!(handle.num_active_requests(), 1);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn unix_socket() {
+        use std::env;
+        use std::fs;
+        use std::os::unix::net::UnixStream;
+        use std::time::SystemTime;
+        use std::time::UNIX_EPOCH;
+
+        let nanos = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().subsec_nanos();
+        let path = env::temp_dir().join(format!("rouille-test-{}.sock", nanos));
+
+        let server = Server::new_unix(&path, |request| {
+            Response::text(format!("{} {}", request.url(), request.remote_addr()))
+        }).unwrap();
+        assert!(server.listen_addr().to_ip().is_none());
+        let handle = server.shutdown_handle();
+        let thread = thread::spawn(move || server.run());
+
+        let mut stream = UnixStream::connect(&path).unwrap();
+        stream.write_all(b"GET /hello HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\r\n")
+              .unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        assert!(response.starts_with("HTTP/1.1 200"));
+        assert!(response.ends_with("/hello 0.0.0.0:0"));
+
+        handle.shutdown();
+        thread.join().unwrap();
+        fs::remove_file(&path).unwrap();
+    }
 }
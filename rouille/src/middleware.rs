@@ -0,0 +1,392 @@
+// Copyright (c) 2016 The Rouille developers
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>,
+// at your option. All files in the project carrying such
+// notice may not be copied, modified, or distributed except
+// according to those terms.
+
+//! Composable layers around the handler of a server.
+//!
+//! A middleware is an object that implements the `Middleware` trait. It can inspect a request
+//! before it reaches the handler, answer it directly instead of the handler, and modify the
+//! response produced by the handler.
+//!
+//! Middlewares are usually registered on a `Server` with `Server::with_middleware`. The first
+//! middleware that is registered is the outermost one: its `before` hook is called first and its
+//! `after` hook is called last.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use std::io;
+//! use rouille::Server;
+//! use rouille::Response;
+//! use rouille::middleware::Compression;
+//! use rouille::middleware::Logger;
+//! use rouille::middleware::RequestId;
+//!
+//! let server = Server::new("localhost:0", |_| Response::text("hello world")).unwrap()
+//!     .with_middleware(Logger::new(io::stdout()))
+//!     .with_middleware(RequestId::new())
+//!     .with_middleware(Compression);
+//! server.run();
+//! ```
+//!
+//! Middlewares can also be used without a `Server` with the `call_chain` function.
+//!
+//! # Writing a middleware
+//!
+//! ```
+//! use rouille::Request;
+//! use rouille::Response;
+//! use rouille::middleware::Middleware;
+//!
+//! struct RequireHttps;
+//!
+//! impl Middleware for RequireHttps {
+//!     fn before(&self, request: &Request) -> Option<Response> {
+//!         if request.is_secure() {
+//!             None
+//!         } else {
+//!             Some(Response::text("HTTPS is required").with_status_code(403))
+//!         }
+//!     }
+//! }
+//! ```
+
+use std::io::Write;
+use std::panic;
+use std::sync::Mutex;
+use rand;
+
+use Request;
+use Response;
+use content_encoding;
+use log;
+
+/// Layer around the handler of a server.
+///
+/// All the methods have a default implementation that does nothing, so you only need to
+/// implement the ones you need.
+pub trait Middleware: Send + Sync {
+    /// Called before the request is passed to the next middleware or to the handler.
+    ///
+    /// If this returns `Some`, the request is not passed further and the returned response is
+    /// used instead. The `after` hook of this middleware is not called in that situation, but the
+    /// ones of the middlewares registered before this one are.
+    #[inline]
+    fn before(&self, request: &Request) -> Option<Response> {
+        let _ = request;
+        None
+    }
+
+    /// Called with the response returned by the next middleware or by the handler.
+    #[inline]
+    fn after(&self, request: &Request, response: Response) -> Response {
+        let _ = request;
+        response
+    }
+
+    /// Wraps the rest of the chain. `next` passes a request to the next middleware, or to the
+    /// handler if this middleware is the last one.
+    ///
+    /// The default implementation calls `before`, then `next`, then `after`. Override this method
+    /// if you need to keep some state during the processing of a request, or if you want to pass
+    /// a different request to the rest of the chain.
+    fn around(&self, request: &Request, next: &Fn(&Request) -> Response) -> Response {
+        if let Some(response) = self.before(request) {
+            return response;
+        }
+
+        let response = next(request);
+        self.after(request, response)
+    }
+}
+
+/// Passes a request through a list of middlewares and then to a handler.
+///
+/// The first middleware of the list is the outermost one.
+///
+/// # Example
+///
+/// ```
+/// use rouille::Request;
+/// use rouille::Response;
+/// use rouille::middleware;
+/// use rouille::middleware::Compression;
+/// use rouille::middleware::Middleware;
+///
+/// fn handle(request: &Request) -> Response {
+///     let middlewares: Vec<Box<Middleware>> = vec![Box::new(Compression)];
+///     middleware::call_chain(request, &middlewares, |_| Response::text("hello world"))
+/// }
+/// ```
+pub fn call_chain<F>(request: &Request, middlewares: &[Box<Middleware>], handler: F) -> Response
+    where F: Fn(&Request) -> Response
+{
+    call_chain_inner(request, middlewares, &handler)
+}
+
+fn call_chain_inner(request: &Request, middlewares: &[Box<Middleware>],
+                    handler: &Fn(&Request) -> Response) -> Response
+{
+    match middlewares.split_first() {
+        Some((first, rest)) => first.around(request, &|rq| call_chain_inner(rq, rest, handler)),
+        None => handler(request),
+    }
+}
+
+/// Middleware that writes a line for each request to a writer.
+///
+/// The format of the line is the same as with [the `log` function](../fn.log.html). The writer
+/// is only locked while the line is being written, and not during the processing of the request.
+pub struct Logger<W> {
+    output: Mutex<W>,
+}
+
+impl<W> Logger<W> where W: Write + Send {
+    /// Builds a new `Logger` that writes to `output`.
+    #[inline]
+    pub fn new(output: W) -> Logger<W> {
+        Logger { output: Mutex::new(output) }
+    }
+
+    /// Destroys the `Logger` and returns the underlying writer.
+    #[inline]
+    pub fn into_inner(self) -> W {
+        match self.output.into_inner() {
+            Ok(w) => w,
+            Err(poisoned) => poisoned.into_inner(),
+        }
+    }
+}
+
+impl<W> Middleware for Logger<W> where W: Write + Send {
+    fn around(&self, request: &Request, next: &Fn(&Request) -> Response) -> Response {
+        let mut line = Vec::new();
+        let response = panic::catch_unwind(panic::AssertUnwindSafe(|| {
+            log(request, &mut line, || next(request))
+        }));
+
+        {
+            let mut output = match self.output.lock() {
+                Ok(o) => o,
+                Err(poisoned) => poisoned.into_inner(),
+            };
+            let _ = output.write_all(&line);
+        }
+
+        match response {
+            Ok(response) => response,
+            Err(payload) => panic::resume_unwind(payload),
+        }
+    }
+}
+
+/// Middleware that compresses the responses with
+/// [the `content_encoding::apply` function](../content_encoding/fn.apply.html).
+#[derive(Debug, Copy, Clone, Default)]
+pub struct Compression;
+
+impl Middleware for Compression {
+    #[inline]
+    fn after(&self, request: &Request, response: Response) -> Response {
+        content_encoding::apply(request, response)
+    }
+}
+
+/// Middleware that assigns an identifier to each request.
+///
+/// If the request doesn't already have the header, a random identifier is generated and added to
+/// the request that is passed to the rest of the chain, so that the handler can read it with
+/// `request.header("X-Request-Id")`. The identifier is also added to the response.
+#[derive(Debug, Clone)]
+pub struct RequestId {
+    header: String,
+    trust_client: bool,
+}
+
+impl RequestId {
+    /// Builds a `RequestId` middleware that uses the `X-Request-Id` header.
+    #[inline]
+    pub fn new() -> RequestId {
+        RequestId::with_header("X-Request-Id")
+    }
+
+    /// Builds a `RequestId` middleware that uses the given header.
+    #[inline]
+    pub fn with_header<S>(header: S) -> RequestId where S: Into<String> {
+        RequestId {
+            header: header.into(),
+            trust_client: true,
+        }
+    }
+
+    /// Sets whether an identifier sent by the client is kept. If `false`, an identifier is always
+    /// generated. The default is `true`, which is appropriate if the server is behind a reverse
+    /// proxy that assigns identifiers.
+    #[inline]
+    pub fn trust_client(mut self, trust: bool) -> RequestId {
+        self.trust_client = trust;
+        self
+    }
+}
+
+impl Default for RequestId {
+    #[inline]
+    fn default() -> RequestId {
+        RequestId::new()
+    }
+}
+
+impl Middleware for RequestId {
+    fn around(&self, request: &Request, next: &Fn(&Request) -> Response) -> Response {
+        let existing = if self.trust_client {
+            request.header(&self.header).map(|id| id.to_owned())
+        } else {
+            None
+        };
+
+        let (id, response) = match existing {
+            Some(id) => {
+                let response = next(request);
+                (id, response)
+            },
+            None => {
+                let id = format!("{:016x}", rand::random::<u64>());
+                let mut headers: Vec<(String, String)> = request.headers.iter()
+                    .filter(|&&(ref k, _)| !k.eq_ignore_ascii_case(&self.header))
+                    .cloned().collect();
+                headers.push((self.header.clone(), id.clone()));
+
+                let request = Request {
+                    method: request.method.clone(),
+                    url: request.url.clone(),
+                    headers: headers,
+                    https: request.https,
+                    data: request.data.clone(),
+                    remote_addr: request.remote_addr,
+                    http_version: request.http_version,
+                };
+
+                let response = next(&request);
+                (id, response)
+            },
+        };
+
+        response.with_unique_header(self.header.clone(), id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use std::sync::Mutex;
+
+    use Request;
+    use Response;
+    use super::Logger;
+    use super::Middleware;
+    use super::RequestId;
+    use super::call_chain;
+
+    struct Record {
+        name: &'static str,
+        events: Arc<Mutex<Vec<String>>>,
+        short_circuit: bool,
+    }
+
+    impl Middleware for Record {
+        fn before(&self, _: &Request) -> Option<Response> {
+            self.events.lock().unwrap().push(format!("before {}", self.name));
+            if self.short_circuit {
+                Some(Response::text("short").with_status_code(403))
+            } else {
+                None
+            }
+        }
+
+        fn after(&self, _: &Request, response: Response) -> Response {
+            self.events.lock().unwrap().push(format!("after {}", self.name));
+            response
+        }
+    }
+
+    fn record(name: &'static str, events: &Arc<Mutex<Vec<String>>>, short_circuit: bool)
+              -> Box<Middleware>
+    {
+        Box::new(Record { name: name, events: events.clone(), short_circuit: short_circuit })
+    }
+
+    #[test]
+    fn order() {
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let chain = vec![record("a", &events, false), record("b", &events, false)];
+
+        let request = Request::fake_http("GET", "/", vec![], vec![]);
+        let response = call_chain(&request, &chain, |_| {
+            events.lock().unwrap().push("handler".to_owned());
+            Response::text("")
+        });
+
+        assert_eq!(response.status_code, 200);
+        assert_eq!(*events.lock().unwrap(),
+                   vec!["before a", "before b", "handler", "after b", "after a"]);
+    }
+
+    #[test]
+    fn short_circuit() {
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let chain = vec![record("a", &events, false), record("b", &events, true),
+                         record("c", &events, false)];
+
+        let request = Request::fake_http("GET", "/", vec![], vec![]);
+        let response = call_chain(&request, &chain, |_| panic!());
+
+        assert_eq!(response.status_code, 403);
+        assert_eq!(*events.lock().unwrap(), vec!["before a", "before b", "after a"]);
+    }
+
+    #[test]
+    fn request_id_generated() {
+        let request = Request::fake_http("GET", "/", vec![], vec![]);
+        let chain: Vec<Box<Middleware>> = vec![Box::new(RequestId::new())];
+        let response = call_chain(&request, &chain, |rq| {
+            Response::text(rq.header("X-Request-Id").unwrap())
+        });
+
+        let id = response.headers.iter().find(|&&(ref k, _)| k == "X-Request-Id")
+                         .map(|&(_, ref v)| v.clone().into_owned()).unwrap();
+        assert_eq!(id.len(), 16);
+    }
+
+    #[test]
+    fn request_id_kept() {
+        let request = Request::fake_http("GET", "/", vec![("X-Request-Id".to_owned(),
+                                                           "abc".to_owned())], vec![]);
+        let chain: Vec<Box<Middleware>> = vec![Box::new(RequestId::new())];
+        let response = call_chain(&request, &chain, |rq| {
+            assert_eq!(rq.header("X-Request-Id"), Some("abc"));
+            Response::text("")
+        });
+        assert!(response.headers.iter().any(|&(ref k, ref v)| k == "X-Request-Id" && v == "abc"));
+
+        let chain: Vec<Box<Middleware>> = vec![Box::new(RequestId::new().trust_client(false))];
+        call_chain(&request, &chain, |rq| {
+            assert!(rq.header("X-Request-Id") != Some("abc"));
+            Response::text("")
+        });
+    }
+
+    #[test]
+    fn logger() {
+        let logger = Logger::new(Vec::new());
+        let request = Request::fake_http("GET", "/hello", vec![], vec![]);
+        logger.around(&request, &|_| Response::text("").with_status_code(201));
+        let output = String::from_utf8(logger.into_inner()).unwrap();
+        assert!(output.contains("GET /hello"));
+        assert!(output.ends_with("201\n"));
+    }
+}
@@ -0,0 +1,513 @@
+// Copyright (c) 2016 The Rouille developers
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>,
+// at your option. All files in the project carrying such
+// notice may not be copied, modified, or distributed except
+// according to those terms.
+
+//! Support for HTTP range requests.
+//!
+//! A client can ask for only a part of a resource by passing a `Range` header, for example in
+//! order to resume an interrupted download or to seek inside a video. The `apply` function of
+//! this module turns a response whose body is a file into a `206 Partial Content` response that
+//! only contains the requested parts.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use std::fs::File;
+//! use rouille::range;
+//! use rouille::Request;
+//! use rouille::Response;
+//!
+//! fn handle(request: &Request) -> Response {
+//!     let file = File::open("video.mp4").unwrap();
+//!     range::apply(request, Response::from_file("video/mp4", file))
+//! }
+//! ```
+//!
+//! > **Note**: The [`match_assets`](../fn.match_assets.html) function already calls `apply`.
+
+use std::cmp;
+use std::collections::VecDeque;
+use std::fs::File;
+use std::io;
+use std::io::Cursor;
+use std::io::Read;
+use std::io::Seek;
+use std::io::SeekFrom;
+use std::mem;
+use rand;
+use rand::Rng;
+
+use Request;
+use Response;
+use ResponseBody;
+
+// The AsciiExt import is needed for Rust older than 1.23.0. These two lines can
+// be removed when supporting older Rust is no longer needed.
+#[allow(unused_imports)]
+use std::ascii::AsciiExt;
+
+/// Maximum number of ranges that a request can ask for. Requests with more ranges are answered
+/// with the whole content.
+const MAX_RANGES: usize = 64;
+
+/// Answers a range request.
+///
+/// If the response is a `200` response whose body was built with `ResponseBody::from_file`, adds
+/// an `Accept-Ranges: bytes` header to it. Then, if the request has a `Range` header:
+///
+/// - If the request has an `If-Range` header that doesn't match the `ETag` or the
+///   `Last-Modified` header of the response, the response is returned unchanged.
+/// - If the header asks for one range, the response becomes a `206 Partial Content` response
+///   that contains this range and a `Content-Range` header.
+/// - If the header asks for multiple ranges, the response becomes a `206 Partial Content`
+///   response whose body is a `multipart/byteranges` document. Overlapping ranges are merged.
+/// - If none of the ranges can be satisfied, the response becomes an empty
+///   `416 Range Not Satisfiable` response.
+///
+/// Any other response is returned unchanged. Malformed `Range` headers are ignored, as required
+/// by the HTTP specifications.
+///
+/// # Example
+///
+/// ```
+/// use rouille::range;
+/// use rouille::Request;
+/// use rouille::Response;
+///
+/// fn handle(request: &Request) -> Response {
+///     // This response isn't backed by a file, so `apply` doesn't do anything.
+///     range::apply(request, Response::text("hello world"))
+/// }
+/// ```
+pub fn apply(request: &Request, mut response: Response) -> Response {
+    if response.status_code != 200 || !response.data.is_file() {
+        return response;
+    }
+
+    if request.method() != "GET" && request.method() != "HEAD" {
+        return response;
+    }
+
+    response = response.with_unique_header("Accept-Ranges", "bytes");
+
+    let range_header = match request.header("Range") {
+        Some(h) => h,
+        None => return response,
+    };
+
+    if !if_range_matches(request, &response) {
+        return response;
+    }
+
+    let body = mem::replace(&mut response.data, ResponseBody::empty());
+    let (file, len) = match body.into_file() {
+        Ok(f) => f,
+        Err(body) => {
+            response.data = body;
+            return response;
+        }
+    };
+
+    let ranges = match parse_range_header(range_header, len) {
+        Some(r) => r,
+        None => {
+            response.data = ResponseBody::from_file(file);
+            return response;
+        },
+    };
+
+    if ranges.is_empty() {
+        return Response {
+            status_code: 416,
+            headers: vec![("Content-Range".into(), format!("bytes */{}", len).into())],
+            data: ResponseBody::empty(),
+            upgrade: None,
+        };
+    }
+
+    if ranges.len() == 1 {
+        let (start, end) = ranges[0];
+        response.status_code = 206;
+        response.data = ResponseBody::from_reader_and_size(FileRange::new(file, start, end),
+                                                           (end - start + 1) as usize);
+        return response.with_unique_header("Content-Range",
+                                           format!("bytes {}-{}/{}", start, end, len));
+    }
+
+    let content_type = response.headers.iter()
+        .find(|&&(ref k, _)| k.eq_ignore_ascii_case("Content-Type"))
+        .map(|&(_, ref v)| v.clone());
+    let boundary = generate_boundary();
+
+    response.status_code = 206;
+    response.data = multipart_body(file, len, &ranges, content_type.as_ref().map(|t| &t[..]),
+                                   &boundary);
+    response.with_unique_header("Content-Type",
+                                format!("multipart/byteranges; boundary={}", boundary))
+}
+
+/// Returns `false` if the request has an `If-Range` header that doesn't match the response.
+fn if_range_matches(request: &Request, response: &Response) -> bool {
+    let if_range = match request.header("If-Range") {
+        Some(h) => h.trim(),
+        None => return true,
+    };
+
+    // Weak entity tags are never suitable for ranges.
+    if if_range.starts_with("W/") {
+        return false;
+    }
+
+    response.headers.iter().any(|&(ref key, ref value)| {
+        (key.eq_ignore_ascii_case("ETag") || key.eq_ignore_ascii_case("Last-Modified")) &&
+            !value.starts_with("W/") && value.trim() == if_range
+    })
+}
+
+/// Parses the value of a `Range` header, given the length of the content.
+///
+/// Returns `None` if the header is malformed or uses an unknown unit, in which case it must be
+/// ignored. Otherwise returns the list of satisfiable ranges as inclusive `(start, end)` pairs,
+/// sorted and with overlapping ranges merged. An empty list means that no range can be
+/// satisfied.
+fn parse_range_header(header: &str, len: u64) -> Option<Vec<(u64, u64)>> {
+    let header = header.trim();
+    if !header.is_char_boundary(6) || !header[..6].eq_ignore_ascii_case("bytes=") {
+        return None;
+    }
+
+    let mut ranges = Vec::new();
+
+    for spec in header[6..].split(',') {
+        let spec = spec.trim();
+        if spec.is_empty() {
+            continue;
+        }
+
+        let dash = match spec.find('-') {
+            Some(p) => p,
+            None => return None,
+        };
+        let (first, last) = (spec[..dash].trim(), spec[dash + 1..].trim());
+
+        let range = if first.is_empty() {
+            // Suffix range, for example `-500` for the last 500 bytes.
+            let suffix: u64 = match last.parse() {
+                Ok(s) => s,
+                Err(_) => return None,
+            };
+            if suffix == 0 || len == 0 {
+                None
+            } else {
+                Some((len.saturating_sub(suffix), len - 1))
+            }
+
+        } else {
+            let start: u64 = match first.parse() {
+                Ok(s) => s,
+                Err(_) => return None,
+            };
+            let end: u64 = if last.is_empty() {
+                u64::max_value()
+            } else {
+                match last.parse() {
+                    Ok(e) => e,
+                    Err(_) => return None,
+                }
+            };
+            if end < start {
+                return None;
+            }
+            if start >= len {
+                None
+            } else {
+                Some((start, cmp::min(end, len - 1)))
+            }
+        };
+
+        if let Some(range) = range {
+            ranges.push(range);
+        }
+    }
+
+    if ranges.len() > MAX_RANGES {
+        return None;
+    }
+
+    ranges.sort();
+    let mut merged: Vec<(u64, u64)> = Vec::with_capacity(ranges.len());
+    for (start, end) in ranges {
+        if let Some(last) = merged.last_mut() {
+            if start <= last.1.saturating_add(1) {
+                last.1 = cmp::max(last.1, end);
+                continue;
+            }
+        }
+        merged.push((start, end));
+    }
+
+    Some(merged)
+}
+
+/// Generates a string suitable for separating the parts of a `multipart/byteranges` body.
+fn generate_boundary() -> String {
+    rand::thread_rng()
+        .gen_ascii_chars()
+        .filter(|&c| (c >= 'a' && c <= 'z') || (c >= 'A' && c <= 'Z') || (c >= '0' && c <= '9'))
+        .take(32).collect()
+}
+
+/// Builds the body of a `multipart/byteranges` response.
+fn multipart_body(file: File, len: u64, ranges: &[(u64, u64)], content_type: Option<&str>,
+                  boundary: &str) -> ResponseBody
+{
+    let mut parts = Vec::with_capacity(ranges.len());
+    let mut total_len = 0;
+
+    for &(start, end) in ranges {
+        let mut part_header = format!("\r\n--{}\r\n", boundary);
+        if let Some(content_type) = content_type {
+            part_header.push_str(&format!("Content-Type: {}\r\n", content_type));
+        }
+        part_header.push_str(&format!("Content-Range: bytes {}-{}/{}\r\n\r\n", start, end, len));
+
+        total_len += part_header.len() as u64 + (end - start + 1);
+        parts.push((part_header.into_bytes(), start, end));
+    }
+
+    let trailer = format!("\r\n--{}--\r\n", boundary).into_bytes();
+    total_len += trailer.len() as u64;
+
+    let reader = MultipartRanges {
+        file,
+        parts: parts.into_iter().collect(),
+        current: None,
+        trailer: Cursor::new(trailer),
+    };
+
+    ResponseBody::from_reader_and_size(reader, total_len as usize)
+}
+
+/// Reads the range `start..=end` of a file.
+struct FileRange {
+    file: File,
+    start: u64,
+    remaining: u64,
+    seeked: bool,
+}
+
+impl FileRange {
+    fn new(file: File, start: u64, end: u64) -> FileRange {
+        FileRange {
+            file,
+            start,
+            remaining: end - start + 1,
+            seeked: false,
+        }
+    }
+}
+
+impl Read for FileRange {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if !self.seeked {
+            try!(self.file.seek(SeekFrom::Start(self.start)));
+            self.seeked = true;
+        }
+
+        if self.remaining == 0 {
+            return Ok(0);
+        }
+
+        let max = cmp::min(buf.len() as u64, self.remaining) as usize;
+        let num = try!(self.file.read(&mut buf[..max]));
+        if num == 0 {
+            return Err(io::Error::new(io::ErrorKind::UnexpectedEof,
+                                      "file was truncated while being sent"));
+        }
+        self.remaining -= num as u64;
+        Ok(num)
+    }
+}
+
+/// Reads the parts of a `multipart/byteranges` body one after the other.
+struct MultipartRanges {
+    file: File,
+    // Header of each part, and range of the file that follows it.
+    parts: VecDeque<(Vec<u8>, u64, u64)>,
+    // Part currently being read.
+    current: Option<(Cursor<Vec<u8>>, FileRange)>,
+    trailer: Cursor<Vec<u8>>,
+}
+
+impl Read for MultipartRanges {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        loop {
+            if let Some((ref mut header, ref mut range)) = self.current {
+                let num = try!(header.read(buf));
+                if num != 0 {
+                    return Ok(num);
+                }
+                let num = try!(range.read(buf));
+                if num != 0 || buf.is_empty() {
+                    return Ok(num);
+                }
+            }
+
+            match self.parts.pop_front() {
+                Some((header, start, end)) => {
+                    let file = try!(self.file.try_clone());
+                    self.current = Some((Cursor::new(header), FileRange::new(file, start, end)));
+                },
+                None => {
+                    self.current = None;
+                    return self.trailer.read(buf);
+                },
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs::File;
+    use std::io::Read;
+    use std::io::Write;
+    use std::path::PathBuf;
+    use Request;
+    use Response;
+    use super::apply;
+    use super::parse_range_header;
+
+    fn temp_file(name: &str, content: &[u8]) -> PathBuf {
+        let mut path = ::std::env::temp_dir();
+        path.push(format!("rouille-range-test-{}", name));
+        File::create(&path).unwrap().write_all(content).unwrap();
+        path
+    }
+
+    fn body(response: Response) -> Vec<u8> {
+        let (mut reader, size) = response.data.into_reader_and_size();
+        let mut out = Vec::new();
+        reader.read_to_end(&mut out).unwrap();
+        if let Some(size) = size {
+            assert_eq!(size, out.len());
+        }
+        out
+    }
+
+    fn header<'a>(response: &'a Response, name: &str) -> Option<&'a str> {
+        response.headers.iter().find(|&&(ref k, _)| k.eq_ignore_ascii_case(name))
+                .map(|&(_, ref v)| &v[..])
+    }
+
+    fn request_with(headers: Vec<(&str, &str)>) -> Request {
+        let headers = headers.into_iter().map(|(k, v)| (k.to_owned(), v.to_owned())).collect();
+        Request::fake_http("GET", "/", headers, vec![])
+    }
+
+    #[test]
+    fn parse_simple() {
+        assert_eq!(parse_range_header("bytes=0-499", 1000), Some(vec![(0, 499)]));
+        assert_eq!(parse_range_header("bytes=500-", 1000), Some(vec![(500, 999)]));
+        assert_eq!(parse_range_header("bytes=-200", 1000), Some(vec![(800, 999)]));
+        assert_eq!(parse_range_header("bytes=900-5000", 1000), Some(vec![(900, 999)]));
+        assert_eq!(parse_range_header("bytes=-5000", 1000), Some(vec![(0, 999)]));
+    }
+
+    #[test]
+    fn parse_multiple_merges() {
+        assert_eq!(parse_range_header("bytes=500-600, 0-10, 5-20, 601-700", 1000),
+                   Some(vec![(0, 20), (500, 700)]));
+    }
+
+    #[test]
+    fn parse_unsatisfiable() {
+        assert_eq!(parse_range_header("bytes=1000-", 1000), Some(vec![]));
+        assert_eq!(parse_range_header("bytes=-0", 1000), Some(vec![]));
+    }
+
+    #[test]
+    fn parse_malformed() {
+        assert_eq!(parse_range_header("items=0-5", 1000), None);
+        assert_eq!(parse_range_header("bytes=5", 1000), None);
+        assert_eq!(parse_range_header("bytes=5-2", 1000), None);
+        assert_eq!(parse_range_header("bytes=a-b", 1000), None);
+    }
+
+    #[test]
+    fn not_a_file() {
+        let request = request_with(vec![("Range", "bytes=0-1")]);
+        let response = apply(&request, Response::text("hello world"));
+        assert_eq!(response.status_code, 200);
+        assert_eq!(header(&response, "Accept-Ranges"), None);
+    }
+
+    #[test]
+    fn no_range_header() {
+        let path = temp_file("no-range", b"hello world");
+        let response = Response::from_file("text/plain", File::open(&path).unwrap());
+        let response = apply(&request_with(vec![]), response);
+        assert_eq!(response.status_code, 200);
+        assert_eq!(header(&response, "Accept-Ranges"), Some("bytes"));
+        assert_eq!(body(response), b"hello world");
+    }
+
+    #[test]
+    fn single_range() {
+        let path = temp_file("single", b"hello world");
+        let response = Response::from_file("text/plain", File::open(&path).unwrap());
+        let response = apply(&request_with(vec![("Range", "bytes=6-")]), response);
+        assert_eq!(response.status_code, 206);
+        assert_eq!(header(&response, "Content-Range"), Some("bytes 6-10/11"));
+        assert_eq!(body(response), b"world");
+    }
+
+    #[test]
+    fn multiple_ranges() {
+        let path = temp_file("multiple", b"hello world");
+        let response = Response::from_file("text/plain", File::open(&path).unwrap());
+        let response = apply(&request_with(vec![("Range", "bytes=0-1,-2")]), response);
+        assert_eq!(response.status_code, 206);
+
+        let boundary = header(&response, "Content-Type").unwrap()
+                            .trim_left_matches("multipart/byteranges; boundary=").to_owned();
+        let expected = format!("\r\n--{b}\r\nContent-Type: text/plain\r\n\
+                                Content-Range: bytes 0-1/11\r\n\r\nhe\
+                                \r\n--{b}\r\nContent-Type: text/plain\r\n\
+                                Content-Range: bytes 9-10/11\r\n\r\nld\
+                                \r\n--{b}--\r\n", b = boundary);
+        assert_eq!(String::from_utf8(body(response)).unwrap(), expected);
+    }
+
+    #[test]
+    fn not_satisfiable() {
+        let path = temp_file("unsatisfiable", b"hello world");
+        let response = Response::from_file("text/plain", File::open(&path).unwrap());
+        let response = apply(&request_with(vec![("Range", "bytes=20-30")]), response);
+        assert_eq!(response.status_code, 416);
+        assert_eq!(header(&response, "Content-Range"), Some("bytes */11"));
+    }
+
+    #[test]
+    fn if_range() {
+        let path = temp_file("if-range", b"hello world");
+
+        let response = Response::from_file("text/plain", File::open(&path).unwrap())
+                                .with_etag_keep("\"abc\"");
+        let request = request_with(vec![("Range", "bytes=0-4"), ("If-Range", "\"abc\"")]);
+        let response = apply(&request, response);
+        assert_eq!(response.status_code, 206);
+        assert_eq!(body(response), b"hello");
+
+        let response = Response::from_file("text/plain", File::open(&path).unwrap())
+                                .with_etag_keep("\"def\"");
+        let response = apply(&request, response);
+        assert_eq!(response.status_code, 200);
+        assert_eq!(body(response), b"hello world");
+    }
+}
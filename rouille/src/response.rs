@@ -36,10 +36,8 @@ pub struct Response {
     ///
     /// The value of the following headers will be ignored from this list, even if present:
     ///
-    /// - Accept-Ranges
     /// - Connection
     /// - Content-Length
-    /// - Content-Range
     /// - Trailer
     /// - Transfer-Encoding
     ///
@@ -266,6 +264,9 @@ impl Response {
 
     /// Builds a 200 `Response` with the content of a file.
     ///
+    /// Pass the response to [`range::apply`](range/fn.apply.html) in order to answer requests
+    /// that only ask for a part of the file.
+    ///
     /// # Example
     ///
     /// ```no_run
@@ -385,10 +386,90 @@ impl Response {
     /// ```
     #[inline]
     pub fn basic_http_auth_login_required(realm: &str) -> Response {
-        // TODO: escape the realm
         Response {
             status_code: 401,
-            headers: vec![("WWW-Authenticate".into(), format!("Basic realm=\"{}\"", realm).into())],
+            headers: vec![("WWW-Authenticate".into(),
+                           format!("Basic realm={}", quote(realm)).into())],
+            data: ResponseBody::empty(),
+            upgrade: None,
+        }
+    }
+
+    /// Builds a `Response` that returns a `401 Not Authorized` status and a `WWW-Authenticate`
+    /// header asking for a bearer token.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rouille::Response;
+    /// let response = Response::bearer_http_auth_login_required("realm");
+    /// ```
+    #[inline]
+    pub fn bearer_http_auth_login_required(realm: &str) -> Response {
+        Response {
+            status_code: 401,
+            headers: vec![("WWW-Authenticate".into(),
+                           format!("Bearer realm={}", quote(realm)).into())],
+            data: ResponseBody::empty(),
+            upgrade: None,
+        }
+    }
+
+    /// Builds a `Response` that returns a `401 Not Authorized` status and a `WWW-Authenticate`
+    /// header indicating that the bearer token sent by the client is invalid or has expired.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rouille::Response;
+    /// let response = Response::bearer_http_auth_invalid_token("realm");
+    /// ```
+    #[inline]
+    pub fn bearer_http_auth_invalid_token(realm: &str) -> Response {
+        Response {
+            status_code: 401,
+            headers: vec![("WWW-Authenticate".into(),
+                           format!("Bearer realm={}, error=\"invalid_token\"",
+                                   quote(realm)).into())],
+            data: ResponseBody::empty(),
+            upgrade: None,
+        }
+    }
+
+    /// Builds a `Response` that returns a `401 Not Authorized` status and `WWW-Authenticate`
+    /// headers with digest HTTP auth challenges.
+    ///
+    /// Two challenges are sent, one with the `SHA-256` algorithm and one with the `MD5`
+    /// algorithm for older clients. The nonce should be generated with
+    /// [`DigestNonces::generate`](input/struct.DigestNonces.html#method.generate). Set `stale`
+    /// to `true` if the client has sent valid credentials but with an expired nonce.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use rouille::Response;
+    /// use rouille::input::DigestNonces;
+    ///
+    /// let nonces = DigestNonces::new(Duration::from_secs(300));
+    /// let response = Response::digest_http_auth_login_required("realm", &nonces.generate(), false);
+    /// ```
+    pub fn digest_http_auth_login_required(realm: &str, nonce: &str, stale: bool) -> Response {
+        let challenge = |algorithm: &str| {
+            let mut challenge = format!("Digest realm={}, qop=\"auth\", algorithm={}, nonce={}",
+                                        quote(realm), algorithm, quote(nonce));
+            if stale {
+                challenge.push_str(", stale=true");
+            }
+            challenge.into()
+        };
+
+        Response {
+            status_code: 401,
+            headers: vec![
+                ("WWW-Authenticate".into(), challenge("SHA-256")),
+                ("WWW-Authenticate".into(), challenge("MD5")),
+            ],
             data: ResponseBody::empty(),
             upgrade: None,
         }
@@ -689,10 +770,17 @@ impl Response {
 /// let body = ResponseBody::from_string("hello world");
 /// ```
 pub struct ResponseBody {
-    data: Box<Read + Send>,
+    data: ResponseData,
     data_length: Option<usize>,
 }
 
+/// Where the body of a response comes from.
+enum ResponseData {
+    Reader(Box<Read + Send>),
+    // Kept separately so that `range::apply` can seek inside the file.
+    File(File),
+}
+
 impl ResponseBody {
     /// Builds a `ResponseBody` that doesn't return any data.
     ///
@@ -705,7 +793,7 @@ impl ResponseBody {
     #[inline]
     pub fn empty() -> ResponseBody {
         ResponseBody {
-            data: Box::new(io::empty()),
+            data: ResponseData::Reader(Box::new(io::empty())),
             data_length: Some(0),
         }
     }
@@ -727,11 +815,35 @@ impl ResponseBody {
     #[inline]
     pub fn from_reader<R>(data: R) -> ResponseBody where R: Read + Send + 'static {
         ResponseBody {
-            data: Box::new(data),
+            data: ResponseData::Reader(Box::new(data)),
             data_length: None,
         }
     }
 
+    /// Builds a new `ResponseBody` that will read the data from a `Read`, when the length of the
+    /// data is known in advance.
+    ///
+    /// The reader must return exactly `size` bytes.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::io;
+    /// use std::io::Read;
+    /// use rouille::ResponseBody;
+    ///
+    /// let body = ResponseBody::from_reader_and_size(io::stdin().take(128), 128);
+    /// ```
+    #[inline]
+    pub fn from_reader_and_size<R>(data: R, size: usize) -> ResponseBody
+        where R: Read + Send + 'static
+    {
+        ResponseBody {
+            data: ResponseData::Reader(Box::new(data)),
+            data_length: Some(size),
+        }
+    }
+
     /// Builds a new `ResponseBody` that returns the given data.
     ///
     /// # Example
@@ -746,7 +858,7 @@ impl ResponseBody {
         let len = data.len();
 
         ResponseBody {
-            data: Box::new(Cursor::new(data)),
+            data: ResponseData::Reader(Box::new(Cursor::new(data))),
             data_length: Some(len),
         }
     }
@@ -767,7 +879,7 @@ impl ResponseBody {
         let len = file.metadata().map(|metadata| metadata.len() as usize).ok();
 
         ResponseBody {
-            data: Box::new(file),
+            data: ResponseData::File(file),
             data_length: len,
         }
     }
@@ -791,10 +903,51 @@ impl ResponseBody {
     /// unknown.
     #[inline]
     pub fn into_reader_and_size(self) -> (Box<Read + Send>, Option<usize>) {
-        (self.data, self.data_length)
+        match self.data {
+            ResponseData::Reader(reader) => (reader, self.data_length),
+            ResponseData::File(file) => (Box::new(file), self.data_length),
+        }
+    }
+
+    /// Returns true if the body was built with `from_file`.
+    #[inline]
+    pub(crate) fn is_file(&self) -> bool {
+        match self.data {
+            ResponseData::File(_) => true,
+            ResponseData::Reader(_) => false,
+        }
+    }
+
+    /// Returns the length of the body, if it is known.
+    #[inline]
+    pub(crate) fn known_length(&self) -> Option<usize> {
+        self.data_length
+    }
+
+    /// If the body was built with `from_file` and its length is known, returns the file and its
+    /// length. Otherwise returns the body unchanged.
+    pub(crate) fn into_file(self) -> Result<(File, u64), ResponseBody> {
+        match (self.data, self.data_length) {
+            (ResponseData::File(file), Some(len)) => Ok((file, len as u64)),
+            (data, data_length) => Err(ResponseBody { data, data_length }),
+        }
     }
 }
 
+// Turns a value into a quoted string suitable for the parameters of a header.
+fn quote(value: &str) -> String {
+    let mut out = String::with_capacity(value.len() + 2);
+    out.push('"');
+    for c in value.chars() {
+        if c == '"' || c == '\\' {
+            out.push('\\');
+        }
+        out.push(c);
+    }
+    out.push('"');
+    out
+}
+
 #[cfg(test)]
 mod tests {
     use Response;
@@ -842,4 +995,19 @@ mod tests {
         assert_eq!(r.headers.len(), 1);
         assert_eq!(r.headers[0], ("foo".into(), "Bar".into()));
     }
+
+    #[test]
+    fn login_required_escapes_realm() {
+        let r = Response::basic_http_auth_login_required("a \"b\" \\");
+        assert_eq!(r.headers[0].1, "Basic realm=\"a \\\"b\\\" \\\\\"");
+    }
+
+    #[test]
+    fn digest_login_required() {
+        let r = Response::digest_http_auth_login_required("realm", "abc", true);
+        assert_eq!(r.status_code, 401);
+        assert_eq!(r.headers.len(), 2);
+        assert_eq!(r.headers[1].1, "Digest realm=\"realm\", qop=\"auth\", algorithm=MD5, \
+                                    nonce=\"abc\", stale=true");
+    }
 }
@@ -0,0 +1,690 @@
+// Copyright (c) 2016 The Rouille developers
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>,
+// at your option. All files in the project carrying such
+// notice may not be copied, modified, or distributed except
+// according to those terms.
+
+//! Routing of requests at runtime.
+//!
+//! Contrary to the `router!` macro, the `Router` struct of this module is built at runtime. This
+//! makes it possible to list the routes that it contains, and to answer with a
+//! `405 Method Not Allowed` error when the URL of a request matches a route but its method
+//! doesn't.
+//!
+//! # Example
+//!
+//! ```
+//! use rouille::Response;
+//! use rouille::routing::Router;
+//!
+//! let issues = Router::new()
+//!     .get("/", |_, _| Response::text("list of issues"))
+//!     .get("/{id:uuid}", |_, params| {
+//!         Response::text(format!("issue {}", params.get("id").unwrap()))
+//!     })
+//!     .post("/", |_, _| Response::text("issue created"));
+//!
+//! let router = Router::new()
+//!     .get("/", |_, _| Response::text("hello world"))
+//!     .mount("/issues", issues);
+//!
+//! for route in router.routes() {
+//!     println!("{} {}", route.method(), route.pattern());
+//! }
+//!
+//! // let response = router.handle(&request);
+//! ```
+//!
+//! # Patterns
+//!
+//! A pattern is a URL path made of segments separated with `/`. Each segment is either a literal
+//! that must match the URL exactly, or a parameter between `{` and `}` that matches a whole
+//! segment. A parameter has a name and optionally a type, written `{name:type}`. The types are:
+//!
+//! - `string`: Any non-empty segment. This is the default.
+//! - `int`: A signed integer that fits in an `i64`.
+//! - `uint`: An unsigned integer that fits in a `u64`.
+//! - `uuid`: A UUID in its hyphenated form, for example `67e55044-10b1-426f-9247-bb680e5fe0c8`.
+//! - `path`: The rest of the URL, including any `/`. Must be the last segment of the pattern.
+//!
+//! The segments of the URL are percent-decoded before being matched, which means that `%2F` is
+//! not considered as a separator. The query string is ignored.
+//!
+//! The value of a `path` parameter is made of the decoded segments joined with `/`. An encoded
+//! `%2F` inside one of these segments is therefore decoded as well, and can't be told apart from
+//! a separator in the value of the parameter.
+//!
+//! # Methods
+//!
+//! If no route matches the method of a request but some routes match its URL, then the router
+//! answers with a `405` error and an `Allow` header. `HEAD` requests are handled by the `GET`
+//! routes unless a `HEAD` route matches first, and `OPTIONS` requests are answered with an empty
+//! `204` response and an `Allow` header unless an `OPTIONS` route matches.
+
+use std::fmt;
+use std::str::FromStr;
+
+use url::percent_encoding;
+
+use Request;
+use Response;
+
+/// Routes requests to handlers depending on their method and URL.
+///
+/// See [the module-level documentation](index.html).
+pub struct Router {
+    entries: Vec<Entry>,
+}
+
+enum Entry {
+    Route(Route),
+    Mount(String, Router),
+}
+
+struct Route {
+    method: String,
+    pattern: String,
+    segments: Vec<Segment>,
+    handler: Box<Fn(&Request, &Params) -> Response + Send + Sync>,
+}
+
+enum Segment {
+    Literal(String),
+    Param(String, ParamType),
+}
+
+#[derive(Copy, Clone, PartialEq, Eq)]
+enum ParamType {
+    String,
+    Int,
+    UInt,
+    Uuid,
+    Path,
+}
+
+// Result of looking for a route in a router.
+enum Dispatch {
+    Found(Response),
+    // The URL matched some routes, but not the method. Contains the methods of these routes.
+    MethodNotAllowed(Vec<String>),
+    NotFound,
+}
+
+impl Router {
+    /// Builds a new router without any route.
+    #[inline]
+    pub fn new() -> Router {
+        Router { entries: Vec::new() }
+    }
+
+    /// Adds a route that matches requests with the given method and a URL that matches
+    /// `pattern`.
+    ///
+    /// Routes are tried in the order in which they were added.
+    ///
+    /// # Panic
+    ///
+    /// Panics if the pattern is invalid. See [the module-level documentation](index.html) for the
+    /// syntax of patterns.
+    pub fn route<F>(mut self, method: &str, pattern: &str, handler: F) -> Router
+        where F: Fn(&Request, &Params) -> Response + Send + Sync + 'static
+    {
+        let segments = match parse_pattern(pattern) {
+            Ok(s) => s,
+            Err(err) => panic!("Invalid route pattern {:?}: {}", pattern, err),
+        };
+
+        self.entries.push(Entry::Route(Route {
+            method: method.to_uppercase(),
+            pattern: pattern.to_owned(),
+            segments: segments,
+            handler: Box::new(handler),
+        }));
+        self
+    }
+
+    /// Shortcut for `route("GET", pattern, handler)`.
+    #[inline]
+    pub fn get<F>(self, pattern: &str, handler: F) -> Router
+        where F: Fn(&Request, &Params) -> Response + Send + Sync + 'static
+    {
+        self.route("GET", pattern, handler)
+    }
+
+    /// Shortcut for `route("POST", pattern, handler)`.
+    #[inline]
+    pub fn post<F>(self, pattern: &str, handler: F) -> Router
+        where F: Fn(&Request, &Params) -> Response + Send + Sync + 'static
+    {
+        self.route("POST", pattern, handler)
+    }
+
+    /// Shortcut for `route("PUT", pattern, handler)`.
+    #[inline]
+    pub fn put<F>(self, pattern: &str, handler: F) -> Router
+        where F: Fn(&Request, &Params) -> Response + Send + Sync + 'static
+    {
+        self.route("PUT", pattern, handler)
+    }
+
+    /// Shortcut for `route("PATCH", pattern, handler)`.
+    #[inline]
+    pub fn patch<F>(self, pattern: &str, handler: F) -> Router
+        where F: Fn(&Request, &Params) -> Response + Send + Sync + 'static
+    {
+        self.route("PATCH", pattern, handler)
+    }
+
+    /// Shortcut for `route("DELETE", pattern, handler)`.
+    #[inline]
+    pub fn delete<F>(self, pattern: &str, handler: F) -> Router
+        where F: Fn(&Request, &Params) -> Response + Send + Sync + 'static
+    {
+        self.route("DELETE", pattern, handler)
+    }
+
+    /// Mounts another router under `prefix`.
+    ///
+    /// Requests whose URL starts with `prefix` followed by either `/` or nothing are passed to
+    /// `router` after the prefix has been removed with `Request::remove_prefix`. If `router`
+    /// doesn't have any matching route, the next routes of this router are tried.
+    ///
+    /// # Panic
+    ///
+    /// Panics if `prefix` doesn't start with `/` or ends with `/`.
+    pub fn mount<S>(mut self, prefix: S, router: Router) -> Router where S: Into<String> {
+        let prefix = prefix.into();
+        assert!(prefix.starts_with('/') && !prefix.ends_with('/'),
+                "Invalid mount prefix {:?}", prefix);
+        self.entries.push(Entry::Mount(prefix, router));
+        self
+    }
+
+    /// Returns the list of the routes of this router, including the ones of the mounted
+    /// routers.
+    pub fn routes(&self) -> Vec<RouteInfo> {
+        let mut routes = Vec::new();
+        self.collect_routes("", &mut routes);
+        routes
+    }
+
+    fn collect_routes(&self, prefix: &str, out: &mut Vec<RouteInfo>) {
+        for entry in &self.entries {
+            match *entry {
+                Entry::Route(ref route) => {
+                    out.push(RouteInfo {
+                        method: route.method.clone(),
+                        pattern: format!("{}{}", prefix, route.pattern),
+                    });
+                },
+                Entry::Mount(ref mount_prefix, ref router) => {
+                    router.collect_routes(&format!("{}{}", prefix, mount_prefix), out);
+                },
+            }
+        }
+    }
+
+    /// Dispatches a request to the matching route and returns its response.
+    ///
+    /// Returns a `404` response if no route matches the URL of the request, so that this
+    /// function can be used with the `find_route!` macro.
+    pub fn handle(&self, request: &Request) -> Response {
+        match self.dispatch(request) {
+            Dispatch::Found(response) => response,
+
+            Dispatch::MethodNotAllowed(methods) => {
+                let allow = allow_header(&methods);
+                if request.method().eq_ignore_ascii_case("OPTIONS") {
+                    Response::empty_204().with_unique_header("Allow", allow)
+                } else {
+                    Response::text("Method Not Allowed").with_status_code(405)
+                                                         .with_unique_header("Allow", allow)
+                }
+            },
+
+            Dispatch::NotFound => Response::empty_404(),
+        }
+    }
+
+    fn dispatch(&self, request: &Request) -> Dispatch {
+        let method = request.method().to_uppercase();
+        let segments = url_segments(request.raw_url());
+
+        let mut allowed = Vec::new();
+        let mut head_fallback = None;
+
+        for entry in &self.entries {
+            match *entry {
+                Entry::Route(ref route) => {
+                    let params = match route.matches(&segments) {
+                        Some(p) => p,
+                        None => continue,
+                    };
+
+                    if route.method == method {
+                        return Dispatch::Found((route.handler)(request, &params));
+                    }
+
+                    if method == "HEAD" && route.method == "GET" && head_fallback.is_none() {
+                        head_fallback = Some((route, params));
+                    }
+
+                    allowed.push(route.method.clone());
+                },
+
+                Entry::Mount(ref prefix, ref router) => {
+                    if !has_prefix(request.raw_url(), prefix) {
+                        continue;
+                    }
+
+                    let sub_request = match request.remove_prefix(prefix) {
+                        Some(r) => r,
+                        None => continue,
+                    };
+
+                    match router.dispatch(&sub_request) {
+                        Dispatch::Found(response) => return Dispatch::Found(response),
+                        Dispatch::MethodNotAllowed(methods) => allowed.extend(methods),
+                        Dispatch::NotFound => (),
+                    }
+                },
+            }
+        }
+
+        // Note that we don't need to remove the body of the response, as it is never sent in
+        // response to a `HEAD` request.
+        if let Some((route, params)) = head_fallback {
+            return Dispatch::Found((route.handler)(request, &params));
+        }
+
+        if allowed.is_empty() {
+            Dispatch::NotFound
+        } else {
+            Dispatch::MethodNotAllowed(allowed)
+        }
+    }
+}
+
+impl Default for Router {
+    #[inline]
+    fn default() -> Router {
+        Router::new()
+    }
+}
+
+impl fmt::Debug for Router {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Router")
+         .field("routes", &self.routes())
+         .finish()
+    }
+}
+
+impl Route {
+    fn matches(&self, url: &[String]) -> Option<Params> {
+        let mut params = Params { values: Vec::new() };
+
+        for (num, segment) in self.segments.iter().enumerate() {
+            match *segment {
+                Segment::Literal(ref literal) => {
+                    match url.get(num) {
+                        Some(s) if s == literal => (),
+                        _ => return None,
+                    }
+                },
+
+                Segment::Param(ref name, ParamType::Path) => {
+                    if num >= url.len() {
+                        return None;
+                    }
+                    params.values.push((name.clone(), url[num..].join("/")));
+                    return Some(params);
+                },
+
+                Segment::Param(ref name, ty) => {
+                    let value = match url.get(num) {
+                        Some(v) => v,
+                        None => return None,
+                    };
+                    if !ty.accepts(value) {
+                        return None;
+                    }
+                    params.values.push((name.clone(), value.clone()));
+                },
+            }
+        }
+
+        if url.len() != self.segments.len() {
+            return None;
+        }
+
+        Some(params)
+    }
+}
+
+impl ParamType {
+    fn accepts(&self, value: &str) -> bool {
+        match *self {
+            ParamType::String => !value.is_empty(),
+            ParamType::Int => i64::from_str(value).is_ok(),
+            ParamType::UInt => u64::from_str(value).is_ok() && !value.starts_with('+'),
+            ParamType::Uuid => is_uuid(value),
+            ParamType::Path => true,
+        }
+    }
+}
+
+fn is_uuid(value: &str) -> bool {
+    if value.len() != 36 {
+        return false;
+    }
+
+    value.bytes().enumerate().all(|(n, c)| {
+        match n {
+            8 | 13 | 18 | 23 => c == b'-',
+            _ => (c >= b'0' && c <= b'9') || (c >= b'a' && c <= b'f') || (c >= b'A' && c <= b'F'),
+        }
+    })
+}
+
+// Splits the path of a raw URL into percent-decoded segments. The first segment is the one
+// before the leading `/`, and is therefore empty for valid URLs.
+fn url_segments(raw_url: &str) -> Vec<String> {
+    let path = match raw_url.find('?') {
+        Some(pos) => &raw_url[..pos],
+        None => raw_url,
+    };
+
+    // A mounted router receives an empty URL when the request targets its prefix exactly.
+    let path = if path.is_empty() { "/" } else { path };
+
+    path.split('/')
+        .map(|s| percent_encoding::percent_decode(s.as_bytes()).decode_utf8_lossy().into_owned())
+        .collect()
+}
+
+// Returns true if `prefix` is followed by a `/`, a query string or nothing in the URL.
+fn has_prefix(raw_url: &str, prefix: &str) -> bool {
+    if !raw_url.starts_with(prefix) {
+        return false;
+    }
+
+    match raw_url[prefix.len()..].chars().next() {
+        None | Some('/') | Some('?') => true,
+        _ => false,
+    }
+}
+
+fn parse_pattern(pattern: &str) -> Result<Vec<Segment>, String> {
+    if !pattern.starts_with('/') {
+        return Err("the pattern must start with `/`".to_owned());
+    }
+
+    let mut segments: Vec<Segment> = Vec::new();
+    let num_segments = pattern.split('/').count();
+
+    for (num, segment) in pattern.split('/').enumerate() {
+        if !segment.starts_with('{') {
+            if segment.contains('{') || segment.contains('}') {
+                return Err(format!("parameters must span a whole segment: {:?}", segment));
+            }
+            segments.push(Segment::Literal(segment.to_owned()));
+            continue;
+        }
+
+        if !segment.ends_with('}') || segment.len() < 2 {
+            return Err(format!("unclosed parameter: {:?}", segment));
+        }
+
+        let inner = &segment[1 .. segment.len() - 1];
+        let (name, ty) = match inner.find(':') {
+            Some(pos) => (inner[..pos].trim(), inner[pos + 1..].trim()),
+            None => (inner.trim(), "string"),
+        };
+
+        let ty = match ty {
+            "string" => ParamType::String,
+            "int" => ParamType::Int,
+            "uint" => ParamType::UInt,
+            "uuid" => ParamType::Uuid,
+            "path" => ParamType::Path,
+            _ => return Err(format!("unknown parameter type: {:?}", ty)),
+        };
+
+        if name.is_empty() {
+            return Err("parameters must have a name".to_owned());
+        }
+
+        let duplicate = segments.iter().any(|s| match *s {
+            Segment::Param(ref n, _) => n == name,
+            _ => false,
+        });
+        if duplicate {
+            return Err(format!("duplicate parameter: {:?}", name));
+        }
+
+        if ty == ParamType::Path && num != num_segments - 1 {
+            return Err("`path` parameters must be the last segment".to_owned());
+        }
+
+        segments.push(Segment::Param(name.to_owned(), ty));
+    }
+
+    Ok(segments)
+}
+
+fn allow_header(methods: &[String]) -> String {
+    let mut list: Vec<&str> = Vec::new();
+    for method in methods {
+        if !list.contains(&&method[..]) {
+            list.push(method);
+        }
+    }
+    if list.contains(&"GET") && !list.contains(&"HEAD") {
+        list.push("HEAD");
+    }
+    if !list.contains(&"OPTIONS") {
+        list.push("OPTIONS");
+    }
+    list.join(", ")
+}
+
+/// Parameters extracted from the URL of a request by a `Router`.
+#[derive(Debug, Clone)]
+pub struct Params {
+    values: Vec<(String, String)>,
+}
+
+impl Params {
+    /// Returns the decoded value of the parameter with the given name.
+    #[inline]
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.values.iter().find(|&&(ref n, _)| n == name).map(|&(_, ref v)| &v[..])
+    }
+
+    /// Parses the value of the parameter with the given name.
+    ///
+    /// Returns `None` if the parameter doesn't exist or if parsing fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rouille::Response;
+    /// use rouille::routing::Router;
+    ///
+    /// let router = Router::new().get("/users/{id:uint}", |_, params| {
+    ///     let id: u64 = params.parse("id").unwrap();
+    ///     Response::text(format!("user #{}", id))
+    /// });
+    /// ```
+    #[inline]
+    pub fn parse<T>(&self, name: &str) -> Option<T> where T: FromStr {
+        self.get(name).and_then(|v| v.parse().ok())
+    }
+
+    /// Returns an iterator to the names and values of the parameters.
+    #[inline]
+    pub fn iter(&self) -> ParamsIter {
+        ParamsIter { iter: self.values.iter() }
+    }
+}
+
+/// Iterator to the parameters of a `Params`.
+pub struct ParamsIter<'a> {
+    iter: ::std::slice::Iter<'a, (String, String)>,
+}
+
+impl<'a> Iterator for ParamsIter<'a> {
+    type Item = (&'a str, &'a str);
+
+    #[inline]
+    fn next(&mut self) -> Option<(&'a str, &'a str)> {
+        self.iter.next().map(|&(ref n, ref v)| (&n[..], &v[..]))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+/// Description of a route of a `Router`. Returned by `Router::routes`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RouteInfo {
+    method: String,
+    pattern: String,
+}
+
+impl RouteInfo {
+    /// Returns the method of the route, in uppercase.
+    #[inline]
+    pub fn method(&self) -> &str {
+        &self.method
+    }
+
+    /// Returns the pattern of the route, including the prefixes of the routers it is mounted in.
+    #[inline]
+    pub fn pattern(&self) -> &str {
+        &self.pattern
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Read;
+
+    use Request;
+    use Response;
+    use super::Router;
+
+    fn router() -> Router {
+        let issues = Router::new()
+            .get("/", |_, _| Response::text("list"))
+            .get("/{id:uuid}", |_, params| Response::text(params.get("id").unwrap()))
+            .delete("/{id:uuid}", |_, _| Response::text("deleted"));
+
+        Router::new()
+            .get("/", |_, _| Response::text("index"))
+            .get("/users/{id:uint}", |_, params| {
+                Response::text(format!("user {}", params.parse::<u64>("id").unwrap()))
+            })
+            .post("/users/{name}", |_, params| Response::text(params.get("name").unwrap()))
+            .get("/files/{path:path}", |_, params| Response::text(params.get("path").unwrap()))
+            .mount("/issues", issues)
+    }
+
+    fn body(response: Response) -> String {
+        let (mut reader, _) = response.data.into_reader_and_size();
+        let mut out = String::new();
+        reader.read_to_string(&mut out).unwrap();
+        out
+    }
+
+    fn header<'a>(response: &'a Response, name: &str) -> Option<&'a str> {
+        response.headers.iter().find(|&&(ref k, _)| k.eq_ignore_ascii_case(name))
+                .map(|&(_, ref v)| &v[..])
+    }
+
+    fn handle(method: &str, url: &str) -> Response {
+        router().handle(&Request::fake_http(method, url, vec![], vec![]))
+    }
+
+    #[test]
+    fn basic_routes() {
+        assert_eq!(body(handle("GET", "/")), "index");
+        assert_eq!(body(handle("GET", "/users/12?foo=bar")), "user 12");
+        assert_eq!(body(handle("POST", "/users/hello%20world")), "hello world");
+        assert_eq!(body(handle("GET", "/files/a/b%2Fc/d")), "a/b/c/d");
+    }
+
+    #[test]
+    fn typed_params() {
+        // Only the `POST /users/{name}` route matches.
+        assert_eq!(handle("GET", "/users/abc").status_code, 405);
+        assert_eq!(handle("GET", "/users/-1").status_code, 405);
+        assert_eq!(handle("GET", "/issues/not-a-uuid").status_code, 404);
+        assert_eq!(body(handle("GET", "/issues/67e55044-10b1-426f-9247-bb680e5fe0c8")),
+                   "67e55044-10b1-426f-9247-bb680e5fe0c8");
+    }
+
+    #[test]
+    fn not_found() {
+        assert_eq!(handle("GET", "/nothing").status_code, 404);
+        assert_eq!(handle("GET", "/users").status_code, 404);
+        assert_eq!(handle("GET", "/issuesfoo").status_code, 404);
+    }
+
+    #[test]
+    fn method_not_allowed() {
+        let response = handle("PUT", "/users/12");
+        assert_eq!(response.status_code, 405);
+        assert_eq!(header(&response, "Allow"), Some("GET, POST, HEAD, OPTIONS"));
+
+        let response = handle("POST", "/issues/67e55044-10b1-426f-9247-bb680e5fe0c8");
+        assert_eq!(response.status_code, 405);
+        assert_eq!(header(&response, "Allow"), Some("GET, DELETE, HEAD, OPTIONS"));
+    }
+
+    #[test]
+    fn head_and_options() {
+        let response = handle("HEAD", "/users/5");
+        assert_eq!(response.status_code, 200);
+        assert_eq!(body(response), "user 5");
+
+        let response = handle("OPTIONS", "/");
+        assert_eq!(response.status_code, 204);
+        assert_eq!(header(&response, "Allow"), Some("GET, HEAD, OPTIONS"));
+    }
+
+    #[test]
+    fn mounted_root() {
+        assert_eq!(body(handle("GET", "/issues")), "list");
+        assert_eq!(body(handle("GET", "/issues/")), "list");
+    }
+
+    #[test]
+    fn routes_list() {
+        let routes = router().routes();
+        let routes = routes.iter().map(|r| format!("{} {}", r.method(), r.pattern()))
+                           .collect::<Vec<_>>();
+        assert_eq!(routes, vec![
+            "GET /", "GET /users/{id:uint}", "POST /users/{name}", "GET /files/{path:path}",
+            "GET /issues/", "GET /issues/{id:uuid}", "DELETE /issues/{id:uuid}",
+        ]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn invalid_type() {
+        Router::new().get("/{id:float}", |_, _| Response::text(""));
+    }
+
+    #[test]
+    #[should_panic]
+    fn path_not_last() {
+        Router::new().get("/{p:path}/foo", |_, _| Response::text(""));
+    }
+}
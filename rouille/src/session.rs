@@ -32,62 +32,314 @@
 //!     })
 //! }
 //! ```
+//!
+//! # Storing data
+//!
+//! The `session` function only assigns an identifier to each client, and it is up to you to
+//! associate data to this identifier. Alternatively, you can use the `session_with_store`
+//! function, which loads and saves the data of the session from a `SessionStore`. This data is
+//! then accessible with the `get`, `set` and `remove` methods of `Session`.
+//!
+//! Two stores are provided: `MemorySessionStore` keeps the sessions in memory, and
+//! `FileSessionStore` stores each session in a file inside of a directory. You can also
+//! implement the `SessionStore` trait yourself, for example to store sessions in a database.
+//!
+//! ```
+//! use rouille::Request;
+//! use rouille::Response;
+//! use rouille::session;
+//! use rouille::session::MemorySessionStore;
+//! use rouille::session::SessionConfig;
+//!
+//! fn handle_request(request: &Request, store: &MemorySessionStore) -> Response {
+//!     let config = SessionConfig::new("SID", 3600).secure(true);
+//!
+//!     session::session_with_store(request, &config, store, |session| {
+//!         let visits = session.get::<u32>("visits").unwrap_or(0) + 1;
+//!         session.set("visits", &visits);
+//!         Response::text(format!("You visited this page {} times", visits))
+//!     })
+//! }
+//! ```
+//!
+//! Expired sessions are never returned by a store, but they may still occupy some space. Call
+//! `SessionStore::sweep` from time to time to remove them.
 
 use std::borrow::Cow;
+use std::collections::HashMap;
+use std::fs;
+use std::fs::File;
+use std::io;
+use std::io::Write;
+use std::path::PathBuf;
+use std::sync::Mutex;
 use std::sync::atomic::AtomicBool;
 use std::sync::atomic::Ordering;
+use std::time::Duration;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
 use rand;
 use rand::Rng;
+use serde;
+use serde_json;
 
 use Request;
 use Response;
 use input;
 
+/// Handles a session.
+///
+/// Looks for a cookie named `cookie_name` in the request. If the client doesn't have one, a new
+/// identifier is generated and sent back with the response if the handler called
+/// `Session::id()`. The cookie expires after `timeout_s` seconds.
+///
+/// The data of the session is not loaded or saved. See `session_with_store` for this.
 pub fn session<'r, F>(request: &'r Request, cookie_name: &str, timeout_s: u64, inner: F) -> Response
     where F: FnOnce(&Session<'r>) -> Response
 {
-    let mut cookie = input::cookies(request).into_iter();
-    let cookie = cookie.find(|&(ref k, _)| k == &cookie_name);
-    let cookie = cookie.map(|(_, v)| v);
+    session_with_config(request, &SessionConfig::new(cookie_name, timeout_s), inner)
+}
 
-    let session = if let Some(cookie) = cookie {
-        Session {
-            key_was_retreived: AtomicBool::new(false),
-            key_was_given: true,
-            key: cookie.into(),
-        }
-    } else {
-        Session {
-            key_was_retreived: AtomicBool::new(false),
-            key_was_given: false,
-            key: generate_session_id().into(),
-        }
+/// Same as `session`, but uses the options of a `SessionConfig` for the cookie.
+pub fn session_with_config<'r, F>(request: &'r Request, config: &SessionConfig, inner: F)
+                                  -> Response
+    where F: FnOnce(&Session<'r>) -> Response
+{
+    let session = match find_cookie(request, &config.cookie_name) {
+        Some(cookie) => Session::new(cookie.into(), true, HashMap::new()),
+        None => Session::new(generate_session_id().into(), false, HashMap::new()),
     };
 
     let mut response = inner(&session);
 
     if session.key_was_retreived.load(Ordering::Relaxed) {       // TODO: use `get_mut()`
         // FIXME: correct interactions with existing headers
-        // TODO: allow setting domain
-        let header_value = format!("{}={}; Max-Age={}; Path=/; HttpOnly",
-                                    cookie_name, session.key, timeout_s);
+        let header_value = config.cookie_header(&session.key, config.timeout_s);
+        response.headers.push(("Set-Cookie".into(), header_value.into()));
+    }
+
+    response
+}
+
+/// Handles a session whose data is kept in a `SessionStore`.
+///
+/// If the client sends a session ID that exists in the store, its data is loaded and made
+/// available through the `Session`. Otherwise, a new ID is generated. Note that in the latter
+/// case the ID sent by the client is never reused, in order to prevent session fixation attacks.
+///
+/// After `inner` returns, the data of the session is saved back in the store and its expiration
+/// is pushed back by `timeout_s` seconds. If the data of the session is empty, the session is
+/// removed from the store instead and the cookie is deleted.
+///
+/// If the store returns an error, a response with a status code of 500 is returned.
+pub fn session_with_store<'r, S, F>(request: &'r Request, config: &SessionConfig, store: &S,
+                                    inner: F) -> Response
+    where S: ?Sized + SessionStore,
+          F: FnOnce(&Session<'r>) -> Response
+{
+    let existing = match find_cookie(request, &config.cookie_name) {
+        Some(cookie) => match store.load(cookie) {
+            Ok(Some(data)) => Some((cookie, data)),
+            Ok(None) => None,
+            Err(_) => return store_error_response(),
+        },
+        None => None,
+    };
+
+    let session = match existing {
+        Some((cookie, data)) => Session::new(cookie.into(), true, data),
+        None => Session::new(generate_session_id().into(), false, HashMap::new()),
+    };
+
+    let mut response = inner(&session);
+
+    let key_was_retreived = session.key_was_retreived.load(Ordering::Relaxed);
+    let data = session.data.into_inner().unwrap();
+
+    let max_age = if !data.is_empty() {
+        let expires = SystemTime::now() + Duration::from_secs(config.timeout_s);
+        if store.save(&session.key, &data, expires).is_err() {
+            return store_error_response();
+        }
+        Some(config.timeout_s)
+
+    } else if session.key_was_given {
+        if store.remove(&session.key).is_err() {
+            return store_error_response();
+        }
+        if key_was_retreived { Some(config.timeout_s) } else { Some(0) }
+
+    } else if key_was_retreived {
+        Some(config.timeout_s)
+
+    } else {
+        None
+    };
+
+    if let Some(max_age) = max_age {
+        // FIXME: correct interactions with existing headers
+        let header_value = config.cookie_header(&session.key, max_age);
         response.headers.push(("Set-Cookie".into(), header_value.into()));
     }
 
     response
 }
 
+fn find_cookie<'r>(request: &'r Request, cookie_name: &str) -> Option<&'r str> {
+    input::cookies(request).find(|&(k, _)| k == cookie_name).map(|(_, v)| v)
+}
+
+fn store_error_response() -> Response {
+    Response::text("Failed to access the session store").with_status_code(500)
+}
+
+/// Options for the cookie that holds the session ID.
+///
+/// By default, the cookie has a path of `/`, has the `HttpOnly` attribute, doesn't have the
+/// `Secure` attribute, and has no domain and no `SameSite` attribute.
+///
+/// # Example
+///
+/// ```
+/// use rouille::session::SameSite;
+/// use rouille::session::SessionConfig;
+///
+/// let config = SessionConfig::new("SID", 3600)
+///     .domain("example.com")
+///     .secure(true)
+///     .same_site(SameSite::Lax);
+/// ```
+#[derive(Debug, Clone)]
+pub struct SessionConfig {
+    cookie_name: String,
+    timeout_s: u64,
+    domain: Option<String>,
+    path: String,
+    secure: bool,
+    http_only: bool,
+    same_site: Option<SameSite>,
+}
+
+impl SessionConfig {
+    /// Builds a new configuration for a cookie named `cookie_name` that expires after
+    /// `timeout_s` seconds.
+    pub fn new<S>(cookie_name: S, timeout_s: u64) -> SessionConfig where S: Into<String> {
+        SessionConfig {
+            cookie_name: cookie_name.into(),
+            timeout_s: timeout_s,
+            domain: None,
+            path: "/".to_owned(),
+            secure: false,
+            http_only: true,
+            same_site: None,
+        }
+    }
+
+    /// Sets the `Domain` attribute of the cookie.
+    #[inline]
+    pub fn domain<S>(mut self, domain: S) -> SessionConfig where S: Into<String> {
+        self.domain = Some(domain.into());
+        self
+    }
+
+    /// Sets the `Path` attribute of the cookie. The default is `/`.
+    #[inline]
+    pub fn path<S>(mut self, path: S) -> SessionConfig where S: Into<String> {
+        self.path = path.into();
+        self
+    }
+
+    /// Sets whether the cookie has the `Secure` attribute, in which case the client only sends
+    /// it over HTTPS. The default is `false`.
+    #[inline]
+    pub fn secure(mut self, secure: bool) -> SessionConfig {
+        self.secure = secure;
+        self
+    }
+
+    /// Sets whether the cookie has the `HttpOnly` attribute, in which case it can't be accessed
+    /// from Javascript. The default is `true`.
+    #[inline]
+    pub fn http_only(mut self, http_only: bool) -> SessionConfig {
+        self.http_only = http_only;
+        self
+    }
+
+    /// Sets the `SameSite` attribute of the cookie.
+    #[inline]
+    pub fn same_site(mut self, same_site: SameSite) -> SessionConfig {
+        self.same_site = Some(same_site);
+        self
+    }
+
+    /// Returns the name of the cookie.
+    #[inline]
+    pub fn cookie_name(&self) -> &str {
+        &self.cookie_name
+    }
+
+    /// Returns the number of seconds after which a session expires.
+    #[inline]
+    pub fn timeout_s(&self) -> u64 {
+        self.timeout_s
+    }
+
+    fn cookie_header(&self, key: &str, max_age: u64) -> String {
+        let mut value = format!("{}={}; Max-Age={}; Path={}", self.cookie_name, key, max_age,
+                                self.path);
+        if let Some(ref domain) = self.domain {
+            value.push_str("; Domain=");
+            value.push_str(domain);
+        }
+        if self.secure {
+            value.push_str("; Secure");
+        }
+        if self.http_only {
+            value.push_str("; HttpOnly");
+        }
+        match self.same_site {
+            Some(SameSite::Strict) => value.push_str("; SameSite=Strict"),
+            Some(SameSite::Lax) => value.push_str("; SameSite=Lax"),
+            Some(SameSite::None) => value.push_str("; SameSite=None"),
+            None => (),
+        }
+        value
+    }
+}
+
+/// Value of the `SameSite` attribute of a cookie.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum SameSite {
+    /// The cookie is only sent for requests that originate from the same site.
+    Strict,
+    /// The cookie is also sent when the user navigates to the site from another site.
+    Lax,
+    /// The cookie is sent with all requests. Browsers require the cookie to be `Secure`.
+    None,
+}
+
 /// Contains the ID of the session.
 pub struct Session<'r> {
     key_was_retreived: AtomicBool,
     key_was_given: bool,
     key: Cow<'r, str>,
+    data: Mutex<SessionData>,
 }
 
 impl<'r> Session<'r> {
+    fn new(key: Cow<'r, str>, key_was_given: bool, data: SessionData) -> Session<'r> {
+        Session {
+            key_was_retreived: AtomicBool::new(false),
+            key_was_given: key_was_given,
+            key: key,
+            data: Mutex::new(data),
+        }
+    }
+
     /// Returns true if the client gave us a session ID.
     ///
-    /// If this returns false, then we are sure that no data is available.
+    /// If this returns false, then we are sure that no data is available. When using
+    /// `session_with_store`, this returns true only if the session exists in the store.
     #[inline]
     pub fn client_has_sid(&self) -> bool {
         self.key_was_given
@@ -100,12 +352,297 @@ impl<'r> Session<'r> {
         &self.key
     }
 
-    /*/// Generates a new id. This modifies the value returned by `id()`.
-    // TODO: implement
+    /// Returns the value associated to `key` in the data of the session.
+    ///
+    /// Returns `None` if there is no such value or if it can't be deserialized as a `T`.
+    ///
+    /// The data is only loaded when using `session_with_store`.
+    pub fn get<T>(&self, key: &str) -> Option<T> where T: serde::de::DeserializeOwned {
+        let data = self.data.lock().unwrap();
+        match data.get(key) {
+            Some(value) => serde_json::from_str(value).ok(),
+            None => None,
+        }
+    }
+
+    /// Associates a value to `key` in the data of the session.
+    ///
+    /// The data is only saved when using `session_with_store`.
+    ///
+    /// # Panic
+    ///
+    /// Panics if the value can't be serialized to JSON.
+    pub fn set<T>(&self, key: &str, value: &T) where T: ?Sized + serde::Serialize {
+        let value = serde_json::to_string(value).unwrap();
+        self.data.lock().unwrap().insert(key.to_owned(), value);
+    }
+
+    /// Removes the value associated to `key` from the data of the session.
+    ///
+    /// Returns true if there was such a value.
+    pub fn remove(&self, key: &str) -> bool {
+        self.data.lock().unwrap().remove(key).is_some()
+    }
+
+    /// Removes all the data of the session.
+    ///
+    /// When using `session_with_store`, this removes the session from the store.
+    pub fn clear(&self) {
+        self.data.lock().unwrap().clear();
+    }
+
+    /// Returns true if the session contains some data.
+    pub fn has_data(&self) -> bool {
+        !self.data.lock().unwrap().is_empty()
+    }
+}
+
+/// Data of a session, as stored in a `SessionStore`.
+///
+/// The keys are the ones passed to `Session::set`, and the values are serialized as JSON.
+pub type SessionData = HashMap<String, String>;
+
+/// Storage for the data of sessions.
+///
+/// The methods of this trait can be called from multiple threads at once.
+pub trait SessionStore: Send + Sync {
+    /// Loads the data of the session with the given ID.
+    ///
+    /// Must return `Ok(None)` if the session doesn't exist or has expired.
+    fn load(&self, id: &str) -> io::Result<Option<SessionData>>;
+
+    /// Saves the data of the session with the given ID, overwriting any previous data. The
+    /// session must be considered as expired after `expires`.
+    fn save(&self, id: &str, data: &SessionData, expires: SystemTime) -> io::Result<()>;
+
+    /// Removes the session with the given ID. Does nothing if it doesn't exist.
+    fn remove(&self, id: &str) -> io::Result<()>;
+
+    /// Removes all the sessions that have expired. Returns the number of sessions that were
+    /// removed.
+    fn sweep(&self) -> io::Result<usize>;
+}
+
+/// Session store that keeps the sessions in memory.
+///
+/// All the sessions are lost when the store is destroyed, and the sessions are not shared
+/// between processes.
+#[derive(Debug, Default)]
+pub struct MemorySessionStore {
+    sessions: Mutex<HashMap<String, (SystemTime, SessionData)>>,
+}
+
+impl MemorySessionStore {
+    /// Builds a new empty store.
+    #[inline]
+    pub fn new() -> MemorySessionStore {
+        MemorySessionStore::default()
+    }
+
+    /// Returns the number of sessions in the store, including the ones that have expired but
+    /// haven't been swept yet.
+    pub fn len(&self) -> usize {
+        self.sessions.lock().unwrap().len()
+    }
+
+    /// Returns true if the store doesn't contain any session.
+    pub fn is_empty(&self) -> bool {
+        self.sessions.lock().unwrap().is_empty()
+    }
+}
+
+impl SessionStore for MemorySessionStore {
+    fn load(&self, id: &str) -> io::Result<Option<SessionData>> {
+        let mut sessions = self.sessions.lock().unwrap();
+        let expired = match sessions.get(id) {
+            Some(&(expires, ref data)) => {
+                if expires > SystemTime::now() {
+                    return Ok(Some(data.clone()));
+                }
+                true
+            },
+            None => false,
+        };
+        if expired {
+            sessions.remove(id);
+        }
+        Ok(None)
+    }
+
+    fn save(&self, id: &str, data: &SessionData, expires: SystemTime) -> io::Result<()> {
+        let mut sessions = self.sessions.lock().unwrap();
+        sessions.insert(id.to_owned(), (expires, data.clone()));
+        Ok(())
+    }
+
+    fn remove(&self, id: &str) -> io::Result<()> {
+        self.sessions.lock().unwrap().remove(id);
+        Ok(())
+    }
+
+    fn sweep(&self) -> io::Result<usize> {
+        let now = SystemTime::now();
+        let mut sessions = self.sessions.lock().unwrap();
+        let before = sessions.len();
+        sessions.retain(|_, &mut (expires, _)| expires > now);
+        Ok(before - sessions.len())
+    }
+}
+
+/// Session store that stores each session in a file inside of a directory.
+///
+/// The files are named after the ID of the session and contain JSON. Only IDs made of ASCII
+/// letters and digits are accepted, which is the case of the IDs generated by
+/// `generate_session_id`. Loading any other ID returns `Ok(None)`.
+#[derive(Debug, Clone)]
+pub struct FileSessionStore {
+    directory: PathBuf,
+}
+
+#[derive(Serialize, Deserialize)]
+struct StoredSession {
+    expires: u64,
+    data: SessionData,
+}
+
+impl FileSessionStore {
+    /// Builds a store that uses the given directory. The directory is created if it doesn't
+    /// exist.
+    pub fn new<P>(directory: P) -> io::Result<FileSessionStore> where P: Into<PathBuf> {
+        let directory = directory.into();
+        try!(fs::create_dir_all(&directory));
+        Ok(FileSessionStore { directory: directory })
+    }
+
+    /// Returns the directory where the sessions are stored.
     #[inline]
-    pub fn regenerate_id(&self) {
-        unimplemented!()
-    }*/
+    pub fn directory(&self) -> &PathBuf {
+        &self.directory
+    }
+
+    fn path(&self, id: &str) -> Option<PathBuf> {
+        let valid = !id.is_empty() && id.len() <= 256 &&
+                    id.chars().all(|c| (c >= 'a' && c <= 'z') || (c >= 'A' && c <= 'Z') ||
+                                       (c >= '0' && c <= '9'));
+        if !valid {
+            return None;
+        }
+        Some(self.directory.join(id))
+    }
+
+    fn read(path: &PathBuf) -> io::Result<Option<StoredSession>> {
+        let file = match File::open(path) {
+            Ok(f) => f,
+            Err(ref err) if err.kind() == io::ErrorKind::NotFound => return Ok(None),
+            Err(err) => return Err(err),
+        };
+        match serde_json::from_reader(io::BufReader::new(file)) {
+            Ok(session) => Ok(Some(session)),
+            Err(err) => Err(io::Error::new(io::ErrorKind::InvalidData, err)),
+        }
+    }
+}
+
+fn unix_timestamp(time: SystemTime) -> u64 {
+    match time.duration_since(UNIX_EPOCH) {
+        Ok(d) => d.as_secs(),
+        Err(_) => 0,
+    }
+}
+
+impl SessionStore for FileSessionStore {
+    fn load(&self, id: &str) -> io::Result<Option<SessionData>> {
+        let path = match self.path(id) {
+            Some(p) => p,
+            None => return Ok(None),
+        };
+
+        match try!(FileSessionStore::read(&path)) {
+            Some(session) => {
+                if session.expires > unix_timestamp(SystemTime::now()) {
+                    Ok(Some(session.data))
+                } else {
+                    try!(self.remove(id));
+                    Ok(None)
+                }
+            },
+            None => Ok(None),
+        }
+    }
+
+    fn save(&self, id: &str, data: &SessionData, expires: SystemTime) -> io::Result<()> {
+        let path = match self.path(id) {
+            Some(p) => p,
+            None => return Err(io::Error::new(io::ErrorKind::InvalidInput,
+                                              "Invalid session ID")),
+        };
+
+        let stored = StoredSession {
+            expires: unix_timestamp(expires),
+            data: data.clone(),
+        };
+
+        // We write to a temporary file and rename it afterwards, so that another thread or
+        // process never reads a partially-written session.
+        let tmp_path = self.directory.join(format!("{}.{}.tmp", id, rand::random::<u32>()));
+        {
+            let mut file = try!(File::create(&tmp_path));
+            let result = serde_json::to_writer(&mut file, &stored)
+                .map_err(|err| io::Error::new(io::ErrorKind::Other, err))
+                .and_then(|_| file.flush());
+            if let Err(err) = result {
+                let _ = fs::remove_file(&tmp_path);
+                return Err(err);
+            }
+        }
+        if let Err(err) = fs::rename(&tmp_path, &path) {
+            let _ = fs::remove_file(&tmp_path);
+            return Err(err);
+        }
+        Ok(())
+    }
+
+    fn remove(&self, id: &str) -> io::Result<()> {
+        let path = match self.path(id) {
+            Some(p) => p,
+            None => return Ok(()),
+        };
+
+        match fs::remove_file(&path) {
+            Ok(()) => Ok(()),
+            Err(ref err) if err.kind() == io::ErrorKind::NotFound => Ok(()),
+            Err(err) => Err(err),
+        }
+    }
+
+    fn sweep(&self) -> io::Result<usize> {
+        let now = unix_timestamp(SystemTime::now());
+        let mut removed = 0;
+
+        for entry in try!(fs::read_dir(&self.directory)) {
+            let entry = try!(entry);
+            let id = match entry.file_name().into_string() {
+                Ok(id) => id,
+                Err(_) => continue,
+            };
+            let path = match self.path(&id) {
+                Some(p) => p,
+                None => continue,
+            };
+            // Files that can't be read or parsed are left alone, as they may be written to
+            // by another process at the same time.
+            let expired = match FileSessionStore::read(&path) {
+                Ok(Some(session)) => session.expires <= now,
+                _ => false,
+            };
+            if expired {
+                try!(self.remove(&id));
+                removed += 1;
+            }
+        }
+
+        Ok(removed)
+    }
 }
 
 /// Generates a string suitable for a session ID.
@@ -120,3 +657,170 @@ pub fn generate_session_id() -> String {
                                    (c >= '0' && c <= '9'))
                       .take(64).collect::<String>()
 }
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+    use std::fs;
+    use std::time::Duration;
+    use std::time::SystemTime;
+
+    use Request;
+    use Response;
+    use super::FileSessionStore;
+    use super::MemorySessionStore;
+    use super::SameSite;
+    use super::SessionConfig;
+    use super::SessionData;
+    use super::SessionStore;
+    use super::session;
+    use super::session_with_store;
+
+    fn request_with_cookie(cookie: Option<&str>) -> Request {
+        let headers = match cookie {
+            Some(c) => vec![("Cookie".to_owned(), c.to_owned())],
+            None => vec![],
+        };
+        Request::fake_http("GET", "/", headers, vec![])
+    }
+
+    fn set_cookie(response: &Response) -> Option<String> {
+        response.headers.iter().find(|&&(ref k, _)| k.eq_ignore_ascii_case("Set-Cookie"))
+                .map(|&(_, ref v)| v.clone().into_owned())
+    }
+
+    fn cookie_value(set_cookie: &str) -> String {
+        let pair = set_cookie.split(';').next().unwrap();
+        pair.splitn(2, '=').nth(1).unwrap().to_owned()
+    }
+
+    fn data(key: &str, value: &str) -> SessionData {
+        let mut data = SessionData::new();
+        data.insert(key.to_owned(), value.to_owned());
+        data
+    }
+
+    #[test]
+    fn default_cookie() {
+        let request = request_with_cookie(None);
+        let response = session(&request, "SID", 3600, |s| Response::text(s.id()));
+        let cookie = set_cookie(&response).unwrap();
+        assert!(cookie.starts_with("SID="));
+        assert!(cookie.ends_with("; Max-Age=3600; Path=/; HttpOnly"));
+    }
+
+    #[test]
+    fn cookie_attributes() {
+        let config = SessionConfig::new("SID", 60).domain("example.com").path("/app")
+                                                  .secure(true).http_only(false)
+                                                  .same_site(SameSite::Strict);
+        assert_eq!(config.cookie_header("abc", 60),
+                   "SID=abc; Max-Age=60; Path=/app; Domain=example.com; Secure; SameSite=Strict");
+    }
+
+    #[test]
+    fn store_round_trip() {
+        let store = MemorySessionStore::new();
+        let config = SessionConfig::new("SID", 3600);
+
+        let request = request_with_cookie(None);
+        let response = session_with_store(&request, &config, &store, |s| {
+            assert!(!s.client_has_sid());
+            assert_eq!(s.get::<u32>("counter"), None);
+            s.set("counter", &5u32);
+            Response::text("")
+        });
+        let cookie = set_cookie(&response).unwrap();
+        assert_eq!(store.len(), 1);
+
+        let header = format!("SID={}", cookie_value(&cookie));
+        let request = request_with_cookie(Some(&header));
+        session_with_store(&request, &config, &store, |s| {
+            assert!(s.client_has_sid());
+            assert_eq!(s.get::<u32>("counter"), Some(5));
+            assert_eq!(s.get::<String>("counter"), None);
+            Response::text("")
+        });
+    }
+
+    #[test]
+    fn no_cookie_without_data() {
+        let store = MemorySessionStore::new();
+        let config = SessionConfig::new("SID", 3600);
+
+        let request = request_with_cookie(None);
+        let response = session_with_store(&request, &config, &store, |_| Response::text(""));
+        assert!(set_cookie(&response).is_none());
+        assert!(store.is_empty());
+    }
+
+    #[test]
+    fn unknown_id_is_replaced() {
+        let store = MemorySessionStore::new();
+        let config = SessionConfig::new("SID", 3600);
+
+        let request = request_with_cookie(Some("SID=attacker"));
+        let response = session_with_store(&request, &config, &store, |s| {
+            assert!(!s.client_has_sid());
+            s.set("user", "bob");
+            Response::text("")
+        });
+        let id = cookie_value(&set_cookie(&response).unwrap());
+        assert!(id != "attacker");
+        assert!(store.load(&id).unwrap().is_some());
+        assert!(store.load("attacker").unwrap().is_none());
+    }
+
+    #[test]
+    fn clear_removes_session() {
+        let store = MemorySessionStore::new();
+        let config = SessionConfig::new("SID", 3600);
+        let expires = SystemTime::now() + Duration::from_secs(60);
+        store.save("abc", &data("user", "\"bob\""), expires).unwrap();
+
+        let request = request_with_cookie(Some("SID=abc"));
+        let response = session_with_store(&request, &config, &store, |s| {
+            assert_eq!(s.get::<String>("user"), Some("bob".to_owned()));
+            s.clear();
+            Response::text("")
+        });
+        assert!(store.is_empty());
+        assert!(set_cookie(&response).unwrap().contains("Max-Age=0"));
+    }
+
+    #[test]
+    fn memory_sweep() {
+        let store = MemorySessionStore::new();
+        let now = SystemTime::now();
+        store.save("old", &data("a", "1"), now - Duration::from_secs(1)).unwrap();
+        store.save("new", &data("a", "1"), now + Duration::from_secs(60)).unwrap();
+
+        assert_eq!(store.sweep().unwrap(), 1);
+        assert_eq!(store.len(), 1);
+        assert!(store.load("old").unwrap().is_none());
+        assert!(store.load("new").unwrap().is_some());
+    }
+
+    #[test]
+    fn file_store() {
+        let dir = env::temp_dir().join("rouille-session-test-file-store");
+        let _ = fs::remove_dir_all(&dir);
+        let store = FileSessionStore::new(&dir).unwrap();
+        let now = SystemTime::now();
+
+        store.save("old", &data("a", "1"), now - Duration::from_secs(10)).unwrap();
+        store.save("new", &data("a", "2"), now + Duration::from_secs(60)).unwrap();
+        assert_eq!(store.load("new").unwrap(), Some(data("a", "2")));
+        assert!(store.load("missing").unwrap().is_none());
+
+        assert_eq!(store.sweep().unwrap(), 1);
+        assert!(!dir.join("old").exists());
+
+        store.remove("new").unwrap();
+        assert!(store.load("new").unwrap().is_none());
+        assert!(store.save("../escape", &data("a", "1"), now).is_err());
+        assert!(store.load("../escape").unwrap().is_none());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,338 @@
+// Copyright (c) 2016 The Rouille developers
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>,
+// at your option. All files in the project carrying such
+// notice may not be copied, modified, or distributed except
+// according to those terms.
+
+//! Server-sent events.
+//!
+//! Server-sent events are a way for the server to push messages to a web page through a response
+//! that never ends. In the browser, they are received with the `EventSource` API. Contrary to
+//! websockets, the client can't send messages through the same connection.
+//!
+//! In order to send events, create a channel with `std::sync::mpsc::channel`, pass the receiver
+//! to `EventStream::new` and return the response. Each `Event` sent on the sender is then
+//! forwarded to the client as soon as possible. The response ends when all the senders have been
+//! destroyed.
+//!
+//! If the client disconnects, the receiver is destroyed the next time the server tries to write
+//! to the connection, and sending on the sender returns an error. Keep-alive comments are sent
+//! regularly so that this is detected even if no event is sent.
+//!
+//! > **Note**: The thread that processed the request is busy as long as the stream is open.
+//! > Keep this in mind if you use `Server::pool_size`.
+//!
+//! Clients using HTTP 1.0 don't support the chunked transfer encoding, so the stream is sent to
+//! them as it is and its end is marked by closing the connection. If such a client asked to keep
+//! the connection alive, the end of the stream is only detected once the connection is closed by
+//! the client or times out.
+//!
+//! # Example
+//!
+//! ```
+//! use std::sync::mpsc;
+//! use std::thread;
+//! use rouille::Request;
+//! use rouille::Response;
+//! use rouille::sse;
+//!
+//! fn handle(request: &Request) -> Response {
+//!     let first_id = sse::last_event_id(request).and_then(|id| id.parse().ok()).unwrap_or(0);
+//!
+//!     let (sender, receiver) = mpsc::channel();
+//!     thread::spawn(move || {
+//!         for id in first_id + 1 .. first_id + 10u32 {
+//!             let event = sse::Event::new(format!("record #{}", id)).id(id.to_string())
+//!                                                                  .event("record");
+//!             if sender.send(event).is_err() {
+//!                 // The client has disconnected.
+//!                 break;
+//!             }
+//!         }
+//!     });
+//!
+//!     sse::EventStream::new(receiver).into_response()
+//! }
+//! ```
+
+use std::cmp;
+use std::io;
+use std::io::Read;
+use std::sync::mpsc::Receiver;
+use std::sync::mpsc::RecvTimeoutError;
+use std::time::Duration;
+
+use Request;
+use Response;
+use ResponseBody;
+
+/// Returns the value of the `Last-Event-ID` header of the request.
+///
+/// Browsers send this header when they reconnect after the connection has been lost. It contains
+/// the `id` of the last event that was received, and can be used in order to resume the stream.
+#[inline]
+pub fn last_event_id(request: &Request) -> Option<&str> {
+    request.header("Last-Event-ID")
+}
+
+/// An event that can be sent to the client.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Event {
+    id: Option<String>,
+    event: Option<String>,
+    data: String,
+    retry: Option<Duration>,
+}
+
+impl Event {
+    /// Builds a new event with the given data. The data can contain multiple lines.
+    #[inline]
+    pub fn new<S>(data: S) -> Event where S: Into<String> {
+        Event {
+            id: None,
+            event: None,
+            data: data.into(),
+            retry: None,
+        }
+    }
+
+    /// Sets the identifier of the event. The browser sends back the identifier of the last event
+    /// in the `Last-Event-ID` header when it reconnects.
+    ///
+    /// Line breaks are removed from the identifier.
+    #[inline]
+    pub fn id<S>(mut self, id: S) -> Event where S: Into<String> {
+        self.id = Some(id.into());
+        self
+    }
+
+    /// Sets the type of the event. The default type in the browser is `message`.
+    ///
+    /// Line breaks are removed from the type.
+    #[inline]
+    pub fn event<S>(mut self, event: S) -> Event where S: Into<String> {
+        self.event = Some(event.into());
+        self
+    }
+
+    /// Sets the duration that the browser waits before reconnecting if the connection is lost.
+    #[inline]
+    pub fn retry(mut self, retry: Duration) -> Event {
+        self.retry = Some(retry);
+        self
+    }
+
+    // Appends the serialized event to `out`.
+    fn write_to(&self, out: &mut Vec<u8>) {
+        if let Some(ref event) = self.event {
+            write_field(out, "event", event);
+        }
+
+        if let Some(ref id) = self.id {
+            write_field(out, "id", id);
+        }
+
+        if let Some(retry) = self.retry {
+            write_field(out, "retry", &duration_to_millis(retry).to_string());
+        }
+
+        // A `\r\n`, a `\r` or a `\n` all count as line breaks in the data.
+        let data = self.data.replace("\r\n", "\n").replace('\r', "\n");
+        for line in data.split('\n') {
+            out.extend_from_slice(b"data: ");
+            out.extend_from_slice(line.as_bytes());
+            out.push(b'\n');
+        }
+
+        out.push(b'\n');
+    }
+}
+
+fn write_field(out: &mut Vec<u8>, name: &str, value: &str) {
+    out.extend_from_slice(name.as_bytes());
+    out.extend_from_slice(b": ");
+    out.extend(value.bytes().filter(|&b| b != b'\r' && b != b'\n'));
+    out.push(b'\n');
+}
+
+fn duration_to_millis(duration: Duration) -> u64 {
+    duration.as_secs() * 1000 + u64::from(duration.subsec_nanos() / 1_000_000)
+}
+
+/// Builds a response that streams events to the client.
+pub struct EventStream {
+    receiver: Receiver<Event>,
+    keep_alive: Option<Duration>,
+    retry: Option<Duration>,
+}
+
+impl EventStream {
+    /// Builds an `EventStream` that sends the events received on `receiver`.
+    ///
+    /// By default, a keep-alive comment is sent when no event has been sent for 15 seconds.
+    #[inline]
+    pub fn new(receiver: Receiver<Event>) -> EventStream {
+        EventStream {
+            receiver: receiver,
+            keep_alive: Some(Duration::from_secs(15)),
+            retry: None,
+        }
+    }
+
+    /// Sets the interval after which a keep-alive comment is sent if no event has been sent.
+    /// `None` disables keep-alive comments.
+    #[inline]
+    pub fn keep_alive(mut self, interval: Option<Duration>) -> EventStream {
+        self.keep_alive = interval;
+        self
+    }
+
+    /// Sets the reconnection delay that is sent to the client at the start of the stream.
+    #[inline]
+    pub fn retry(mut self, retry: Duration) -> EventStream {
+        self.retry = Some(retry);
+        self
+    }
+
+    /// Turns the stream into a `Response`.
+    ///
+    /// The response has a `Content-Type` of `text/event-stream`, and its body has no known
+    /// length, which means that it is sent with the chunked transfer encoding, or until the
+    /// connection is closed for HTTP 1.0 clients.
+    pub fn into_response(self) -> Response {
+        let mut buffer = Vec::new();
+        if let Some(retry) = self.retry {
+            write_field(&mut buffer, "retry", &duration_to_millis(retry).to_string());
+            buffer.push(b'\n');
+        }
+
+        let reader = EventReader {
+            receiver: self.receiver,
+            keep_alive: self.keep_alive,
+            buffer: buffer,
+            position: 0,
+        };
+
+        Response {
+            status_code: 200,
+            headers: vec![
+                ("Content-Type".into(), "text/event-stream".into()),
+                ("Cache-Control".into(), "no-cache".into()),
+            ],
+            data: ResponseBody::from_reader(reader),
+            upgrade: None,
+        }
+    }
+}
+
+// Reader that produces the body of the response.
+struct EventReader {
+    receiver: Receiver<Event>,
+    keep_alive: Option<Duration>,
+    // Data that has been produced but not read yet, starting at `position`.
+    buffer: Vec<u8>,
+    position: usize,
+}
+
+impl Read for EventReader {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        while self.position >= self.buffer.len() {
+            self.buffer.clear();
+            self.position = 0;
+
+            let event = match self.keep_alive {
+                Some(keep_alive) => match self.receiver.recv_timeout(keep_alive) {
+                    Ok(event) => Some(event),
+                    Err(RecvTimeoutError::Timeout) => None,
+                    Err(RecvTimeoutError::Disconnected) => return Ok(0),
+                },
+                None => match self.receiver.recv() {
+                    Ok(event) => Some(event),
+                    Err(_) => return Ok(0),
+                },
+            };
+
+            match event {
+                Some(event) => event.write_to(&mut self.buffer),
+                None => self.buffer.extend_from_slice(b":\n\n"),
+            }
+        }
+
+        let len = cmp::min(buf.len(), self.buffer.len() - self.position);
+        buf[..len].copy_from_slice(&self.buffer[self.position .. self.position + len]);
+        self.position += len;
+        Ok(len)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Read;
+    use std::sync::mpsc;
+    use std::time::Duration;
+
+    use Request;
+    use super::Event;
+    use super::EventStream;
+    use super::last_event_id;
+
+    fn serialize(event: Event) -> String {
+        let mut out = Vec::new();
+        event.write_to(&mut out);
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn event_format() {
+        assert_eq!(serialize(Event::new("hello")), "data: hello\n\n");
+        assert_eq!(serialize(Event::new("a\nb\r\nc").id("5").event("update")),
+                   "event: update\nid: 5\ndata: a\ndata: b\ndata: c\n\n");
+        assert_eq!(serialize(Event::new("").retry(Duration::from_millis(1500))),
+                   "retry: 1500\ndata: \n\n");
+        assert_eq!(serialize(Event::new("x").id("1\n2")), "id: 12\ndata: x\n\n");
+    }
+
+    #[test]
+    fn stream() {
+        let (sender, receiver) = mpsc::channel();
+        sender.send(Event::new("one")).unwrap();
+        sender.send(Event::new("two").id("2")).unwrap();
+        drop(sender);
+
+        let response = EventStream::new(receiver).retry(Duration::from_secs(3)).into_response();
+        assert!(response.headers.iter().any(|&(ref k, ref v)| {
+            k == "Content-Type" && v == "text/event-stream"
+        }));
+
+        let (mut reader, len) = response.data.into_reader_and_size();
+        assert_eq!(len, None);
+        let mut body = String::new();
+        reader.read_to_string(&mut body).unwrap();
+        assert_eq!(body, "retry: 3000\n\ndata: one\n\nid: 2\ndata: two\n\n");
+    }
+
+    #[test]
+    fn keep_alive() {
+        let (sender, receiver) = mpsc::channel::<Event>();
+        let response = EventStream::new(receiver).keep_alive(Some(Duration::from_millis(10)))
+                                                 .into_response();
+        let (mut reader, _) = response.data.into_reader_and_size();
+
+        let mut buf = [0; 16];
+        let len = reader.read(&mut buf).unwrap();
+        assert_eq!(&buf[..len], b":\n\n");
+
+        drop(sender);
+        assert_eq!(reader.read(&mut buf).unwrap(), 0);
+    }
+
+    #[test]
+    fn last_event_id_header() {
+        let request = Request::fake_http("GET", "/", vec![("Last-Event-ID".to_owned(),
+                                                           "42".to_owned())], vec![]);
+        assert_eq!(last_event_id(&request), Some("42"));
+    }
+}
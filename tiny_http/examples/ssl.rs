@@ -1,9 +1,9 @@
 extern crate tiny_http;
 
-#[cfg(not(feature = "ssl"))]
-fn main() { println!("This example requires the `ssl` feature to be enabled"); }
+#[cfg(not(any(feature = "ssl", feature = "ssl-rustls")))]
+fn main() { println!("This example requires the `ssl` or `ssl-rustls` feature to be enabled"); }
 
-#[cfg(feature = "ssl")]
+#[cfg(any(feature = "ssl", feature = "ssl-rustls"))]
 fn main() {
     use tiny_http::{Server, Response};
 
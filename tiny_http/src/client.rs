@@ -17,15 +17,22 @@ use std::ascii::AsciiExt;
 
 use std::io::Error as IoError;
 use std::io::Result as IoResult;
-use std::io::{ErrorKind, Read, BufReader, BufWriter};
+use std::io::{ErrorKind, Read, BufRead, BufReader, BufWriter};
 
 use std::net::SocketAddr;
 use std::str::FromStr;
+use std::sync::Arc;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering::Relaxed;
+use std::time::Instant;
 
 use common::{HTTPVersion, Method};
 use util::{SequentialReader, SequentialReaderBuilder, SequentialWriterBuilder};
 use util::RefinedTcpStream;
 
+#[cfg(feature = "http2")]
+use http2;
+use Limits;
 use Request;
 
 /// A ClientConnection is an object that will store a socket to a client
@@ -50,6 +57,19 @@ pub struct ClientConnection {
 
     // true if the connection goes through SSL
     secure: bool,
+
+    // protocol negotiated with ALPN
+    alpn_protocol: Option<String>,
+
+    // set if the body of the last request has been rejected, see `Request::expects_continue`
+    body_rejected: Arc<AtomicBool>,
+
+    // timeouts and limits of the server
+    limits: Limits,
+
+    // set once the client has switched to HTTP/2, which then handles the connection
+    #[cfg(feature = "http2")]
+    http2: Option<http2::Connection>,
 }
 
 /// Error that can happen when reading a request.
@@ -60,16 +80,28 @@ enum ReadError {
     /// the client sent an unrecognized `Expect` header
     ExpectationFailed(HTTPVersion),
 
+    /// the request line and headers are larger than allowed, or there are too many headers
+    HeaderTooLarge,
+
+    /// the `Content-Length` of the request is larger than allowed
+    BodyTooLarge(HTTPVersion),
+
+    /// the client sent the beginning of the connection preface of HTTP/2
+    #[cfg(feature = "http2")]
+    Http2Preface,
+
     ReadIoError(IoError),
 }
 
 impl ClientConnection {
     /// Creates a new ClientConnection that takes ownership of the TcpStream.
-    pub fn new(write_socket: RefinedTcpStream, mut read_socket: RefinedTcpStream)
-               -> ClientConnection
+    pub fn new(write_socket: RefinedTcpStream, mut read_socket: RefinedTcpStream,
+               limits: Limits) -> ClientConnection
     {
         let remote_addr = read_socket.peer_addr();
         let secure = read_socket.secure();
+        let alpn_protocol = read_socket.alpn_protocol();
+        read_socket.set_write_timeout(limits.write_timeout).ok();
 
         let mut source = SequentialReaderBuilder::new(BufReader::with_capacity(1024, read_socket));
         let first_header = source.next().unwrap();
@@ -81,29 +113,77 @@ impl ClientConnection {
             next_header_source: first_header,
             no_more_requests: false,
             secure: secure,
+            alpn_protocol: alpn_protocol,
+            body_rejected: Arc::new(AtomicBool::new(false)),
+            limits: limits,
+            #[cfg(feature = "http2")]
+            http2: None,
         }
     }
 
+    /// Waits until the previous request has been read and the first byte of the next request
+    /// is available, within the keep-alive timeout.
+    ///
+    /// Returns false if the connection has been closed or if the timeout has expired. Otherwise,
+    /// starts the timer of the header timeout.
+    fn wait_for_request(&mut self) -> bool {
+        let limits = self.limits;
+        let reader = self.next_header_source.get_mut();
+
+        // the body of the previous request has not been received, so we don't know where the
+        // next request starts
+        if self.body_rejected.load(Relaxed) {
+            return false;
+        }
+
+        if reader.get_mut().set_read_timeout(limits.keep_alive_timeout).is_err() {
+            return false;
+        }
+
+        match reader.fill_buf() {
+            Ok(data) if !data.is_empty() => (),
+            _ => return false,
+        };
+
+        let deadline = limits.header_timeout.map(|timeout| Instant::now() + timeout);
+        reader.get_mut().set_read_timeout(limits.read_timeout)
+                        .and_then(|_| reader.get_mut().set_read_deadline(deadline))
+                        .is_ok()
+    }
+
     /// Reads the next line from self.next_header_source.
     ///
     /// Reads until `CRLF` is reached. The next read will start
     ///  at the first byte of the new line.
-    fn read_next_line(&mut self) -> IoResult<AsciiString> {
+    ///
+    /// `bytes_left` is the number of bytes that can still be read as part of the header, and is
+    ///  decreased by the size of the line.
+    fn read_next_line(&mut self, bytes_left: &mut Option<usize>) -> Result<AsciiString, ReadError> {
         let mut buf = Vec::new();
         let mut prev_byte_was_cr = false;
 
         loop {
+            if *bytes_left == Some(0) {
+                return Err(ReadError::HeaderTooLarge);
+            }
+
             let byte = self.next_header_source.by_ref().bytes().next();
 
             let byte = match byte {
-                Some(b) => try!(b),
-                None => return Err(IoError::new(ErrorKind::ConnectionAborted, "Unexpected EOF"))
+                Some(b) => try!(b.map_err(ReadError::ReadIoError)),
+                None => return Err(ReadError::ReadIoError(
+                    IoError::new(ErrorKind::ConnectionAborted, "Unexpected EOF")))
             };
 
+            if let Some(ref mut left) = *bytes_left {
+                *left -= 1;
+            }
+
             if byte == b'\n' && prev_byte_was_cr {
                 buf.pop();  // removing the '\r'
                 return AsciiString::from_ascii(buf)
-                    .map_err(|_| IoError::new(ErrorKind::InvalidInput, "Header is not in ASCII"))
+                    .map_err(|_| ReadError::ReadIoError(
+                        IoError::new(ErrorKind::InvalidInput, "Header is not in ASCII")))
             }
 
             prev_byte_was_cr = byte == b'\r';
@@ -115,10 +195,12 @@ impl ClientConnection {
     /// Reads a request from the stream.
     /// Blocks until the header has been read.
     fn read(&mut self) -> Result<Request, ReadError> {
+        let mut bytes_left = self.limits.max_header_bytes;
+
         let (method, path, version, headers) = {
             // reading the request line
             let (method, path, version) = {
-                let line = try!(self.read_next_line().map_err(|e| ReadError::ReadIoError(e)));
+                let line = try!(self.read_next_line(&mut bytes_left));
 
                 try!(parse_request_line(
                     line.as_str().trim()    // TODO: remove this conversion
@@ -129,9 +211,14 @@ impl ClientConnection {
             let headers = {
                 let mut headers = Vec::new();
                 loop {
-                    let line = try!(self.read_next_line().map_err(|e| ReadError::ReadIoError(e)));
+                    let line = try!(self.read_next_line(&mut bytes_left));
 
                     if line.len() == 0 { break };
+
+                    if self.limits.max_headers.map_or(false, |max| headers.len() >= max) {
+                        return Err(ReadError::HeaderTooLarge);
+                    }
+
                     headers.push(
                         match FromStr::from_str(line.as_str().trim()) {    // TODO: remove this conversion
                             Ok(h) => h,
@@ -146,21 +233,37 @@ impl ClientConnection {
             (method, path, version, headers)
         };
 
+        // the connection preface of HTTP/2 starts with `PRI * HTTP/2.0` followed by an empty
+        // line, which looks like a request without headers
+        #[cfg(feature = "http2")]
+        {
+            if method.as_str() == "PRI" && path == "*" && version == (2, 0) && headers.is_empty() {
+                return Err(ReadError::Http2Preface);
+            }
+        }
+
+        // the header timeout doesn't apply to the body
+        try!(self.next_header_source.get_mut().get_mut().set_read_deadline(None)
+                 .map_err(ReadError::ReadIoError));
+
         // building the writer for the request
         let writer = self.sink.next().unwrap();
+        self.body_rejected = Arc::new(AtomicBool::new(false));
 
         // follow-up for next potential request
         let mut data_source = self.source.next().unwrap();
         ::std::mem::swap(&mut self.next_header_source, &mut data_source);
 
         // building the next reader
-        let request = try!(::request::new_request(self.secure, method, path, version.clone(),
-                headers, self.remote_addr.as_ref().unwrap().clone(), data_source, writer)
+        let request = try!(::request::new_request(self.secure, self.alpn_protocol.clone(), method,
+                path, version.clone(), headers, self.remote_addr.as_ref().unwrap().clone(),
+                data_source, writer, &self.limits, self.body_rejected.clone())
             .map_err(|e| {
                 use request;
                 match e {
                     request::RequestCreationError::CreationIoError(e) => ReadError::ReadIoError(e),
-                    request::RequestCreationError::ExpectationFailed => ReadError::ExpectationFailed(version)
+                    request::RequestCreationError::ExpectationFailed => ReadError::ExpectationFailed(version),
+                    request::RequestCreationError::BodyTooLarge => ReadError::BodyTooLarge(version),
                 }
             }));
 
@@ -176,6 +279,13 @@ impl Iterator for ClientConnection {
     fn next(&mut self) -> Option<Request> {
         use {Response, StatusCode};
 
+        #[cfg(feature = "http2")]
+        {
+            if let Some(ref mut connection) = self.http2 {
+                return connection.next(&mut self.next_header_source);
+            }
+        }
+
         // the client sent a "connection: close" header in this previous request
         //  or is using HTTP 1.0, meaning that no new request will come
         if self.no_more_requests {
@@ -183,6 +293,11 @@ impl Iterator for ClientConnection {
         }
 
         loop {
+            if !self.wait_for_request() {
+                return None;    // the client has closed the connection or has been idle for too
+                                // long
+            }
+
             let rq = match self.read() {
                 Err(ReadError::WrongRequestLine) => {
                     let writer = self.sink.next().unwrap();
@@ -200,7 +315,8 @@ impl Iterator for ClientConnection {
                                     // se we have to close
                 },
 
-                Err(ReadError::ReadIoError(ref err)) if err.kind() == ErrorKind::TimedOut => {
+                Err(ReadError::ReadIoError(ref err)) if err.kind() == ErrorKind::TimedOut ||
+                                                        err.kind() == ErrorKind::WouldBlock => {
                     // request timeout
                     let writer = self.sink.next().unwrap();
                     let response = Response::new_empty(StatusCode(408));
@@ -215,6 +331,37 @@ impl Iterator for ClientConnection {
                     return None;    // TODO: should be recoverable, but needs handling in case of body
                 },
 
+                Err(ReadError::HeaderTooLarge) => {
+                    let writer = self.sink.next().unwrap();
+                    let response = Response::new_empty(StatusCode(431));
+                    response.raw_print(writer, HTTPVersion(1, 1), &[], false, None).ok();
+                    return None;    // we don't know where the next request would start,
+                                    // so we have to close
+                },
+
+                Err(ReadError::BodyTooLarge(ver)) => {
+                    let writer = self.sink.next().unwrap();
+                    let response = Response::new_empty(StatusCode(413));
+                    response.raw_print(writer, ver, &[], false, None).ok();
+                    return None;    // closing the connection instead of reading the body
+                },
+
+                #[cfg(feature = "http2")]
+                Err(ReadError::Http2Preface) => {
+                    // the rest of the preface is `SM` followed by an empty line
+                    let mut end = [0; 6];
+                    match self.next_header_source.read_exact(&mut end) {
+                        Ok(()) if &end == b"SM\r\n\r\n" => (),
+                        _ => return None,
+                    };
+
+                    let writer = self.sink.next().unwrap();
+                    self.http2 = Some(http2::Connection::new(writer, self.secure,
+                        self.alpn_protocol.clone(), self.remote_addr.as_ref().unwrap().clone(),
+                        self.limits));
+                    return self.next();
+                },
+
                 Err(ReadError::ReadIoError(_)) =>
                     return None,
 
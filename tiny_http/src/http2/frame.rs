@@ -0,0 +1,168 @@
+// Copyright 2015 The tiny-http Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// Frames of HTTP/2, defined in section 6 of RFC 7540.
+
+use std::io::Write;
+use std::io::Result as IoResult;
+
+// types of frames
+pub const DATA: u8 = 0x0;
+pub const HEADERS: u8 = 0x1;
+pub const PRIORITY: u8 = 0x2;
+pub const RST_STREAM: u8 = 0x3;
+pub const SETTINGS: u8 = 0x4;
+pub const PUSH_PROMISE: u8 = 0x5;
+pub const PING: u8 = 0x6;
+pub const GOAWAY: u8 = 0x7;
+pub const WINDOW_UPDATE: u8 = 0x8;
+pub const CONTINUATION: u8 = 0x9;
+
+// flags, whose meaning depends on the type of the frame
+pub const FLAG_END_STREAM: u8 = 0x1;
+pub const FLAG_ACK: u8 = 0x1;
+pub const FLAG_END_HEADERS: u8 = 0x4;
+pub const FLAG_PADDED: u8 = 0x8;
+pub const FLAG_PRIORITY: u8 = 0x20;
+
+// identifiers of the settings
+pub const SETTINGS_HEADER_TABLE_SIZE: u16 = 0x1;
+pub const SETTINGS_ENABLE_PUSH: u16 = 0x2;
+pub const SETTINGS_MAX_CONCURRENT_STREAMS: u16 = 0x3;
+pub const SETTINGS_INITIAL_WINDOW_SIZE: u16 = 0x4;
+pub const SETTINGS_MAX_FRAME_SIZE: u16 = 0x5;
+pub const SETTINGS_MAX_HEADER_LIST_SIZE: u16 = 0x6;
+
+// error codes of RST_STREAM and GOAWAY
+pub const NO_ERROR: u32 = 0x0;
+pub const PROTOCOL_ERROR: u32 = 0x1;
+pub const INTERNAL_ERROR: u32 = 0x2;
+pub const FLOW_CONTROL_ERROR: u32 = 0x3;
+pub const FRAME_SIZE_ERROR: u32 = 0x6;
+pub const REFUSED_STREAM: u32 = 0x7;
+pub const COMPRESSION_ERROR: u32 = 0x9;
+pub const ENHANCE_YOUR_CALM: u32 = 0xb;
+
+/// Size of the header of a frame.
+pub const HEADER_LENGTH: usize = 9;
+
+/// Maximum size of the payload of the frames, unless a larger one has been negotiated.
+pub const DEFAULT_MAX_FRAME_SIZE: usize = 16384;
+
+/// Largest frame size that a peer can set with SETTINGS_MAX_FRAME_SIZE.
+pub const MAX_MAX_FRAME_SIZE: usize = 16777215;
+
+/// Largest size of a flow control window.
+pub const MAX_WINDOW_SIZE: i64 = 0x7fffffff;
+
+/// Size of the flow control windows when a connection starts.
+pub const DEFAULT_WINDOW_SIZE: i64 = 65535;
+
+/// Header of a frame.
+pub struct FrameHeader {
+    pub length: usize,
+    pub kind: u8,
+    pub flags: u8,
+    pub stream_id: u32,
+}
+
+impl FrameHeader {
+    pub fn parse(data: &[u8; HEADER_LENGTH]) -> FrameHeader {
+        FrameHeader {
+            length: (data[0] as usize) << 16 | (data[1] as usize) << 8 | data[2] as usize,
+            kind: data[3],
+            flags: data[4],
+            stream_id: read_u32(&data[5 ..]) & 0x7fffffff,
+        }
+    }
+
+    #[inline]
+    pub fn has_flag(&self, flag: u8) -> bool {
+        self.flags & flag != 0
+    }
+}
+
+/// Writes a frame. Doesn't flush the writer.
+pub fn write_frame<W>(mut writer: W, kind: u8, flags: u8, stream_id: u32, payload: &[u8])
+                      -> IoResult<()> where W: Write
+{
+    let length = payload.len();
+    let mut header = [0; HEADER_LENGTH];
+    header[0] = (length >> 16) as u8;
+    header[1] = (length >> 8) as u8;
+    header[2] = length as u8;
+    header[3] = kind;
+    header[4] = flags;
+    header[5 ..].copy_from_slice(&u32_bytes(stream_id));
+
+    try!(writer.write_all(&header));
+    writer.write_all(payload)
+}
+
+/// Reads a big-endian integer from the first four bytes of `data`.
+#[inline]
+pub fn read_u32(data: &[u8]) -> u32 {
+    (data[0] as u32) << 24 | (data[1] as u32) << 16 | (data[2] as u32) << 8 | data[3] as u32
+}
+
+#[inline]
+pub fn u32_bytes(value: u32) -> [u8; 4] {
+    [(value >> 24) as u8, (value >> 16) as u8, (value >> 8) as u8, value as u8]
+}
+
+/// Removes the padding of a DATA or HEADERS frame.
+///
+/// Returns an error if the padding is longer than the frame.
+pub fn strip_padding<'a>(header: &FrameHeader, payload: &'a [u8]) -> Result<&'a [u8], ()> {
+    if !header.has_flag(FLAG_PADDED) {
+        return Ok(payload);
+    }
+
+    let padding = match payload.first() {
+        Some(&p) => p as usize,
+        None => return Err(()),
+    };
+
+    if padding >= payload.len() {
+        return Err(());
+    }
+
+    Ok(&payload[1 .. payload.len() - padding])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{FrameHeader, FLAG_PADDED};
+
+    #[test]
+    fn frame_header() {
+        let mut data = Vec::new();
+        super::write_frame(&mut data, super::HEADERS, 0x5, 0x80000003, b"abc").unwrap();
+        assert_eq!(data, b"\x00\x00\x03\x01\x05\x80\x00\x00\x03abc");
+
+        let mut header = [0; super::HEADER_LENGTH];
+        header.copy_from_slice(&data[.. 9]);
+        let header = FrameHeader::parse(&header);
+        assert_eq!((header.length, header.kind, header.flags), (3, super::HEADERS, 0x5));
+        assert_eq!(header.stream_id, 3);
+    }
+
+    #[test]
+    fn padding() {
+        let header = FrameHeader { length: 6, kind: super::DATA, flags: FLAG_PADDED, stream_id: 1 };
+        assert_eq!(super::strip_padding(&header, b"\x02abcxx"), Ok(&b"abc"[..]));
+        assert!(super::strip_padding(&header, b"\x06abcxx").is_err());
+        assert!(super::strip_padding(&header, b"").is_err());
+    }
+}
@@ -0,0 +1,429 @@
+// Copyright 2015 The tiny-http Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// Header compression of HTTP/2, defined in RFC 7541.
+//
+// The decoder supports the whole format. The encoder only produces literals that are not added
+// to the dynamic table and not compressed with Huffman, which the decoders must accept too.
+
+use std::collections::VecDeque;
+
+use super::huffman::HuffmanDecoder;
+
+/// A header decoded from a header block, as a name and a value.
+pub type HeaderLine = (Vec<u8>, Vec<u8>);
+
+// table of appendix A of RFC 7541, whose first entry has the index 1
+static STATIC_TABLE: [(&'static str, &'static str); 61] = [
+    (":authority", ""),
+    (":method", "GET"),
+    (":method", "POST"),
+    (":path", "/"),
+    (":path", "/index.html"),
+    (":scheme", "http"),
+    (":scheme", "https"),
+    (":status", "200"),
+    (":status", "204"),
+    (":status", "206"),
+    (":status", "304"),
+    (":status", "400"),
+    (":status", "404"),
+    (":status", "500"),
+    ("accept-charset", ""),
+    ("accept-encoding", "gzip, deflate"),
+    ("accept-language", ""),
+    ("accept-ranges", ""),
+    ("accept", ""),
+    ("access-control-allow-origin", ""),
+    ("age", ""),
+    ("allow", ""),
+    ("authorization", ""),
+    ("cache-control", ""),
+    ("content-disposition", ""),
+    ("content-encoding", ""),
+    ("content-language", ""),
+    ("content-length", ""),
+    ("content-location", ""),
+    ("content-range", ""),
+    ("content-type", ""),
+    ("cookie", ""),
+    ("date", ""),
+    ("etag", ""),
+    ("expect", ""),
+    ("expires", ""),
+    ("from", ""),
+    ("host", ""),
+    ("if-match", ""),
+    ("if-modified-since", ""),
+    ("if-none-match", ""),
+    ("if-range", ""),
+    ("if-unmodified-since", ""),
+    ("last-modified", ""),
+    ("link", ""),
+    ("location", ""),
+    ("max-forwards", ""),
+    ("proxy-authenticate", ""),
+    ("proxy-authorization", ""),
+    ("range", ""),
+    ("referer", ""),
+    ("refresh", ""),
+    ("retry-after", ""),
+    ("server", ""),
+    ("set-cookie", ""),
+    ("strict-transport-security", ""),
+    ("transfer-encoding", ""),
+    ("user-agent", ""),
+    ("vary", ""),
+    ("via", ""),
+    ("www-authenticate", ""),
+];
+
+// index of `:status` in the static table
+const STATUS_INDEX: usize = 8;
+
+/// Decodes the header blocks received from a client.
+///
+/// The same decoder must be used for all the header blocks of a connection, in the order in
+/// which they have been received, since they share the dynamic table.
+pub struct Decoder {
+    // the most recent entry is at the front
+    dynamic_table: VecDeque<HeaderLine>,
+    // size of the entries of the dynamic table, as defined by RFC 7541
+    size: usize,
+    // maximum size of the dynamic table chosen by the client
+    max_size: usize,
+    // maximum size of the dynamic table allowed by our SETTINGS_HEADER_TABLE_SIZE
+    allowed_max_size: usize,
+    huffman: HuffmanDecoder,
+}
+
+impl Decoder {
+    pub fn new(allowed_max_size: usize) -> Decoder {
+        Decoder {
+            dynamic_table: VecDeque::new(),
+            size: 0,
+            max_size: allowed_max_size,
+            allowed_max_size: allowed_max_size,
+            huffman: HuffmanDecoder::new(),
+        }
+    }
+
+    /// Decodes a complete header block.
+    ///
+    /// An error means that the state of the decoder is no longer in sync with the client, and
+    /// the connection must be closed with a `COMPRESSION_ERROR`.
+    pub fn decode(&mut self, mut block: &[u8]) -> Result<Vec<HeaderLine>, ()> {
+        let mut headers = Vec::new();
+
+        while let Some(&first) = block.first() {
+            if first & 0x80 != 0 {
+                // indexed header field
+                let index = try!(decode_integer(&mut block, 7));
+                let entry = try!(self.entry(index));
+                headers.push(entry);
+
+            } else if first & 0xc0 == 0x40 {
+                // literal header field with incremental indexing
+                let entry = try!(self.decode_literal(&mut block, 6));
+                self.insert(entry.clone());
+                headers.push(entry);
+
+            } else if first & 0xe0 == 0x20 {
+                // dynamic table size update, only allowed at the beginning of a block
+                if !headers.is_empty() {
+                    return Err(());
+                }
+
+                let max_size = try!(decode_integer(&mut block, 5));
+                if max_size > self.allowed_max_size {
+                    return Err(());
+                }
+
+                self.max_size = max_size;
+                self.evict(0);
+
+            } else {
+                // literal header field without indexing or never indexed
+                let entry = try!(self.decode_literal(&mut block, 4));
+                headers.push(entry);
+            }
+        }
+
+        Ok(headers)
+    }
+
+    // Returns the entry of the static or dynamic table that has the given index.
+    fn entry(&self, index: usize) -> Result<HeaderLine, ()> {
+        if index == 0 {
+            return Err(());
+        }
+
+        if index <= STATIC_TABLE.len() {
+            let (name, value) = STATIC_TABLE[index - 1];
+            return Ok((name.as_bytes().to_vec(), value.as_bytes().to_vec()));
+        }
+
+        self.dynamic_table.get(index - STATIC_TABLE.len() - 1).cloned().ok_or(())
+    }
+
+    // Decodes a literal header field whose name index has `prefix` bits.
+    fn decode_literal(&mut self, block: &mut &[u8], prefix: u8) -> Result<HeaderLine, ()> {
+        let name = match try!(decode_integer(block, prefix)) {
+            0 => try!(self.decode_string(block)),
+            index => try!(self.entry(index)).0,
+        };
+
+        let value = try!(self.decode_string(block));
+        Ok((name, value))
+    }
+
+    fn decode_string(&mut self, block: &mut &[u8]) -> Result<Vec<u8>, ()> {
+        let huffman = match block.first() {
+            Some(&b) => b & 0x80 != 0,
+            None => return Err(()),
+        };
+
+        let length = try!(decode_integer(block, 7));
+        if block.len() < length {
+            return Err(());
+        }
+
+        let (data, rest) = block.split_at(length);
+        *block = rest;
+
+        if huffman {
+            self.huffman.decode(data)
+        } else {
+            Ok(data.to_vec())
+        }
+    }
+
+    fn insert(&mut self, entry: HeaderLine) {
+        let size = entry_size(&entry);
+        self.evict(size);
+
+        // an entry larger than the table empties it and is not inserted
+        if size <= self.max_size {
+            self.size += size;
+            self.dynamic_table.push_front(entry);
+        }
+    }
+
+    // Removes the oldest entries until `additional` bytes can be added to the table.
+    fn evict(&mut self, additional: usize) {
+        while self.size + additional > self.max_size {
+            match self.dynamic_table.pop_back() {
+                Some(entry) => self.size -= entry_size(&entry),
+                None => break,
+            }
+        }
+    }
+}
+
+// Size of an entry of the dynamic table, as defined in section 4.1 of RFC 7541.
+fn entry_size(&(ref name, ref value): &HeaderLine) -> usize {
+    name.len() + value.len() + 32
+}
+
+// Decodes an integer whose first byte has a `prefix` bits long prefix.
+fn decode_integer(block: &mut &[u8], prefix: u8) -> Result<usize, ()> {
+    let mask = (1u8 << prefix) - 1;
+
+    let mut value = match block.first() {
+        Some(&b) => (b & mask) as usize,
+        None => return Err(()),
+    };
+    *block = &block[1 ..];
+
+    if value < mask as usize {
+        return Ok(value);
+    }
+
+    let mut shift = 0;
+    loop {
+        let byte = match block.first() {
+            Some(&b) => b,
+            None => return Err(()),
+        };
+        *block = &block[1 ..];
+
+        // the values that we accept always fit in 28 bits
+        if shift > 21 {
+            return Err(());
+        }
+
+        value += ((byte & 0x7f) as usize) << shift;
+        shift += 7;
+
+        if byte & 0x80 == 0 {
+            return Ok(value);
+        }
+    }
+}
+
+// Encodes an integer. `flags` are the bits of the first byte that precede the prefix.
+fn encode_integer(out: &mut Vec<u8>, flags: u8, prefix: u8, mut value: usize) {
+    let mask = (1usize << prefix) - 1;
+
+    if value < mask {
+        out.push(flags | value as u8);
+        return;
+    }
+
+    out.push(flags | mask as u8);
+    value -= mask;
+
+    while value >= 0x80 {
+        out.push(0x80 | (value & 0x7f) as u8);
+        value >>= 7;
+    }
+
+    out.push(value as u8);
+}
+
+fn encode_string(out: &mut Vec<u8>, data: &[u8]) {
+    encode_integer(out, 0, 7, data.len());
+    out.extend_from_slice(data);
+}
+
+/// Appends a dynamic table size update to a header block.
+///
+/// Since the encoder never adds entries to the dynamic table, the client can shrink it at will,
+/// but the change must be acknowledged by the next header block.
+pub fn encode_table_size_update(out: &mut Vec<u8>, max_size: usize) {
+    encode_integer(out, 0x20, 5, max_size);
+}
+
+/// Appends the `:status` pseudo-header to a header block.
+pub fn encode_status(out: &mut Vec<u8>, status: u16) {
+    let value = status.to_string();
+
+    let index = STATIC_TABLE.iter().position(|&(name, v)| name == ":status" && v == value);
+    match index {
+        Some(index) => encode_integer(out, 0x80, 7, index + 1),
+        None => {
+            encode_integer(out, 0, 4, STATUS_INDEX);
+            encode_string(out, value.as_bytes());
+        },
+    }
+}
+
+/// Appends a header to a header block, as a literal without indexing.
+///
+/// The name must be in lowercase.
+pub fn encode_header(out: &mut Vec<u8>, name: &[u8], value: &[u8]) {
+    out.push(0);
+    encode_string(out, name);
+    encode_string(out, value);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Decoder, HeaderLine};
+
+    fn lines(headers: &[(&str, &str)]) -> Vec<HeaderLine> {
+        headers.iter().map(|&(n, v)| (n.as_bytes().to_vec(), v.as_bytes().to_vec())).collect()
+    }
+
+    #[test]
+    fn requests_without_huffman() {
+        // appendix C.3 of RFC 7541
+        let mut decoder = Decoder::new(4096);
+
+        let headers = decoder.decode(&[0x82, 0x86, 0x84, 0x41, 0x0f, 0x77, 0x77, 0x77, 0x2e,
+                                       0x65, 0x78, 0x61, 0x6d, 0x70, 0x6c, 0x65, 0x2e, 0x63,
+                                       0x6f, 0x6d]).unwrap();
+        assert_eq!(headers, lines(&[(":method", "GET"), (":scheme", "http"), (":path", "/"),
+                                    (":authority", "www.example.com")]));
+        assert_eq!(decoder.size, 57);
+
+        let headers = decoder.decode(&[0x82, 0x86, 0x84, 0xbe, 0x58, 0x08, 0x6e, 0x6f, 0x2d,
+                                       0x63, 0x61, 0x63, 0x68, 0x65]).unwrap();
+        assert_eq!(headers, lines(&[(":method", "GET"), (":scheme", "http"), (":path", "/"),
+                                    (":authority", "www.example.com"),
+                                    ("cache-control", "no-cache")]));
+        assert_eq!(decoder.size, 110);
+
+        let headers = decoder.decode(&[0x82, 0x87, 0x85, 0xbf, 0x40, 0x0a, 0x63, 0x75, 0x73,
+                                       0x74, 0x6f, 0x6d, 0x2d, 0x6b, 0x65, 0x79, 0x0c, 0x63,
+                                       0x75, 0x73, 0x74, 0x6f, 0x6d, 0x2d, 0x76, 0x61, 0x6c,
+                                       0x75, 0x65]).unwrap();
+        assert_eq!(headers, lines(&[(":method", "GET"), (":scheme", "https"),
+                                    (":path", "/index.html"), (":authority", "www.example.com"),
+                                    ("custom-key", "custom-value")]));
+        assert_eq!(decoder.size, 164);
+    }
+
+    #[test]
+    fn requests_with_huffman() {
+        // appendix C.4 of RFC 7541
+        let mut decoder = Decoder::new(4096);
+
+        let headers = decoder.decode(&[0x82, 0x86, 0x84, 0x41, 0x8c, 0xf1, 0xe3, 0xc2, 0xe5,
+                                       0xf2, 0x3a, 0x6b, 0xa0, 0xab, 0x90, 0xf4, 0xff]).unwrap();
+        assert_eq!(headers, lines(&[(":method", "GET"), (":scheme", "http"), (":path", "/"),
+                                    (":authority", "www.example.com")]));
+
+        let headers = decoder.decode(&[0x82, 0x86, 0x84, 0xbe, 0x58, 0x86, 0xa8, 0xeb, 0x10,
+                                       0x64, 0x9c, 0xbf]).unwrap();
+        assert_eq!(headers[4], lines(&[("cache-control", "no-cache")])[0]);
+
+        let headers = decoder.decode(&[0x82, 0x87, 0x85, 0xbf, 0x40, 0x88, 0x25, 0xa8, 0x49,
+                                       0xe9, 0x5b, 0xa9, 0x7d, 0x7f, 0x89, 0x25, 0xa8, 0x49,
+                                       0xe9, 0x5b, 0xb8, 0xe8, 0xb4, 0xbf]).unwrap();
+        assert_eq!(headers[4], lines(&[("custom-key", "custom-value")])[0]);
+        assert_eq!(decoder.size, 164);
+    }
+
+    #[test]
+    fn table_size_update() {
+        let mut decoder = Decoder::new(4096);
+        decoder.decode(&[0x40, 0x01, 0x61, 0x01, 0x62]).unwrap();
+        assert_eq!(decoder.dynamic_table.len(), 1);
+
+        // shrinking the table to 0 evicts everything
+        assert!(decoder.decode(&[0x20]).unwrap().is_empty());
+        assert_eq!(decoder.size, 0);
+        assert!(decoder.decode(&[0xbe]).is_err());
+
+        // larger than allowed, or not at the beginning of the block
+        assert!(decoder.decode(&[0x3f, 0xe2, 0x1f]).is_err());
+        assert!(decoder.decode(&[0x82, 0x20]).is_err());
+    }
+
+    #[test]
+    fn invalid_blocks() {
+        let mut decoder = Decoder::new(4096);
+        assert!(decoder.decode(&[0x80]).is_err());
+        assert!(decoder.decode(&[0xff, 0x80]).is_err());
+        assert!(decoder.decode(&[0x00, 0x05, 0x61]).is_err());
+        assert!(decoder.decode(&[0xff, 0xff, 0xff, 0xff, 0xff, 0x7f]).is_err());
+    }
+
+    #[test]
+    fn encoding() {
+        let mut block = Vec::new();
+        super::encode_status(&mut block, 200);
+        super::encode_status(&mut block, 418);
+        super::encode_header(&mut block, b"content-type", b"text/plain");
+        super::encode_header(&mut block, &[b'x'; 200], b"");
+
+        let headers = Decoder::new(4096).decode(&block).unwrap();
+        assert_eq!(headers[0], lines(&[(":status", "200")])[0]);
+        assert_eq!(headers[1], lines(&[(":status", "418")])[0]);
+        assert_eq!(headers[2], lines(&[("content-type", "text/plain")])[0]);
+        assert_eq!(headers[3], (vec![b'x'; 200], vec![]));
+        assert_eq!(block[0], 0x88);
+    }
+}
@@ -0,0 +1,156 @@
+// Copyright 2015 The tiny-http Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// Huffman code used to compress strings in HPACK, defined in appendix B of RFC 7541.
+//
+// The code is canonical, so it is entirely described by the length of the code of each
+// symbol: the codes of a given length are consecutive, ordered by symbol, and follow the codes
+// of the shorter lengths. Symbol 256 is the end-of-string marker, which must never be decoded.
+
+const CODE_LENGTHS: [u8; 257] = [
+    13, 23, 28, 28, 28, 28, 28, 28, 28, 24, 30, 28, 28, 30, 28, 28,
+    28, 28, 28, 28, 28, 28, 30, 28, 28, 28, 28, 28, 28, 28, 28, 28,
+    6, 10, 10, 12, 13, 6, 8, 11, 10, 10, 8, 11, 8, 6, 6, 6,
+    5, 5, 5, 6, 6, 6, 6, 6, 6, 6, 7, 8, 15, 6, 12, 10,
+    13, 6, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7,
+    7, 7, 7, 7, 7, 7, 7, 7, 8, 7, 8, 13, 19, 13, 14, 6,
+    15, 5, 6, 5, 6, 5, 6, 6, 6, 5, 7, 7, 6, 6, 6, 5,
+    6, 7, 6, 5, 5, 6, 7, 7, 7, 7, 7, 15, 11, 14, 13, 28,
+    20, 22, 20, 20, 22, 22, 22, 23, 22, 23, 23, 23, 23, 23, 24, 23,
+    24, 24, 22, 23, 24, 23, 23, 23, 23, 21, 22, 23, 22, 23, 23, 24,
+    22, 21, 20, 22, 22, 23, 23, 21, 23, 22, 22, 24, 21, 22, 23, 23,
+    21, 21, 22, 21, 23, 22, 23, 23, 20, 22, 22, 22, 23, 22, 22, 23,
+    26, 26, 20, 19, 22, 23, 22, 25, 26, 26, 26, 27, 27, 26, 24, 25,
+    19, 21, 26, 27, 27, 26, 27, 24, 21, 21, 26, 26, 28, 27, 27, 27,
+    20, 24, 20, 21, 22, 21, 21, 23, 22, 22, 25, 25, 24, 24, 26, 23,
+    26, 27, 26, 26, 27, 27, 27, 27, 27, 28, 27, 27, 27, 27, 27, 26,
+    30,];
+
+const END_OF_STRING: u16 = 256;
+const MAX_CODE_LENGTH: usize = 30;
+
+/// Decodes strings compressed with the Huffman code of HPACK.
+pub struct HuffmanDecoder {
+    // first code of each length
+    first_code: [u32; MAX_CODE_LENGTH + 1],
+    // number of codes of each length
+    count: [u32; MAX_CODE_LENGTH + 1],
+    // position in `symbols` of the first symbol of each length
+    first_index: [usize; MAX_CODE_LENGTH + 1],
+    // symbols sorted by the length of their code
+    symbols: Vec<u16>,
+}
+
+impl HuffmanDecoder {
+    pub fn new() -> HuffmanDecoder {
+        let mut symbols: Vec<u16> = (0 .. CODE_LENGTHS.len() as u16).collect();
+        symbols.sort_by_key(|&s| CODE_LENGTHS[s as usize]);
+
+        let mut count = [0; MAX_CODE_LENGTH + 1];
+        for &length in CODE_LENGTHS.iter() {
+            count[length as usize] += 1;
+        }
+
+        let mut first_code = [0; MAX_CODE_LENGTH + 1];
+        let mut first_index = [0; MAX_CODE_LENGTH + 1];
+        let mut code = 0;
+        let mut index = 0;
+        for length in 1 .. MAX_CODE_LENGTH + 1 {
+            first_code[length] = code;
+            first_index[length] = index;
+            code = (code + count[length]) << 1;
+            index += count[length] as usize;
+        }
+
+        HuffmanDecoder {
+            first_code: first_code,
+            count: count,
+            first_index: first_index,
+            symbols: symbols,
+        }
+    }
+
+    /// Decodes a string. Returns an error if the data is not a valid sequence of codes followed
+    /// by at most 7 bits of padding made of ones.
+    pub fn decode(&self, data: &[u8]) -> Result<Vec<u8>, ()> {
+        let mut output = Vec::with_capacity(data.len() * 8 / 5);
+
+        // bits of the code being read, and their number
+        let mut code = 0u32;
+        let mut length = 0;
+
+        for &byte in data {
+            for shift in (0 .. 8).rev() {
+                code = (code << 1) | ((byte >> shift) & 1) as u32;
+                length += 1;
+
+                if length > MAX_CODE_LENGTH {
+                    return Err(());
+                }
+
+                let offset = code.wrapping_sub(self.first_code[length]);
+                if code >= self.first_code[length] && offset < self.count[length] {
+                    let symbol = self.symbols[self.first_index[length] + offset as usize];
+                    if symbol == END_OF_STRING {
+                        return Err(());
+                    }
+
+                    output.push(symbol as u8);
+                    code = 0;
+                    length = 0;
+                }
+            }
+        }
+
+        // the padding is the beginning of the end-of-string code, which is made of ones
+        if length > 7 || code != (1 << length) - 1 {
+            return Err(());
+        }
+
+        Ok(output)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::HuffmanDecoder;
+
+    #[test]
+    fn rfc_examples() {
+        // strings of appendix C.4 and C.6 of RFC 7541
+        let decoder = HuffmanDecoder::new();
+        let decode = |data: &[u8]| String::from_utf8(decoder.decode(data).unwrap()).unwrap();
+
+        assert_eq!(decode(&[0xf1, 0xe3, 0xc2, 0xe5, 0xf2, 0x3a, 0x6b, 0xa0, 0xab, 0x90, 0xf4,
+                            0xff]), "www.example.com");
+        assert_eq!(decode(&[0xa8, 0xeb, 0x10, 0x64, 0x9c, 0xbf]), "no-cache");
+        assert_eq!(decode(&[0x25, 0xa8, 0x49, 0xe9, 0x5b, 0xb8, 0xe8, 0xb4, 0xbf]),
+                   "custom-value");
+        assert_eq!(decode(&[0x64, 0x02]), "302");
+        assert_eq!(decode(&[0xae, 0xc3, 0x77, 0x1a, 0x4b]), "private");
+        assert_eq!(decode(&[]), "");
+    }
+
+    #[test]
+    fn invalid_padding() {
+        let decoder = HuffmanDecoder::new();
+
+        // "no-cache" padded with a zero
+        assert!(decoder.decode(&[0xa8, 0xeb, 0x10, 0x64, 0x9c, 0xbe]).is_err());
+        // more than 7 bits of padding
+        assert!(decoder.decode(&[0x64, 0x02, 0xff]).is_err());
+        // end-of-string marker
+        assert!(decoder.decode(&[0xff, 0xff, 0xff, 0xff]).is_err());
+    }
+}
@@ -0,0 +1,1318 @@
+// Copyright 2015 The tiny-http Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// Server side of HTTP/2, defined in RFC 7540.
+//
+// A client starts an HTTP/2 connection with the connection preface, either directly on a
+// cleartext connection ("prior knowledge") or after choosing `h2` with ALPN. The preface begins
+// like an HTTP/1 request line, which `ClientConnection` recognizes before handing the connection
+// over to the `Connection` of this module.
+//
+// The thread of the connection reads the frames and produces a `Request` for each stream. The
+// body of a request is buffered by this thread, within the limits of the flow control window,
+// until the handler reads it. The handler writes the response with `Response::raw_print` like for
+// HTTP/1, and the writer of the request turns the status line and the headers into a HEADERS
+// frame and the body into DATA frames. All the frames are written to the socket under the lock
+// of the state shared by the connection and its streams.
+
+use std::ascii::AsciiExt;
+
+use std::cmp;
+use std::collections::{HashMap, VecDeque};
+use std::io::{BufReader, BufWriter, Read, Write};
+use std::io::{Error as IoError, ErrorKind};
+use std::io::Result as IoResult;
+use std::mem;
+use std::net::SocketAddr;
+use std::str::{self, FromStr};
+use std::sync::{Arc, Condvar, Mutex, MutexGuard};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use {Header, HTTPVersion, Limits, Method, Request, Response, StatusCode};
+use util::{RefinedTcpStream, SequentialReader, SequentialWriter};
+
+use self::frame::*;
+use self::hpack::HeaderLine;
+
+mod frame;
+mod hpack;
+mod huffman;
+
+/// Reader of the socket, which stays owned by the `ClientConnection`.
+pub type Reader = SequentialReader<BufReader<RefinedTcpStream>>;
+
+/// Writer of the socket, used by the connection and by the responses of all the streams.
+pub type Writer = SequentialWriter<BufWriter<RefinedTcpStream>>;
+
+// maximum number of streams that a client can open at the same time
+const MAX_CONCURRENT_STREAMS: usize = 100;
+
+// size of the flow control windows for receiving the bodies of the requests
+const STREAM_WINDOW_SIZE: i64 = 256 * 1024;
+const CONNECTION_WINDOW_SIZE: i64 = 1024 * 1024;
+
+// maximum size of the dynamic table of the decoder, which is the default one
+const HEADER_TABLE_SIZE: usize = 4096;
+
+// maximum size of the headers of a request when `Limits::max_header_bytes` is `None`, as counted
+// by SETTINGS_MAX_HEADER_LIST_SIZE
+const DEFAULT_MAX_HEADER_LIST_SIZE: usize = 64 * 1024;
+
+// duration of each read on an encrypted connection, see `Connection::read_bytes`
+const POLL_INTERVAL_MS: u64 = 10;
+
+/// An HTTP/2 connection, which produces the requests of its streams.
+pub struct Connection {
+    shared: Arc<Shared>,
+
+    decoder: hpack::Decoder,
+
+    // true if the connection goes through SSL
+    secure: bool,
+
+    // protocol negotiated with ALPN
+    alpn_protocol: Option<String>,
+
+    // address of the client
+    remote_addr: SocketAddr,
+
+    limits: Limits,
+
+    // identifier of the last stream opened by the client
+    last_stream_id: u32,
+
+    // false until the SETTINGS frame that starts the connection has been received
+    settings_received: bool,
+
+    // set once the client has sent GOAWAY, after which it doesn't open streams anymore
+    goaway_received: bool,
+
+    // header block whose CONTINUATION frames are being received, with the stream and the flags
+    // of its HEADERS frame
+    pending_headers: Option<(u32, u8, Vec<u8>)>,
+
+    // set once the connection is closed
+    finished: bool,
+}
+
+// State shared by the connection and by the readers and writers of its streams.
+struct Shared {
+    state: Mutex<State>,
+
+    // notified whenever the state changes
+    changed: Condvar,
+
+    limits: Limits,
+}
+
+struct State {
+    writer: Writer,
+
+    streams: HashMap<u32, Stream>,
+
+    // flow control windows of the connection
+    send_window: i64,
+    receive_window: i64,
+
+    // data received on the connection that has been consumed, but not yet given back to the
+    // client with WINDOW_UPDATE
+    unacknowledged: i64,
+
+    // settings of the client
+    initial_send_window: i64,
+    max_frame_size: usize,
+
+    // set when the client has changed SETTINGS_HEADER_TABLE_SIZE, which must be acknowledged at
+    // the beginning of the next header block
+    table_size_changed: bool,
+
+    // set once the connection is closed, after which nothing can be sent anymore
+    closed: bool,
+}
+
+struct Stream {
+    // flow control windows of the stream
+    send_window: i64,
+    receive_window: i64,
+
+    // see `State::unacknowledged`
+    unacknowledged: i64,
+
+    // body received and not yet read by the handler
+    data: VecDeque<u8>,
+
+    // trailers received after the body
+    trailers: Vec<Header>,
+
+    // true once the client has sent the whole request, or if the stream has been reset
+    remote_closed: bool,
+
+    // true if the stream has been reset by either side
+    reset: bool,
+
+    // true once the reader of the body is destroyed, after which the body is discarded
+    reader_dropped: bool,
+
+    // true once the writer of the response is destroyed
+    writer_dropped: bool,
+}
+
+/// Error that closes the connection.
+enum Error {
+    /// The client doesn't follow the protocol. The connection is closed with GOAWAY and this
+    /// error code.
+    Protocol(u32),
+
+    /// The socket has been closed, has failed or has expired.
+    Io(IoError),
+}
+
+impl From<IoError> for Error {
+    fn from(err: IoError) -> Error {
+        Error::Io(err)
+    }
+}
+
+impl Connection {
+    /// Starts HTTP/2 on a connection whose preface has been received.
+    pub fn new(writer: Writer, secure: bool, alpn_protocol: Option<String>,
+               remote_addr: SocketAddr, limits: Limits) -> Connection
+    {
+        let mut state = State {
+            writer: writer,
+            streams: HashMap::new(),
+            send_window: DEFAULT_WINDOW_SIZE,
+            receive_window: CONNECTION_WINDOW_SIZE,
+            unacknowledged: 0,
+            initial_send_window: DEFAULT_WINDOW_SIZE,
+            max_frame_size: DEFAULT_MAX_FRAME_SIZE,
+            table_size_changed: false,
+            closed: false,
+        };
+
+        let mut settings = Vec::new();
+        push_setting(&mut settings, SETTINGS_MAX_CONCURRENT_STREAMS,
+                     MAX_CONCURRENT_STREAMS as u32);
+        push_setting(&mut settings, SETTINGS_INITIAL_WINDOW_SIZE, STREAM_WINDOW_SIZE as u32);
+        push_setting(&mut settings, SETTINGS_MAX_HEADER_LIST_SIZE,
+                     cmp::min(max_header_list_size(&limits), u32::max_value() as usize) as u32);
+
+        // the server starts with its settings, and the window of the connection is enlarged
+        // right away since it can't be changed with a setting
+        let increment = u32_bytes((CONNECTION_WINDOW_SIZE - DEFAULT_WINDOW_SIZE) as u32);
+
+        // in case of error the state is closed, which is noticed when reading the first frame
+        state.write_frame(SETTINGS, 0, 0, &settings)
+             .and_then(|_| state.write_frame(WINDOW_UPDATE, 0, 0, &increment)).ok();
+
+        Connection {
+            shared: Arc::new(Shared {
+                state: Mutex::new(state),
+                changed: Condvar::new(),
+                limits: limits,
+            }),
+            decoder: hpack::Decoder::new(HEADER_TABLE_SIZE),
+            secure: secure,
+            alpn_protocol: alpn_protocol,
+            remote_addr: remote_addr,
+            limits: limits,
+            last_stream_id: 0,
+            settings_received: false,
+            goaway_received: false,
+            pending_headers: None,
+            finished: false,
+        }
+    }
+
+    /// Reads frames from `reader` until a new request is received.
+    ///
+    /// Returns `None` once the connection is closed. The streams that are still open are
+    /// reset.
+    pub fn next(&mut self, reader: &mut Reader) -> Option<Request> {
+        while !self.finished {
+            if self.goaway_received && self.active_streams() == 0 {
+                self.finish(Some(NO_ERROR));
+                break;
+            }
+
+            match self.read_frame(reader) {
+                Ok(Some(rq)) => return Some(rq),
+                Ok(None) => (),
+                Err(Error::Protocol(code)) => self.finish(Some(code)),
+                Err(Error::Io(ref err)) if err.kind() == ErrorKind::TimedOut ||
+                                           err.kind() == ErrorKind::WouldBlock => {
+                    self.finish(Some(NO_ERROR))
+                },
+                Err(Error::Io(_)) => self.finish(None),
+            }
+        }
+
+        None
+    }
+
+    fn active_streams(&self) -> usize {
+        self.shared.state.lock().unwrap().streams.len()
+    }
+
+    // Closes the connection, after sending GOAWAY with the given error code.
+    fn finish(&mut self, error: Option<u32>) {
+        let mut state = self.shared.state.lock().unwrap();
+
+        if let Some(code) = error {
+            let mut payload = u32_bytes(self.last_stream_id).to_vec();
+            payload.extend_from_slice(&u32_bytes(code));
+            state.write_frame(GOAWAY, 0, 0, &payload).ok();
+        }
+
+        state.closed = true;
+        self.shared.changed.notify_all();
+        self.finished = true;
+    }
+
+    // Fills `buf` with data from the socket, until `deadline`.
+    //
+    // Reading an encrypted connection locks the SSL stream, which prevents the responses from
+    // being written in the meantime. Encrypted connections are therefore read with short
+    // timeouts, between which the writers can take the lock.
+    fn read_bytes(&self, reader: &mut Reader, buf: &mut [u8], deadline: Option<Instant>)
+                  -> Result<(), Error>
+    {
+        {
+            let socket = reader.get_mut().get_mut();
+            let timeout = if self.secure {
+                Some(Duration::from_millis(POLL_INTERVAL_MS))
+            } else {
+                None
+            };
+            try!(socket.set_read_timeout(timeout));
+            try!(socket.set_read_deadline(deadline));
+        }
+
+        let mut filled = 0;
+        while filled < buf.len() {
+            match reader.read(&mut buf[filled ..]) {
+                Ok(0) => return Err(Error::Io(IoError::new(ErrorKind::UnexpectedEof,
+                                                           "Connection closed by the client"))),
+                Ok(read) => filled += read,
+                Err(ref err) if err.kind() == ErrorKind::Interrupted => (),
+                Err(ref err) if self.secure && (err.kind() == ErrorKind::TimedOut ||
+                                                err.kind() == ErrorKind::WouldBlock) &&
+                                deadline.map_or(true, |d| Instant::now() < d) => {
+                    thread::yield_now();
+                },
+                Err(err) => return Err(Error::Io(err)),
+            }
+        }
+
+        Ok(())
+    }
+
+    // Reads and handles the next frame. Returns the request of the stream opened by the frame,
+    // if any.
+    fn read_frame(&mut self, reader: &mut Reader) -> Result<Option<Request>, Error> {
+        // the keep-alive timeout applies while there is no request in progress
+        let deadline = if self.active_streams() == 0 {
+            self.limits.keep_alive_timeout.map(|timeout| Instant::now() + timeout)
+        } else {
+            None
+        };
+
+        let mut header = [0; HEADER_LENGTH];
+        try!(self.read_bytes(reader, &mut header, deadline));
+        let header = FrameHeader::parse(&header);
+
+        // we never change SETTINGS_MAX_FRAME_SIZE
+        if header.length > DEFAULT_MAX_FRAME_SIZE {
+            return Err(Error::Protocol(FRAME_SIZE_ERROR));
+        }
+
+        let deadline = self.limits.read_timeout.map(|timeout| Instant::now() + timeout);
+        let mut payload = vec![0; header.length];
+        try!(self.read_bytes(reader, &mut payload, deadline));
+
+        // a header block can't be interrupted by other frames
+        if let Some((id, _, _)) = self.pending_headers {
+            if header.kind != CONTINUATION || header.stream_id != id {
+                return Err(Error::Protocol(PROTOCOL_ERROR));
+            }
+        }
+
+        // the connection starts with the settings of the client
+        if !self.settings_received && header.kind != SETTINGS {
+            return Err(Error::Protocol(PROTOCOL_ERROR));
+        }
+
+        match header.kind {
+            DATA => self.on_data(&header, &payload).map(|_| None),
+            HEADERS => self.on_headers(&header, &payload),
+            CONTINUATION => self.on_continuation(&header, &payload),
+            PRIORITY => self.on_priority(&header, &payload).map(|_| None),
+            RST_STREAM => self.on_rst_stream(&header, &payload).map(|_| None),
+            SETTINGS => self.on_settings(&header, &payload).map(|_| None),
+            PING => self.on_ping(&header, &payload).map(|_| None),
+            GOAWAY => self.on_goaway(&header).map(|_| None),
+            WINDOW_UPDATE => self.on_window_update(&header, &payload).map(|_| None),
+            // clients can't push
+            PUSH_PROMISE => Err(Error::Protocol(PROTOCOL_ERROR)),
+            // frames of unknown types must be ignored
+            _ => Ok(None),
+        }
+    }
+
+    fn on_data(&mut self, header: &FrameHeader, payload: &[u8]) -> Result<(), Error> {
+        let id = header.stream_id;
+        if id == 0 {
+            return Err(Error::Protocol(PROTOCOL_ERROR));
+        }
+
+        let data = try!(strip_padding(header, payload)
+                            .map_err(|_| Error::Protocol(PROTOCOL_ERROR)));
+
+        // the padding counts in the flow control windows
+        let length = payload.len() as i64;
+
+        let mut state = self.shared.state.lock().unwrap();
+
+        state.receive_window -= length;
+        if state.receive_window < 0 {
+            return Err(Error::Protocol(FLOW_CONTROL_ERROR));
+        }
+
+        // the stream is already closed, for example because the response has been sent without
+        // waiting for the body
+        if state.streams.get(&id).map_or(true, |s| s.remote_closed) {
+            if id > self.last_stream_id {
+                return Err(Error::Protocol(PROTOCOL_ERROR));
+            }
+
+            try!(state.release(id, length));
+            return Ok(());
+        }
+
+        let exceeded = {
+            let stream = state.streams.get_mut(&id).unwrap();
+            stream.receive_window -= length;
+            stream.receive_window < 0
+        };
+
+        if exceeded {
+            try!(state.reset(id, FLOW_CONTROL_ERROR));
+            try!(state.release(id, length));
+            self.shared.changed.notify_all();
+            return Ok(());
+        }
+
+        let consumed = {
+            let stream = state.streams.get_mut(&id).unwrap();
+            if stream.reader_dropped {
+                length
+            } else {
+                stream.data.extend(data.iter());
+                length - data.len() as i64
+            }
+        };
+
+        if consumed != 0 {
+            try!(state.release(id, consumed));
+        }
+
+        if header.has_flag(FLAG_END_STREAM) {
+            state.streams.get_mut(&id).unwrap().remote_closed = true;
+            try!(state.cleanup(id));
+        }
+
+        self.shared.changed.notify_all();
+        Ok(())
+    }
+
+    fn on_headers(&mut self, header: &FrameHeader, payload: &[u8])
+                  -> Result<Option<Request>, Error>
+    {
+        if header.stream_id == 0 {
+            return Err(Error::Protocol(PROTOCOL_ERROR));
+        }
+
+        let mut fragment = try!(strip_padding(header, payload)
+                                    .map_err(|_| Error::Protocol(PROTOCOL_ERROR)));
+
+        // the priority of the stream is ignored
+        if header.has_flag(FLAG_PRIORITY) {
+            if fragment.len() < 5 {
+                return Err(Error::Protocol(FRAME_SIZE_ERROR));
+            }
+            fragment = &fragment[5 ..];
+        }
+
+        if header.has_flag(FLAG_END_HEADERS) {
+            return self.on_header_block(header.stream_id, header.flags, fragment);
+        }
+
+        self.pending_headers = Some((header.stream_id, header.flags, fragment.to_vec()));
+        Ok(None)
+    }
+
+    fn on_continuation(&mut self, header: &FrameHeader, payload: &[u8])
+                       -> Result<Option<Request>, Error>
+    {
+        let (id, flags, mut block) = match self.pending_headers.take() {
+            Some(pending) => pending,
+            None => return Err(Error::Protocol(PROTOCOL_ERROR)),
+        };
+
+        block.extend_from_slice(payload);
+
+        // the Huffman code of an octet is at most 30 bits long, so a valid block is at most four
+        // times larger than the headers that it contains
+        if block.len() > max_header_list_size(&self.limits).saturating_mul(4) {
+            return Err(Error::Protocol(ENHANCE_YOUR_CALM));
+        }
+
+        if header.has_flag(FLAG_END_HEADERS) {
+            return self.on_header_block(id, flags, &block);
+        }
+
+        self.pending_headers = Some((id, flags, block));
+        Ok(None)
+    }
+
+    // Handles a complete header block, which either opens a stream or contains the trailers of
+    // a request.
+    fn on_header_block(&mut self, id: u32, flags: u8, block: &[u8])
+                       -> Result<Option<Request>, Error>
+    {
+        // the block must be decoded even if it is refused, to keep the dynamic table in sync
+        let lines = try!(self.decoder.decode(block)
+                             .map_err(|_| Error::Protocol(COMPRESSION_ERROR)));
+        let end_stream = flags & FLAG_END_STREAM != 0;
+
+        {
+            let mut state = self.shared.state.lock().unwrap();
+
+            if state.streams.get(&id).map_or(false, |s| !s.remote_closed) {
+                // trailers, which must end the stream
+                let trailers = if end_stream { convert_trailers(lines).ok() } else { None };
+
+                match trailers {
+                    Some(trailers) => {
+                        let stream = state.streams.get_mut(&id).unwrap();
+                        stream.trailers = trailers;
+                        stream.remote_closed = true;
+                    },
+                    None => try!(state.reset(id, PROTOCOL_ERROR)),
+                }
+
+                try!(state.cleanup(id));
+                self.shared.changed.notify_all();
+                return Ok(None);
+            }
+
+            // the identifiers of the new streams are odd and increasing
+            if id % 2 == 0 {
+                return Err(Error::Protocol(PROTOCOL_ERROR));
+            }
+            // the client may have sent the block before knowing that the stream was closed
+            if id <= self.last_stream_id {
+                return Ok(None);
+            }
+            self.last_stream_id = id;
+
+            if state.streams.len() >= MAX_CONCURRENT_STREAMS {
+                try!(state.reset(id, REFUSED_STREAM));
+                return Ok(None);
+            }
+
+            let send_window = state.initial_send_window;
+            state.streams.insert(id, Stream {
+                send_window: send_window,
+                receive_window: STREAM_WINDOW_SIZE,
+                unacknowledged: 0,
+                data: VecDeque::new(),
+                trailers: Vec::new(),
+                remote_closed: end_stream,
+                reset: false,
+                reader_dropped: false,
+                writer_dropped: false,
+            });
+        }
+
+        let reader = BodyReader {
+            shared: self.shared.clone(),
+            id: id,
+            trailers: Arc::new(Mutex::new(None)),
+        };
+
+        let writer = ResponseWriter {
+            shared: self.shared.clone(),
+            id: id,
+            head: Vec::new(),
+            headers_sent: false,
+        };
+
+        let size = lines.iter().fold(0, |size, &(ref name, ref value)| {
+            size + name.len() + value.len() + 32
+        });
+        if size > max_header_list_size(&self.limits) ||
+           self.limits.max_headers.map_or(false, |max| lines.len() > max)
+        {
+            respond_with_error(writer, 431);
+            return Ok(None);
+        }
+
+        let (method, path, headers) = match convert_request_headers(lines) {
+            Ok(r) => r,
+            Err(()) => {
+                try!(self.shared.state.lock().unwrap().reset(id, PROTOCOL_ERROR));
+                return Ok(None);
+            },
+        };
+
+        let body_length = headers.iter()
+            .find(|h| h.field.equiv(&"Content-Length"))
+            .and_then(|h| FromStr::from_str(h.value.as_str()).ok());
+
+        if let (Some(length), Some(max)) = (body_length, self.limits.max_body_length) {
+            if length > max {
+                respond_with_error(writer, 413);
+                return Ok(None);
+            }
+        }
+
+        let expects_continue = {
+            match headers.iter().find(|h| h.field.equiv(&"Expect")).map(|h| h.value.as_str()) {
+                None => false,
+                Some(v) if v.eq_ignore_ascii_case("100-continue") => !end_stream,
+                _ => {
+                    respond_with_error(writer, 417);
+                    return Ok(None);
+                },
+            }
+        };
+
+        let trailers = reader.trailers.clone();
+        Ok(Some(::request::new_http2_request(self.secure, self.alpn_protocol.clone(), method,
+                                             path, headers, self.remote_addr, reader, writer,
+                                             body_length, expects_continue,
+                                             self.limits.max_body_length, trailers)))
+    }
+
+    fn on_priority(&mut self, header: &FrameHeader, payload: &[u8]) -> Result<(), Error> {
+        if header.stream_id == 0 {
+            return Err(Error::Protocol(PROTOCOL_ERROR));
+        }
+
+        if payload.len() != 5 {
+            return Err(Error::Protocol(FRAME_SIZE_ERROR));
+        }
+
+        Ok(())
+    }
+
+    fn on_rst_stream(&mut self, header: &FrameHeader, payload: &[u8]) -> Result<(), Error> {
+        let id = header.stream_id;
+        if id == 0 || id > self.last_stream_id {
+            return Err(Error::Protocol(PROTOCOL_ERROR));
+        }
+
+        if payload.len() != 4 {
+            return Err(Error::Protocol(FRAME_SIZE_ERROR));
+        }
+
+        let mut state = self.shared.state.lock().unwrap();
+
+        let discarded = match state.streams.get_mut(&id) {
+            Some(stream) => {
+                stream.reset = true;
+                stream.remote_closed = true;
+                let discarded = stream.data.len();
+                stream.data.clear();
+                discarded
+            },
+            None => 0,
+        };
+
+        try!(state.release(id, discarded as i64));
+        self.shared.changed.notify_all();
+        Ok(())
+    }
+
+    fn on_settings(&mut self, header: &FrameHeader, payload: &[u8]) -> Result<(), Error> {
+        if header.stream_id != 0 {
+            return Err(Error::Protocol(PROTOCOL_ERROR));
+        }
+
+        if header.has_flag(FLAG_ACK) {
+            if !payload.is_empty() {
+                return Err(Error::Protocol(FRAME_SIZE_ERROR));
+            }
+            return Ok(());
+        }
+
+        if payload.len() % 6 != 0 {
+            return Err(Error::Protocol(FRAME_SIZE_ERROR));
+        }
+
+        let mut state = self.shared.state.lock().unwrap();
+
+        for setting in payload.chunks(6) {
+            let identifier = (setting[0] as u16) << 8 | setting[1] as u16;
+            let value = read_u32(&setting[2 ..]);
+
+            match identifier {
+                SETTINGS_HEADER_TABLE_SIZE => state.table_size_changed = true,
+
+                SETTINGS_ENABLE_PUSH if value > 1 => return Err(Error::Protocol(PROTOCOL_ERROR)),
+
+                SETTINGS_INITIAL_WINDOW_SIZE => {
+                    let value = value as i64;
+                    if value > MAX_WINDOW_SIZE {
+                        return Err(Error::Protocol(FLOW_CONTROL_ERROR));
+                    }
+
+                    // the change applies to the windows of the existing streams
+                    let delta = value - state.initial_send_window;
+                    state.initial_send_window = value;
+                    for stream in state.streams.values_mut() {
+                        stream.send_window += delta;
+                        if stream.send_window > MAX_WINDOW_SIZE {
+                            return Err(Error::Protocol(FLOW_CONTROL_ERROR));
+                        }
+                    }
+                },
+
+                SETTINGS_MAX_FRAME_SIZE => {
+                    let value = value as usize;
+                    if value < DEFAULT_MAX_FRAME_SIZE || value > MAX_MAX_FRAME_SIZE {
+                        return Err(Error::Protocol(PROTOCOL_ERROR));
+                    }
+                    state.max_frame_size = value;
+                },
+
+                // the other settings don't concern the server
+                _ => (),
+            }
+        }
+
+        try!(state.write_frame(SETTINGS, FLAG_ACK, 0, &[]));
+        self.settings_received = true;
+        self.shared.changed.notify_all();
+        Ok(())
+    }
+
+    fn on_ping(&mut self, header: &FrameHeader, payload: &[u8]) -> Result<(), Error> {
+        if header.stream_id != 0 {
+            return Err(Error::Protocol(PROTOCOL_ERROR));
+        }
+
+        if payload.len() != 8 {
+            return Err(Error::Protocol(FRAME_SIZE_ERROR));
+        }
+
+        if !header.has_flag(FLAG_ACK) {
+            let mut state = self.shared.state.lock().unwrap();
+            try!(state.write_frame(PING, FLAG_ACK, 0, payload));
+        }
+
+        Ok(())
+    }
+
+    fn on_goaway(&mut self, header: &FrameHeader) -> Result<(), Error> {
+        if header.stream_id != 0 {
+            return Err(Error::Protocol(PROTOCOL_ERROR));
+        }
+
+        // the requests in progress are finished before closing the connection
+        self.goaway_received = true;
+        Ok(())
+    }
+
+    fn on_window_update(&mut self, header: &FrameHeader, payload: &[u8]) -> Result<(), Error> {
+        if payload.len() != 4 {
+            return Err(Error::Protocol(FRAME_SIZE_ERROR));
+        }
+
+        let id = header.stream_id;
+        let increment = (read_u32(payload) & 0x7fffffff) as i64;
+
+        let mut state = self.shared.state.lock().unwrap();
+
+        if id == 0 {
+            if increment == 0 {
+                return Err(Error::Protocol(PROTOCOL_ERROR));
+            }
+
+            state.send_window += increment;
+            if state.send_window > MAX_WINDOW_SIZE {
+                return Err(Error::Protocol(FLOW_CONTROL_ERROR));
+            }
+
+        } else if id > self.last_stream_id {
+            return Err(Error::Protocol(PROTOCOL_ERROR));
+
+        } else {
+            let error = match state.streams.get_mut(&id) {
+                Some(ref mut stream) if !stream.reset => {
+                    stream.send_window += increment;
+                    if increment == 0 {
+                        Some(PROTOCOL_ERROR)
+                    } else if stream.send_window > MAX_WINDOW_SIZE {
+                        Some(FLOW_CONTROL_ERROR)
+                    } else {
+                        None
+                    }
+                },
+                // the stream may have been closed while the client was sending the frame
+                _ => None,
+            };
+
+            if let Some(code) = error {
+                try!(state.reset(id, code));
+            }
+        }
+
+        self.shared.changed.notify_all();
+        Ok(())
+    }
+}
+
+impl Drop for Connection {
+    fn drop(&mut self) {
+        // wakes up the handlers that are waiting for the connection
+        if !self.finished {
+            self.finish(None);
+        }
+    }
+}
+
+impl State {
+    // Writes and flushes a frame.
+    fn write_frame(&mut self, kind: u8, flags: u8, stream_id: u32, payload: &[u8])
+                   -> IoResult<()>
+    {
+        if self.closed {
+            return Err(IoError::new(ErrorKind::ConnectionAborted, "The connection is closed"));
+        }
+
+        let result = write_frame(&mut self.writer, kind, flags, stream_id, payload)
+            .and_then(|_| self.writer.flush());
+
+        if result.is_err() {
+            self.closed = true;
+        }
+
+        result
+    }
+
+    // Returns an error if nothing can be sent on the stream anymore.
+    fn check_stream(&self, id: u32) -> IoResult<()> {
+        if self.closed {
+            return Err(IoError::new(ErrorKind::ConnectionAborted, "The connection is closed"));
+        }
+
+        match self.streams.get(&id) {
+            Some(stream) if !stream.reset => Ok(()),
+            _ => Err(IoError::new(ErrorKind::ConnectionReset, "The stream has been reset")),
+        }
+    }
+
+    // Gives back to the client the space of `length` bytes of data that have been consumed,
+    // once enough data has been consumed.
+    fn release(&mut self, id: u32, length: i64) -> IoResult<()> {
+        self.unacknowledged += length;
+        if self.unacknowledged >= CONNECTION_WINDOW_SIZE / 2 {
+            let increment = mem::replace(&mut self.unacknowledged, 0);
+            self.receive_window += increment;
+            try!(self.write_frame(WINDOW_UPDATE, 0, 0, &u32_bytes(increment as u32)));
+        }
+
+        // the window of a stream whose body has been received doesn't matter anymore
+        let increment = match self.streams.get_mut(&id) {
+            Some(ref mut stream) if !stream.remote_closed => {
+                stream.unacknowledged += length;
+                if stream.unacknowledged >= STREAM_WINDOW_SIZE / 2 {
+                    let increment = mem::replace(&mut stream.unacknowledged, 0);
+                    stream.receive_window += increment;
+                    Some(increment)
+                } else {
+                    None
+                }
+            },
+            _ => None,
+        };
+
+        match increment {
+            Some(increment) => self.write_frame(WINDOW_UPDATE, 0, id, &u32_bytes(increment as u32)),
+            None => Ok(()),
+        }
+    }
+
+    // Resets a stream with RST_STREAM.
+    fn reset(&mut self, id: u32, code: u32) -> IoResult<()> {
+        if let Some(stream) = self.streams.get_mut(&id) {
+            if stream.reset {
+                return Ok(());
+            }
+            stream.reset = true;
+            stream.remote_closed = true;
+        }
+
+        self.write_frame(RST_STREAM, 0, id, &u32_bytes(code))
+    }
+
+    // Forgets a stream once its reader and its writer have been destroyed.
+    fn cleanup(&mut self, id: u32) -> IoResult<()> {
+        let (done, remote_closed) = match self.streams.get(&id) {
+            Some(stream) => (stream.reader_dropped && stream.writer_dropped, stream.remote_closed),
+            None => return Ok(()),
+        };
+
+        if !done {
+            return Ok(());
+        }
+
+        self.streams.remove(&id);
+
+        // the response is complete, so the rest of the body is not needed
+        if !remote_closed {
+            try!(self.write_frame(RST_STREAM, 0, id, &u32_bytes(NO_ERROR)));
+        }
+
+        Ok(())
+    }
+}
+
+impl Shared {
+    // Waits until the state changes, or until `timeout` expires.
+    fn wait<'a>(&self, state: MutexGuard<'a, State>, timeout: Option<Duration>)
+                -> IoResult<MutexGuard<'a, State>>
+    {
+        match timeout {
+            Some(timeout) => {
+                let (state, result) = self.changed.wait_timeout(state, timeout).unwrap();
+                if result.timed_out() {
+                    return Err(IoError::new(ErrorKind::TimedOut, "Timeout expired"));
+                }
+                Ok(state)
+            },
+            None => Ok(self.changed.wait(state).unwrap()),
+        }
+    }
+
+    // Sends the status and the headers of a response.
+    fn send_headers(&self, id: u32, status: u16, headers: &[HeaderLine]) -> IoResult<()> {
+        let mut state = self.state.lock().unwrap();
+        try!(state.check_stream(id));
+
+        let mut block = Vec::new();
+        if state.table_size_changed {
+            // the encoder doesn't use the dynamic table
+            hpack::encode_table_size_update(&mut block, 0);
+            state.table_size_changed = false;
+        }
+
+        hpack::encode_status(&mut block, status);
+        for &(ref name, ref value) in headers {
+            hpack::encode_header(&mut block, name, value);
+        }
+
+        // the block is split into a HEADERS frame and CONTINUATION frames, which are written
+        // together since no other frame can be sent in the meantime
+        let max_frame_size = state.max_frame_size;
+        let mut chunks = block.chunks(max_frame_size).peekable();
+        let mut kind = HEADERS;
+        loop {
+            let chunk = chunks.next().unwrap_or(&[]);
+            let flags = if chunks.peek().is_none() { FLAG_END_HEADERS } else { 0 };
+            try!(state.write_frame(kind, flags, id, chunk));
+
+            if flags != 0 {
+                return Ok(());
+            }
+            kind = CONTINUATION;
+        }
+    }
+
+    // Sends a part of the body of a response, when the flow control windows allow it.
+    fn send_data(&self, id: u32, mut data: &[u8]) -> IoResult<()> {
+        let mut state = self.state.lock().unwrap();
+
+        while !data.is_empty() {
+            try!(state.check_stream(id));
+
+            let window = cmp::min(state.send_window, state.streams[&id].send_window);
+            if window <= 0 {
+                state = try!(self.wait(state, self.limits.write_timeout));
+                continue;
+            }
+
+            let length = cmp::min(cmp::min(window as usize, state.max_frame_size), data.len());
+            try!(state.write_frame(DATA, 0, id, &data[.. length]));
+
+            state.send_window -= length as i64;
+            state.streams.get_mut(&id).unwrap().send_window -= length as i64;
+            data = &data[length ..];
+        }
+
+        Ok(())
+    }
+}
+
+/// Reader of the body of a request, which is received by the thread of the connection.
+struct BodyReader {
+    shared: Arc<Shared>,
+    id: u32,
+    // filled with the trailers once the body has been read entirely
+    trailers: Arc<Mutex<Option<Vec<Header>>>>,
+}
+
+impl Read for BodyReader {
+    fn read(&mut self, buf: &mut [u8]) -> IoResult<usize> {
+        if buf.is_empty() {
+            return Ok(0);
+        }
+
+        let mut state = self.shared.state.lock().unwrap();
+
+        loop {
+            let read = {
+                let stream = state.streams.get_mut(&self.id).unwrap();
+
+                if stream.reset {
+                    return Err(IoError::new(ErrorKind::ConnectionReset,
+                                            "The stream has been reset"));
+                }
+
+                if stream.data.is_empty() && stream.remote_closed {
+                    let mut trailers = self.trailers.lock().unwrap();
+                    if trailers.is_none() {
+                        *trailers = Some(mem::replace(&mut stream.trailers, Vec::new()));
+                    }
+                    return Ok(0);
+                }
+
+                let read = cmp::min(stream.data.len(), buf.len());
+                for (dest, byte) in buf.iter_mut().zip(stream.data.drain(.. read)) {
+                    *dest = byte;
+                }
+                read
+            };
+
+            if read != 0 {
+                try!(state.release(self.id, read as i64));
+                return Ok(read);
+            }
+
+            if state.closed {
+                return Err(IoError::new(ErrorKind::ConnectionAborted,
+                                        "The connection is closed"));
+            }
+
+            state = try!(self.shared.wait(state, self.shared.limits.read_timeout));
+        }
+    }
+}
+
+impl Drop for BodyReader {
+    fn drop(&mut self) {
+        let mut state = self.shared.state.lock().unwrap();
+
+        // the rest of the body is discarded
+        let discarded = {
+            let stream = state.streams.get_mut(&self.id).unwrap();
+            stream.reader_dropped = true;
+            let discarded = stream.data.len();
+            stream.data.clear();
+            discarded
+        };
+
+        state.release(self.id, discarded as i64)
+             .and_then(|_| state.cleanup(self.id)).ok();
+        self.shared.changed.notify_all();
+    }
+}
+
+/// Writer of the response of a stream.
+///
+/// The response is written in the format of HTTP/1 by `Response::raw_print`. Its status line
+/// and headers are sent in a HEADERS frame, and the rest is the body, which is sent in DATA
+/// frames.
+struct ResponseWriter {
+    shared: Arc<Shared>,
+    id: u32,
+    // beginning of the response, until the end of its headers
+    head: Vec<u8>,
+    // true once the headers of the final response have been sent
+    headers_sent: bool,
+}
+
+impl Write for ResponseWriter {
+    fn write(&mut self, buf: &[u8]) -> IoResult<usize> {
+        if self.headers_sent {
+            try!(self.shared.send_data(self.id, buf));
+            return Ok(buf.len());
+        }
+
+        self.head.extend_from_slice(buf);
+
+        // informational responses, like `100 Continue`, are followed by another response
+        while let Some(end) = self.head.windows(4).position(|w| w == b"\r\n\r\n") {
+            let rest = self.head.split_off(end + 4);
+            let (status, headers) = try!(parse_response_head(&self.head[.. end])
+                .map_err(|_| IoError::new(ErrorKind::InvalidData, "Invalid response")));
+            self.head = rest;
+
+            try!(self.shared.send_headers(self.id, status, &headers));
+
+            if status >= 200 {
+                self.headers_sent = true;
+                let body = mem::replace(&mut self.head, Vec::new());
+                if !body.is_empty() {
+                    try!(self.shared.send_data(self.id, &body));
+                }
+                break;
+            }
+        }
+
+        Ok(buf.len())
+    }
+
+    #[inline]
+    fn flush(&mut self) -> IoResult<()> {
+        // the frames are flushed as soon as they are written
+        Ok(())
+    }
+}
+
+impl Drop for ResponseWriter {
+    fn drop(&mut self) {
+        let mut state = self.shared.state.lock().unwrap();
+
+        // the end of the response is marked by an empty DATA frame, unless the response is
+        // incomplete
+        let result = if !self.headers_sent {
+            state.reset(self.id, INTERNAL_ERROR)
+        } else if state.check_stream(self.id).is_ok() {
+            state.write_frame(DATA, FLAG_END_STREAM, self.id, &[])
+        } else {
+            Ok(())
+        };
+
+        if let Some(stream) = state.streams.get_mut(&self.id) {
+            stream.writer_dropped = true;
+        }
+
+        result.and_then(|_| state.cleanup(self.id)).ok();
+        self.shared.changed.notify_all();
+    }
+}
+
+// Answers a request that can't be handled.
+fn respond_with_error(writer: ResponseWriter, status: u16) {
+    let response = Response::new_empty(StatusCode(status));
+    response.raw_print(writer, HTTPVersion(2, 0), &[], false, None).ok();
+}
+
+// Maximum size of the headers of a request, as counted by SETTINGS_MAX_HEADER_LIST_SIZE.
+fn max_header_list_size(limits: &Limits) -> usize {
+    limits.max_header_bytes.unwrap_or(DEFAULT_MAX_HEADER_LIST_SIZE)
+}
+
+fn push_setting(settings: &mut Vec<u8>, identifier: u16, value: u32) {
+    settings.push((identifier >> 8) as u8);
+    settings.push(identifier as u8);
+    settings.extend_from_slice(&u32_bytes(value));
+}
+
+// Returns true for the headers that only concern an HTTP/1 connection, which are forbidden in
+// HTTP/2.
+fn is_connection_specific(name: &[u8]) -> bool {
+    match name {
+        b"connection" | b"keep-alive" | b"proxy-connection" | b"transfer-encoding" |
+        b"upgrade" => true,
+        _ => false,
+    }
+}
+
+// Converts a regular header received from the client. The names must be in lowercase.
+fn convert_header(name: Vec<u8>, value: Vec<u8>) -> Result<Header, ()> {
+    if name.iter().any(|&b| b'A' <= b && b <= b'Z') || is_connection_specific(&name) {
+        return Err(());
+    }
+
+    if name == b"te" && value != b"trailers" {
+        return Err(());
+    }
+
+    Header::from_bytes(name, value)
+}
+
+// Converts the headers of a request to the form used by `Request`: the pseudo-headers give the
+// method and the path, and `:authority` becomes the `Host` header.
+fn convert_request_headers(lines: Vec<HeaderLine>) -> Result<(Method, String, Vec<Header>), ()> {
+    let mut method = None;
+    let mut path = None;
+    let mut authority = None;
+    let mut scheme = None;
+    let mut headers = Vec::with_capacity(lines.len() + 1);
+    let mut cookies: Vec<Vec<u8>> = Vec::new();
+
+    for (name, value) in lines {
+        if name.first() == Some(&b':') {
+            // the pseudo-headers come first, and only once
+            if !headers.is_empty() || !cookies.is_empty() {
+                return Err(());
+            }
+
+            let pseudo_header = match &name[..] {
+                b":method" => &mut method,
+                b":path" => &mut path,
+                b":authority" => &mut authority,
+                b":scheme" => &mut scheme,
+                _ => return Err(()),
+            };
+
+            if pseudo_header.is_some() {
+                return Err(());
+            }
+
+            *pseudo_header = Some(value);
+            continue;
+        }
+
+        // the cookies can be split into several headers, which are joined for HTTP/1
+        if name == b"cookie" {
+            cookies.push(value);
+            continue;
+        }
+
+        headers.push(try!(convert_header(name, value)));
+    }
+
+    if !cookies.is_empty() {
+        let mut value = Vec::new();
+        for cookie in cookies {
+            if !value.is_empty() {
+                value.extend_from_slice(b"; ");
+            }
+            value.extend_from_slice(&cookie);
+        }
+        headers.push(try!(convert_header(b"cookie".to_vec(), value)));
+    }
+
+    if let Some(authority) = authority {
+        if !headers.iter().any(|h| h.field.equiv(&"Host")) {
+            headers.insert(0, try!(Header::from_bytes(&b"host"[..], authority)));
+        }
+    }
+
+    let method = match method.as_ref().and_then(|m| str::from_utf8(m).ok()) {
+        Some(m) => try!(Method::from_str(m)),
+        None => return Err(()),
+    };
+
+    let path = match path.and_then(|p| String::from_utf8(p).ok()) {
+        Some(ref p) if p.is_empty() => return Err(()),
+        Some(p) => p,
+        None => return Err(()),
+    };
+
+    Ok((method, path, headers))
+}
+
+// Converts the trailers of a request, which can't contain pseudo-headers.
+fn convert_trailers(lines: Vec<HeaderLine>) -> Result<Vec<Header>, ()> {
+    lines.into_iter().map(|(name, value)| {
+        if name.first() == Some(&b':') {
+            return Err(());
+        }
+        convert_header(name, value)
+    }).collect()
+}
+
+// Parses the status line and the headers written by `Response::raw_print`. The headers that
+// are specific to HTTP/1 are removed.
+fn parse_response_head(head: &[u8]) -> Result<(u16, Vec<HeaderLine>), ()> {
+    let head = try!(str::from_utf8(head).map_err(|_| ()));
+    let mut lines = head.split("\r\n");
+
+    let status = match lines.next().and_then(|line| line.split(' ').nth(1)) {
+        Some(status) => try!(status.parse().map_err(|_| ())),
+        None => return Err(()),
+    };
+
+    let mut headers = Vec::new();
+    for line in lines {
+        let colon = try!(line.find(':').ok_or(()));
+        let name = line[.. colon].trim().to_ascii_lowercase().into_bytes();
+        if is_connection_specific(&name) {
+            continue;
+        }
+
+        headers.push((name, line[colon + 1 ..].trim().as_bytes().to_vec()));
+    }
+
+    Ok((status, headers))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::HeaderLine;
+
+    fn lines(headers: &[(&str, &str)]) -> Vec<HeaderLine> {
+        headers.iter().map(|&(n, v)| (n.as_bytes().to_vec(), v.as_bytes().to_vec())).collect()
+    }
+
+    #[test]
+    fn request_headers() {
+        let (method, path, headers) = super::convert_request_headers(lines(&[
+            (":method", "POST"), (":scheme", "https"), (":authority", "example.com"),
+            (":path", "/upload?a=b"), ("cookie", "a=1"), ("content-type", "text/plain"),
+            ("cookie", "b=2"),
+        ])).unwrap();
+
+        assert!(method == ::Method::Post);
+        assert_eq!(path, "/upload?a=b");
+
+        let headers: Vec<String> = headers.iter().map(|h| h.to_string()).collect();
+        assert_eq!(headers, vec!["host: example.com", "content-type: text/plain",
+                                 "cookie: a=1; b=2"]);
+    }
+
+    #[test]
+    fn invalid_request_headers() {
+        let convert = |h: &[(&str, &str)]| super::convert_request_headers(lines(h)).is_err();
+
+        assert!(convert(&[(":method", "GET")]));
+        assert!(convert(&[(":method", "GET"), (":path", "")]));
+        assert!(convert(&[(":method", "GET"), (":path", "/"), (":path", "/")]));
+        assert!(convert(&[(":method", "GET"), ("accept", "*/*"), (":path", "/")]));
+        assert!(convert(&[(":method", "GET"), (":path", "/"), (":protocol", "ws")]));
+        assert!(convert(&[(":method", "GET"), (":path", "/"), ("Accept", "*/*")]));
+        assert!(convert(&[(":method", "GET"), (":path", "/"), ("connection", "close")]));
+        assert!(convert(&[(":method", "GET"), (":path", "/"), ("te", "gzip")]));
+        assert!(!convert(&[(":method", "GET"), (":path", "/"), ("te", "trailers")]));
+    }
+
+    #[test]
+    fn response_head() {
+        let (status, headers) = super::parse_response_head(
+            b"HTTP/2.0 404 Not Found\r\nContent-Type: text/plain\r\nConnection: close\r\n\
+              Content-Length: 5").unwrap();
+
+        assert_eq!(status, 404);
+        assert_eq!(headers, lines(&[("content-type", "text/plain"), ("content-length", "5")]));
+
+        assert!(super::parse_response_head(b"HTTP/2.0 abc\r\n").is_err());
+    }
+}
@@ -102,6 +102,14 @@ All that remains to do is call `request.respond()`:
 # let response = tiny_http::Response::from_file(File::open(&Path::new("image.png")).unwrap());
 let _ = request.respond(response);
 ```
+
+# HTTP/2
+
+With the `http2` feature, the server also accepts HTTP/2 connections, either through SSL if the
+client chooses `h2` with ALPN (see `SslOptions::alpn_protocols`), or in cleartext if the client
+starts the connection with the HTTP/2 preface (known as "prior knowledge"). Each stream of the
+connection produces a `Request` whose `http_version()` is `2.0`, and the requests of a connection
+can be answered in any order. The `Response` objects are sent the same way as with HTTP/1.
 */
 #![crate_name = "tiny_http"]
 #![crate_type = "lib"]
@@ -111,36 +119,59 @@ let _ = request.respond(response);
 extern crate log;
 
 extern crate ascii;
-extern crate chunked_transfer;
 extern crate encoding;
 extern crate url;
 extern crate chrono;
 
+#[cfg(unix)]
+extern crate listenfd;
+#[cfg(unix)]
+extern crate nix;
+
 #[cfg(feature = "ssl")]
 extern crate openssl;
+#[cfg(feature = "ssl-rustls")]
+extern crate rustls;
+#[cfg(feature = "ssl-rustls")]
+extern crate webpki;
 
+#[cfg(all(feature = "ssl", feature = "ssl-rustls"))]
+compile_error!("The `ssl` and `ssl-rustls` features of tiny-http can't be enabled at the same time");
+
+use std::cmp;
 use std::error::Error;
 use std::io::Error as IoError;
 use std::io::Result as IoResult;
-use std::sync::Arc;
+use std::sync::{Arc, RwLock};
 use std::sync::atomic::AtomicBool;
+use std::sync::atomic::AtomicUsize;
 use std::thread;
 use std::net;
-use std::net::{ToSocketAddrs, TcpStream, Shutdown};
+use std::net::ToSocketAddrs;
 use std::time::Duration;
 use std::sync::atomic::Ordering::Relaxed;
 
+#[cfg(unix)]
+use std::os::unix::net::UnixListener;
+#[cfg(unix)]
+use std::path::Path;
+
 use client::ClientConnection;
-use util::MessagesQueue;
+use util::{MessagesQueue, RefinedTcpStream};
 
 pub use common::{Header, HeaderField, HTTPVersion, Method, StatusCode};
+pub use listener::{Listener, ListenAddr};
 pub use request::{Request, ReadWrite};
 pub use response::{ResponseBox, Response};
 
 mod client;
 mod common;
+#[cfg(feature = "http2")]
+mod http2;
+mod listener;
 mod request;
 mod response;
+mod ssl;
 
 #[allow(dead_code)]     // TODO: remove when everything is implemented
 mod util;
@@ -158,8 +189,14 @@ pub struct Server {
     // queue for messages received by child threads
     messages: Arc<MessagesQueue<Message>>,
 
-    // result of TcpListener::local_addr()
-    listening_addr: net::SocketAddr,
+    // result of Listener::listen_addr()
+    listening_addr: ListenAddr,
+
+    // number of connections currently open
+    num_connections: Arc<AtomicUsize>,
+
+    // context used to accept new encrypted connections, if the server uses SSL
+    ssl: Option<Arc<RwLock<ssl::SslContext>>>,
 }
 
 enum Message {
@@ -200,7 +237,67 @@ pub struct ServerConfig<A> where A: ToSocketAddrs {
     pub ssl: Option<SslConfig>,
 }
 
+/// Represents the parameters of a server that accepts connections from a `Listener`.
+///
+/// This is the same as `ServerConfig`, without the address and with the additional SSL
+/// parameters and limits. In order to apply limits to a server listening on an address, bind a
+/// `std::net::TcpListener` and pass it to `Server::from_listener`.
+#[derive(Debug, Clone, Default)]
+pub struct ListenerConfig {
+    /// If `Some`, then the server will use SSL to encode the communications.
+    pub ssl: Option<SslConfig>,
+
+    /// Additional SSL parameters. Ignored if `ssl` is `None`.
+    pub ssl_options: SslOptions,
+
+    /// Timeouts and limits applied to the connections.
+    pub limits: Limits,
+}
+
+/// Timeouts and limits applied to the connections of a server.
+///
+/// The default value has no timeout and no limit.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub struct Limits {
+    /// Maximum duration of each read on the socket while receiving a request. When it expires
+    /// while reading the body, reading the body returns an error.
+    pub read_timeout: Option<Duration>,
+
+    /// Maximum duration between the first byte of a request and the end of its headers. When
+    /// it expires, the server answers with `408 Request Timeout` and closes the connection.
+    pub header_timeout: Option<Duration>,
+
+    /// Maximum duration that a connection stays open while waiting for a request. When it
+    /// expires, the connection is closed.
+    pub keep_alive_timeout: Option<Duration>,
+
+    /// Maximum duration of each write on the socket.
+    pub write_timeout: Option<Duration>,
+
+    /// Maximum size in bytes of the request line and headers of a request. Requests with larger
+    /// headers are answered with `431 Request Header Fields Too Large`.
+    ///
+    /// HTTP/2 requests are limited to 64 kiB of headers when this is `None`.
+    pub max_header_bytes: Option<usize>,
+
+    /// Maximum number of headers of a request. Requests with more headers are answered with
+    /// `431 Request Header Fields Too Large`.
+    pub max_headers: Option<usize>,
+
+    /// Maximum length of the body of a request. Requests with a larger `Content-Length` are
+    /// answered with `413 Payload Too Large`. Reading a chunked body fails with an error of kind
+    /// `InvalidData` once it has produced more data.
+    pub max_body_length: Option<usize>,
+
+    /// Maximum number of connections open at the same time. Additional clients are answered
+    /// with `503 Service Unavailable`.
+    pub max_connections: Option<usize>,
+}
+
 /// Configuration of the server for SSL.
+///
+/// The certificates and private keys are in the PEM format. SSL is implemented with OpenSSL if
+/// the `ssl` feature is enabled, or with rustls if the `ssl-rustls` feature is enabled.
 #[derive(Debug, Clone)]
 pub struct SslConfig {
     /// Contains the public certificate to send to clients.
@@ -209,6 +306,32 @@ pub struct SslConfig {
     pub private_key: Vec<u8>,
 }
 
+/// Additional parameters of the server for SSL, which require the `ssl-rustls` feature.
+///
+/// They are passed to `Server::from_listener` in a `ListenerConfig`, or to `Server::reload_ssl`.
+#[derive(Debug, Clone, Default)]
+pub struct SslOptions {
+    /// Certificates to send instead of `SslConfig::certificate` to the clients that ask for a
+    /// specific server name with SNI.
+    pub sni_certificates: Vec<SniCertificate>,
+    /// Protocols that the server supports, in order of preference, for ALPN (for example
+    /// `http/1.1`). The negotiated protocol is returned by `Request::alpn_protocol`. With the
+    /// `http2` feature, include `h2` to offer HTTP/2.
+    pub alpn_protocols: Vec<String>,
+}
+
+/// Certificate of a server name, chosen with SNI.
+#[derive(Debug, Clone)]
+pub struct SniCertificate {
+    /// The server name that the client asks for, for example `example.com`. The comparison is
+    /// case-insensitive.
+    pub server_name: String,
+    /// Contains the public certificate to send to clients.
+    pub certificate: Vec<u8>,
+    /// Contains the private key of the certificate.
+    pub private_key: Vec<u8>,
+}
+
 impl Server {
     /// Shortcut for a simple server on a specific address.
     #[inline]
@@ -222,7 +345,7 @@ impl Server {
     }
 
     /// Shortcut for an HTTPS server on a specific address.
-    #[cfg(feature = "ssl")]
+    #[cfg(any(feature = "ssl", feature = "ssl-rustls"))]
     #[inline]
     pub fn https<A>(addr: A, config: SslConfig)
                     -> Result<Server, Box<Error + Send + Sync + 'static>>
@@ -234,53 +357,74 @@ impl Server {
         })
     }
 
+    /// Shortcut for a simple server that listens on a Unix domain socket.
+    ///
+    /// The socket file is created at `path`, and binding fails if a file already exists there.
+    /// The file is not removed when the server is destroyed.
+    #[cfg(unix)]
+    #[inline]
+    pub fn http_unix<P>(path: P) -> Result<Server, Box<Error + Send + Sync + 'static>>
+        where P: AsRef<Path>
+    {
+        let listener = try!(UnixListener::bind(path));
+        Server::from_listener(listener, ListenerConfig::default())
+    }
+
     /// Builds a new server that listens on the specified address.
     pub fn new<A>(config: ServerConfig<A>) -> Result<Server, Box<Error + Send + Sync + 'static>>
         where A: ToSocketAddrs
     {
+        let listener = try!(net::TcpListener::bind(config.addr));
+        Server::from_listener(listener, ListenerConfig {
+            ssl: config.ssl,
+            ssl_options: SslOptions::default(),
+            limits: Limits::default(),
+        })
+    }
+
+    /// Builds a new server that accepts connections from a socket that is already listening.
+    ///
+    /// This can be used with a `Listener` returned by `Listener::from_listen_fds`, in order to
+    /// use the sockets passed by a service manager. SSL is only supported on TCP sockets.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # #[cfg(unix)] fn main() {
+    /// use tiny_http::{Listener, ListenerConfig, Server};
+    ///
+    /// let listener = Listener::from_listen_fds().unwrap().into_iter().next()
+    ///                         .expect("no socket has been passed by the service manager");
+    /// let server = Server::from_listener(listener, ListenerConfig::default()).unwrap();
+    /// # }
+    /// # #[cfg(not(unix))] fn main() {}
+    /// ```
+    pub fn from_listener<L>(listener: L, config: ListenerConfig)
+                            -> Result<Server, Box<Error + Send + Sync + 'static>>
+        where L: Into<Listener>
+    {
+        let server = listener.into();
+        let ListenerConfig { ssl, ssl_options, limits } = config;
+
         // building the "close" variable
         let close_trigger = Arc::new(AtomicBool::new(false));
 
-        // building the TcpListener
-        let (server, local_addr) = {
-            let listener = try!(net::TcpListener::bind(config.addr));
-            let local_addr = try!(listener.local_addr());
-            debug!("Server listening on {}", local_addr);
-            (listener, local_addr)
+        let local_addr = try!(server.listen_addr());
+        debug!("Server listening on {}", local_addr);
+
+        let is_tcp = match server {
+            Listener::Tcp(_) => true,
+            #[cfg(unix)]
+            Listener::Unix(_) => false,
         };
+        if ssl.is_some() && !is_tcp {
+            return Err("SSL is only supported on TCP sockets".to_owned().into());
+        }
 
         // building the SSL capabilities
-        #[cfg(feature = "ssl")]
-        type SslContext = openssl::ssl::SslContext;
-        #[cfg(not(feature = "ssl"))]
-        type SslContext = ();
-        let ssl: Option<SslContext> = match config.ssl {
-            #[cfg(feature = "ssl")]
-            Some(mut config) => {
-                use openssl::ssl;
-                use openssl::x509::X509;
-                use openssl::pkey::PKey;
-                use openssl::ssl::SslVerifyMode;
-
-                let mut ctxt = try!(SslContext::builder(ssl::SslMethod::tls()));
-                try!(ctxt.set_cipher_list("DEFAULT"));
-                let certificate = try!(X509::from_pem(&config.certificate[..]));
-                try!(ctxt.set_certificate(&certificate));
-                let private_key = try!(PKey::private_key_from_pem(&config.private_key[..]));
-                try!(ctxt.set_private_key(&private_key));
-                ctxt.set_verify(SslVerifyMode::NONE);
-                try!(ctxt.check_private_key());
-
-                // let's wipe the certificate and private key from memory, because we're
-                // better safe than sorry
-                for b in &mut config.certificate { *b = 0; }
-                for b in &mut config.private_key { *b = 0; }
-
-                Some(ctxt.build())
-            },
-            #[cfg(not(feature = "ssl"))]
-            Some(_) => return Err("Building a server with SSL requires enabling the `ssl` feature \
-                                   in tiny-http".to_owned().into()),
+        let ssl = match ssl {
+            Some(config) => Some(Arc::new(RwLock::new(try!(ssl::build_context(config,
+                                                                             ssl_options))))),
             None => None,
         };
 
@@ -288,61 +432,74 @@ impl Server {
         // and ClientConnection objects are pushed in the messages queue
         let messages = MessagesQueue::with_capacity(8);
 
+        let num_connections = Arc::new(AtomicUsize::new(0));
+
         let inside_close_trigger = close_trigger.clone();
         let inside_messages = messages.clone();
+        let inside_num_connections = num_connections.clone();
+        let inside_ssl = ssl.clone();
         thread::spawn(move || {
             // a tasks pool is used to dispatch the connections into threads
             let tasks_pool = util::TaskPool::new();
 
             debug!("Running accept thread");
             while !inside_close_trigger.load(Relaxed) {
-                let new_client = match server.accept() {
-                    Ok((sock, _)) => {
-                        use util::RefinedTcpStream;
-                        let (read_closable, write_closable) = match ssl {
-                            None => {
-                                RefinedTcpStream::new(sock)
-                            },
-                            #[cfg(feature = "ssl")]
-                            Some(ref ssl) => {
-                                let ssl = openssl::ssl::Ssl::new(ssl).expect("Couldn't create ssl");
-                                // trying to apply SSL over the connection
-                                // if an error occurs, we just close the socket and resume listening
-                                let sock = match ssl.accept(sock) {
-                                    Ok(s) => s,
-                                    Err(_) => continue
-                                };
-
-                                RefinedTcpStream::new(sock)
-                            },
-                            #[cfg(not(feature = "ssl"))]
-                            Some(_) => unreachable!(),
-                        };
-
-                        Ok(ClientConnection::new(write_closable, read_closable))
-                    },
-                    Err(e) => Err(e),
-                };
-
-                match new_client {
-                    Ok(client) => {
-                        let messages = inside_messages.clone();
-                        let mut client = Some(client);
-                        tasks_pool.spawn(Box::new(move || {
-                            if let Some(client) = client.take() {
-                                for rq in client {
-                                    messages.push(rq.into());
-                                }
-                            }
-                        }));
-                    },
-
+                let connection = match server.accept() {
+                    Ok(c) => c,
                     Err(e) => {
                         error!("Error accepting new client: {}", e);
                         inside_messages.push(e.into());
                         break;
                     }
-                }
+                };
+
+                let too_many = limits.max_connections.map_or(false, |max| {
+                    inside_num_connections.load(Relaxed) >= max
+                });
+                let guard = if too_many {
+                    None
+                } else {
+                    Some(ConnectionGuard::new(inside_num_connections.clone()))
+                };
+
+                // the context may be replaced by `reload_ssl` at any time
+                let ssl = inside_ssl.as_ref().map(|context| context.read().unwrap().clone());
+
+                // the SSL handshake and the rest of the connection happen in a worker, so that a
+                // slow client doesn't delay the other ones
+                let messages = inside_messages.clone();
+                let mut connection = Some((connection, ssl, guard));
+                tasks_pool.spawn(Box::new(move || {
+                    if let Some((connection, ssl, guard)) = connection.take() {
+                        // if an error occurs during the handshake, we just close the socket
+                        let (read_closable, mut write_closable) =
+                            match open_connection(connection, ssl, &limits) {
+                                Ok(c) => c,
+                                Err(_) => return,
+                            };
+
+                        let _guard = match guard {
+                            Some(g) => g,
+                            None => {
+                                // answering and closing the connection
+                                let max_timeout = Duration::from_secs(1);
+                                let timeout = limits.write_timeout.map_or(max_timeout, |t| {
+                                    cmp::min(t, max_timeout)
+                                });
+                                write_closable.set_write_timeout(Some(timeout)).ok();
+                                let response = Response::new_empty(StatusCode(503));
+                                response.raw_print(&mut write_closable, HTTPVersion(1, 1), &[],
+                                                   false, None).ok();
+                                return;
+                            },
+                        };
+
+                        let client = ClientConnection::new(write_closable, read_closable, limits);
+                        for rq in client {
+                            messages.push(rq.into());
+                        }
+                    }
+                }));
             }
             debug!("Terminating accept thread");
         });
@@ -352,6 +509,8 @@ impl Server {
             messages: messages,
             close: close_trigger,
             listening_addr: local_addr,
+            num_connections: num_connections,
+            ssl: ssl,
         })
     }
 
@@ -364,15 +523,44 @@ impl Server {
     }
 
     /// Returns the address the server is listening to.
+    ///
+    /// # Panic
+    ///
+    /// Panics if the server is listening on a Unix domain socket. Use `listen_addr` instead if
+    /// this can happen.
     #[inline]
     pub fn server_addr(&self) -> net::SocketAddr {
+        self.listening_addr.to_ip().expect("the server is listening on a Unix domain socket")
+    }
+
+    /// Returns the address the server is listening to, which can be a Unix domain socket.
+    #[inline]
+    pub fn listen_addr(&self) -> ListenAddr {
         self.listening_addr.clone()
     }
 
     /// Returns the number of clients currently connected to the server.
+    #[inline]
     pub fn num_connections(&self) -> usize {
-        unimplemented!()
-        //self.requests_receiver.lock().len()
+        self.num_connections.load(Relaxed)
+    }
+
+    /// Replaces the certificates and the other SSL parameters of the server.
+    ///
+    /// The new configuration applies to the connections accepted afterwards, which allows
+    /// renewing certificates without restarting the server. Returns an error if the server
+    /// doesn't use SSL or if the configuration is invalid, in which case the previous
+    /// configuration is kept.
+    pub fn reload_ssl(&self, config: SslConfig, options: SslOptions)
+                      -> Result<(), Box<Error + Send + Sync + 'static>>
+    {
+        let context = match self.ssl {
+            Some(ref c) => c,
+            None => return Err("The server doesn't use SSL".to_owned().into()),
+        };
+
+        *context.write().unwrap() = try!(ssl::build_context(config, options));
+        Ok(())
     }
 
     /// Blocks until an HTTP request has been submitted and returns it.
@@ -402,6 +590,49 @@ impl Server {
     }
 }
 
+// Performs the SSL handshake if the server uses SSL, and returns the reading and writing halves
+// of the connection.
+#[cfg_attr(not(any(feature = "ssl", feature = "ssl-rustls")), allow(unused_variables))]
+fn open_connection(connection: listener::Connection, ssl: Option<ssl::SslContext>,
+                   limits: &Limits) -> IoResult<(RefinedTcpStream, RefinedTcpStream)>
+{
+    let sock = match connection {
+        listener::Connection::Tcp(sock) => sock,
+        #[cfg(unix)]
+        listener::Connection::Unix(sock) => return Ok(RefinedTcpStream::new(sock)),
+    };
+
+    match ssl {
+        None => Ok(RefinedTcpStream::new(sock)),
+        #[cfg(any(feature = "ssl", feature = "ssl-rustls"))]
+        Some(context) => {
+            // the handshake is subject to the same timeouts as reading a request
+            try!(sock.set_read_timeout(limits.header_timeout.or(limits.read_timeout)));
+            try!(sock.set_write_timeout(limits.write_timeout));
+            let sock = try!(ssl::accept(&context, sock));
+            Ok(RefinedTcpStream::new(sock))
+        },
+        #[cfg(not(any(feature = "ssl", feature = "ssl-rustls")))]
+        Some(_) => unreachable!(),
+    }
+}
+
+// Counts an open connection as long as it is alive.
+struct ConnectionGuard(Arc<AtomicUsize>);
+
+impl ConnectionGuard {
+    fn new(counter: Arc<AtomicUsize>) -> ConnectionGuard {
+        counter.fetch_add(1, Relaxed);
+        ConnectionGuard(counter)
+    }
+}
+
+impl Drop for ConnectionGuard {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Relaxed);
+    }
+}
+
 impl<'a> Iterator for IncomingRequests<'a> {
     type Item = Request;
     fn next(&mut self) -> Option<Request> {
@@ -413,9 +644,6 @@ impl Drop for Server {
     fn drop(&mut self) {
         self.close.store(true, Relaxed);
         // Connect briefly to ourselves to unblock the accept thread
-        let maybe_stream = TcpStream::connect(self.listening_addr);
-        if let Ok(stream) = maybe_stream {
-            let _ = stream.shutdown(Shutdown::Both);
-        }
+        listener::wake_up(&self.listening_addr);
     }
 }
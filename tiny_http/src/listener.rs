@@ -0,0 +1,195 @@
+// Copyright 2015 The tiny-http Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+use std::io::Result as IoResult;
+use std::net::{self, TcpListener, TcpStream, Shutdown};
+
+#[cfg(unix)]
+use std::env;
+#[cfg(unix)]
+use std::io::{Error as IoError, ErrorKind};
+#[cfg(unix)]
+use std::os::unix::io::{AsRawFd, RawFd};
+#[cfg(unix)]
+use std::os::unix::net::{self as unix_net, UnixListener, UnixStream};
+
+#[cfg(unix)]
+use listenfd::ListenFd;
+#[cfg(unix)]
+use nix::sys::socket::{getsockopt, sockopt};
+
+/// A listening socket that a `Server` accepts connections from.
+///
+/// A `Listener` can be built from a `std::net::TcpListener` or a
+/// `std::os::unix::net::UnixListener` with `From`, and passed to `Server::from_listener`.
+pub enum Listener {
+    /// A TCP socket.
+    Tcp(TcpListener),
+    /// A Unix domain socket.
+    #[cfg(unix)]
+    Unix(UnixListener),
+}
+
+/// Address that a `Server` is listening on.
+#[derive(Debug, Clone)]
+pub enum ListenAddr {
+    /// An IP address and a port.
+    IP(net::SocketAddr),
+    /// The address of a Unix domain socket.
+    #[cfg(unix)]
+    Unix(unix_net::SocketAddr),
+}
+
+impl ListenAddr {
+    /// Returns the IP address and port, if this is an IP address.
+    #[inline]
+    pub fn to_ip(&self) -> Option<net::SocketAddr> {
+        match *self {
+            ListenAddr::IP(addr) => Some(addr),
+            #[cfg(unix)]
+            ListenAddr::Unix(_) => None,
+        }
+    }
+
+    /// Returns the address of the Unix domain socket, if this is one.
+    #[cfg(unix)]
+    #[inline]
+    pub fn to_unix(&self) -> Option<&unix_net::SocketAddr> {
+        match *self {
+            ListenAddr::IP(_) => None,
+            ListenAddr::Unix(ref addr) => Some(addr),
+        }
+    }
+}
+
+impl fmt::Display for ListenAddr {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ListenAddr::IP(ref addr) => write!(formatter, "{}", addr),
+            #[cfg(unix)]
+            ListenAddr::Unix(ref addr) => match addr.as_pathname() {
+                Some(path) => write!(formatter, "unix:{}", path.display()),
+                None => write!(formatter, "unix:(unnamed)"),
+            },
+        }
+    }
+}
+
+impl From<TcpListener> for Listener {
+    #[inline]
+    fn from(listener: TcpListener) -> Listener {
+        Listener::Tcp(listener)
+    }
+}
+
+#[cfg(unix)]
+impl From<UnixListener> for Listener {
+    #[inline]
+    fn from(listener: UnixListener) -> Listener {
+        Listener::Unix(listener)
+    }
+}
+
+/// A connection accepted by a `Listener`.
+pub(crate) enum Connection {
+    Tcp(TcpStream),
+    #[cfg(unix)]
+    Unix(UnixStream),
+}
+
+impl Listener {
+    /// Returns the address that the socket is listening on.
+    pub fn listen_addr(&self) -> IoResult<ListenAddr> {
+        match *self {
+            Listener::Tcp(ref listener) => listener.local_addr().map(ListenAddr::IP),
+            #[cfg(unix)]
+            Listener::Unix(ref listener) => listener.local_addr().map(ListenAddr::Unix),
+        }
+    }
+
+    /// Returns the sockets passed by the service manager with the systemd socket activation
+    /// protocol.
+    ///
+    /// The sockets are read from the file descriptors that start at 3, whose number is given by
+    /// the `LISTEN_FDS` environment variable. If the `LISTEN_PID` environment variable is set and
+    /// doesn't match the current process, or if `LISTEN_FDS` is not set, an empty list is
+    /// returned.
+    ///
+    /// The environment variables are removed afterwards, so that the sockets are only taken once
+    /// and are not inherited by child processes. Each socket must be a TCP or Unix stream socket
+    /// in the listening state, otherwise an error is returned.
+    #[cfg(unix)]
+    pub fn from_listen_fds() -> IoResult<Vec<Listener>> {
+        let mut fds = ListenFd::from_env();
+        env::remove_var("LISTEN_FDNAMES");
+
+        (0 .. fds.len()).map(|index| take_listener(&mut fds, index)).collect()
+    }
+
+    /// Accepts a new connection.
+    pub(crate) fn accept(&self) -> IoResult<Connection> {
+        match *self {
+            Listener::Tcp(ref listener) => listener.accept().map(|(s, _)| Connection::Tcp(s)),
+            #[cfg(unix)]
+            Listener::Unix(ref listener) => listener.accept().map(|(s, _)| Connection::Unix(s)),
+        }
+    }
+}
+
+/// Connects briefly to a socket, in order to unblock a thread that is waiting in `accept`.
+pub(crate) fn wake_up(addr: &ListenAddr) {
+    match *addr {
+        ListenAddr::IP(addr) => {
+            if let Ok(stream) = TcpStream::connect(addr) {
+                let _ = stream.shutdown(Shutdown::Both);
+            }
+        },
+        #[cfg(unix)]
+        ListenAddr::Unix(ref addr) => {
+            if let Some(path) = addr.as_pathname() {
+                if let Ok(stream) = UnixStream::connect(path) {
+                    let _ = stream.shutdown(Shutdown::Both);
+                }
+            }
+        },
+    }
+}
+
+// Takes ownership of an inherited socket, and checks that it is listening.
+#[cfg(unix)]
+fn take_listener(fds: &mut ListenFd, index: usize) -> IoResult<Listener> {
+    // On error, `ListenFd` leaves the file descriptor in place, so that we can try another type.
+    let listener = match fds.take_tcp_listener(index) {
+        Ok(Some(listener)) => Listener::Tcp(listener),
+        _ => match fds.take_unix_listener(index) {
+            Ok(Some(listener)) => Listener::Unix(listener),
+            _ => return Err(IoError::new(ErrorKind::InvalidInput,
+                                         format!("inherited socket #{} is not a TCP or Unix \
+                                                  stream socket", index))),
+        },
+    };
+
+    let fd: RawFd = match listener {
+        Listener::Tcp(ref listener) => listener.as_raw_fd(),
+        Listener::Unix(ref listener) => listener.as_raw_fd(),
+    };
+
+    match getsockopt(fd, sockopt::AcceptConn) {
+        Ok(true) => Ok(listener),
+        Ok(false) => Err(IoError::new(ErrorKind::InvalidInput,
+                                      format!("inherited socket #{} is not listening", index))),
+        Err(err) => Err(IoError::new(ErrorKind::Other, err)),
+    }
+}
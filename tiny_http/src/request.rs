@@ -20,10 +20,12 @@ use std::io::{self, Cursor, Read, Write, ErrorKind};
 use std::net::SocketAddr;
 use std::fmt;
 use std::str::FromStr;
+use std::sync::{Arc, Mutex};
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering::Relaxed;
 
-use {Header, HTTPVersion, Method, Response, StatusCode};
-use util::EqualReader;
-use chunked_transfer::Decoder;
+use {Header, HTTPVersion, Limits, Method, Response, StatusCode};
+use util::{ChunkedDecoder, EqualReader, LimitedReader};
 
 /// Represents an HTTP request made by a client.
 ///
@@ -45,7 +47,7 @@ use chunked_transfer::Decoder;
 ///  - The body of a request is large enough (handling requires pipelining requires storing the
 ///    body of the request in a buffer ; if the body is too big, tiny-http will avoid doing that)
 ///  - A request sends a `Expect: 100-continue` header (which means that the client waits to
-///    know whether its body will be processed before sending it)
+///    know whether its body will be processed before sending it, see `expects_continue`)
 ///  - A request sends a `Connection: close` header or `Connection: upgrade` header (used for
 ///    websockets), which indicates that this is the last request that will be received on this
 ///    connection
@@ -57,6 +59,10 @@ use chunked_transfer::Decoder;
 /// sent back to the client.
 /// This means that if your code fails during the handling of a request, this "internal server
 /// error" response will automatically be sent during the stack unwinding.
+///
+/// If reading a chunked body failed because it is invalid, the status code is instead 400 (bad
+/// request), or 431 (request header fields too large) if its trailers exceed the limits of the
+/// server.
 pub struct Request {
     // where to read the body from
     data_reader: Option<Box<Read + Send + 'static>>,
@@ -69,6 +75,9 @@ pub struct Request {
     // true if HTTPS, false if HTTP
     secure: bool,
 
+    // protocol negotiated with ALPN
+    alpn_protocol: Option<String>,
+
     method: Method,
 
     path: String,
@@ -81,6 +90,17 @@ pub struct Request {
 
     // true if a `100 Continue` response must be sent when `as_reader()` is called
     must_send_continue: bool,
+
+    // set when the request is answered while the client is still waiting for a `100 Continue`,
+    // in which case the body will never be read and the connection must be closed
+    body_rejected: Arc<AtomicBool>,
+
+    // trailer headers of a chunked body, once it has been read entirely
+    trailers: Arc<Mutex<Option<Vec<Header>>>>,
+
+    // status code of the default response if reading the chunked body failed because it is
+    // invalid, in which case the connection must be closed
+    body_error: Arc<Mutex<Option<StatusCode>>>,
 }
 
 /// Error that can happen when building a `Request` object.
@@ -88,6 +108,9 @@ pub enum RequestCreationError {
     /// The client sent an `Expect` header that was not recognized by tiny-http.
     ExpectationFailed,
 
+    /// The `Content-Length` of the request is larger than the maximum allowed.
+    BodyTooLarge,
+
     /// Error while reading data from the socket during the creation of the `Request`.
     CreationIoError(IoError),
 }
@@ -107,9 +130,17 @@ impl From<IoError> for RequestCreationError {
 /// It is the responsibility of the `Request` to read only the data of the request and not further.
 ///
 /// The `Write` object will be used by the `Request` to write the response.
-pub fn new_request<R, W>(secure: bool, method: Method, path: String,
-                         version: HTTPVersion, headers: Vec<Header>,
-                         remote_addr: SocketAddr, mut source_data: R, writer: W)
+///
+/// Requests with a `Content-Length` larger than `limits.max_body_length` are refused, and
+/// reading a chunked body fails once it has produced more data or if its trailers exceed
+/// `limits.max_header_bytes` or `limits.max_headers`.
+///
+/// `body_rejected` is set if the request is answered while the client is still waiting for a
+/// `100 Continue`. From then on, reading from `source_data` produces EOF.
+pub fn new_request<R, W>(secure: bool, alpn_protocol: Option<String>, method: Method,
+                         path: String, version: HTTPVersion, headers: Vec<Header>,
+                         remote_addr: SocketAddr, source_data: R, writer: W,
+                         limits: &Limits, body_rejected: Arc<AtomicBool>)
                          -> Result<Request, RequestCreationError>
                          where R: Read + Send + 'static, W: Write + Send + 'static
 {
@@ -130,6 +161,13 @@ pub fn new_request<R, W>(secure: bool, method: Method, path: String,
                .and_then(|h| FromStr::from_str(h.value.as_str()).ok())
     };
 
+    let max_body_length = limits.max_body_length;
+    if let (Some(length), Some(max)) = (content_length, max_body_length) {
+        if length > max {
+            return Err(RequestCreationError::BodyTooLarge);
+        }
+    }
+
     // true if the client sent a `Expect: 100-continue` header
     let expects_continue = {
         match headers.iter().find(|h: &&Header| h.field.equiv(&"Expect")).map(|h| h.value.as_str()) {
@@ -140,6 +178,14 @@ pub fn new_request<R, W>(secure: bool, method: Method, path: String,
         }
     };
 
+    let mut source_data = RejectableReader {
+        reader: source_data,
+        rejected: body_rejected.clone(),
+    };
+
+    let trailers = Arc::new(Mutex::new(None));
+    let body_error = Arc::new(Mutex::new(None));
+
     // true if the client sent a `Connection: upgrade` header
     let connection_upgrade = {
         match headers.iter().find(|h: &&Header| h.field.equiv(&"Connection")).map(|h| h.value.as_str()) {
@@ -189,7 +235,12 @@ pub fn new_request<R, W>(secure: bool, method: Method, path: String,
         } else if transfer_encoding.is_some() {
             // if a transfer-encoding was specified, then "chunked" is ALWAYS applied
             // over the message (RFC2616 #3.6)
-            Box::new(Decoder::new(source_data)) as Box<Read + Send + 'static>
+            let decoder = ChunkedDecoder::new(source_data, limits, trailers.clone(),
+                                              body_error.clone());
+            match max_body_length {
+                Some(max) => Box::new(LimitedReader::new(decoder, max)) as Box<Read + Send + 'static>,
+                None => Box::new(decoder) as Box<Read + Send + 'static>,
+            }
 
         } else {
             // if we have neither a Content-Length nor a Transfer-Encoding,
@@ -203,15 +254,75 @@ pub fn new_request<R, W>(secure: bool, method: Method, path: String,
         response_writer: Some(Box::new(writer) as Box<Write + Send + 'static>),
         remote_addr: remote_addr,
         secure: secure,
+        alpn_protocol: alpn_protocol,
         method: method,
         path: path,
         http_version: version,
         headers: headers,
         body_length: content_length,
         must_send_continue: expects_continue,
+        body_rejected: body_rejected,
+        trailers: trailers,
+        body_error: body_error,
     })
 }
 
+/// Builds a request received through HTTP/2.
+///
+/// Contrary to `new_request`, the body is delimited by `source_data` itself, which fills
+/// `trailers` once it has been read entirely. The headers have already been checked:
+/// `body_length` is the value of the `Content-Length` header, and `expects_continue` is true if
+/// the client is waiting for a `100 Continue`.
+#[cfg(feature = "http2")]
+pub fn new_http2_request<R, W>(secure: bool, alpn_protocol: Option<String>, method: Method,
+                               path: String, headers: Vec<Header>, remote_addr: SocketAddr,
+                               source_data: R, writer: W, body_length: Option<usize>,
+                               expects_continue: bool, max_body_length: Option<usize>,
+                               trailers: Arc<Mutex<Option<Vec<Header>>>>) -> Request
+                               where R: Read + Send + 'static, W: Write + Send + 'static
+{
+    let reader = match max_body_length {
+        Some(max) => Box::new(LimitedReader::new(source_data, max)) as Box<Read + Send + 'static>,
+        None => Box::new(source_data) as Box<Read + Send + 'static>,
+    };
+
+    Request {
+        data_reader: Some(reader),
+        response_writer: Some(Box::new(writer) as Box<Write + Send + 'static>),
+        remote_addr: remote_addr,
+        secure: secure,
+        alpn_protocol: alpn_protocol,
+        method: method,
+        path: path,
+        http_version: HTTPVersion(2, 0),
+        headers: headers,
+        body_length: body_length,
+        must_send_continue: expects_continue,
+        // the body is delimited by the frames of its stream, so refusing it doesn't affect the
+        // connection
+        body_rejected: Arc::new(AtomicBool::new(false)),
+        trailers: trailers,
+        body_error: Arc::new(Mutex::new(None)),
+    }
+}
+
+// Reader of the data of the request that produces EOF once the body has been rejected, so that
+// the rest of the body is not waited for.
+struct RejectableReader<R> {
+    reader: R,
+    rejected: Arc<AtomicBool>,
+}
+
+impl<R> Read for RejectableReader<R> where R: Read {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if self.rejected.load(Relaxed) {
+            return Ok(0);
+        }
+
+        self.reader.read(buf)
+    }
+}
+
 impl Request {
     /// Returns true if the request was made through HTTPS.
     #[inline]
@@ -219,6 +330,16 @@ impl Request {
         self.secure
     }
 
+    /// Returns the protocol negotiated with the client with ALPN during the SSL handshake, for
+    /// example `http/1.1`.
+    ///
+    /// Returns `None` if the request was not made through HTTPS or if no protocol was
+    /// negotiated. See `SslOptions::alpn_protocols`.
+    #[inline]
+    pub fn alpn_protocol(&self) -> Option<&str> {
+        self.alpn_protocol.as_ref().map(|p| &p[..])
+    }
+
     /// Returns the method requested by the client (eg. `GET`, `POST`, etc.).
     #[inline]
     pub fn method(&self) -> &Method {
@@ -251,6 +372,44 @@ impl Request {
         self.body_length
     }
 
+    /// Returns the trailer headers that the client sent after a body with the chunked transfer
+    /// encoding.
+    ///
+    /// Returns `None` if the body doesn't use the chunked transfer encoding or if it hasn't been
+    /// read entirely yet.
+    pub fn trailers(&self) -> Option<Vec<Header>> {
+        self.trailers.lock().unwrap().clone()
+    }
+
+    /// Returns true if the client sent a `Expect: 100-continue` header and is waiting for a
+    /// `100 Continue` response before sending the body of the request.
+    ///
+    /// In order to refuse the body, for example if it is too large, answer the request with
+    /// `respond` without reading the body. The client then doesn't send the body, and the
+    /// connection is closed after the response. Otherwise call `send_continue`, or `as_reader`
+    /// which sends the `100 Continue` automatically.
+    #[inline]
+    pub fn expects_continue(&self) -> bool {
+        self.must_send_continue
+    }
+
+    /// Sends a `100 Continue` response, which tells the client to send the body of the request.
+    ///
+    /// Does nothing if `expects_continue` returns false, for example if the `100 Continue` has
+    /// already been sent.
+    pub fn send_continue(&mut self) -> Result<(), IoError> {
+        if !self.must_send_continue {
+            return Ok(());
+        }
+
+        self.must_send_continue = false;
+
+        let writer = self.response_writer.as_mut().unwrap();
+        let msg = Response::new_empty(StatusCode(100));
+        try!(msg.raw_print(writer.by_ref(), self.http_version.clone(), &self.headers, true, None));
+        writer.flush()
+    }
+
     /// Returns the address of the client that sent this request.
     ///
     /// Note that this is gathered from the socket. If you receive the request from a proxy,
@@ -308,14 +467,7 @@ impl Request {
     ///  function will send back a `100 Continue` response.
     #[inline]
     pub fn as_reader(&mut self) -> &mut Read {
-        if self.must_send_continue {
-            let msg = Response::new_empty(StatusCode(100));
-            msg.raw_print(self.response_writer.as_mut().unwrap().by_ref(),
-                          self.http_version.clone(), &self.headers, true, None).ok();
-            self.response_writer.as_mut().unwrap().flush().ok();
-            self.must_send_continue = false;
-        }
-
+        self.send_continue().ok();
         self.data_reader.as_mut().unwrap()
     }
 
@@ -355,6 +507,9 @@ impl Request {
     }
 
     /// Sends a response to this request.
+    ///
+    /// If the client is still waiting for a `100 Continue` (see `expects_continue`), its body is
+    /// refused and the connection is closed after the response.
     #[inline]
     pub fn respond<R>(mut self, response: Response<R>) -> Result<(), IoError>
         where R: Read
@@ -362,9 +517,23 @@ impl Request {
         self.respond_impl(response)
     }
 
-    fn respond_impl<R>(&mut self, response: Response<R>) -> Result<(), IoError>
+    fn respond_impl<R>(&mut self, mut response: Response<R>) -> Result<(), IoError>
         where R: Read
     {
+        // The client is waiting for a `100 Continue` before sending the body. The body is skipped
+        // instead of waiting for it, and the connection must be closed since we don't know
+        // whether the client will send it anyway.
+        if self.must_send_continue {
+            self.body_rejected.store(true, Relaxed);
+            response.insert_connection_close();
+        }
+
+        // The chunked body is invalid, so we don't know where the next request starts.
+        if self.body_error.lock().unwrap().is_some() {
+            self.body_rejected.store(true, Relaxed);
+            response.insert_connection_close();
+        }
+
         // Droping the request reader now so that further requests can start processing immediately.
         self.data_reader = None;
 
@@ -396,13 +565,16 @@ impl fmt::Debug for Request {
 
 impl Drop for Request {
     fn drop(&mut self) {
-        // Droping the request reader now so that further requests can start processing immediately.
-        self.data_reader = None;
-
+        // `respond_impl` drops the request reader itself, after checking whether the connection
+        // must be closed because of an invalid body.
         if self.response_writer.is_some() {
-            let response = Response::empty(500);
+            let status = self.body_error.lock().unwrap().clone().unwrap_or(StatusCode(500));
+            let response = Response::empty(status);
             let _ = self.respond_impl(response);        // ignoring any potential error
         }
+
+        // Droping the request reader now so that further requests can start processing immediately.
+        self.data_reader = None;
     }
 }
 
@@ -30,9 +30,7 @@ use std::str::FromStr;
 /// Some headers cannot be changed. Trying to define the value
 /// of one of these will have no effect:
 ///
-///  - `Accept-Ranges`
 ///  - `Connection`
-///  - `Content-Range`
 ///  - `Trailer`
 ///  - `Transfer-Encoding`
 ///  - `Upgrade`
@@ -213,9 +211,7 @@ impl<R> Response<R> where R: Read {
         let header = header.into();
 
         // ignoring forbidden headers
-        if header.field.equiv(&"Accept-Ranges") ||
-           header.field.equiv(&"Connection") ||
-           header.field.equiv(&"Content-Range") ||
+        if header.field.equiv(&"Connection") ||
            header.field.equiv(&"Trailer") ||
            header.field.equiv(&"Transfer-Encoding") ||
            header.field.equiv(&"Upgrade")
@@ -263,6 +259,22 @@ impl<R> Response<R> where R: Read {
         }
     }
 
+    /// Adds a `Connection: close` header, which `add_header` refuses.
+    pub(crate) fn insert_connection_close(&mut self) {
+        if self.headers.iter().any(|h| h.field.equiv(&"Connection")) {
+            return;
+        }
+        self.headers.push(Header::from_bytes(&b"Connection"[..], &b"close"[..]).unwrap());
+    }
+
+    // Returns true if the response is a stream of server-sent events, whose body is sent as it
+    // is produced instead of being buffered.
+    fn is_event_stream(&self) -> bool {
+        self.headers.iter().any(|h| {
+            h.field.equiv(&"Content-Type") && h.value.as_str().starts_with("text/event-stream")
+        })
+    }
+
     /// Prints the HTTP response to a writer.
     ///
     /// This function is the one used to send the response to the client's socket.
@@ -271,7 +283,13 @@ impl<R> Response<R> where R: Read {
     /// The HTTP version and headers passed as arguments are used to
     ///  decide which features (most notably, encoding) to use.
     ///
-    /// Note: does not flush the writer.
+    /// Note: does not flush the writer, except for streams of server-sent events (responses with
+    /// a `Content-Type` of `text/event-stream`), which are flushed as their data is produced. If
+    /// the length of such a stream is unknown and the client uses HTTP 1.0, the stream is sent
+    /// without being buffered along with a `Connection: close` header, and its end is marked by
+    /// closing the connection. This requires the client not to have asked to keep the connection
+    /// alive, otherwise the connection is only closed once the client closes it or the keep-alive
+    /// timeout of the server expires.
     pub fn raw_print<W: Write>(mut self, mut writer: W, http_version: HTTPVersion,
                                request_headers: &[Header], do_not_send_body: bool,
                                upgrade: Option<&str>)
@@ -280,6 +298,12 @@ impl<R> Response<R> where R: Read {
         let mut transfer_encoding = Some(choose_transfer_encoding(request_headers,
                                     &http_version, &self.data_length, false /* TODO */));
 
+        // HTTP/2 has its own framing, done by the writer, and the body is written as it is
+        let http2 = http_version >= (2, 0);
+        if http2 {
+            transfer_encoding = None;
+        }
+
         // add `Date` if not in the headers
         if self.headers.iter().find(|h| h.field.equiv(&"Date")).is_none() {
             self.headers.insert(0, build_date_header());
@@ -299,11 +323,18 @@ impl<R> Response<R> where R: Read {
             transfer_encoding = None;
         }
 
+        let event_stream = self.is_event_stream();
+
         // if the transfer encoding is identity, the content length must be known ; therefore if
         // we don't know it, we buffer the entire response first here
         // while this is an expensive operation, it is only ever needed for clients using HTTP 1.0
+        // streams of events never end, so they are sent until the connection is closed instead
         let (mut reader, data_length) = match (self.data_length, transfer_encoding) {
             (Some(l), _) => (Box::new(self.reader) as Box<Read>, Some(l)),
+            (None, Some(TransferEncoding::Identity)) if event_stream => {
+                self.insert_connection_close();
+                (Box::new(self.reader) as Box<Read>, None)
+            },
             (None, Some(TransferEncoding::Identity)) => {
                 let mut buf = Vec::new();
                 try!(self.reader.read_to_end(&mut buf));
@@ -330,12 +361,20 @@ impl<R> Response<R> where R: Read {
             },
 
             Some(TransferEncoding::Identity) => {
-                assert!(data_length.is_some());
-                let data_length = data_length.unwrap();
+                assert!(data_length.is_some() || event_stream);
+                if let Some(data_length) = data_length {
+                    self.headers.push(
+                        Header::from_bytes(&b"Content-Length"[..], format!("{}", data_length).as_bytes()).unwrap()
+                    )
+                }
+            },
 
-                self.headers.push(
-                    Header::from_bytes(&b"Content-Length"[..], format!("{}", data_length).as_bytes()).unwrap()
-                )
+            None if http2 => {
+                if let Some(data_length) = data_length {
+                    self.headers.push(
+                        Header::from_bytes(&b"Content-Length"[..], format!("{}", data_length).as_bytes()).unwrap()
+                    )
+                }
             },
 
             _ => ()
@@ -350,10 +389,11 @@ impl<R> Response<R> where R: Read {
             match transfer_encoding {
 
                 Some(TransferEncoding::Chunked) => {
-                    use chunked_transfer::Encoder;
+                    try!(copy_blocks(&mut reader, &mut writer, true, event_stream));
+                },
 
-                    let mut writer = Encoder::new(writer);
-                    try!(io::copy(&mut reader, &mut writer));
+                Some(TransferEncoding::Identity) if data_length.is_none() => {
+                    try!(copy_blocks(&mut reader, &mut writer, false, true));
                 },
 
                 Some(TransferEncoding::Identity) => {
@@ -369,6 +409,22 @@ impl<R> Response<R> where R: Read {
                     }
                 },
 
+                None if http2 => {
+                    use util::EqualReader;
+
+                    // each block of data is sent by the writer as soon as it has been read
+                    match data_length {
+                        Some(data_length) => {
+                            let (mut equ_reader, _) =
+                                EqualReader::new(reader.by_ref(), data_length);
+                            try!(io::copy(&mut equ_reader, &mut writer));
+                        },
+                        None => {
+                            try!(io::copy(&mut reader, &mut writer));
+                        },
+                    }
+                },
+
                 _ => ()
 
             }
@@ -378,6 +434,39 @@ impl<R> Response<R> where R: Read {
     }
 }
 
+// Copies a body block by block as it is read. Each block is sent as a chunk if `chunked` is
+// true, and the writer is flushed after each block if `flush` is true so that streams of events
+// reach the client without delay.
+fn copy_blocks<R, W>(reader: &mut R, writer: &mut W, chunked: bool, flush: bool) -> IoResult<()>
+    where R: Read, W: Write
+{
+    let mut buffer = vec![0; 8192];
+    loop {
+        let len = match reader.read(&mut buffer) {
+            Ok(0) => break,
+            Ok(len) => len,
+            Err(ref err) if err.kind() == io::ErrorKind::Interrupted => continue,
+            Err(err) => return Err(err),
+        };
+
+        if chunked {
+            try!(write!(writer, "{:x}\r\n", len));
+        }
+        try!(writer.write_all(&buffer[..len]));
+        if chunked {
+            try!(writer.write_all(b"\r\n"));
+        }
+        if flush {
+            try!(writer.flush());
+        }
+    }
+
+    if chunked {
+        try!(writer.write_all(b"0\r\n\r\n"));
+    }
+    Ok(())
+}
+
 impl<R> Response<R> where R: Read + Send + 'static {
     /// Turns this response into a `Response<Box<Read + Send>>`.
     pub fn boxed(self) -> ResponseBox {
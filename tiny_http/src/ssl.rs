@@ -0,0 +1,252 @@
+// Copyright 2015 The tiny-http Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// SSL support, implemented either with OpenSSL (the `ssl` feature) or with rustls (the
+// `ssl-rustls` feature). Both backends expose the same items:
+//
+// - `SslContext`, built from an `SslConfig` and shared by all the connections.
+// - `SslStream`, the server side of an encrypted connection over a `TcpStream`.
+// - `accept`, which performs the handshake with a client.
+// - `alpn_protocol`, which returns the protocol negotiated with ALPN.
+
+use std::error::Error;
+
+use {SslConfig, SslOptions};
+
+pub use self::backend::*;
+
+/// Builds the context used to accept encrypted connections.
+pub fn build_context(mut config: SslConfig, mut options: SslOptions)
+                     -> Result<SslContext, Box<Error + Send + Sync + 'static>>
+{
+    let context = backend::build_context(&config, &options);
+
+    // let's wipe the certificates and private keys from memory, because we're
+    // better safe than sorry
+    for b in &mut config.certificate { *b = 0; }
+    for b in &mut config.private_key { *b = 0; }
+    for sni in &mut options.sni_certificates {
+        for b in &mut sni.certificate { *b = 0; }
+        for b in &mut sni.private_key { *b = 0; }
+    }
+
+    context
+}
+
+#[cfg(feature = "ssl")]
+mod backend {
+    use std::error::Error;
+    use std::io::{Error as IoError, ErrorKind};
+    use std::io::Result as IoResult;
+    use std::net::TcpStream;
+
+    use openssl::ssl::{self, SslMethod, SslVerifyMode};
+    use openssl::x509::X509;
+    use openssl::pkey::PKey;
+
+    use {SslConfig, SslOptions};
+
+    pub type SslContext = ssl::SslContext;
+    pub type SslStream = ssl::SslStream<TcpStream>;
+
+    pub fn build_context(config: &SslConfig, options: &SslOptions)
+                         -> Result<SslContext, Box<Error + Send + Sync + 'static>>
+    {
+        if !options.sni_certificates.is_empty() || !options.alpn_protocols.is_empty() {
+            return Err("SNI and ALPN require the `ssl-rustls` feature of tiny-http"
+                           .to_owned().into());
+        }
+
+        let mut ctxt = try!(SslContext::builder(SslMethod::tls()));
+        try!(ctxt.set_cipher_list("DEFAULT"));
+        let certificate = try!(X509::from_pem(&config.certificate[..]));
+        try!(ctxt.set_certificate(&certificate));
+        let private_key = try!(PKey::private_key_from_pem(&config.private_key[..]));
+        try!(ctxt.set_private_key(&private_key));
+        ctxt.set_verify(SslVerifyMode::NONE);
+        try!(ctxt.check_private_key());
+        Ok(ctxt.build())
+    }
+
+    pub fn accept(context: &SslContext, sock: TcpStream) -> IoResult<SslStream> {
+        let ssl = try!(ssl::Ssl::new(context).map_err(|e| IoError::new(ErrorKind::Other, e)));
+        ssl.accept(sock).map_err(|e| IoError::new(ErrorKind::Other, e.to_string()))
+    }
+
+    #[inline]
+    pub fn alpn_protocol(_: &SslStream) -> Option<String> {
+        // ALPN is refused by `build_context`
+        None
+    }
+}
+
+#[cfg(feature = "ssl-rustls")]
+mod backend {
+    use std::collections::HashMap;
+    use std::error::Error;
+    use std::io::{Error as IoError, ErrorKind, Read, Write};
+    use std::io::Result as IoResult;
+    use std::net::TcpStream;
+    use std::sync::Arc;
+
+    use rustls::{self, NoClientAuth, ResolvesServerCert, ServerSession, Session, SignatureScheme};
+    use rustls::internal::pemfile;
+    use rustls::sign::{self, CertifiedKey};
+    use webpki::DNSNameRef;
+
+    use {SslConfig, SslOptions};
+
+    pub type SslContext = Arc<rustls::ServerConfig>;
+
+    /// Server side of a TLS connection.
+    pub struct SslStream {
+        session: ServerSession,
+        sock: TcpStream,
+    }
+
+    impl SslStream {
+        #[inline]
+        pub fn get_ref(&self) -> &TcpStream {
+            &self.sock
+        }
+
+        #[inline]
+        pub fn get_mut(&mut self) -> &mut TcpStream {
+            &mut self.sock
+        }
+    }
+
+    impl Read for SslStream {
+        fn read(&mut self, buf: &mut [u8]) -> IoResult<usize> {
+            rustls::Stream::new(&mut self.session, &mut self.sock).read(buf)
+        }
+    }
+
+    impl Write for SslStream {
+        fn write(&mut self, buf: &[u8]) -> IoResult<usize> {
+            rustls::Stream::new(&mut self.session, &mut self.sock).write(buf)
+        }
+
+        fn flush(&mut self) -> IoResult<()> {
+            rustls::Stream::new(&mut self.session, &mut self.sock).flush()
+        }
+    }
+
+    // Chooses the certificate that matches the name sent by the client with SNI, or the default
+    // one.
+    struct CertificateResolver {
+        default: CertifiedKey,
+        // the keys are in lowercase
+        by_name: HashMap<String, CertifiedKey>,
+    }
+
+    impl ResolvesServerCert for CertificateResolver {
+        fn resolve(&self, server_name: Option<DNSNameRef>, _: &[SignatureScheme])
+                   -> Option<CertifiedKey>
+        {
+            let key = server_name.and_then(|name| {
+                let name: &str = name.into();
+                self.by_name.get(&name.to_ascii_lowercase())
+            });
+
+            Some(key.unwrap_or(&self.default).clone())
+        }
+    }
+
+    // Parses a PEM certificate chain and its PEM private key, in PKCS#8 or RSA format.
+    fn certified_key(certificate: &[u8], private_key: &[u8])
+                     -> Result<CertifiedKey, Box<Error + Send + Sync + 'static>>
+    {
+        let certificates = try!(pemfile::certs(&mut &certificate[..])
+                                    .map_err(|_| "Invalid PEM certificate"));
+        if certificates.is_empty() {
+            return Err("No certificate found".into());
+        }
+
+        let mut keys = try!(pemfile::pkcs8_private_keys(&mut &private_key[..])
+                                .map_err(|_| "Invalid PEM private key"));
+        if keys.is_empty() {
+            keys = try!(pemfile::rsa_private_keys(&mut &private_key[..])
+                            .map_err(|_| "Invalid PEM private key"));
+        }
+
+        let key = match keys.into_iter().next() {
+            Some(k) => k,
+            None => return Err("No private key found".into()),
+        };
+
+        let key = try!(sign::any_supported_type(&key).map_err(|_| "Unsupported private key"));
+        Ok(CertifiedKey::new(certificates, Arc::new(key)))
+    }
+
+    pub fn build_context(config: &SslConfig, options: &SslOptions)
+                         -> Result<SslContext, Box<Error + Send + Sync + 'static>>
+    {
+        let default = try!(certified_key(&config.certificate, &config.private_key));
+
+        let mut by_name = HashMap::with_capacity(options.sni_certificates.len());
+        for sni in &options.sni_certificates {
+            let key = try!(certified_key(&sni.certificate, &sni.private_key));
+            by_name.insert(sni.server_name.to_ascii_lowercase(), key);
+        }
+
+        let mut context = rustls::ServerConfig::new(NoClientAuth::new());
+        context.cert_resolver = Arc::new(CertificateResolver {
+            default: default,
+            by_name: by_name,
+        });
+        let protocols = options.alpn_protocols.iter().map(|p| p.as_bytes().to_vec())
+                                                      .collect::<Vec<_>>();
+        context.set_protocols(&protocols);
+        Ok(Arc::new(context))
+    }
+
+    pub fn accept(context: &SslContext, mut sock: TcpStream) -> IoResult<SslStream> {
+        let mut session = ServerSession::new(context);
+
+        while session.is_handshaking() {
+            let (read, written) = try!(session.complete_io(&mut sock));
+            if read == 0 && written == 0 {
+                return Err(IoError::new(ErrorKind::UnexpectedEof,
+                                        "Connection closed during the TLS handshake"));
+            }
+        }
+
+        Ok(SslStream {
+            session: session,
+            sock: sock,
+        })
+    }
+
+    #[inline]
+    pub fn alpn_protocol(stream: &SslStream) -> Option<String> {
+        stream.session.get_alpn_protocol().and_then(|p| String::from_utf8(p.to_vec()).ok())
+    }
+}
+
+#[cfg(not(any(feature = "ssl", feature = "ssl-rustls")))]
+mod backend {
+    use std::error::Error;
+
+    use {SslConfig, SslOptions};
+
+    pub type SslContext = ();
+
+    pub fn build_context(_: &SslConfig, _: &SslOptions)
+                         -> Result<SslContext, Box<Error + Send + Sync + 'static>>
+    {
+        Err("Building a server with SSL requires enabling the `ssl` or `ssl-rustls` feature in \
+             tiny-http".to_owned().into())
+    }
+}
@@ -0,0 +1,305 @@
+// Copyright 2015 The tiny-http Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io::{Error as IoError, ErrorKind, Read};
+use std::io::Result as IoResult;
+use std::str::FromStr;
+use std::sync::{Arc, Mutex};
+
+use {Header, Limits, StatusCode};
+
+/// Decodes a body sent with the chunked transfer encoding, and keeps the trailer headers.
+///
+/// The trailer headers that follow the last chunk are stored in the `Arc` passed to `new` once
+/// the end of the body has been reached.
+///
+/// The lines with the size of the chunks and the trailers are subject to the `max_header_bytes`
+/// and `max_headers` limits. If the body is invalid or its trailers are too large, reading fails
+/// and the status code of the response to send, `400` or `431`, is stored in `error_status`.
+pub struct ChunkedDecoder<R> where R: Read {
+    // where the chunks come from
+    source: R,
+
+    // remaining size of the chunk being read
+    // none if we are not in a chunk
+    remaining_chunk_size: Option<usize>,
+
+    // true once the last chunk and the trailers have been read
+    finished: bool,
+
+    // maximum size of each line with the size of a chunk, and of the trailers as a whole
+    max_header_bytes: Option<usize>,
+
+    // maximum number of trailers
+    max_trailers: Option<usize>,
+
+    trailers: Arc<Mutex<Option<Vec<Header>>>>,
+
+    error_status: Arc<Mutex<Option<StatusCode>>>,
+}
+
+impl<R> ChunkedDecoder<R> where R: Read {
+    pub fn new(source: R, limits: &Limits, trailers: Arc<Mutex<Option<Vec<Header>>>>,
+               error_status: Arc<Mutex<Option<StatusCode>>>) -> ChunkedDecoder<R>
+    {
+        ChunkedDecoder {
+            source: source,
+            remaining_chunk_size: None,
+            finished: false,
+            max_header_bytes: limits.max_header_bytes,
+            max_trailers: limits.max_headers,
+            trailers: trailers,
+            error_status: error_status,
+        }
+    }
+
+    // Reads a line terminated with CRLF, without the CRLF.
+    //
+    // `bytes_left` is the number of bytes that can still be read, and is decreased by the size
+    // of the line. Returns `None` if the line is longer.
+    fn read_line(&mut self, bytes_left: &mut Option<usize>) -> IoResult<Option<Vec<u8>>> {
+        let mut line = Vec::new();
+
+        loop {
+            if *bytes_left == Some(0) {
+                return Ok(None);
+            }
+
+            let byte = match self.source.by_ref().bytes().next() {
+                Some(b) => try!(b),
+                None => return Err(self.error(400)),
+            };
+
+            if let Some(ref mut left) = *bytes_left {
+                *left -= 1;
+            }
+
+            if byte == b'\n' && line.last() == Some(&b'\r') {
+                line.pop();
+                return Ok(Some(line));
+            }
+
+            line.push(byte);
+        }
+    }
+
+    fn read_chunk_size(&mut self) -> IoResult<usize> {
+        let mut bytes_left = self.max_header_bytes;
+        let line = match try!(self.read_line(&mut bytes_left)) {
+            Some(line) => line,
+            None => return Err(self.error(400)),
+        };
+
+        // chunk extensions are ignored
+        let size = match line.iter().position(|&b| b == b';') {
+            Some(pos) => &line[.. pos],
+            None => &line[..],
+        };
+
+        match ::std::str::from_utf8(size).ok()
+                                         .and_then(|s| usize::from_str_radix(s.trim(), 16).ok())
+        {
+            Some(size) => Ok(size),
+            None => Err(self.error(400)),
+        }
+    }
+
+    fn read_trailers(&mut self) -> IoResult<Vec<Header>> {
+        let mut trailers = Vec::new();
+        let mut bytes_left = self.max_header_bytes;
+
+        loop {
+            let line = match try!(self.read_line(&mut bytes_left)) {
+                Some(line) => line,
+                None => return Err(self.error(431)),
+            };
+
+            if line.is_empty() {
+                return Ok(trailers);
+            }
+
+            if self.max_trailers.map_or(false, |max| trailers.len() >= max) {
+                return Err(self.error(431));
+            }
+
+            let header = String::from_utf8(line).ok().and_then(|l| Header::from_str(&l).ok());
+            match header {
+                Some(header) => trailers.push(header),
+                None => return Err(self.error(400)),
+            }
+        }
+    }
+
+    fn read_crlf(&mut self) -> IoResult<()> {
+        let mut crlf = [0; 2];
+        try!(self.source.read_exact(&mut crlf));
+        if &crlf != b"\r\n" {
+            return Err(self.error(400));
+        }
+        Ok(())
+    }
+
+    // Stores the status code of the response to send, and returns the error to produce.
+    fn error(&self, status: u16) -> IoError {
+        *self.error_status.lock().unwrap() = Some(StatusCode(status));
+
+        if status == 431 {
+            IoError::new(ErrorKind::InvalidData, "The trailers of the body are too large")
+        } else {
+            IoError::new(ErrorKind::InvalidInput, "Error while decoding chunks")
+        }
+    }
+}
+
+impl<R> Read for ChunkedDecoder<R> where R: Read {
+    fn read(&mut self, buf: &mut [u8]) -> IoResult<usize> {
+        if self.finished || buf.is_empty() {
+            return Ok(0);
+        }
+
+        let remaining_chunk_size = match self.remaining_chunk_size {
+            Some(size) => size,
+            None => {
+                let size = try!(self.read_chunk_size());
+
+                // the last chunk is followed by the trailers
+                if size == 0 {
+                    let trailers = try!(self.read_trailers());
+                    *self.trailers.lock().unwrap() = Some(trailers);
+                    self.finished = true;
+                    return Ok(0);
+                }
+
+                size
+            }
+        };
+
+        let max = if buf.len() < remaining_chunk_size { buf.len() } else { remaining_chunk_size };
+        let read = try!(self.source.read(&mut buf[.. max]));
+        if read == 0 {
+            return Err(IoError::new(ErrorKind::UnexpectedEof, "Unexpected end of chunk"));
+        }
+
+        self.remaining_chunk_size = if read == remaining_chunk_size {
+            try!(self.read_crlf());
+            None
+        } else {
+            Some(remaining_chunk_size - read)
+        };
+
+        Ok(read)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ChunkedDecoder;
+    use std::io::{ErrorKind, Read};
+    use std::sync::{Arc, Mutex};
+    use {Limits, StatusCode};
+
+    fn decode(data: &[u8]) -> (Result<String, ErrorKind>, Option<Vec<String>>) {
+        let (body, trailers, _) = decode_with_limits(data, Default::default());
+        (body, trailers)
+    }
+
+    fn decode_with_limits(data: &[u8], limits: Limits)
+                          -> (Result<String, ErrorKind>, Option<Vec<String>>, Option<StatusCode>)
+    {
+        let trailers = Arc::new(Mutex::new(None));
+        let error_status = Arc::new(Mutex::new(None));
+        let mut decoder = ChunkedDecoder::new(data, &limits, trailers.clone(),
+                                              error_status.clone());
+
+        let mut body = String::new();
+        let result = decoder.read_to_string(&mut body).map(|_| body).map_err(|e| e.kind());
+
+        let trailers = trailers.lock().unwrap().as_ref()
+                               .map(|t| t.iter().map(|h| h.to_string()).collect());
+        let error_status = error_status.lock().unwrap().clone();
+        (result, trailers, error_status)
+    }
+
+    #[test]
+    fn without_trailers() {
+        let (body, trailers) = decode(b"3\r\nhel\r\nb;ext=1\r\nlo world!!!\r\n0\r\n\r\n");
+        assert_eq!(body, Ok("hello world!!!".to_owned()));
+        assert_eq!(trailers, Some(vec![]));
+    }
+
+    #[test]
+    fn with_trailers() {
+        let (body, trailers) = decode(b"5\r\nhello\r\n0\r\nChecksum: abc\r\nExpires: never\r\n\r\n");
+        assert_eq!(body, Ok("hello".to_owned()));
+        assert_eq!(trailers, Some(vec!["Checksum: abc".to_owned(), "Expires: never".to_owned()]));
+    }
+
+    #[test]
+    fn invalid() {
+        assert_eq!(decode(b"m\r\n\r\n").0, Err(ErrorKind::InvalidInput));
+        assert_eq!(decode(b"3\r\nhello\r\n0\r\n\r\n").0, Err(ErrorKind::InvalidInput));
+        assert_eq!(decode(b"5\r\nhello\r\n0\r\nnot a header\r\n\r\n").1, None);
+        assert_eq!(decode_with_limits(b"m\r\n\r\n", Default::default()).2,
+                   Some(StatusCode(400)));
+    }
+
+    #[test]
+    fn truncated() {
+        let (body, trailers) = decode(b"5\r\nhel");
+        assert!(body.is_err());
+        assert_eq!(trailers, None);
+    }
+
+    #[test]
+    fn chunk_size_too_long() {
+        let limits = Limits { max_header_bytes: Some(16), .. Default::default() };
+
+        let (body, _, status) = decode_with_limits(b"5;ext=123456\r\nhello\r\n0\r\n\r\n", limits);
+        assert_eq!(body, Ok("hello".to_owned()));
+        assert_eq!(status, None);
+
+        let (body, _, status) = decode_with_limits(b"5;extension=123456\r\nhello\r\n0\r\n\r\n",
+                                                   limits);
+        assert_eq!(body, Err(ErrorKind::InvalidInput));
+        assert_eq!(status, Some(StatusCode(400)));
+    }
+
+    #[test]
+    fn trailers_too_large() {
+        let data = b"5\r\nhello\r\n0\r\nChecksum: abc\r\nExpires: never\r\n\r\n";
+
+        let limits = Limits { max_header_bytes: Some(33), .. Default::default() };
+        assert_eq!(decode_with_limits(data, limits).0, Ok("hello".to_owned()));
+
+        let limits = Limits { max_header_bytes: Some(32), .. Default::default() };
+        let (body, trailers, status) = decode_with_limits(data, limits);
+        assert_eq!(body, Err(ErrorKind::InvalidData));
+        assert_eq!(trailers, None);
+        assert_eq!(status, Some(StatusCode(431)));
+    }
+
+    #[test]
+    fn too_many_trailers() {
+        let data = b"5\r\nhello\r\n0\r\nChecksum: abc\r\nExpires: never\r\n\r\n";
+
+        let limits = Limits { max_headers: Some(2), .. Default::default() };
+        assert_eq!(decode_with_limits(data, limits).1.map(|t| t.len()), Some(2));
+
+        let limits = Limits { max_headers: Some(1), .. Default::default() };
+        let (body, trailers, status) = decode_with_limits(data, limits);
+        assert_eq!(body, Err(ErrorKind::InvalidData));
+        assert_eq!(trailers, None);
+        assert_eq!(status, Some(StatusCode(431)));
+    }
+}
@@ -0,0 +1,74 @@
+// Copyright 2015 The tiny-http Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io::{Error as IoError, ErrorKind, Read};
+use std::io::Result as IoResult;
+
+/// A `Reader` that returns an error if a sub-reader produces more than a given number of bytes.
+///
+/// Contrary to `Read::take`, the data is not silently truncated.
+pub struct LimitedReader<R> where R: Read {
+    reader: R,
+    remaining: usize,
+}
+
+impl<R> LimitedReader<R> where R: Read {
+    pub fn new(reader: R, limit: usize) -> LimitedReader<R> {
+        LimitedReader {
+            reader: reader,
+            remaining: limit,
+        }
+    }
+}
+
+impl<R> Read for LimitedReader<R> where R: Read {
+    fn read(&mut self, buf: &mut [u8]) -> IoResult<usize> {
+        if buf.is_empty() {
+            return Ok(0);
+        }
+
+        // reading one more byte than allowed, in order to detect that the limit is exceeded
+        let max = if buf.len() > self.remaining { self.remaining + 1 } else { buf.len() };
+        let len = try!(self.reader.read(&mut buf[.. max]));
+
+        if len > self.remaining {
+            return Err(IoError::new(ErrorKind::InvalidData, "The body is too large"));
+        }
+
+        self.remaining -= len;
+        Ok(len)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::LimitedReader;
+    use std::io::{Cursor, ErrorKind, Read};
+
+    #[test]
+    fn test_within_limit() {
+        let mut reader = LimitedReader::new(Cursor::new(b"hello".to_vec()), 5);
+        let mut string = String::new();
+        reader.read_to_string(&mut string).unwrap();
+        assert_eq!(string, "hello");
+    }
+
+    #[test]
+    fn test_over_limit() {
+        let mut reader = LimitedReader::new(Cursor::new(b"hello world".to_vec()), 5);
+        let mut data = Vec::new();
+        let err = reader.read_to_end(&mut data).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidData);
+    }
+}
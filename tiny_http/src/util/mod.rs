@@ -12,9 +12,11 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub use self::chunked_decoder::ChunkedDecoder;
 pub use self::custom_stream::CustomStream;
 pub use self::encoding_decoder::EncodingDecoder;
 pub use self::equal_reader::EqualReader;
+pub use self::limited_reader::LimitedReader;
 pub use self::messages_queue::MessagesQueue;
 pub use self::refined_tcp_stream::RefinedTcpStream;
 pub use self::sequential::{SequentialReaderBuilder, SequentialReader};
@@ -23,9 +25,11 @@ pub use self::task_pool::TaskPool;
 
 use std::str::FromStr;
 
+mod chunked_decoder;
 mod custom_stream;
 mod encoding_decoder;
 mod equal_reader;
+mod limited_reader;
 mod messages_queue;
 mod refined_tcp_stream;
 mod sequential;
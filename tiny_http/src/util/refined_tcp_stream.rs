@@ -13,24 +13,37 @@
 // limitations under the License.
 
 use std::io::{Read, Write};
+use std::io::{Error as IoError, ErrorKind};
 use std::io::Result as IoResult;
 use std::net::{SocketAddr, TcpStream, Shutdown};
+use std::time::{Duration, Instant};
 
-#[cfg(feature = "ssl")]
+#[cfg(unix)]
+use std::net::{IpAddr, Ipv4Addr};
+#[cfg(unix)]
+use std::os::unix::net::UnixStream;
+
+#[cfg(any(feature = "ssl", feature = "ssl-rustls"))]
 use std::sync::{Arc, Mutex};
-#[cfg(feature = "ssl")]
-use openssl::ssl::SslStream;
+#[cfg(any(feature = "ssl", feature = "ssl-rustls"))]
+use ssl::{self, SslStream};
 
 pub struct RefinedTcpStream {
     stream: Stream,
     close_read: bool,
     close_write: bool,
+    // timeout of each read, see `set_read_timeout`
+    read_timeout: Option<Duration>,
+    // see `set_read_deadline`
+    read_deadline: Option<Instant>,
 }
 
 pub enum Stream {
     Http(TcpStream),
-    #[cfg(feature = "ssl")]
-    Https(Arc<Mutex<SslStream<TcpStream>>>),
+    #[cfg(any(feature = "ssl", feature = "ssl-rustls"))]
+    Https(Arc<Mutex<SslStream>>),
+    #[cfg(unix)]
+    Unix(UnixStream),
 }
 
 impl From<TcpStream> for Stream {
@@ -40,10 +53,18 @@ impl From<TcpStream> for Stream {
     }
 }
 
-#[cfg(feature = "ssl")]
-impl From<SslStream<TcpStream>> for Stream {
+#[cfg(unix)]
+impl From<UnixStream> for Stream {
     #[inline]
-    fn from(stream: SslStream<TcpStream>) -> Stream {
+    fn from(stream: UnixStream) -> Stream {
+        Stream::Unix(stream)
+    }
+}
+
+#[cfg(any(feature = "ssl", feature = "ssl-rustls"))]
+impl From<SslStream> for Stream {
+    #[inline]
+    fn from(stream: SslStream) -> Stream {
         Stream::Https(Arc::new(Mutex::new(stream)))
     }
 }
@@ -56,20 +77,26 @@ impl RefinedTcpStream {
 
         let read = match stream {
             Stream::Http(ref stream) => Stream::Http(stream.try_clone().unwrap()),
-            #[cfg(feature = "ssl")]
+            #[cfg(any(feature = "ssl", feature = "ssl-rustls"))]
             Stream::Https(ref stream) => Stream::Https(stream.clone()),
+            #[cfg(unix)]
+            Stream::Unix(ref stream) => Stream::Unix(stream.try_clone().unwrap()),
         };
 
         let read = RefinedTcpStream {
             stream: read,
             close_read: true,
             close_write: false,
+            read_timeout: None,
+            read_deadline: None,
         };
 
         let write = RefinedTcpStream {
             stream: stream,
             close_read: false,
             close_write: true,
+            read_timeout: None,
+            read_deadline: None,
         };
 
         (read, write)
@@ -80,16 +107,72 @@ impl RefinedTcpStream {
     pub fn secure(&self) -> bool {
         match self.stream {
             Stream::Http(_) => false,
-            #[cfg(feature = "ssl")]
+            #[cfg(any(feature = "ssl", feature = "ssl-rustls"))]
             Stream::Https(_) => true,
+            #[cfg(unix)]
+            Stream::Unix(_) => false,
         }
     }
 
+    /// Returns the protocol negotiated with ALPN, if any.
+    pub fn alpn_protocol(&self) -> Option<String> {
+        match self.stream {
+            Stream::Http(_) => None,
+            #[cfg(any(feature = "ssl", feature = "ssl-rustls"))]
+            Stream::Https(ref stream) => ssl::alpn_protocol(&stream.lock().unwrap()),
+            #[cfg(unix)]
+            Stream::Unix(_) => None,
+        }
+    }
+
+    /// Sets the timeout of each read on the socket.
+    pub fn set_read_timeout(&mut self, timeout: Option<Duration>) -> IoResult<()> {
+        self.read_timeout = timeout;
+        self.apply_read_timeout(timeout)
+    }
+
+    /// Sets an instant after which reading fails with a `TimedOut` error. Each read also blocks
+    /// at most until that instant.
+    pub fn set_read_deadline(&mut self, deadline: Option<Instant>) -> IoResult<()> {
+        self.read_deadline = deadline;
+        if deadline.is_none() {
+            let timeout = self.read_timeout;
+            try!(self.apply_read_timeout(timeout));
+        }
+        Ok(())
+    }
+
+    /// Sets the timeout of each write on the socket. This applies to both halves of the
+    /// connection.
+    pub fn set_write_timeout(&mut self, timeout: Option<Duration>) -> IoResult<()> {
+        match self.stream {
+            Stream::Http(ref stream) => stream.set_write_timeout(timeout),
+            #[cfg(any(feature = "ssl", feature = "ssl-rustls"))]
+            Stream::Https(ref stream) => stream.lock().unwrap().get_ref().set_write_timeout(timeout),
+            #[cfg(unix)]
+            Stream::Unix(ref stream) => stream.set_write_timeout(timeout),
+        }
+    }
+
+    fn apply_read_timeout(&mut self, timeout: Option<Duration>) -> IoResult<()> {
+        match self.stream {
+            Stream::Http(ref stream) => stream.set_read_timeout(timeout),
+            #[cfg(any(feature = "ssl", feature = "ssl-rustls"))]
+            Stream::Https(ref stream) => stream.lock().unwrap().get_ref().set_read_timeout(timeout),
+            #[cfg(unix)]
+            Stream::Unix(ref stream) => stream.set_read_timeout(timeout),
+        }
+    }
+
+    /// Returns the address of the client. Clients connected through a Unix domain socket have no
+    /// IP address, and `0.0.0.0:0` is returned for them.
     pub fn peer_addr(&mut self) -> IoResult<SocketAddr> {
         match self.stream {
             Stream::Http(ref mut stream) => stream.peer_addr(),
-            #[cfg(feature = "ssl")]
+            #[cfg(any(feature = "ssl", feature = "ssl-rustls"))]
             Stream::Https(ref mut stream) => stream.lock().unwrap().get_ref().peer_addr(),
+            #[cfg(unix)]
+            Stream::Unix(_) => Ok(SocketAddr::new(IpAddr::V4(Ipv4Addr::new(0, 0, 0, 0)), 0)),
         }
     }
 }
@@ -100,8 +183,10 @@ impl Drop for RefinedTcpStream {
             match self.stream {
                 // ignoring outcome
                 Stream::Http(ref mut stream) => stream.shutdown(Shutdown::Read).ok(),
-                #[cfg(feature = "ssl")]
+                #[cfg(any(feature = "ssl", feature = "ssl-rustls"))]
                 Stream::Https(ref mut stream) => stream.lock().unwrap().get_mut().shutdown(Shutdown::Read).ok(),
+                #[cfg(unix)]
+                Stream::Unix(ref mut stream) => stream.shutdown(Shutdown::Read).ok(),
             };
         }
 
@@ -109,8 +194,10 @@ impl Drop for RefinedTcpStream {
             match self.stream {
                 // ignoring outcome
                 Stream::Http(ref mut stream) => stream.shutdown(Shutdown::Write).ok(),
-                #[cfg(feature = "ssl")]
+                #[cfg(any(feature = "ssl", feature = "ssl-rustls"))]
                 Stream::Https(ref mut stream) => stream.lock().unwrap().get_mut().shutdown(Shutdown::Write).ok(),
+                #[cfg(unix)]
+                Stream::Unix(ref mut stream) => stream.shutdown(Shutdown::Write).ok(),
             };
         }
     }
@@ -118,10 +205,26 @@ impl Drop for RefinedTcpStream {
 
 impl Read for RefinedTcpStream {
     fn read(&mut self, buf: &mut [u8]) -> IoResult<usize> {
+        if let Some(deadline) = self.read_deadline {
+            let now = Instant::now();
+            if now >= deadline {
+                return Err(IoError::new(ErrorKind::TimedOut, "Read deadline has expired"));
+            }
+
+            let remaining = deadline - now;
+            let timeout = match self.read_timeout {
+                Some(timeout) if timeout < remaining => timeout,
+                _ => remaining,
+            };
+            try!(self.apply_read_timeout(Some(timeout)));
+        }
+
         match self.stream {
             Stream::Http(ref mut stream) => stream.read(buf),
-            #[cfg(feature = "ssl")]
+            #[cfg(any(feature = "ssl", feature = "ssl-rustls"))]
             Stream::Https(ref mut stream) => stream.lock().unwrap().read(buf),
+            #[cfg(unix)]
+            Stream::Unix(ref mut stream) => stream.read(buf),
         }
     }
 }
@@ -130,16 +233,20 @@ impl Write for RefinedTcpStream {
     fn write(&mut self, buf: &[u8]) -> IoResult<usize> {
         match self.stream {
             Stream::Http(ref mut stream) => stream.write(buf),
-            #[cfg(feature = "ssl")]
+            #[cfg(any(feature = "ssl", feature = "ssl-rustls"))]
             Stream::Https(ref mut stream) => stream.lock().unwrap().write(buf),
+            #[cfg(unix)]
+            Stream::Unix(ref mut stream) => stream.write(buf),
         }
     }
 
     fn flush(&mut self) -> IoResult<()> {
         match self.stream {
             Stream::Http(ref mut stream) => stream.flush(),
-            #[cfg(feature = "ssl")]
+            #[cfg(any(feature = "ssl", feature = "ssl-rustls"))]
             Stream::Https(ref mut stream) => stream.lock().unwrap().flush(),
+            #[cfg(unix)]
+            Stream::Unix(ref mut stream) => stream.flush(),
         }
     }
 }
@@ -60,6 +60,25 @@ impl<R: Read + Send> SequentialReaderBuilder<R> {
     }
 }
 
+impl<R: Read + Send> SequentialReader<R> {
+    /// Waits until the previous readers have been destroyed, then returns the underlying reader.
+    pub fn get_mut(&mut self) -> &mut R {
+        let reader = match self.inner {
+            SequentialReaderInner::Waiting(ref mut recv) => Some(recv.recv().unwrap()),
+            _ => None,
+        };
+
+        if let Some(reader) = reader {
+            self.inner = SequentialReaderInner::MyTurn(reader);
+        }
+
+        match self.inner {
+            SequentialReaderInner::MyTurn(ref mut reader) => reader,
+            _ => unreachable!(),
+        }
+    }
+}
+
 impl<W: Write + Send> SequentialWriterBuilder<W> {
     pub fn new(writer: W) -> SequentialWriterBuilder<W> {
         SequentialWriterBuilder {
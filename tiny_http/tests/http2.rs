@@ -0,0 +1,194 @@
+#![cfg(feature = "http2")]
+
+extern crate tiny_http;
+
+use std::io::{Read, Write};
+use std::net::TcpStream;
+use std::thread;
+use std::time::Duration;
+
+#[allow(dead_code)]
+mod support;
+
+const PREFACE: &'static [u8] = b"PRI * HTTP/2.0\r\n\r\nSM\r\n\r\n";
+
+fn write_frame(client: &mut TcpStream, kind: u8, flags: u8, stream: u32, payload: &[u8]) {
+    let len = payload.len();
+    let mut frame = vec![(len >> 16) as u8, (len >> 8) as u8, len as u8, kind, flags,
+                         (stream >> 24) as u8, (stream >> 16) as u8, (stream >> 8) as u8,
+                         stream as u8];
+    frame.extend_from_slice(payload);
+    client.write_all(&frame).unwrap();
+}
+
+/// Reads a frame and returns its type, flags, stream and payload.
+fn read_frame(client: &mut TcpStream) -> (u8, u8, u32, Vec<u8>) {
+    let mut header = [0; 9];
+    client.read_exact(&mut header).unwrap();
+    let len = (header[0] as usize) << 16 | (header[1] as usize) << 8 | header[2] as usize;
+    let stream = (header[5] as u32) << 24 | (header[6] as u32) << 16 |
+                 (header[7] as u32) << 8 | header[8] as u32;
+    let mut payload = vec![0; len];
+    client.read_exact(&mut payload).unwrap();
+    (header[3], header[4], stream, payload)
+}
+
+/// Connects with prior knowledge and exchanges the settings.
+fn new_http2_client() -> (tiny_http::Server, TcpStream) {
+    let (server, mut client) = support::new_one_server_one_client();
+    client.set_read_timeout(Some(Duration::from_secs(5))).unwrap();
+    client.write_all(PREFACE).unwrap();
+    write_frame(&mut client, 0x4, 0, 0, &[]);
+
+    // settings of the server, window update, and acknowledgement of our settings
+    let (kind, flags, _, _) = read_frame(&mut client);
+    assert_eq!((kind, flags), (0x4, 0));
+    assert_eq!(read_frame(&mut client).0, 0x8);
+    let (kind, flags, _, _) = read_frame(&mut client);
+    assert_eq!((kind, flags), (0x4, 0x1));
+
+    (server, client)
+}
+
+// header block of a request, as literals without indexing
+fn request_block(method: &str, path: &str) -> Vec<u8> {
+    let mut block = Vec::new();
+    for &(name, value) in &[(":method", method), (":scheme", "http"), (":path", path),
+                            (":authority", "localhost")] {
+        block.push(0);
+        block.push(name.len() as u8);
+        block.extend_from_slice(name.as_bytes());
+        block.push(value.len() as u8);
+        block.extend_from_slice(value.as_bytes());
+    }
+    block
+}
+
+#[test]
+fn prior_knowledge() {
+    let (server, mut client) = new_http2_client();
+
+    write_frame(&mut client, 0x1, 0x5, 1, &request_block("GET", "/first"));
+    write_frame(&mut client, 0x1, 0x4, 3, &request_block("POST", "/second"));
+    write_frame(&mut client, 0x0, 0x1, 3, b"hello");
+
+    let first = server.recv().unwrap();
+    let mut second = server.recv().unwrap();
+    assert_eq!(first.url(), "/first");
+    assert_eq!(*first.http_version(), (2, 0));
+    assert!(first.headers().iter().any(|h| h.field.equiv("Host") && h.value.as_str() == "localhost"));
+    assert_eq!(*second.method(), tiny_http::Method::Post);
+
+    let mut body = String::new();
+    second.as_reader().read_to_string(&mut body).unwrap();
+    assert_eq!(body, "hello");
+
+    // the responses are sent in any order
+    second.respond(tiny_http::Response::from_string("world")).unwrap();
+    first.respond(tiny_http::Response::empty(204)).unwrap();
+
+    let mut received = Vec::new();
+    while received.len() < 4 {
+        let (kind, flags, stream, payload) = read_frame(&mut client);
+        match kind {
+            0x1 => received.push((stream, "headers", payload[0], payload.len())),
+            0x0 if flags & 0x1 != 0 => received.push((stream, "end", 0, payload.len())),
+            0x0 => assert_eq!((stream, &payload[..]), (3, &b"world"[..])),
+            _ => (),
+        }
+    }
+
+    // `:status` is encoded with its index in the static table
+    assert_eq!(received[0].0, 3);
+    assert_eq!(received[0].2, 0x88);
+    assert_eq!(received[1], (3, "end", 0, 0));
+    assert_eq!(received[2].0, 1);
+    assert_eq!(received[2].2, 0x89);
+    assert_eq!(received[3], (1, "end", 0, 0));
+}
+
+#[test]
+fn flow_control() {
+    let (server, mut client) = new_http2_client();
+
+    write_frame(&mut client, 0x1, 0x5, 1, &request_block("GET", "/"));
+    let request = server.recv().unwrap();
+    thread::spawn(move || {
+        request.respond(tiny_http::Response::from_data(vec![b'x'; 100000])).unwrap();
+    });
+
+    // the default window of the client is 65535 bytes
+    let mut received = 0;
+    while received < 65535 {
+        let (kind, _, _, payload) = read_frame(&mut client);
+        if kind == 0x0 {
+            received += payload.len();
+        }
+    }
+    assert_eq!(received, 65535);
+
+    client.set_read_timeout(Some(Duration::from_millis(200))).unwrap();
+    let mut byte = [0];
+    assert!(client.read(&mut byte).is_err());
+    client.set_read_timeout(Some(Duration::from_secs(5))).unwrap();
+
+    write_frame(&mut client, 0x8, 0, 0, &[0, 1, 0, 0]);
+    write_frame(&mut client, 0x8, 0, 1, &[0, 1, 0, 0]);
+
+    loop {
+        let (kind, flags, _, payload) = read_frame(&mut client);
+        if kind == 0x0 {
+            received += payload.len();
+            if flags & 0x1 != 0 {
+                break;
+            }
+        }
+    }
+    assert_eq!(received, 100000);
+}
+
+#[test]
+fn ping_and_goaway() {
+    let (_server, mut client) = new_http2_client();
+
+    write_frame(&mut client, 0x6, 0, 0, b"12345678");
+    let (kind, flags, _, payload) = read_frame(&mut client);
+    assert_eq!((kind, flags, &payload[..]), (0x6, 0x1, &b"12345678"[..]));
+
+    // streams opened by the client have odd identifiers
+    write_frame(&mut client, 0x1, 0x5, 2, &request_block("GET", "/"));
+    let (kind, _, _, payload) = read_frame(&mut client);
+    assert_eq!(kind, 0x7);
+    assert_eq!(&payload[4 ..], &[0, 0, 0, 1]);
+
+    let mut rest = Vec::new();
+    client.read_to_end(&mut rest).unwrap();
+    assert!(rest.is_empty());
+}
+
+#[test]
+fn continuation_flood() {
+    let (_server, mut client) = support::new_one_server_one_client();
+    client.set_read_timeout(Some(Duration::from_secs(5))).unwrap();
+    client.write_all(PREFACE).unwrap();
+    write_frame(&mut client, 0x4, 0, 0, &[]);
+
+    // the size of the headers is limited even without `Limits::max_header_bytes`
+    let (kind, _, _, payload) = read_frame(&mut client);
+    assert_eq!(kind, 0x4);
+    assert!(payload.chunks(6).any(|s| s == &[0, 0x6, 0, 1, 0, 0][..]));
+
+    // a header block that never ends is refused once it is larger than four times that size
+    write_frame(&mut client, 0x1, 0x1, 1, &request_block("GET", "/"));
+    for _ in 0 .. 16 {
+        write_frame(&mut client, 0x9, 0, 1, &[0; 16384]);
+    }
+
+    loop {
+        let (kind, _, _, payload) = read_frame(&mut client);
+        if kind == 0x7 {
+            assert_eq!(&payload[4 ..], &[0, 0, 0, 0xb]);
+            break;
+        }
+    }
+}
@@ -81,3 +81,81 @@ fn unsupported_expect_header() {
     client.read_to_string(&mut content).unwrap();
     assert!(&content[9..].starts_with("417"));   // 417 status code
 }
+
+#[test]
+fn expect_100_continue_rejected() {
+    let (server, client) = support::new_one_server_one_client();
+
+    let mut client = client;
+    (write!(client, "POST / HTTP/1.1\r\nHost: localhost\r\nExpect: 100-continue\r\nContent-Type: text/plain; charset=utf8\r\nContent-Length: 100000\r\n\r\n")).unwrap();
+    client.flush().unwrap();
+
+    thread::spawn(move || {
+        let request = server.recv().unwrap();
+        assert!(request.expects_continue());
+        request.respond(tiny_http::Response::empty(413)).unwrap();
+    });
+
+    // the connection is closed without waiting for the body
+    let mut content = String::new();
+    client.read_to_string(&mut content).unwrap();
+    assert!(&content[9..].starts_with("413"));
+    assert!(content.contains("Connection: close"));
+    assert!(!content.contains("100 Continue"));
+}
+
+#[test]
+fn expect_100_continue_explicit() {
+    let (server, client) = support::new_one_server_one_client();
+
+    let mut client = client;
+    (write!(client, "POST / HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\nExpect: 100-continue\r\nContent-Type: text/plain; charset=utf8\r\nContent-Length: 5\r\n\r\n")).unwrap();
+    client.flush().unwrap();
+
+    let (tx, rx) = mpsc::channel();
+
+    thread::spawn(move || {
+        let mut request = server.recv().unwrap();
+        request.send_continue().unwrap();
+        assert!(!request.expects_continue());
+        let mut output = String::new();
+        request.as_reader().read_to_string(&mut output).unwrap();
+        assert_eq!(output, "hello");
+        tx.send(()).unwrap();
+    });
+
+    let mut content = Vec::new();
+    while !content.ends_with(b"\r\n\r\n") {
+        let mut byte = [0];
+        client.read_exact(&mut byte).unwrap();
+        content.push(byte[0]);
+    }
+    assert!(content.starts_with(b"HTTP/1.1 100 Continue\r\n"));
+
+    (write!(client, "hello")).unwrap();
+    client.flush().unwrap();
+
+    rx.recv().unwrap();
+}
+
+#[test]
+fn chunked_input_with_trailers() {
+    let (server, client) = support::new_one_server_one_client();
+
+    {
+        let mut client = client;
+        (write!(client, "POST / HTTP/1.1\r\nHost: localhost\r\nTransfer-Encoding: chunked\r\nTrailer: Checksum\r\n\r\n5\r\nhello\r\n0\r\nChecksum: 1234\r\n\r\n")).unwrap();
+    }
+
+    let mut request = server.recv().unwrap();
+    assert!(request.trailers().is_none());
+
+    let mut output = String::new();
+    request.as_reader().read_to_string(&mut output).unwrap();
+    assert_eq!(output, "hello");
+
+    let trailers = request.trailers().unwrap();
+    assert_eq!(trailers.len(), 1);
+    assert!(trailers[0].field.equiv("Checksum"));
+    assert_eq!(trailers[0].value.as_str(), "1234");
+}
@@ -0,0 +1,58 @@
+// `Listener::from_listen_fds` takes the sockets starting at file descriptor 3, where a service
+// manager puts them. The test replaces that file descriptor, so it has its own process.
+#![cfg(unix)]
+
+extern crate nix;
+extern crate tiny_http;
+
+use std::env;
+use std::io::{Result as IoResult, Write};
+use std::net::{TcpListener, TcpStream, UdpSocket};
+use std::os::unix::io::{IntoRawFd, RawFd};
+use std::process;
+
+use nix::unistd::{close, dup2};
+
+// Passes a file descriptor to `Listener::from_listen_fds` as a service manager would.
+fn from_listen_fd(fd: RawFd) -> IoResult<Vec<tiny_http::Listener>> {
+    if fd != 3 {
+        dup2(fd, 3).unwrap();
+        close(fd).unwrap();
+    }
+    env::set_var("LISTEN_FDS", "1");
+    env::set_var("LISTEN_PID", process::id().to_string());
+    tiny_http::Listener::from_listen_fds()
+}
+
+#[test]
+fn listen_fds() {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let port = listener.local_addr().unwrap().port();
+    {
+        let mut listeners = from_listen_fd(listener.into_raw_fd()).unwrap();
+        assert_eq!(listeners.len(), 1);
+        assert!(env::var("LISTEN_FDS").is_err());
+        assert_eq!(listeners[0].listen_addr().unwrap().to_ip().unwrap().port(), port);
+
+        let server = tiny_http::Server::from_listener(listeners.remove(0), Default::default())
+            .unwrap();
+        let mut client = TcpStream::connect(("127.0.0.1", port)).unwrap();
+        (write!(client, "GET / HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\r\n"))
+            .unwrap();
+        server.recv().unwrap().respond(tiny_http::Response::from_string("hello world")).unwrap();
+    }
+
+    // A connected socket is a stream socket, but it is not listening.
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let stream = TcpStream::connect(listener.local_addr().unwrap()).unwrap();
+    assert!(from_listen_fd(stream.into_raw_fd()).is_err());
+
+    let socket = UdpSocket::bind("127.0.0.1:0").unwrap();
+    assert!(from_listen_fd(socket.into_raw_fd()).is_err());
+
+    // The sockets are meant for another process.
+    env::set_var("LISTEN_PID", "1");
+    env::set_var("LISTEN_FDS", "1");
+    assert!(tiny_http::Listener::from_listen_fds().unwrap().is_empty());
+    assert!(env::var("LISTEN_FDS").is_err());
+}
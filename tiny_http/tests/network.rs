@@ -173,3 +173,325 @@ fn connection_timeout() {
     tx_stop.send(());
 }
 */
+
+// Reader that produces one piece of data each time something is sent on the channel.
+struct ChannelReader(std::sync::mpsc::Receiver<&'static str>);
+
+impl Read for ChannelReader {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        match self.0.recv() {
+            Ok(data) => {
+                buf[..data.len()].copy_from_slice(data.as_bytes());
+                Ok(data.len())
+            },
+            Err(_) => Ok(0),
+        }
+    }
+}
+
+// Answers the next request with a stream of events produced by the returned sender.
+fn respond_with_event_stream(server: tiny_http::Server) -> std::sync::mpsc::Sender<&'static str> {
+    let (sender, receiver) = std::sync::mpsc::channel();
+    thread::spawn(move || {
+        let rq = server.recv().unwrap();
+        let content_type = tiny_http::Header::from_bytes(&b"Content-Type"[..],
+                                                         &b"text/event-stream"[..]).unwrap();
+        let response = tiny_http::Response::empty(200)
+                            .with_header(content_type)
+                            .with_data(ChannelReader(receiver), None);
+        rq.respond(response).unwrap();
+    });
+    sender
+}
+
+// Reads from the client until the received data contains `expected`.
+fn read_until(client: &mut TcpStream, expected: &str) -> String {
+    client.set_read_timeout(Some(Duration::from_secs(5))).unwrap();
+    let mut received = Vec::new();
+    let mut buf = [0; 256];
+    while !String::from_utf8_lossy(&received).contains(expected) {
+        let len = client.read(&mut buf).unwrap();
+        assert!(len != 0);
+        received.extend_from_slice(&buf[..len]);
+    }
+    String::from_utf8(received).unwrap()
+}
+
+#[test]
+fn event_stream_chunks_are_flushed() {
+    let (server, mut client) = support::new_one_server_one_client();
+    (write!(client, "GET / HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\r\n")).unwrap();
+
+    let sender = respond_with_event_stream(server);
+    sender.send("hello").unwrap();
+
+    // The first chunk must reach the client while the reader is still blocked.
+    read_until(&mut client, "5\r\nhello\r\n");
+
+    drop(sender);
+    let mut rest = String::new();
+    client.read_to_string(&mut rest).unwrap();
+    assert!(rest.ends_with("0\r\n\r\n"));
+}
+
+#[test]
+fn event_stream_http_1_0() {
+    let (server, mut client) = support::new_one_server_one_client();
+    (write!(client, "GET / HTTP/1.0\r\nHost: localhost\r\n\r\n")).unwrap();
+
+    let sender = respond_with_event_stream(server);
+    sender.send("hello").unwrap();
+
+    // The stream isn't buffered, and its end is marked by closing the connection.
+    let received = read_until(&mut client, "hello");
+    assert!(received.contains("Connection: close\r\n"));
+    assert!(!received.contains("Content-Length"));
+
+    drop(sender);
+    let mut rest = String::new();
+    client.read_to_string(&mut rest).unwrap();
+    assert_eq!(rest, "");
+}
+
+#[test]
+fn from_tcp_listener() {
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let port = listener.local_addr().unwrap().port();
+    let server = tiny_http::Server::from_listener(listener, Default::default()).unwrap();
+    assert_eq!(server.server_addr().port(), port);
+
+    let mut client = TcpStream::connect(("127.0.0.1", port)).unwrap();
+    (write!(client, "GET / HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\r\n")).unwrap();
+
+    let rq = server.recv().unwrap();
+    rq.respond(tiny_http::Response::from_string("hello world")).unwrap();
+
+    let mut content = String::new();
+    client.read_to_string(&mut content).unwrap();
+    assert!(content.ends_with("hello world"));
+}
+
+#[cfg(unix)]
+#[test]
+fn unix_socket() {
+    use std::os::unix::net::UnixStream;
+
+    let nanos = std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH).unwrap()
+                                                .subsec_nanos();
+    let path = std::env::temp_dir().join(format!("tiny-http-test-{}.sock", nanos));
+    let _ = std::fs::remove_file(&path);
+
+    {
+        let server = tiny_http::Server::http_unix(&path).unwrap();
+        assert_eq!(server.listen_addr().to_unix().and_then(|a| a.as_pathname()),
+                   Some(path.as_path()));
+
+        let mut client = UnixStream::connect(&path).unwrap();
+        (write!(client, "GET /hello HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\r\n"))
+            .unwrap();
+
+        let rq = server.recv().unwrap();
+        assert_eq!(rq.url(), "/hello");
+        assert!(!rq.secure());
+        rq.respond(tiny_http::Response::from_string("hello world")).unwrap();
+
+        let mut content = String::new();
+        client.read_to_string(&mut content).unwrap();
+        assert!(content.starts_with("HTTP/1.1 200"));
+        assert!(content.ends_with("hello world"));
+    }
+
+    std::fs::remove_file(&path).unwrap();
+}
+
+// Creates a server with the given limits and a client connected to it.
+fn new_server_with_limits(limits: tiny_http::Limits) -> (tiny_http::Server, TcpStream) {
+    let listener = std::net::TcpListener::bind("0.0.0.0:0").unwrap();
+    let port = listener.local_addr().unwrap().port();
+    let server = tiny_http::Server::from_listener(listener, tiny_http::ListenerConfig {
+        limits: limits,
+        .. Default::default()
+    }).unwrap();
+    let client = TcpStream::connect(("127.0.0.1", port)).unwrap();
+    (server, client)
+}
+
+#[test]
+fn body_too_large() {
+    let (_server, mut client) = new_server_with_limits(tiny_http::Limits {
+        max_body_length: Some(16),
+        .. Default::default()
+    });
+
+    (write!(client, "POST / HTTP/1.1\r\nHost: localhost\r\nContent-Length: 17\r\n\r\n")).unwrap();
+
+    let mut content = String::new();
+    client.read_to_string(&mut content).unwrap();
+    assert!(content.starts_with("HTTP/1.1 413"));
+}
+
+#[test]
+fn chunked_body_too_large() {
+    let (server, mut client) = new_server_with_limits(tiny_http::Limits {
+        max_body_length: Some(4),
+        .. Default::default()
+    });
+
+    (write!(client, "POST / HTTP/1.1\r\nHost: localhost\r\nTransfer-Encoding: chunked\r\n\r\n\
+                     5\r\nhello\r\n0\r\n\r\n")).unwrap();
+
+    let mut rq = server.recv().unwrap();
+    let mut body = String::new();
+    let err = rq.as_reader().read_to_string(&mut body).unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+}
+
+#[test]
+fn too_many_headers() {
+    let (_server, mut client) = new_server_with_limits(tiny_http::Limits {
+        max_headers: Some(2),
+        .. Default::default()
+    });
+
+    (write!(client, "GET / HTTP/1.1\r\nHost: localhost\r\nA: a\r\nB: b\r\n\r\n")).unwrap();
+
+    let mut content = String::new();
+    client.read_to_string(&mut content).unwrap();
+    assert!(content.starts_with("HTTP/1.1 431"));
+}
+
+#[test]
+fn headers_too_large() {
+    let (_server, mut client) = new_server_with_limits(tiny_http::Limits {
+        max_header_bytes: Some(64),
+        .. Default::default()
+    });
+
+    let value = "a".repeat(64);
+    (write!(client, "GET / HTTP/1.1\r\nHost: localhost\r\nA: {}\r\n\r\n", value)).unwrap();
+
+    let mut content = String::new();
+    client.read_to_string(&mut content).unwrap();
+    assert!(content.starts_with("HTTP/1.1 431"));
+}
+
+#[test]
+fn chunked_trailers_too_large() {
+    let (server, mut client) = new_server_with_limits(tiny_http::Limits {
+        max_headers: Some(2),
+        .. Default::default()
+    });
+
+    // the second request must not be read, since the connection is closed after the first one
+    (write!(client, "POST / HTTP/1.1\r\nHost: localhost\r\nTransfer-Encoding: chunked\r\n\r\n\
+                     5\r\nhello\r\n0\r\nA: a\r\nB: b\r\nC: c\r\n\r\n\
+                     GET / HTTP/1.1\r\nHost: localhost\r\n\r\n")).unwrap();
+
+    {
+        let mut rq = server.recv().unwrap();
+        let mut body = String::new();
+        let err = rq.as_reader().read_to_string(&mut body).unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+        assert!(rq.trailers().is_none());
+    }
+
+    let mut content = String::new();
+    client.read_to_string(&mut content).unwrap();
+    assert!(content.starts_with("HTTP/1.1 431"));
+    assert_eq!(content.matches("HTTP/1.1").count(), 1);
+}
+
+#[test]
+fn invalid_chunked_body() {
+    let (server, mut client) = new_server_with_limits(Default::default());
+
+    (write!(client, "POST / HTTP/1.1\r\nHost: localhost\r\nTransfer-Encoding: chunked\r\n\r\n\
+                     zz\r\nhello\r\n0\r\n\r\n")).unwrap();
+
+    {
+        let mut rq = server.recv().unwrap();
+        let mut body = String::new();
+        let err = rq.as_reader().read_to_string(&mut body).unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidInput);
+    }
+
+    let mut content = String::new();
+    client.read_to_string(&mut content).unwrap();
+    assert!(content.starts_with("HTTP/1.1 400"));
+}
+
+#[test]
+fn header_timeout() {
+    let (_server, mut client) = new_server_with_limits(tiny_http::Limits {
+        header_timeout: Some(Duration::from_millis(500)),
+        .. Default::default()
+    });
+
+    // sending the headers slowly, so that no individual read times out
+    (write!(client, "GET / HTTP/1.1\r\n")).unwrap();
+    for _ in 0 .. 4 {
+        thread::sleep(Duration::from_millis(200));
+        let _ = write!(client, "A: a\r\n");
+    }
+
+    client.set_read_timeout(Some(Duration::from_secs(5))).unwrap();
+    let mut content = String::new();
+    client.read_to_string(&mut content).unwrap();
+    assert!(content.starts_with("HTTP/1.1 408"));
+}
+
+#[test]
+fn keep_alive_timeout() {
+    let (server, mut client) = new_server_with_limits(tiny_http::Limits {
+        keep_alive_timeout: Some(Duration::from_millis(200)),
+        .. Default::default()
+    });
+
+    (write!(client, "GET / HTTP/1.1\r\nHost: localhost\r\n\r\n")).unwrap();
+    let rq = server.recv().unwrap();
+    rq.respond(tiny_http::Response::from_string("hello world")).unwrap();
+
+    // the connection is closed without a response once the client has been idle for too long
+    client.set_read_timeout(Some(Duration::from_secs(5))).unwrap();
+    let mut content = String::new();
+    client.read_to_string(&mut content).unwrap();
+    assert!(content.ends_with("hello world"));
+}
+
+#[test]
+fn max_connections() {
+    let (server, _client) = new_server_with_limits(tiny_http::Limits {
+        max_connections: Some(1),
+        .. Default::default()
+    });
+
+    // waiting for the first connection to be accepted
+    for _ in 0 .. 100 {
+        if server.num_connections() == 1 { break; }
+        thread::sleep(Duration::from_millis(10));
+    }
+    assert_eq!(server.num_connections(), 1);
+
+    let port = server.server_addr().port();
+    let mut client = TcpStream::connect(("127.0.0.1", port)).unwrap();
+    let mut content = String::new();
+    client.read_to_string(&mut content).unwrap();
+    assert!(content.starts_with("HTTP/1.1 503"));
+}
+
+#[test]
+fn http_without_ssl() {
+    let (server, mut client) = support::new_one_server_one_client();
+    (write!(client, "GET / HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\r\n")).unwrap();
+
+    let rq = server.recv().unwrap();
+    assert!(!rq.secure());
+    assert_eq!(rq.alpn_protocol(), None);
+    rq.respond(tiny_http::Response::from_string("hello world")).unwrap();
+
+    let config = tiny_http::SslConfig {
+        certificate: Vec::new(),
+        private_key: Vec::new(),
+    };
+    assert!(server.reload_ssl(config, Default::default()).is_err());
+}
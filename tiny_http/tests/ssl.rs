@@ -0,0 +1,170 @@
+#![cfg(feature = "ssl-rustls")]
+
+extern crate rustls;
+extern crate tiny_http;
+extern crate webpki;
+
+use std::io::{Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+
+use rustls::Session;
+use rustls::internal::pemfile;
+use tiny_http::{ListenerConfig, SniCertificate, SslConfig, SslOptions};
+
+// The certificates in `tests/ssl` are signed by `ca-cert.pem`. `localhost-cert.pem` is valid for
+// `localhost` and `example-cert.pem` for `example.com`.
+const CA_CERT: &[u8] = include_bytes!("ssl/ca-cert.pem");
+const LOCALHOST_CERT: &[u8] = include_bytes!("ssl/localhost-cert.pem");
+const LOCALHOST_KEY: &[u8] = include_bytes!("ssl/localhost-key.pem");
+const EXAMPLE_CERT: &[u8] = include_bytes!("ssl/example-cert.pem");
+const EXAMPLE_KEY: &[u8] = include_bytes!("ssl/example-key.pem");
+
+fn new_server(limits: tiny_http::Limits) -> (tiny_http::Server, u16) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let port = listener.local_addr().unwrap().port();
+
+    let server = tiny_http::Server::from_listener(listener, ListenerConfig {
+        ssl: Some(SslConfig {
+            certificate: LOCALHOST_CERT.to_vec(),
+            private_key: LOCALHOST_KEY.to_vec(),
+        }),
+        ssl_options: SslOptions {
+            sni_certificates: vec![SniCertificate {
+                server_name: "Example.com".to_owned(),
+                certificate: EXAMPLE_CERT.to_vec(),
+                private_key: EXAMPLE_KEY.to_vec(),
+            }],
+            alpn_protocols: vec!["http/1.1".to_owned()],
+        },
+        limits: limits,
+    }).unwrap();
+
+    (server, port)
+}
+
+// Connects to the server with the given server name, and returns the session once the handshake
+// is done.
+fn connect(port: u16, server_name: &str, protocols: &[&str])
+           -> (rustls::ClientSession, TcpStream)
+{
+    let mut config = rustls::ClientConfig::new();
+    config.root_store.add_pem_file(&mut &CA_CERT[..]).unwrap();
+    config.set_protocols(&protocols.iter().map(|p| p.as_bytes().to_vec()).collect::<Vec<_>>());
+
+    let name = webpki::DNSNameRef::try_from_ascii_str(server_name).unwrap();
+    let mut session = rustls::ClientSession::new(&Arc::new(config), name);
+    let mut sock = TcpStream::connect(("127.0.0.1", port)).unwrap();
+    sock.set_read_timeout(Some(Duration::from_secs(5))).unwrap();
+    while session.is_handshaking() {
+        session.complete_io(&mut sock).unwrap();
+    }
+
+    (session, sock)
+}
+
+// Sends a request through the session and returns the body of the response.
+fn request(server: &tiny_http::Server, session: &mut rustls::ClientSession, sock: &mut TcpStream,
+           expected_alpn: Option<&str>) -> String
+{
+    {
+        let mut stream = rustls::Stream::new(session, sock);
+        (write!(stream, "GET / HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\r\n"))
+            .unwrap();
+    }
+
+    let rq = server.recv().unwrap();
+    assert!(rq.secure());
+    assert_eq!(rq.alpn_protocol(), expected_alpn);
+    rq.respond(tiny_http::Response::from_string("hello world")).unwrap();
+
+    // the connection may be closed without a `close_notify` alert, which is an error for rustls
+    let mut stream = rustls::Stream::new(session, sock);
+    let mut content = Vec::new();
+    let mut buf = [0; 1024];
+    loop {
+        match stream.read(&mut buf) {
+            Ok(0) | Err(_) => break,
+            Ok(n) => content.extend_from_slice(&buf[.. n]),
+        }
+    }
+    String::from_utf8(content).unwrap()
+}
+
+fn peer_certificate(session: &rustls::ClientSession) -> rustls::Certificate {
+    session.get_peer_certificates().unwrap().remove(0)
+}
+
+fn certificate(pem: &[u8]) -> rustls::Certificate {
+    pemfile::certs(&mut &pem[..]).unwrap().remove(0)
+}
+
+#[test]
+fn default_certificate() {
+    let (server, port) = new_server(Default::default());
+
+    let (mut session, mut sock) = connect(port, "localhost", &[]);
+    assert!(peer_certificate(&session) == certificate(LOCALHOST_CERT));
+    assert_eq!(session.get_alpn_protocol(), None);
+
+    let content = request(&server, &mut session, &mut sock, None);
+    assert!(content.starts_with("HTTP/1.1 200"));
+    assert!(content.ends_with("hello world"));
+}
+
+#[test]
+fn sni_and_alpn() {
+    let (server, port) = new_server(Default::default());
+
+    let (mut session, mut sock) = connect(port, "example.com", &["spdy/1", "http/1.1"]);
+    assert!(peer_certificate(&session) == certificate(EXAMPLE_CERT));
+    assert_eq!(session.get_alpn_protocol(), Some(&b"http/1.1"[..]));
+
+    let content = request(&server, &mut session, &mut sock, Some("http/1.1"));
+    assert!(content.ends_with("hello world"));
+}
+
+#[test]
+fn reload_certificates() {
+    let (server, port) = new_server(Default::default());
+
+    // swapping the two certificates
+    server.reload_ssl(SslConfig {
+        certificate: EXAMPLE_CERT.to_vec(),
+        private_key: EXAMPLE_KEY.to_vec(),
+    }, SslOptions {
+        sni_certificates: vec![SniCertificate {
+            server_name: "localhost".to_owned(),
+            certificate: LOCALHOST_CERT.to_vec(),
+            private_key: LOCALHOST_KEY.to_vec(),
+        }],
+        alpn_protocols: Vec::new(),
+    }).unwrap();
+
+    let (mut session, mut sock) = connect(port, "localhost", &["http/1.1"]);
+    assert!(peer_certificate(&session) == certificate(LOCALHOST_CERT));
+    assert_eq!(session.get_alpn_protocol(), None);
+    assert!(request(&server, &mut session, &mut sock, None).ends_with("hello world"));
+}
+
+#[test]
+fn slow_handshake_does_not_block_other_clients() {
+    let (server, port) = new_server(tiny_http::Limits {
+        read_timeout: Some(Duration::from_millis(200)),
+        .. Default::default()
+    });
+
+    // a client that never starts the handshake
+    let mut idle = TcpStream::connect(("127.0.0.1", port)).unwrap();
+    thread::sleep(Duration::from_millis(50));
+
+    let (mut session, mut sock) = connect(port, "localhost", &[]);
+    assert!(request(&server, &mut session, &mut sock, None).ends_with("hello world"));
+
+    // the idle client is disconnected once the read timeout expires
+    idle.set_read_timeout(Some(Duration::from_secs(5))).unwrap();
+    let mut buf = Vec::new();
+    assert_eq!(idle.read_to_end(&mut buf).unwrap(), 0);
+}
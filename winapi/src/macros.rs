@@ -129,6 +129,22 @@ macro_rules! MAKEINTRESOURCE {
 }
 #[macro_export]
 macro_rules! RIDL {
+    (#[uuid($l:expr, $w1:expr, $w2:expr,
+        $b1:expr, $b2:expr, $b3:expr, $b4:expr, $b5:expr, $b6:expr, $b7:expr, $b8:expr)]
+    class $class:ident;) => (
+        pub enum $class {}
+        impl $crate::Class for $class {
+            #[inline]
+            fn uuidof() -> $crate::shared::guiddef::GUID {
+                $crate::shared::guiddef::GUID {
+                    Data1: $l,
+                    Data2: $w1,
+                    Data3: $w2,
+                    Data4: [$b1, $b2, $b3, $b4, $b5, $b6, $b7, $b8],
+                }
+            }
+        }
+    );
     (#[uuid($($uuid:expr),+)]
     interface $interface:ident ($vtbl:ident) {$(
         $(#[$($attrs:tt)*])* fn $method:ident($($p:ident : $t:ty,)*) -> $rtr:ty,
@@ -259,6 +275,11 @@ macro_rules! UNION {
             #[inline]
             fn clone(&self) -> $name { *self }
         }
+        #[cfg(feature = "impl-default")]
+        impl Default for $name {
+            #[inline]
+            fn default() -> $name { unsafe { $crate::_core::mem::zeroed() } }
+        }
         impl $name {$(
             #[inline]
             pub unsafe fn $variant(&self) -> &$ftype {
@@ -283,6 +304,11 @@ macro_rules! UNION {
             #[inline]
             fn clone(&self) -> $name { *self }
         }
+        #[cfg(feature = "impl-default")]
+        impl Default for $name {
+            #[inline]
+            fn default() -> $name { unsafe { $crate::_core::mem::zeroed() } }
+        }
         impl $name {$(
             #[inline]
             pub unsafe fn $variant(&self) -> &$ftype {
@@ -346,15 +372,19 @@ macro_rules! STRUCT {
     ($(#[$attrs:meta])* struct $name:ident {
         $($field:ident: $ftype:ty,)+
     }) => (
-        #[repr(C)] $(#[$attrs])*
+        #[repr(C)] #[derive(Copy)] $(#[$attrs])*
         pub struct $name {
             $(pub $field: $ftype,)+
         }
-        impl Copy for $name {}
         impl Clone for $name {
             #[inline]
             fn clone(&self) -> $name { *self }
         }
+        #[cfg(feature = "impl-default")]
+        impl Default for $name {
+            #[inline]
+            fn default() -> $name { unsafe { $crate::_core::mem::zeroed() } }
+        }
     );
 }
 macro_rules! IFDEF {
@@ -82,6 +82,8 @@ ENUM!{enum DXGI_SWAP_CHAIN_FLAG {
     DXGI_SWAP_CHAIN_FLAG_FOREGROUND_LAYER = 128,
     DXGI_SWAP_CHAIN_FLAG_FULLSCREEN_VIDEO = 256,
     DXGI_SWAP_CHAIN_FLAG_YUV_VIDEO = 512,
+    DXGI_SWAP_CHAIN_FLAG_HW_PROTECTED = 1024,
+    DXGI_SWAP_CHAIN_FLAG_ALLOW_TEARING = 2048,
 }}
 STRUCT!{struct DXGI_SWAP_CHAIN_DESC {
     BufferDesc: DXGI_MODE_DESC,
@@ -93,7 +95,7 @@ STRUCT!{struct DXGI_SWAP_CHAIN_DESC {
     SwapEffect: DXGI_SWAP_EFFECT,
     Flags: UINT,
 }}
-RIDL!(#[uuid(0xaec22fb8, 0x76f3, 0x4639, 0x9b, 0xe0, 0x28, 0xeb, 0x43, 0xa6, 0x7a, 0x2e)]
+RIDL!{#[uuid(0xaec22fb8, 0x76f3, 0x4639, 0x9b, 0xe0, 0x28, 0xeb, 0x43, 0xa6, 0x7a, 0x2e)]
 interface IDXGIObject(IDXGIObjectVtbl): IUnknown(IUnknownVtbl) {
     fn SetPrivateData(
         Name: REFGUID,
@@ -113,15 +115,15 @@ interface IDXGIObject(IDXGIObjectVtbl): IUnknown(IUnknownVtbl) {
         riid: REFIID,
         ppParent: *mut *mut c_void,
     ) -> HRESULT,
-});
-RIDL!(#[uuid(0x3d3e0379, 0xf9de, 0x4d58, 0xbb, 0x6c, 0x18, 0xd6, 0x29, 0x92, 0xf1, 0xa6)]
+}}
+RIDL!{#[uuid(0x3d3e0379, 0xf9de, 0x4d58, 0xbb, 0x6c, 0x18, 0xd6, 0x29, 0x92, 0xf1, 0xa6)]
 interface IDXGIDeviceSubObject(IDXGIDeviceSubObjectVtbl): IDXGIObject(IDXGIObjectVtbl) {
     fn GetDevice(
         riid: REFIID,
         ppDevice: *mut *mut c_void,
     ) -> HRESULT,
-});
-RIDL!(#[uuid(0x035f3ab4, 0x482e, 0x4e50, 0xb4, 0x1f, 0x8a, 0x7f, 0x8b, 0xd8, 0x96, 0x0b)]
+}}
+RIDL!{#[uuid(0x035f3ab4, 0x482e, 0x4e50, 0xb4, 0x1f, 0x8a, 0x7f, 0x8b, 0xd8, 0x96, 0x0b)]
 interface IDXGIResource(IDXGIResourceVtbl): IDXGIDeviceSubObject(IDXGIDeviceSubObjectVtbl) {
     fn GetSharedHandle(
         pSharedHandle: *mut HANDLE,
@@ -135,8 +137,8 @@ interface IDXGIResource(IDXGIResourceVtbl): IDXGIDeviceSubObject(IDXGIDeviceSubO
     fn GetEvictionPriority(
         pEvictionPriority: *mut UINT,
     ) -> HRESULT,
-});
-RIDL!(#[uuid(0x9d8e1289, 0xd7b3, 0x465f, 0x81, 0x26, 0x25, 0x0e, 0x34, 0x9a, 0xf8, 0x5d)]
+}}
+RIDL!{#[uuid(0x9d8e1289, 0xd7b3, 0x465f, 0x81, 0x26, 0x25, 0x0e, 0x34, 0x9a, 0xf8, 0x5d)]
 interface IDXGIKeyedMutex(IDXGIKeyedMutexVtbl): IDXGIDeviceSubObject(IDXGIDeviceSubObjectVtbl) {
     fn AcquireSync(
         Key: UINT64,
@@ -145,8 +147,11 @@ interface IDXGIKeyedMutex(IDXGIKeyedMutexVtbl): IDXGIDeviceSubObject(IDXGIDevice
     fn ReleaseSync(
         Key: UINT64,
     ) -> HRESULT,
-});
-RIDL!(#[uuid(0xcafcb56c, 0x6ac3, 0x4889, 0xbf, 0x47, 0x9e, 0x23, 0xbb, 0xd2, 0x60, 0xec)]
+}}
+pub const DXGI_MAP_READ: UINT = 1;
+pub const DXGI_MAP_WRITE: UINT = 2;
+pub const DXGI_MAP_DISCARD: UINT = 4;
+RIDL!{#[uuid(0xcafcb56c, 0x6ac3, 0x4889, 0xbf, 0x47, 0x9e, 0x23, 0xbb, 0xd2, 0x60, 0xec)]
 interface IDXGISurface(IDXGISurfaceVtbl): IDXGIDeviceSubObject(IDXGIDeviceSubObjectVtbl) {
     fn GetDesc(
         pDesc: *mut DXGI_SURFACE_DESC,
@@ -156,8 +161,8 @@ interface IDXGISurface(IDXGISurfaceVtbl): IDXGIDeviceSubObject(IDXGIDeviceSubObj
         MapFlags: UINT,
     ) -> HRESULT,
     fn Unmap() -> HRESULT,
-});
-RIDL!(#[uuid(0x4ae63092, 0x6327, 0x4c1b, 0x80, 0xae, 0xbf, 0xe1, 0x2e, 0xa3, 0x2b, 0x86)]
+}}
+RIDL!{#[uuid(0x4ae63092, 0x6327, 0x4c1b, 0x80, 0xae, 0xbf, 0xe1, 0x2e, 0xa3, 0x2b, 0x86)]
 interface IDXGISurface1(IDXGISurface1Vtbl): IDXGISurface(IDXGISurfaceVtbl) {
     fn GetDC(
         Discard: BOOL,
@@ -166,8 +171,8 @@ interface IDXGISurface1(IDXGISurface1Vtbl): IDXGISurface(IDXGISurfaceVtbl) {
     fn ReleaseDC(
         pDirtyRect: *mut RECT,
     ) -> HRESULT,
-});
-RIDL!(#[uuid(0x2411e7e1, 0x12ac, 0x4ccf, 0xbd, 0x14, 0x97, 0x98, 0xe8, 0x53, 0x4d, 0xc0)]
+}}
+RIDL!{#[uuid(0x2411e7e1, 0x12ac, 0x4ccf, 0xbd, 0x14, 0x97, 0x98, 0xe8, 0x53, 0x4d, 0xc0)]
 interface IDXGIAdapter(IDXGIAdapterVtbl): IDXGIObject(IDXGIObjectVtbl) {
     fn EnumOutputs(
         Output: UINT,
@@ -180,8 +185,8 @@ interface IDXGIAdapter(IDXGIAdapterVtbl): IDXGIObject(IDXGIObjectVtbl) {
         InterfaceName: REFGUID,
         pUMDVersion: *mut LARGE_INTEGER,
     ) -> HRESULT,
-});
-RIDL!(#[uuid(0xae02eedb, 0xc735, 0x4690, 0x8d, 0x52, 0x5a, 0x8d, 0xc2, 0x02, 0x13, 0xaa)]
+}}
+RIDL!{#[uuid(0xae02eedb, 0xc735, 0x4690, 0x8d, 0x52, 0x5a, 0x8d, 0xc2, 0x02, 0x13, 0xaa)]
 interface IDXGIOutput(IDXGIOutputVtbl): IDXGIObject(IDXGIObjectVtbl) {
     fn GetDesc(
         pDesc: *mut DXGI_OUTPUT_DESC,
@@ -221,7 +226,7 @@ interface IDXGIOutput(IDXGIOutputVtbl): IDXGIObject(IDXGIObjectVtbl) {
     fn GetFrameStatistics(
         pStats: *mut DXGI_FRAME_STATISTICS,
     ) -> HRESULT,
-});
+}}
 pub const DXGI_MAX_SWAP_CHAIN_BUFFERS: DWORD = 16;
 pub const DXGI_PRESENT_TEST: DWORD = 0x00000001;
 pub const DXGI_PRESENT_DO_NOT_SEQUENCE: DWORD = 0x00000002;
@@ -231,7 +236,10 @@ pub const DXGI_PRESENT_STEREO_PREFER_RIGHT: DWORD = 0x00000010;
 pub const DXGI_PRESENT_STEREO_TEMPORARY_MONO: DWORD = 0x00000020;
 pub const DXGI_PRESENT_RESTRICT_TO_OUTPUT: DWORD = 0x00000040;
 pub const DXGI_PRESENT_USE_DURATION: DWORD = 0x00000100;
-RIDL!(#[uuid(0x310d36a0, 0xd2e7, 0x4c0a, 0xaa, 0x04, 0x6a, 0x9d, 0x23, 0xb8, 0x88, 0x6a)]
+pub const DXGI_PRESENT_ALLOW_TEARING: DWORD = 0x00000200;
+pub const DXGI_ENUM_MODES_INTERLACED: UINT = 1;
+pub const DXGI_ENUM_MODES_SCALING: UINT = 2;
+RIDL!{#[uuid(0x310d36a0, 0xd2e7, 0x4c0a, 0xaa, 0x04, 0x6a, 0x9d, 0x23, 0xb8, 0x88, 0x6a)]
 interface IDXGISwapChain(IDXGISwapChainVtbl): IDXGIDeviceSubObject(IDXGIDeviceSubObjectVtbl) {
     fn Present(
         SyncInterval: UINT,
@@ -272,8 +280,8 @@ interface IDXGISwapChain(IDXGISwapChainVtbl): IDXGIDeviceSubObject(IDXGIDeviceSu
     fn GetLastPresentCount(
         pLastPresentCount: *mut UINT,
     ) -> HRESULT,
-});
-RIDL!(#[uuid(0x7b7166ec, 0x21c7, 0x44ae, 0xb2, 0x1a, 0xc9, 0xae, 0x32, 0x1a, 0xe3, 0x69)]
+}}
+RIDL!{#[uuid(0x7b7166ec, 0x21c7, 0x44ae, 0xb2, 0x1a, 0xc9, 0xae, 0x32, 0x1a, 0xe3, 0x69)]
 interface IDXGIFactory(IDXGIFactoryVtbl): IDXGIObject(IDXGIObjectVtbl) {
     fn EnumAdapters(
         Adapter: UINT,
@@ -295,7 +303,7 @@ interface IDXGIFactory(IDXGIFactoryVtbl): IDXGIObject(IDXGIObjectVtbl) {
         Module: HMODULE,
         ppAdapter: *mut *mut IDXGIAdapter,
     ) -> HRESULT,
-});
+}}
 extern "system" {
     pub fn CreateDXGIFactory(
         riid: REFIID,
@@ -306,7 +314,7 @@ extern "system" {
         ppFactory: *mut *mut c_void,
     ) -> HRESULT;
 }
-RIDL!(#[uuid(0x54ec77fa, 0x1377, 0x44e6, 0x8c, 0x32, 0x88, 0xfd, 0x5f, 0x44, 0xc8, 0x4c)]
+RIDL!{#[uuid(0x54ec77fa, 0x1377, 0x44e6, 0x8c, 0x32, 0x88, 0xfd, 0x5f, 0x44, 0xc8, 0x4c)]
 interface IDXGIDevice(IDXGIDeviceVtbl): IDXGIObject(IDXGIObjectVtbl) {
     fn GetAdapter(
         pAdapter: *mut *mut IDXGIAdapter,
@@ -329,7 +337,7 @@ interface IDXGIDevice(IDXGIDeviceVtbl): IDXGIObject(IDXGIObjectVtbl) {
     fn GetGPUThreadPriority(
         pPriority: *mut INT,
     ) -> HRESULT,
-});
+}}
 ENUM!{enum DXGI_ADAPTER_FLAG {
     DXGI_ADAPTER_FLAG_NONE,
     DXGI_ADAPTER_FLAG_REMOTE,
@@ -351,21 +359,21 @@ STRUCT!{struct DXGI_DISPLAY_COLOR_SPACE {
     PrimaryCoordinates: [[FLOAT; 2]; 8],
     WhitePoints: [[FLOAT; 2]; 16],
 }}
-RIDL!(#[uuid(0x770aae78, 0xf26f, 0x4dba, 0xa8, 0x29, 0x25, 0x3c, 0x83, 0xd1, 0xb3, 0x87)]
+RIDL!{#[uuid(0x770aae78, 0xf26f, 0x4dba, 0xa8, 0x29, 0x25, 0x3c, 0x83, 0xd1, 0xb3, 0x87)]
 interface IDXGIFactory1(IDXGIFactory1Vtbl): IDXGIFactory(IDXGIFactoryVtbl) {
     fn EnumAdapters1(
         Adapter: UINT,
         ppAdapter: *mut *mut IDXGIAdapter1,
     ) -> HRESULT,
     fn IsCurrent() -> BOOL,
-});
-RIDL!(#[uuid(0x29038f61, 0x3839, 0x4626, 0x91, 0xfd, 0x08, 0x68, 0x79, 0x01, 0x1a, 0x05)]
+}}
+RIDL!{#[uuid(0x29038f61, 0x3839, 0x4626, 0x91, 0xfd, 0x08, 0x68, 0x79, 0x01, 0x1a, 0x05)]
 interface IDXGIAdapter1(IDXGIAdapter1Vtbl): IDXGIAdapter(IDXGIAdapterVtbl) {
     fn GetDesc1(
         pDesc: *mut DXGI_ADAPTER_DESC1,
     ) -> HRESULT,
-});
-RIDL!(#[uuid(0x77db970f, 0x6276, 0x48ba, 0xba, 0x28, 0x07, 0x01, 0x43, 0xb4, 0x39, 0x2c)]
+}}
+RIDL!{#[uuid(0x77db970f, 0x6276, 0x48ba, 0xba, 0x28, 0x07, 0x01, 0x43, 0xb4, 0x39, 0x2c)]
 interface IDXGIDevice1(IDXGIDevice1Vtbl): IDXGIDevice(IDXGIDeviceVtbl) {
     fn SetMaximumFrameLatency(
         MaxLatency: UINT,
@@ -373,7 +381,7 @@ interface IDXGIDevice1(IDXGIDevice1Vtbl): IDXGIDevice(IDXGIDeviceVtbl) {
     fn GetMaximumFrameLatency(
         pMaxLatency: *mut UINT,
     ) -> HRESULT,
-});
+}}
 DEFINE_GUID!{IID_IDXGIObject,
     0xaec22fb8, 0x76f3, 0x4639, 0x9b, 0xe0, 0x28, 0xeb, 0x43, 0xa6, 0x7a, 0x2e}
 DEFINE_GUID!{IID_IDXGIDeviceSubObject,
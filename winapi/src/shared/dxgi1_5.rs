@@ -18,7 +18,7 @@ use shared::dxgiformat::DXGI_FORMAT;
 use shared::minwindef::UINT;
 use um::unknwnbase::IUnknown;
 use um::winnt::HRESULT;
-RIDL!(#[uuid(0x80a07424, 0xab52, 0x42eb, 0x83, 0x3c, 0x0c, 0x42, 0xfd, 0x28, 0x2d, 0x98)]
+RIDL!{#[uuid(0x80a07424, 0xab52, 0x42eb, 0x83, 0x3c, 0x0c, 0x42, 0xfd, 0x28, 0x2d, 0x98)]
 interface IDXGIOutput5(IDXGIOutput5Vtbl): IDXGIOutput4(IDXGIOutput4Vtbl) {
     fn DuplicateOutput1(
         pDevice: *mut IUnknown,
@@ -27,7 +27,7 @@ interface IDXGIOutput5(IDXGIOutput5Vtbl): IDXGIOutput4(IDXGIOutput4Vtbl) {
         pSupportedFormats: *const DXGI_FORMAT,
         ppOutputDuplication: *mut *mut IDXGIOutputDuplication,
     )-> HRESULT,
-});
+}}
 ENUM!{enum DXGI_HDR_METADATA_TYPE {
     DXGI_HDR_METADATA_TYPE_NONE = 0,
     DXGI_HDR_METADATA_TYPE_HDR10 = 1,
@@ -42,14 +42,14 @@ STRUCT!{struct DXGI_HDR_METADATA_HDR10 {
     MaxContentLightLevel: UINT16,
     MaxFrameAverageLightLevel: UINT16,
 }}
-RIDL!(#[uuid(0x3d585d5a, 0xbd4a, 0x489e, 0xb1, 0xf4, 0x3d, 0xbc, 0xb6, 0x45, 0x2f, 0xfb)]
+RIDL!{#[uuid(0x3d585d5a, 0xbd4a, 0x489e, 0xb1, 0xf4, 0x3d, 0xbc, 0xb6, 0x45, 0x2f, 0xfb)]
 interface IDXGISwapChain4(IDXGISwapChain4Vtbl): IDXGISwapChain3(IDXGISwapChain3Vtbl) {
     fn SetHDRMetaData(
         Type: DXGI_HDR_METADATA_TYPE,
         Size: UINT,
         pMetaData: *mut c_void,
     )-> HRESULT,
-});
+}}
 ENUM!{enum DXGI_OFFER_RESOURCE_FLAGS {
     DXGI_OFFER_RESOURCE_FLAG_ALLOW_DECOMMIT = 0x1,
 }}
@@ -58,7 +58,7 @@ ENUM!{enum DXGI_RECLAIM_RESOURCE_RESULTS {
     DXGI_RECLAIM_RESOURCE_RESULT_DISCARDED = 1,
     DXGI_RECLAIM_RESOURCE_RESULT_NOT_COMMITTED = 2,
 }}
-RIDL!(#[uuid(0x95b4f95f, 0xd8da, 0x4ca4, 0x9e, 0xe6, 0x3b, 0x76, 0xd5, 0x96, 0x8a, 0x10)]
+RIDL!{#[uuid(0x95b4f95f, 0xd8da, 0x4ca4, 0x9e, 0xe6, 0x3b, 0x76, 0xd5, 0x96, 0x8a, 0x10)]
 interface IDXGIDevice4(IDXGIDevice4Vtbl): IDXGIDevice3(IDXGIDevice3Vtbl) {
     fn OfferResources1(
         NumResources: UINT,
@@ -71,18 +71,18 @@ interface IDXGIDevice4(IDXGIDevice4Vtbl): IDXGIDevice3(IDXGIDevice3Vtbl) {
         ppResources: *mut *mut IDXGIResource,
         pResults: *mut DXGI_RECLAIM_RESOURCE_RESULTS,
     ) -> HRESULT,
-});
+}}
 ENUM!{enum DXGI_FEATURE {
     DXGI_FEATURE_PRESENT_ALLOW_TEARING = 0,
 }}
-RIDL!(#[uuid(0x7632e1f5, 0xee65, 0x4dca, 0x87, 0xfd, 0x84, 0xcd, 0x75, 0xf8, 0x83, 0x8d)]
+RIDL!{#[uuid(0x7632e1f5, 0xee65, 0x4dca, 0x87, 0xfd, 0x84, 0xcd, 0x75, 0xf8, 0x83, 0x8d)]
 interface IDXGIFactory5(IDXGIFactory5Vtbl): IDXGIFactory4(IDXGIFactory4Vtbl) {
     fn CheckFeatureSupport(
         Feature: DXGI_FEATURE,
         pFeatureSupportData: *mut c_void,
         FeatureSupportDataSize: UINT,
     ) -> HRESULT,
-});
+}}
 DEFINE_GUID!{IID_IDXGIOutput5,
     0x80A07424, 0xAB52, 0x42EB, 0x83, 0x3C, 0x0C, 0x42, 0xFD, 0x28, 0x2D, 0x98}
 DEFINE_GUID!{IID_IDXGISwapChain4,
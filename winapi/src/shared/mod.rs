@@ -23,8 +23,11 @@
 #[cfg(feature = "dxgi1_3")] pub mod dxgi1_3;
 #[cfg(feature = "dxgi1_4")] pub mod dxgi1_4;
 #[cfg(feature = "dxgi1_5")] pub mod dxgi1_5;
+#[cfg(feature = "dxgi1_6")] pub mod dxgi1_6;
 #[cfg(feature = "dxgiformat")] pub mod dxgiformat;
 #[cfg(feature = "dxgitype")] pub mod dxgitype;
+#[cfg(feature = "evntprov")] pub mod evntprov;
+#[cfg(feature = "evntrace")] pub mod evntrace;
 pub mod guiddef;
 #[cfg(feature = "hidclass")] pub mod hidclass;
 #[cfg(feature = "hidpi")] pub mod hidpi;
@@ -33,6 +36,7 @@ pub mod guiddef;
 #[cfg(feature = "in6addr")] pub mod in6addr;
 #[cfg(feature = "inaddr")] pub mod inaddr;
 #[cfg(feature = "intsafe")] pub mod intsafe;
+#[cfg(feature = "ks")] pub mod ks;
 #[cfg(feature = "ksmedia")] pub mod ksmedia;
 #[cfg(feature = "ktmtypes")] pub mod ktmtypes;
 #[cfg(feature = "lmcons")] pub mod lmcons;
@@ -47,8 +51,11 @@ pub mod guiddef;
 #[cfg(feature = "rpc")] pub mod rpc;
 #[cfg(feature = "rpcdce")] pub mod rpcdce;
 #[cfg(feature = "rpcndr")] pub mod rpcndr;
+#[cfg(feature = "sddl")] pub mod sddl;
 #[cfg(feature = "sspi")] pub mod sspi;
 #[cfg(feature = "stralign")] pub mod stralign;
+#[cfg(feature = "transportsettingcommon")] pub mod transportsettingcommon;
+#[cfg(feature = "tvout")] pub mod tvout;
 #[cfg(feature = "usb")] pub mod usb;
 #[cfg(feature = "usbiodef")] pub mod usbiodef;
 #[cfg(feature = "usbspec")] pub mod usbspec;
@@ -56,6 +63,7 @@ pub mod guiddef;
 #[cfg(feature = "windowsx")] pub mod windowsx;
 #[cfg(feature = "winerror")] pub mod winerror;
 #[cfg(feature = "winusbio")] pub mod winusbio;
+#[cfg(feature = "wmistr")] pub mod wmistr;
 #[cfg(feature = "wnnc")] pub mod wnnc;
 #[cfg(feature = "ws2def")] pub mod ws2def;
 #[cfg(feature = "ws2ipdef")] pub mod ws2ipdef;
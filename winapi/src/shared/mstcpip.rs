@@ -198,9 +198,9 @@ ENUM!{enum SOCKET_USAGE_TYPE {
 }}
 ENUM!{enum SOCKET_SECURITY_PROTOCOL {
     SOCKET_SECURITY_PROTOCOL_DEFAULT,
-   SOCKET_SECURITY_PROTOCOL_IPSEC,
-   SOCKET_SECURITY_PROTOCOL_IPSEC2,
-   SOCKET_SECURITY_PROTOCOL_INVALID,
+    SOCKET_SECURITY_PROTOCOL_IPSEC,
+    SOCKET_SECURITY_PROTOCOL_IPSEC2,
+    SOCKET_SECURITY_PROTOCOL_INVALID,
 }}
 STRUCT!{struct SOCKET_SECURITY_SETTINGS {
     SecurityProtocol: SOCKET_SECURITY_PROTOCOL,
@@ -472,7 +472,7 @@ extern "system" {
         Port: PUSHORT,
     ) -> LONG;
 }
-DECLARE_HANDLE!(DL_EUI48, _DL_EUI48);
+DECLARE_HANDLE!{DL_EUI48, _DL_EUI48}
 pub type PDL_EUI48 = *mut DL_EUI48;
 extern "system" {
     pub fn RtlEthernetAddressToStringA(
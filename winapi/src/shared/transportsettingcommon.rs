@@ -0,0 +1,11 @@
+// Copyright © 2018 winapi-rs developers
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your option.
+// All files in the project carrying such notice may not be copied, modified, or distributed
+// except according to those terms.
+use shared::guiddef::GUID;
+STRUCT!{struct TRANSPORT_SETTING_ID {
+    Guid: GUID,
+}}
+pub type PTRANSPORT_SETTING_ID = *mut TRANSPORT_SETTING_ID;
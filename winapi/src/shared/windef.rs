@@ -8,30 +8,30 @@
 use ctypes::c_void;
 use shared::minwindef::{DWORD, HFILE, WORD};
 use um::winnt::{LONG, SHORT};
-DECLARE_HANDLE!(HWND, HWND__);
-DECLARE_HANDLE!(HHOOK, HHOOK__);
+DECLARE_HANDLE!{HWND, HWND__}
+DECLARE_HANDLE!{HHOOK, HHOOK__}
 pub type HGDIOBJ = *mut c_void;
-DECLARE_HANDLE!(HACCEL, HACCEL__);
-DECLARE_HANDLE!(HBITMAP, HBITMAP__);
-DECLARE_HANDLE!(HBRUSH, HBRUSH__);
-DECLARE_HANDLE!(HCOLORSPACE, HCOLORSPACE__);
-DECLARE_HANDLE!(HDC, HDC__);
-DECLARE_HANDLE!(HGLRC, HGLRC__);
-DECLARE_HANDLE!(HDESK, HDESK__);
-DECLARE_HANDLE!(HENHMETAFILE, HENHMETAFILE__);
-DECLARE_HANDLE!(HFONT, HFONT__);
-DECLARE_HANDLE!(HICON, HICON__);
-DECLARE_HANDLE!(HMENU, HMENU__);
-DECLARE_HANDLE!(HPALETTE, HPALETTE__);
-DECLARE_HANDLE!(HPEN, HPEN__);
-DECLARE_HANDLE!(HWINEVENTHOOK, HWINEVENTHOOK__);
-DECLARE_HANDLE!(HMONITOR, HMONITOR__);
-DECLARE_HANDLE!(HUMPD, HUMPD__);
+DECLARE_HANDLE!{HACCEL, HACCEL__}
+DECLARE_HANDLE!{HBITMAP, HBITMAP__}
+DECLARE_HANDLE!{HBRUSH, HBRUSH__}
+DECLARE_HANDLE!{HCOLORSPACE, HCOLORSPACE__}
+DECLARE_HANDLE!{HDC, HDC__}
+DECLARE_HANDLE!{HGLRC, HGLRC__}
+DECLARE_HANDLE!{HDESK, HDESK__}
+DECLARE_HANDLE!{HENHMETAFILE, HENHMETAFILE__}
+DECLARE_HANDLE!{HFONT, HFONT__}
+DECLARE_HANDLE!{HICON, HICON__}
+DECLARE_HANDLE!{HMENU, HMENU__}
+DECLARE_HANDLE!{HPALETTE, HPALETTE__}
+DECLARE_HANDLE!{HPEN, HPEN__}
+DECLARE_HANDLE!{HWINEVENTHOOK, HWINEVENTHOOK__}
+DECLARE_HANDLE!{HMONITOR, HMONITOR__}
+DECLARE_HANDLE!{HUMPD, HUMPD__}
 pub type HCURSOR = HICON;
 pub type COLORREF = DWORD;
 pub type LPCOLORREF = *mut DWORD;
 pub const HFILE_ERROR: HFILE = -1;
-STRUCT!{struct RECT {
+STRUCT!{#[debug] struct RECT {
     left: LONG,
     top: LONG,
     right: LONG,
@@ -41,7 +41,7 @@ pub type PRECT = *mut RECT;
 pub type NPRECT = *mut RECT;
 pub type LPRECT = *mut RECT;
 pub type LPCRECT = *const RECT;
-STRUCT!{struct RECTL {
+STRUCT!{#[debug] struct RECTL {
     left: LONG,
     top: LONG,
     right: LONG,
@@ -103,7 +103,7 @@ pub const DC_TRUETYPE: DWORD = 15;
 pub const DC_PAPERNAMES: DWORD = 16;
 pub const DC_ORIENTATION: DWORD = 17;
 pub const DC_COPIES: DWORD = 18;
-DECLARE_HANDLE!(DPI_AWARENESS_CONTEXT, DPI_AWARENESS_CONTEXT__);
+DECLARE_HANDLE!{DPI_AWARENESS_CONTEXT, DPI_AWARENESS_CONTEXT__}
 ENUM!{enum DPI_AWARENESS {
     DPI_AWARENESS_INVALID = -1i32 as u32,
     DPI_AWARENESS_UNAWARE = 0,
@@ -102,7 +102,7 @@ pub type PSOCKET_ADDRESS = *mut SOCKET_ADDRESS;
 pub type LPSOCKET_ADDRESS = *mut SOCKET_ADDRESS;
 STRUCT!{struct SOCKET_ADDRESS_LIST {
     iAddressCount: INT,
-    Address: [SOCKET_ADDRESS; 0],
+    Address: [SOCKET_ADDRESS; 1],
 }}
 pub type PSOCKET_ADDRESS_LIST = *mut SOCKET_ADDRESS_LIST;
 pub type LPSOCKET_ADDRESS_LIST = *mut SOCKET_ADDRESS_LIST;
@@ -5,8 +5,8 @@
 // All files in the project carrying such notice may not be copied, modified, or distributed
 // except according to those terms.
 //! Mappings for the contents of wstypes.h
-use ctypes::{c_double, c_short, c_ushort, wchar_t};
-use shared::guiddef::{GUID};
+use ctypes::{c_double, c_short, c_ushort, c_void, wchar_t};
+use shared::guiddef::GUID;
 use shared::minwindef::{BYTE, DWORD, ULONG, USHORT, WORD};
 use shared::ntdef::{LONG, LONGLONG, ULONGLONG};
 use shared::wtypesbase::{FLAGGED_WORD_BLOB, OLECHAR};
@@ -62,6 +62,12 @@ ENUM!{enum VARENUM {
     VT_RESERVED = 0x8000,
     VT_ILLEGAL = 0xffff,
 }}
+ENUM!{enum DVASPECT {
+    DVASPECT_CONTENT = 1,
+    DVASPECT_THUMBNAIL = 2,
+    DVASPECT_ICON = 4,
+    DVASPECT_DOCPRINT = 8,
+}}
 pub const VT_ILLEGALMASKED: VARENUM = VT_BSTR_BLOB;
 pub const VT_TYPEMASK: VARENUM = VT_BSTR_BLOB;
 pub type PROPID = ULONG;
@@ -69,6 +75,7 @@ STRUCT!{struct PROPERTYKEY {
     fmtid: GUID,
     pid: DWORD,
 }}
+pub type HMETAFILEPICT = *mut c_void;
 pub type DATE = c_double;
 STRUCT!{struct CY {
     int64: LONGLONG,
@@ -87,6 +94,7 @@ pub type wireBSTR = *mut FLAGGED_WORD_BLOB;
 pub type BSTR = *mut OLECHAR;
 pub type LPBSTR = *mut BSTR;
 pub type VARIANT_BOOL = c_short;
+pub const VARIANT_TRUE: VARIANT_BOOL = -1;
 UNION!{union __MIDL_IWinTypes_0001 {
     [usize; 1],
     dwValue dwValue_mut: DWORD,
@@ -8,7 +8,7 @@ use ctypes::c_char;
 use shared::minwindef::{BOOL, DWORD, LPDWORD};
 use um::minwinbase::LPOVERLAPPED;
 use um::winbase::{LPCOMMCONFIG, LPCOMMPROP, LPCOMMTIMEOUTS, LPCOMSTAT, LPDCB};
-use um::winnt::{HANDLE};
+use um::winnt::HANDLE;
 extern "system" {
     pub fn ClearCommBreak(
         hFile: HANDLE
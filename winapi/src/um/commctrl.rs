@@ -50,13 +50,11 @@ pub const ICC_PAGESCROLLER_CLASS: DWORD = 0x1000;
 pub const ICC_NATIVEFNTCTL_CLASS: DWORD = 0x2000;
 pub const ICC_STANDARD_CLASSES: DWORD = 0x4000;
 pub const ICC_LINK_CLASS: DWORD = 0x8000;
-
 extern "system" {
     pub fn InitCommonControlsEx(
         lpInitCtrls: *const INITCOMMONCONTROLSEX,
     ) -> BOOL;
 }
-
 pub const ODT_HEADER: UINT = 100;
 pub const ODT_TAB: UINT = 101;
 pub const ODT_LISTVIEW: UINT = 102;
@@ -293,7 +291,6 @@ pub const ILC_MIRROR: UINT = 0x00002000;
 pub const ILC_PERITEMMIRROR: UINT = 0x00008000;
 pub const ILC_ORIGINALSIZE: UINT = 0x00010000;
 pub const ILC_HIGHQUALITYSCALE: UINT = 0x00020000;
-
 extern "system" {
     pub fn ImageList_Create(
         cx: c_int,
@@ -366,7 +363,6 @@ pub const ILS_SATURATE: DWORD = 0x00000004;
 pub const ILS_ALPHA: DWORD = 0x00000008;
 pub const ILGT_NORMAL: DWORD = 0x00000000;
 pub const ILGT_ASYNC : DWORD = 0x00000001;
-
 extern "system" {
     pub fn ImageList_Draw(
         himl: HIMAGELIST,
@@ -378,7 +374,6 @@ extern "system" {
     ) -> BOOL;
 }
 pub const HBITMAP_CALLBACK: HBITMAP = -1isize as HBITMAP;
-
 extern "system" {
     pub fn ImageList_Replace(
         himl: HIMAGELIST,
@@ -436,7 +431,6 @@ extern "system" {
 }
 pub const ILCF_MOVE: UINT = 0x00000000;
 pub const ILCF_SWAP: UINT = 0x00000001;
-
 extern "system" {
     pub fn ImageList_Copy(
         himlDst: HIMAGELIST,
@@ -2068,7 +2062,7 @@ pub const LPSTR_TEXTCALLBACKW: LPWSTR = -1isize as LPWSTR;
 pub const LPSTR_TEXTCALLBACKA: LPSTR = -1isize as LPSTR;
 pub const I_IMAGECALLBACK: c_int = -1;
 pub const I_IMAGENONE: c_int = -2;
-pub const I_COLUMNSCALLBACK: UINT = -1i32 as UINT;
+pub const I_COLUMNSCALLBACK: UINT = -1i32 as u32;
 pub const LVM_GETITEMA: UINT = LVM_FIRST + 5;
 pub const LVM_GETITEMW: UINT = LVM_FIRST + 75;
 pub const LVM_SETITEMA: UINT = LVM_FIRST + 6;
@@ -2687,7 +2681,7 @@ STRUCT!{struct NMLVDISPINFOW {
 pub type LPNMLVDISPINFOW = *mut NMLVDISPINFOW;
 pub const LVN_KEYDOWN: UINT = LVN_FIRST - 55;
 pub type LV_KEYDOWN = NMLVKEYDOWN;
-STRUCT!{struct NMLVKEYDOWN {
+STRUCT!{#[repr(packed)] struct NMLVKEYDOWN {
     hdr: NMHDR,
     wVKey: WORD,
     flags: UINT,
@@ -3107,7 +3101,7 @@ pub const TVN_ITEMCHANGEDA: UINT = TVN_FIRST - 18;
 pub const TVN_ITEMCHANGEDW: UINT = TVN_FIRST - 19;
 pub const TVN_ASYNCDRAW: UINT = TVN_FIRST - 20;
 pub type TV_KEYDOWN = NMTVKEYDOWN;
-STRUCT!{struct NMTVKEYDOWN {
+STRUCT!{#[repr(packed)] struct NMTVKEYDOWN {
     hdr: NMHDR,
     wVKey: WORD,
     flags: UINT,
@@ -3386,7 +3380,7 @@ pub const TCM_SETUNICODEFORMAT: UINT = CCM_SETUNICODEFORMAT;
 pub const TCM_GETUNICODEFORMAT: UINT = CCM_GETUNICODEFORMAT;
 pub const TCN_KEYDOWN: UINT = TCN_FIRST - 0;
 pub type TC_KEYDOWN = NMTCKEYDOWN;
-STRUCT!{struct NMTCKEYDOWN {
+STRUCT!{#[repr(packed)] struct NMTCKEYDOWN {
     hdr: NMHDR,
     wVKey: WORD,
     flags: UINT,
@@ -3678,7 +3672,7 @@ STRUCT!{struct NMIPADDRESS {
 pub type LPNMIPADDRESS = *mut NMIPADDRESS;
 #[inline]
 pub fn MAKEIPRANGE(low: BYTE, high: BYTE) -> LPARAM {
-    (high << 8 + low) as LPARAM
+    (((high as WORD) << 8) + low as WORD) as LPARAM
 }
 #[inline]
 pub fn MAKEIPADDRESS(b1: DWORD, b2: DWORD, b3: DWORD, b4: DWORD) -> LPARAM {
@@ -3734,9 +3728,9 @@ pub const PGF_SCROLLRIGHT: c_int = 8;
 pub const PGK_SHIFT: BOOL = 1;
 pub const PGK_CONTROL: BOOL = 2;
 pub const PGK_MENU: BOOL = 4;
-STRUCT!{struct NMPGSCROLL {
+STRUCT!{#[repr(packed)] struct NMPGSCROLL {
     hdr: NMHDR,
-    fwKeys: BOOL,
+    fwKeys: WORD,
     rcParent: RECT,
     iDir: c_int,
     iXpos: c_int,
@@ -3906,7 +3900,7 @@ ENUM!{enum TASKDIALOG_NOTIFICATIONS {
     TDN_HELP = 9,
     TDN_EXPANDO_BUTTON_CLICKED = 10,
 }}
-STRUCT!{struct TASKDIALOG_BUTTON {
+STRUCT!{#[repr(packed)] struct TASKDIALOG_BUTTON {
     nButtonID: c_int,
     pszButtonText: PCWSTR,
 }}
@@ -3932,17 +3926,17 @@ ENUM!{enum TASKDIALOG_COMMON_BUTTON_FLAGS {
     TDCBF_RETRY_BUTTON = 0x0010,
     TDCBF_CLOSE_BUTTON = 0x0020,
 }}
-UNION!{union TASKDIALOGCONFIG_u1 {
-    [u8; 8],
+UNION!{#[repr(packed)] union TASKDIALOGCONFIG_u1 {
+    [usize; 1],
     hMainIcon hMainIcon_mut: HICON,
     pszMainIcon pszMainIcon_mut: PCWSTR,
 }}
-UNION!{union TASKDIALOGCONFIG_u2 {
-    [u8; 8],
+UNION!{#[repr(packed)] union TASKDIALOGCONFIG_u2 {
+    [usize; 1],
     hFooterIcon hFooterIcon_mut: HICON,
     pszFooterIcon pszFooterIcon_mut: PCWSTR,
 }}
-STRUCT!{struct TASKDIALOGCONFIG {
+STRUCT!{#[repr(packed)] struct TASKDIALOGCONFIG {
     cbSize: UINT,
     hwndParent: HWND,
     hInstance: HINSTANCE,
@@ -353,12 +353,11 @@ STRUCT!{struct D3D_SHADER_MACRO {
 pub type LPD3D_SHADER_MACRO = *mut D3D_SHADER_MACRO;
 DEFINE_GUID!{IID_ID3D10Blob,
     0x8ba5fb08, 0x5195, 0x40e2, 0xac, 0x58, 0x0d, 0x98, 0x9c, 0x3a, 0x01, 0x02}
-RIDL!(#[uuid(0x8ba5fb08, 0x5195, 0x40e2, 0xac, 0x58, 0xd, 0x98, 0x9c, 0x3a, 0x1, 0x2)]
+RIDL!{#[uuid(0x8ba5fb08, 0x5195, 0x40e2, 0xac, 0x58, 0xd, 0x98, 0x9c, 0x3a, 0x1, 0x2)]
 interface ID3D10Blob(ID3D10BlobVtbl): IUnknown(IUnknownVtbl) {
     fn GetBufferPointer() -> LPVOID,
     fn GetBufferSize() -> SIZE_T,
-}
-);
+}}
 pub type LPD3D10BLOB = *mut ID3D10Blob;
 pub type ID3DBlob = ID3D10Blob;
 pub type LPD3DBLOB = *mut ID3DBlob;
@@ -8,7 +8,7 @@
 use ctypes::{c_double, c_float};
 use shared::ntdef::{HRESULT, LARGE_INTEGER};
 use um::unknwnbase::{IUnknown, IUnknownVtbl};
-RIDL!(#[uuid(0xcbfd91d9, 0x51b2, 0x45e4, 0xb3, 0xde, 0xd1, 0x9c, 0xcf, 0xb8, 0x63, 0xc5)]
+RIDL!{#[uuid(0xcbfd91d9, 0x51b2, 0x45e4, 0xb3, 0xde, 0xd1, 0x9c, 0xcf, 0xb8, 0x63, 0xc5)]
 interface IDCompositionAnimation(IDCompositionAnimationVtbl): IUnknown(IUnknownVtbl) {
     fn Reset() -> HRESULT,
     fn SetAbsoluteBeginTime(
@@ -36,4 +36,4 @@ interface IDCompositionAnimation(IDCompositionAnimationVtbl): IUnknown(IUnknownV
         endOffset: c_double,
         endValue: c_float,
     ) -> HRESULT,
-});
+}}
@@ -5,7 +5,7 @@
 // All files in the project carrying such notice may not be copied, modified, or distributed
 // except according to those terms
 //! This file contains structures, function prototypes, and definitions for the NetService API
-use ctypes::{c_long};
+use ctypes::c_long;
 use shared::lmcons::NET_API_STATUS;
 use shared::minwindef::{DWORD, LPBYTE, LPDWORD};
 use um::winnt::{LPCWSTR, LPWSTR};
@@ -12,7 +12,7 @@ use um::winnt::{LPSTR, WCHAR};
 //109 (Win 7 SDK)
 pub type MMVERSION = UINT;
 pub type MMRESULT = UINT;
-STRUCT!{struct MMTIME_smpte {
+STRUCT!{#[repr(packed)] struct MMTIME_smpte {
     hour: BYTE,
     min: BYTE,
     sec: BYTE,
@@ -21,11 +21,11 @@ STRUCT!{struct MMTIME_smpte {
     dummy: BYTE,
     pad: [BYTE; 2],
 }}
-STRUCT!{struct MMTIME_midi {
+STRUCT!{#[repr(packed)] struct MMTIME_midi {
     songptrpos: DWORD,
 }}
-UNION!{union MMTIME_u {
-    [u8; 8],
+UNION!{#[repr(packed)] union MMTIME_u {
+    [u32; 2],
     ms ms_mut: DWORD,
     sample sample_mut: DWORD,
     cb cb_mut: DWORD,
@@ -33,7 +33,7 @@ UNION!{union MMTIME_u {
     smpte smpte_mut: MMTIME_smpte,
     midi midi_mut: MMTIME_midi,
 }}
-STRUCT!{struct MMTIME {
+STRUCT!{#[repr(packed)] struct MMTIME {
     wType: UINT,
     u: MMTIME_u,
 }}
@@ -122,8 +122,8 @@ pub const WAVERR_STILLPLAYING: MMRESULT = WAVERR_BASE + 1;
 pub const WAVERR_UNPREPARED: MMRESULT = WAVERR_BASE + 2;
 pub const WAVERR_SYNC: MMRESULT = WAVERR_BASE + 3;
 pub const WAVERR_LASTERROR: MMRESULT = WAVERR_BASE + 3;
-DECLARE_HANDLE!(HWAVEIN, HWAVEIN__);
-DECLARE_HANDLE!(HWAVEOUT, HWAVEOUT__);
+DECLARE_HANDLE!{HWAVEIN, HWAVEIN__}
+DECLARE_HANDLE!{HWAVEOUT, HWAVEOUT__}
 pub type LPHWAVEIN = *mut HWAVEIN;
 pub type LPHWAVEOUT = *mut HWAVEOUT;
 pub const WOM_OPEN: UINT = MM_WOM_OPEN;
@@ -139,7 +139,7 @@ pub const WAVE_MAPPED: DWORD = 0x0004;
 pub const WAVE_FORMAT_DIRECT: DWORD = 0x0008;
 pub const WAVE_FORMAT_DIRECT_QUERY: DWORD = WAVE_FORMAT_QUERY | WAVE_FORMAT_DIRECT;
 pub const WAVE_MAPPED_DEFAULT_COMMUNICATION_DEVICE: DWORD = 0x0010;
-STRUCT!{struct WAVEHDR {
+STRUCT!{#[repr(packed)] struct WAVEHDR {
     lpData: LPSTR,
     dwBufferLength: DWORD,
     dwBytesRecorded: DWORD,
@@ -152,7 +152,7 @@ STRUCT!{struct WAVEHDR {
 pub type PWAVEHDR = *mut WAVEHDR;
 pub type NPWAVEHDR = *mut WAVEHDR;
 pub type LPWAVEHDR = *mut WAVEHDR;
-STRUCT!{struct WAVEOUTCAPSW {
+STRUCT!{#[repr(packed)] struct WAVEOUTCAPSW {
     wMid: WORD,
     wPid: WORD,
     vDriverVersion: MMVERSION,
@@ -165,7 +165,7 @@ STRUCT!{struct WAVEOUTCAPSW {
 pub type PWAVEOUTCAPSW = *mut WAVEOUTCAPSW;
 pub type NPWAVEOUTCAPSW = *mut WAVEOUTCAPSW;
 pub type LPWAVEOUTCAPSW = *mut WAVEOUTCAPSW;
-STRUCT!{struct WAVEINCAPSW {
+STRUCT!{#[repr(packed)] struct WAVEINCAPSW {
     wMid: WORD,
     wPid: WORD,
     vDriverVersion: MMVERSION,
@@ -212,14 +212,14 @@ pub const TIMERR_NOERROR: MMRESULT = 0;
 pub const TIMERR_NOCANDO: MMRESULT = TIMERR_BASE + 1;
 pub const TIMERR_STRUCT: MMRESULT = TIMERR_BASE + 33;
 //2198 (Win 7 SDK)
-STRUCT!{struct TIMECAPS {
+STRUCT!{#[repr(packed)] struct TIMECAPS {
     wPeriodMin: UINT,
     wPeriodMax: UINT,
 }}
 pub type PTIMECAPS = *mut TIMECAPS;
 pub type NPTIMECAPS = *mut TIMECAPS;
 pub type LPTIMECAPS = *mut TIMECAPS;
-STRUCT!{struct MIDIHDR {
+STRUCT!{#[repr(packed)] struct MIDIHDR {
     lpData: LPSTR,
     dwBufferLength: DWORD,
     dwBytesRecorded: DWORD,
@@ -228,12 +228,12 @@ STRUCT!{struct MIDIHDR {
     lpNext: *mut MIDIHDR,
     reserved: DWORD_PTR,
     dwOffset: DWORD,
-    dwReserved: [DWORD_PTR; 4],
+    dwReserved: [DWORD_PTR; 8],
 }}
 pub type PMIDIHDR = *mut MIDIHDR;
 pub type NPMIDIHDR = *mut MIDIHDR;
 pub type LPMIDIHDR = *mut MIDIHDR;
-STRUCT!{struct MIDIINCAPSW {
+STRUCT!{#[repr(packed)] struct MIDIINCAPSW {
     wMid: WORD,
     wPid: WORD,
     vDriverVersion: MMVERSION,
@@ -243,7 +243,7 @@ STRUCT!{struct MIDIINCAPSW {
 pub type PMIDIINCAPSW = *mut MIDIINCAPSW;
 pub type NPMIDIINCAPSW = *mut MIDIINCAPSW;
 pub type LPMIDIINCAPSW = *mut MIDIINCAPSW;
-STRUCT!{struct MIDIOUTCAPSW {
+STRUCT!{#[repr(packed)] struct MIDIOUTCAPSW {
     wMid: WORD,
     wPid: WORD,
     vDriverVersion: MMVERSION,
@@ -257,11 +257,11 @@ STRUCT!{struct MIDIOUTCAPSW {
 pub type PMIDIOUTCAPSW = *mut MIDIOUTCAPSW;
 pub type NPMIDIOUTCAPSW = *mut MIDIOUTCAPSW;
 pub type LPMIDIOUTCAPSW = *mut MIDIOUTCAPSW;
-DECLARE_HANDLE!(HMIDIIN, HMIDIIN__);
-DECLARE_HANDLE!(HMIDIOUT, HMIDIOUT__);
+DECLARE_HANDLE!{HMIDIIN, HMIDIIN__}
+DECLARE_HANDLE!{HMIDIOUT, HMIDIOUT__}
 pub type LPHMIDIIN = *mut HMIDIIN;
 pub type LPHMIDIOUT = *mut HMIDIOUT;
-DECLARE_HANDLE!(HMIDISTRM, HMIDISTRM__);
-DECLARE_HANDLE!(HMIDI, HMIDI__);
+DECLARE_HANDLE!{HMIDISTRM, HMIDISTRM__}
+DECLARE_HANDLE!{HMIDI, HMIDI__}
 pub type LPHMIDISTRM = *mut HMIDISTRM;
 pub type LPHMIDI = *mut HMIDI;
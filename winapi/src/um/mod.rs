@@ -6,9 +6,22 @@
 // except according to those terms.
 //! Headers for user mode only
 pub mod gl;
+#[cfg(feature = "accctrl")] pub mod accctrl;
+#[cfg(feature = "aclapi")] pub mod aclapi;
+#[cfg(feature = "appmgmt")] pub mod appmgmt;
 #[cfg(feature = "audioclient")] pub mod audioclient;
 #[cfg(feature = "audiosessiontypes")] pub mod audiosessiontypes;
 #[cfg(feature = "avrt")] pub mod avrt;
+#[cfg(feature = "bits")] pub mod bits;
+#[cfg(feature = "bits10_1")] pub mod bits10_1;
+#[cfg(feature = "bits1_5")] pub mod bits1_5;
+#[cfg(feature = "bits2_0")] pub mod bits2_0;
+#[cfg(feature = "bits2_5")] pub mod bits2_5;
+#[cfg(feature = "bits3_0")] pub mod bits3_0;
+#[cfg(feature = "bits4_0")] pub mod bits4_0;
+#[cfg(feature = "bits5_0")] pub mod bits5_0;
+#[cfg(feature = "bitscfg")] pub mod bitscfg;
+#[cfg(feature = "bitsmsg")] pub mod bitsmsg;
 #[cfg(feature = "cfgmgr32")] pub mod cfgmgr32;
 #[cfg(feature = "cguid")] pub mod cguid;
 #[cfg(feature = "combaseapi")] pub mod combaseapi;
@@ -22,10 +35,12 @@ pub mod gl;
 #[cfg(feature = "d2d1")] pub mod d2d1;
 #[cfg(feature = "d2d1_1")] pub mod d2d1_1;
 #[cfg(feature = "d2d1_2")] pub mod d2d1_2;
+#[cfg(feature = "d2d1_3")] pub mod d2d1_3;
 #[cfg(feature = "d2d1effectauthor")] pub mod d2d1effectauthor;
 #[cfg(feature = "d2d1effects")] pub mod d2d1effects;
 #[cfg(feature = "d2d1effects_1")] pub mod d2d1effects_1;
 #[cfg(feature = "d2d1effects_2")] pub mod d2d1effects_2;
+#[cfg(feature = "d2d1svg")] pub mod d2d1svg;
 #[cfg(feature = "d2dbasetypes")] pub mod d2dbasetypes;
 #[cfg(feature = "d3d")] pub mod d3d;
 #[cfg(feature = "d3d10")] pub mod d3d10;
@@ -43,6 +58,7 @@ pub mod gl;
 #[cfg(feature = "d3d11on12")] pub mod d3d11on12;
 #[cfg(feature = "d3d11sdklayers")] pub mod d3d11sdklayers;
 #[cfg(feature = "d3d11shader")] pub mod d3d11shader;
+#[cfg(feature = "d3d11tokenizedprogramformat")] pub mod d3d11tokenizedprogramformat;
 #[cfg(feature = "d3d12")] pub mod d3d12;
 #[cfg(feature = "d3d12sdklayers")] pub mod d3d12sdklayers;
 #[cfg(feature = "d3d12shader")] pub mod d3d12shader;
@@ -55,6 +71,7 @@ pub mod gl;
 #[cfg(feature = "datetimeapi")] pub mod datetimeapi;
 #[cfg(feature = "davclnt")] pub mod davclnt;
 #[cfg(feature = "dbghelp")] pub mod dbghelp;
+#[cfg(feature = "dbt")] pub mod dbt;
 #[cfg(feature = "dcommon")] pub mod dcommon;
 #[cfg(feature = "dcomp")] pub mod dcomp;
 #[cfg(feature = "dcompanimation")] pub mod dcompanimation;
@@ -63,6 +80,7 @@ pub mod gl;
 #[cfg(feature = "ddrawi")] pub mod ddrawi;
 #[cfg(feature = "ddrawint")] pub mod ddrawint;
 #[cfg(feature = "debugapi")] pub mod debugapi;
+#[cfg(feature = "devicetopology")] pub mod devicetopology;
 #[cfg(feature = "dinput")] pub mod dinput;
 #[cfg(feature = "dmksctl")] pub mod dmksctl;
 #[cfg(feature = "dmusicc")] pub mod dmusicc;
@@ -82,11 +100,17 @@ pub mod gl;
 #[cfg(feature = "dxdiag")] pub mod dxdiag;
 #[cfg(feature = "dxfile")] pub mod dxfile;
 #[cfg(feature = "dxgidebug")] pub mod dxgidebug;
+#[cfg(feature = "dxva2api")] pub mod dxva2api;
+#[cfg(feature = "dxvahd")] pub mod dxvahd;
+#[cfg(feature = "endpointvolume")] pub mod endpointvolume;
 #[cfg(feature = "errhandlingapi")] pub mod errhandlingapi;
+#[cfg(feature = "evntcons")] pub mod evntcons;
+#[cfg(feature = "exdisp")] pub mod exdisp;
 #[cfg(feature = "fibersapi")] pub mod fibersapi;
 #[cfg(feature = "fileapi")] pub mod fileapi;
 #[cfg(feature = "handleapi")] pub mod handleapi;
 #[cfg(feature = "heapapi")] pub mod heapapi;
+#[cfg(feature = "highlevelmonitorconfigurationapi")] pub mod highlevelmonitorconfigurationapi;
 #[cfg(feature = "http")] pub mod http;
 #[cfg(feature = "imm")] pub mod imm;
 #[cfg(feature = "interlockedapi")] pub mod interlockedapi;
@@ -112,6 +136,7 @@ pub mod gl;
 #[cfg(feature = "lmsvc")] pub mod lmsvc;
 #[cfg(feature = "lmuse")] pub mod lmuse;
 #[cfg(feature = "lmwksta")] pub mod lmwksta;
+#[cfg(feature = "lowlevelmonitorconfigurationapi")] pub mod lowlevelmonitorconfigurationapi;
 #[cfg(feature = "lsalookup")] pub mod lsalookup;
 #[cfg(feature = "memoryapi")] pub mod memoryapi;
 #[cfg(feature = "minschannel")] pub mod minschannel;
@@ -121,20 +146,27 @@ pub mod gl;
 #[cfg(feature = "mmsystem")] pub mod mmsystem;
 #[cfg(feature = "msaatext")] pub mod msaatext;
 #[cfg(feature = "mscat")] pub mod mscat;
+#[cfg(feature = "mschapp")] pub mod mschapp;
 #[cfg(feature = "mssip")] pub mod mssip;
 #[cfg(feature = "namedpipeapi")] pub mod namedpipeapi;
 #[cfg(feature = "namespaceapi")] pub mod namespaceapi;
 #[cfg(feature = "nb30")] pub mod nb30;
 #[cfg(feature = "ncrypt")] pub mod ncrypt;
+#[cfg(feature = "ntlsa")] pub mod ntlsa;
 #[cfg(feature = "ntsecapi")] pub mod ntsecapi;
 #[cfg(feature = "oaidl")] pub mod oaidl;
 #[cfg(feature = "objbase")] pub mod objbase;
 #[cfg(feature = "objidl")] pub mod objidl;
 #[cfg(feature = "objidlbase")] pub mod objidlbase;
 #[cfg(feature = "ocidl")] pub mod ocidl;
+#[cfg(feature = "ole2")] pub mod ole2;
 #[cfg(feature = "oleauto")] pub mod oleauto;
 #[cfg(feature = "olectl")] pub mod olectl;
+#[cfg(feature = "oleidl")] pub mod oleidl;
+#[cfg(feature = "opmapi")] pub mod opmapi;
 #[cfg(feature = "pdh")] pub mod pdh;
+#[cfg(feature = "perflib")] pub mod perflib;
+#[cfg(feature = "physicalmonitorenumerationapi")] pub mod physicalmonitorenumerationapi;
 #[cfg(feature = "playsoundapi")] pub mod playsoundapi;
 #[cfg(feature = "powerbase")] pub mod powerbase;
 #[cfg(feature = "powersetting")] pub mod powersetting;
@@ -151,6 +183,7 @@ pub mod gl;
 #[cfg(feature = "psapi")] pub mod psapi;
 #[cfg(feature = "realtimeapiset")] pub mod realtimeapiset;
 #[cfg(feature = "reason")] pub mod reason;
+#[cfg(feature = "restartmanager")] pub mod restartmanager;
 #[cfg(feature = "restrictederrorinfo")] pub mod restrictederrorinfo;
 #[cfg(feature = "rmxfguid")] pub mod rmxfguid;
 #[cfg(feature = "sapi")] pub mod sapi;
@@ -160,6 +193,7 @@ pub mod gl;
 #[cfg(feature = "sapiddk51")] pub mod sapiddk51;
 #[cfg(feature = "schannel")] pub mod schannel;
 #[cfg(feature = "securityappcontainer")] pub mod securityappcontainer;
+#[cfg(feature = "securitybaseapi")] pub mod securitybaseapi;
 #[cfg(feature = "servprov")] pub mod servprov;
 #[cfg(feature = "setupapi")] pub mod setupapi;
 #[cfg(feature = "shellapi")] pub mod shellapi;
@@ -193,10 +227,12 @@ pub mod gl;
 #[cfg(feature = "userenv")] pub mod userenv;
 #[cfg(feature = "usp10")] pub mod usp10;
 #[cfg(feature = "utilapiset")] pub mod utilapiset;
+#[cfg(feature = "uxtheme")] pub mod uxtheme;
 #[cfg(feature = "vsbackup")] pub mod vsbackup;
 #[cfg(feature = "vss")] pub mod vss;
 #[cfg(feature = "vsserror")] pub mod vsserror;
 #[cfg(feature = "vswriter")] pub mod vswriter;
+#[cfg(feature = "wct")] pub mod wct;
 #[cfg(feature = "werapi")] pub mod werapi;
 #[cfg(feature = "winbase")] pub mod winbase;
 #[cfg(feature = "wincodec")] pub mod wincodec;
@@ -205,6 +241,7 @@ pub mod gl;
 #[cfg(feature = "wincred")] pub mod wincred;
 #[cfg(feature = "wincrypt")] pub mod wincrypt;
 #[cfg(feature = "windowsceip")] pub mod windowsceip;
+#[cfg(feature = "winefs")] pub mod winefs;
 #[cfg(feature = "winevt")] pub mod winevt;
 #[cfg(feature = "wingdi")] pub mod wingdi;
 #[cfg(feature = "winhttp")] pub mod winhttp;
@@ -215,6 +252,7 @@ pub mod gl;
 #[cfg(feature = "winnls")] pub mod winnls;
 #[cfg(feature = "winnt")] pub mod winnt;
 #[cfg(feature = "winreg")] pub mod winreg;
+#[cfg(feature = "winsafer")] pub mod winsafer;
 #[cfg(feature = "winscard")] pub mod winscard;
 #[cfg(feature = "winsmcrd")] pub mod winsmcrd;
 #[cfg(feature = "winsock2")] pub mod winsock2;
@@ -1,4 +1,4 @@
-// Copyright © 202017 winapi-rs developers
+// Copyright © 2017 winapi-rs developers
 // Licensed under the Apache License, Version 2.0
 // <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
 // <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your option.
@@ -63,6 +63,9 @@ extern "system" {
         ClientComputerName: LPWSTR,
         ClientComputerNameLength: ULONG,
     ) -> BOOL;
+    pub fn ImpersonateNamedPipeClient(
+        hNamedPipe: HANDLE,
+    ) -> BOOL;
     pub fn GetNamedPipeInfo(
         hNamedPipe: HANDLE,
         lpFlags: LPDWORD,
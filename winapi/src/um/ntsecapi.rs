@@ -5,10 +5,9 @@
 // All files in the project carrying such notice may not be copied, modified, or distributed
 // except according to those terms.
 //! This module defines the Local Security Authority APIs.
-
 use shared::basetsd::{ULONG64, ULONG_PTR};
 use shared::guiddef::GUID;
-use shared::minwindef::{PUCHAR, UCHAR, ULONG, USHORT};
+use shared::minwindef::{PUCHAR, PULONG, UCHAR, ULONG, USHORT};
 use shared::ntdef::NTSTATUS;
 use shared::sspi::SecHandle;
 use um::lsalookup::{
@@ -16,141 +15,141 @@ use um::lsalookup::{
 };
 use um::subauth::{PUNICODE_STRING, STRING, UNICODE_STRING};
 use um::winnt::{
-    ACCESS_MASK, ANYSIZE_ARRAY, BOOLEAN, LARGE_INTEGER, LONG, LUID, PSECURITY_DESCRIPTOR, PSID,
-    PVOID, PWSTR, QUOTA_LIMITS, SHORT, SID_NAME_USE, STANDARD_RIGHTS_EXECUTE, STANDARD_RIGHTS_READ,
-    STANDARD_RIGHTS_REQUIRED, STANDARD_RIGHTS_WRITE, ULONGLONG
+    ACCESS_MASK, ANYSIZE_ARRAY, BOOLEAN, HANDLE, LARGE_INTEGER, LONG, LUID, PACL, PCSTR, PCWSTR,
+    PSECURITY_DESCRIPTOR, PSID, PSTR, PVOID, PWSTR, QUOTA_LIMITS, SECURITY_INFORMATION, SHORT, SID,
+    SID_NAME_USE, STANDARD_RIGHTS_EXECUTE, STANDARD_RIGHTS_READ, STANDARD_RIGHTS_REQUIRED,
+    STANDARD_RIGHTS_WRITE, ULONGLONG
 };
-
-DEFINE_GUID!(Audit_System_SecurityStateChange,
-    0x0cce9210, 0x69ae, 0x11d9, 0xbe, 0xd3, 0x50, 0x50, 0x54, 0x50, 0x30, 0x30);
-DEFINE_GUID!(Audit_System_SecuritySubsystemExtension,
-    0x0cce9211, 0x69ae, 0x11d9, 0xbe, 0xd3, 0x50, 0x50, 0x54, 0x50, 0x30, 0x30);
-DEFINE_GUID!(Audit_System_Integrity,
-    0x0cce9212, 0x69ae, 0x11d9, 0xbe, 0xd3, 0x50, 0x50, 0x54, 0x50, 0x30, 0x30);
-DEFINE_GUID!(Audit_System_IPSecDriverEvents,
-    0x0cce9213, 0x69ae, 0x11d9, 0xbe, 0xd3, 0x50, 0x50, 0x54, 0x50, 0x30, 0x30);
-DEFINE_GUID!(Audit_System_Others,
-    0x0cce9214, 0x69ae, 0x11d9, 0xbe, 0xd3, 0x50, 0x50, 0x54, 0x50, 0x30, 0x30);
-DEFINE_GUID!(Audit_Logon_Logon,
-    0x0cce9215, 0x69ae, 0x11d9, 0xbe, 0xd3, 0x50, 0x50, 0x54, 0x50, 0x30, 0x30);
-DEFINE_GUID!(Audit_Logon_Logoff,
-    0x0cce9216, 0x69ae, 0x11d9, 0xbe, 0xd3, 0x50, 0x50, 0x54, 0x50, 0x30, 0x30);
-DEFINE_GUID!(Audit_Logon_AccountLockout,
-    0x0cce9217, 0x69ae, 0x11d9, 0xbe, 0xd3, 0x50, 0x50, 0x54, 0x50, 0x30, 0x30);
-DEFINE_GUID!(Audit_Logon_IPSecMainMode,
-    0x0cce9218, 0x69ae, 0x11d9, 0xbe, 0xd3, 0x50, 0x50, 0x54, 0x50, 0x30, 0x30);
-DEFINE_GUID!(Audit_Logon_IPSecQuickMode,
-    0x0cce9219, 0x69ae, 0x11d9, 0xbe, 0xd3, 0x50, 0x50, 0x54, 0x50, 0x30, 0x30);
-DEFINE_GUID!(Audit_Logon_IPSecUserMode,
-    0x0cce921a, 0x69ae, 0x11d9, 0xbe, 0xd3, 0x50, 0x50, 0x54, 0x50, 0x30, 0x30);
-DEFINE_GUID!(Audit_Logon_SpecialLogon,
-    0x0cce921b, 0x69ae, 0x11d9, 0xbe, 0xd3, 0x50, 0x50, 0x54, 0x50, 0x30, 0x30);
-DEFINE_GUID!(Audit_Logon_Others,
-    0x0cce921c, 0x69ae, 0x11d9, 0xbe, 0xd3, 0x50, 0x50, 0x54, 0x50, 0x30, 0x30);
-DEFINE_GUID!(Audit_ObjectAccess_FileSystem,
-    0x0cce921d, 0x69ae, 0x11d9, 0xbe, 0xd3, 0x50, 0x50, 0x54, 0x50, 0x30, 0x30);
-DEFINE_GUID!(Audit_ObjectAccess_Registry,
-    0x0cce921e, 0x69ae, 0x11d9, 0xbe, 0xd3, 0x50, 0x50, 0x54, 0x50, 0x30, 0x30);
-DEFINE_GUID!(Audit_ObjectAccess_Kernel,
-    0x0cce921f, 0x69ae, 0x11d9, 0xbe, 0xd3, 0x50, 0x50, 0x54, 0x50, 0x30, 0x30);
-DEFINE_GUID!(Audit_ObjectAccess_Sam,
-    0x0cce9220, 0x69ae, 0x11d9, 0xbe, 0xd3, 0x50, 0x50, 0x54, 0x50, 0x30, 0x30);
-DEFINE_GUID!(Audit_ObjectAccess_CertificationServices,
-    0x0cce9221, 0x69ae, 0x11d9, 0xbe, 0xd3, 0x50, 0x50, 0x54, 0x50, 0x30, 0x30);
-DEFINE_GUID!(Audit_ObjectAccess_ApplicationGenerated,
-    0x0cce9222, 0x69ae, 0x11d9, 0xbe, 0xd3, 0x50, 0x50, 0x54, 0x50, 0x30, 0x30);
-DEFINE_GUID!(Audit_ObjectAccess_Handle,
-    0x0cce9223, 0x69ae, 0x11d9, 0xbe, 0xd3, 0x50, 0x50, 0x54, 0x50, 0x30, 0x30);
-DEFINE_GUID!(Audit_ObjectAccess_Share,
-    0x0cce9224, 0x69ae, 0x11d9, 0xbe, 0xd3, 0x50, 0x50, 0x54, 0x50, 0x30, 0x30);
-DEFINE_GUID!(Audit_ObjectAccess_FirewallPacketDrops,
-    0x0cce9225, 0x69ae, 0x11d9, 0xbe, 0xd3, 0x50, 0x50, 0x54, 0x50, 0x30, 0x30);
-DEFINE_GUID!(Audit_ObjectAccess_FirewallConnection,
-    0x0cce9226, 0x69ae, 0x11d9, 0xbe, 0xd3, 0x50, 0x50, 0x54, 0x50, 0x30, 0x30);
-DEFINE_GUID!(Audit_ObjectAccess_Other,
-    0x0cce9227, 0x69ae, 0x11d9, 0xbe, 0xd3, 0x50, 0x50, 0x54, 0x50, 0x30, 0x30);
-DEFINE_GUID!(Audit_PrivilegeUse_Sensitive,
-    0x0cce9228, 0x69ae, 0x11d9, 0xbe, 0xd3, 0x50, 0x50, 0x54, 0x50, 0x30, 0x30);
-DEFINE_GUID!(Audit_PrivilegeUse_NonSensitive,
-    0x0cce9229, 0x69ae, 0x11d9, 0xbe, 0xd3, 0x50, 0x50, 0x54, 0x50, 0x30, 0x30);
-DEFINE_GUID!(Audit_PrivilegeUse_Others,
-    0x0cce922a, 0x69ae, 0x11d9, 0xbe, 0xd3, 0x50, 0x50, 0x54, 0x50, 0x30, 0x30);
-DEFINE_GUID!(Audit_DetailedTracking_ProcessCreation,
-    0x0cce922b, 0x69ae, 0x11d9, 0xbe, 0xd3, 0x50, 0x50, 0x54, 0x50, 0x30, 0x30);
-DEFINE_GUID!(Audit_DetailedTracking_ProcessTermination,
-    0x0cce922c, 0x69ae, 0x11d9, 0xbe, 0xd3, 0x50, 0x50, 0x54, 0x50, 0x30, 0x30);
-DEFINE_GUID!(Audit_DetailedTracking_DpapiActivity,
-    0x0cce922d, 0x69ae, 0x11d9, 0xbe, 0xd3, 0x50, 0x50, 0x54, 0x50, 0x30, 0x30);
-DEFINE_GUID!(Audit_DetailedTracking_RpcCall,
-    0x0cce922e, 0x69ae, 0x11d9, 0xbe, 0xd3, 0x50, 0x50, 0x54, 0x50, 0x30, 0x30);
-DEFINE_GUID!(Audit_PolicyChange_AuditPolicy,
-    0x0cce922f, 0x69ae, 0x11d9, 0xbe, 0xd3, 0x50, 0x50, 0x54, 0x50, 0x30, 0x30);
-DEFINE_GUID!(Audit_PolicyChange_AuthenticationPolicy,
-    0x0cce9230, 0x69ae, 0x11d9, 0xbe, 0xd3, 0x50, 0x50, 0x54, 0x50, 0x30, 0x30);
-DEFINE_GUID!(Audit_PolicyChange_AuthorizationPolicy,
-    0x0cce9231, 0x69ae, 0x11d9, 0xbe, 0xd3, 0x50, 0x50, 0x54, 0x50, 0x30, 0x30);
-DEFINE_GUID!(Audit_PolicyChange_MpsscvRulePolicy,
-    0x0cce9232, 0x69ae, 0x11d9, 0xbe, 0xd3, 0x50, 0x50, 0x54, 0x50, 0x30, 0x30);
-DEFINE_GUID!(Audit_PolicyChange_WfpIPSecPolicy,
-    0x0cce9233, 0x69ae, 0x11d9, 0xbe, 0xd3, 0x50, 0x50, 0x54, 0x50, 0x30, 0x30);
-DEFINE_GUID!(Audit_PolicyChange_Others,
-    0x0cce9234, 0x69ae, 0x11d9, 0xbe, 0xd3, 0x50, 0x50, 0x54, 0x50, 0x30, 0x30);
-DEFINE_GUID!(Audit_AccountManagement_UserAccount,
-    0x0cce9235, 0x69ae, 0x11d9, 0xbe, 0xd3, 0x50, 0x50, 0x54, 0x50, 0x30, 0x30);
-DEFINE_GUID!(Audit_AccountManagement_ComputerAccount,
-    0x0cce9236, 0x69ae, 0x11d9, 0xbe, 0xd3, 0x50, 0x50, 0x54, 0x50, 0x30, 0x30);
-DEFINE_GUID!(Audit_AccountManagement_SecurityGroup,
-    0x0cce9237, 0x69ae, 0x11d9, 0xbe, 0xd3, 0x50, 0x50, 0x54, 0x50, 0x30, 0x30);
-DEFINE_GUID!(Audit_AccountManagement_DistributionGroup,
-    0x0cce9238, 0x69ae, 0x11d9, 0xbe, 0xd3, 0x50, 0x50, 0x54, 0x50, 0x30, 0x30);
-DEFINE_GUID!(Audit_AccountManagement_ApplicationGroup,
-    0x0cce9239, 0x69ae, 0x11d9,  0xbe, 0xd3, 0x50, 0x50, 0x54, 0x50, 0x30, 0x30);
-DEFINE_GUID!(Audit_AccountManagement_Others,
-    0x0cce923a, 0x69ae, 0x11d9, 0xbe, 0xd3, 0x50, 0x50, 0x54, 0x50, 0x30, 0x30);
-DEFINE_GUID!(Audit_DSAccess_DSAccess,
-    0x0cce923b, 0x69ae, 0x11d9, 0xbe, 0xd3, 0x50, 0x50, 0x54, 0x50, 0x30, 0x30);
-DEFINE_GUID!(Audit_DsAccess_AdAuditChanges,
-    0x0cce923c, 0x69ae, 0x11d9, 0xbe, 0xd3, 0x50, 0x50, 0x54, 0x50, 0x30, 0x30);
-DEFINE_GUID!(Audit_Ds_Replication,
-    0x0cce923d, 0x69ae, 0x11d9, 0xbe, 0xd3, 0x50, 0x50, 0x54, 0x50, 0x30, 0x30);
-DEFINE_GUID!(Audit_Ds_DetailedReplication,
-    0x0cce923e, 0x69ae, 0x11d9, 0xbe, 0xd3, 0x50, 0x50, 0x54, 0x50, 0x30, 0x30);
-DEFINE_GUID!(Audit_AccountLogon_CredentialValidation,
-    0x0cce923f, 0x69ae, 0x11d9, 0xbe, 0xd3, 0x50, 0x50, 0x54, 0x50, 0x30, 0x30);
-DEFINE_GUID!(Audit_AccountLogon_Kerberos,
-    0x0cce9240, 0x69ae, 0x11d9, 0xbe, 0xd3, 0x50, 0x50, 0x54, 0x50, 0x30, 0x30);
-DEFINE_GUID!(Audit_AccountLogon_Others,
-    0x0cce9241, 0x69ae, 0x11d9, 0xbe, 0xd3, 0x50, 0x50, 0x54, 0x50, 0x30, 0x30);
-DEFINE_GUID!(Audit_AccountLogon_KerbCredentialValidation,
-    0x0cce9242, 0x69ae, 0x11d9, 0xbe, 0xd3, 0x50, 0x50, 0x54, 0x50, 0x30, 0x30);
-DEFINE_GUID!(Audit_Logon_NPS,
-    0x0cce9243, 0x69ae, 0x11d9, 0xbe, 0xd3, 0x50, 0x50, 0x54, 0x50, 0x30, 0x30);
-DEFINE_GUID!(Audit_ObjectAccess_DetailedFileShare,
-    0x0cce9244, 0x69ae, 0x11d9, 0xbe, 0xd3, 0x50, 0x50, 0x54, 0x50, 0x30, 0x30);
-DEFINE_GUID!(Audit_ObjectAccess_RemovableStorage,
-    0x0cce9245, 0x69ae, 0x11d9,  0xbe, 0xd3, 0x50, 0x50, 0x54, 0x50, 0x30, 0x30);
-DEFINE_GUID!(Audit_ObjectAccess_CbacStaging,
-    0x0cce9246, 0x69ae, 0x11d9, 0xbe, 0xd3, 0x50, 0x50, 0x54, 0x50, 0x30, 0x30);
-DEFINE_GUID!(Audit_Logon_Claims,
-    0x0cce9247, 0x69ae, 0x11d9, 0xbe, 0xd3, 0x50, 0x50, 0x54, 0x50, 0x30, 0x30);
-DEFINE_GUID!(Audit_System,
-    0x69979848, 0x797a, 0x11d9, 0xbe, 0xd3, 0x50, 0x50, 0x54, 0x50, 0x30, 0x30);
-DEFINE_GUID!(Audit_Logon,
-    0x69979849, 0x797a, 0x11d9, 0xbe, 0xd3, 0x50, 0x50, 0x54, 0x50, 0x30, 0x30);
-DEFINE_GUID!(Audit_ObjectAccess,
-    0x6997984a, 0x797a, 0x11d9, 0xbe, 0xd3, 0x50, 0x50, 0x54, 0x50, 0x30, 0x30);
-DEFINE_GUID!(Audit_PrivilegeUse,
-    0x6997984b, 0x797a, 0x11d9, 0xbe, 0xd3, 0x50, 0x50, 0x54, 0x50, 0x30, 0x30);
-DEFINE_GUID!(Audit_DetailedTracking,
-    0x6997984c, 0x797a, 0x11d9, 0xbe, 0xd3, 0x50, 0x50, 0x54, 0x50, 0x30, 0x30);
-DEFINE_GUID!(Audit_PolicyChange,
-    0x6997984d, 0x797a, 0x11d9, 0xbe, 0xd3, 0x50, 0x50, 0x54, 0x50, 0x30, 0x30);
-DEFINE_GUID!(Audit_AccountManagement,
-    0x6997984e, 0x797a, 0x11d9, 0xbe, 0xd3, 0x50, 0x50, 0x54, 0x50, 0x30, 0x30);
-DEFINE_GUID!(Audit_DirectoryServiceAccess,
-    0x6997984f, 0x797a, 0x11d9, 0xbe, 0xd3, 0x50, 0x50, 0x54, 0x50, 0x30, 0x30);
-DEFINE_GUID!(Audit_AccountLogon,
-    0x69979850, 0x797a, 0x11d9, 0xbe, 0xd3, 0x50, 0x50, 0x54, 0x50, 0x30, 0x30);
+DEFINE_GUID!{Audit_System_SecurityStateChange,
+    0x0cce9210, 0x69ae, 0x11d9, 0xbe, 0xd3, 0x50, 0x50, 0x54, 0x50, 0x30, 0x30}
+DEFINE_GUID!{Audit_System_SecuritySubsystemExtension,
+    0x0cce9211, 0x69ae, 0x11d9, 0xbe, 0xd3, 0x50, 0x50, 0x54, 0x50, 0x30, 0x30}
+DEFINE_GUID!{Audit_System_Integrity,
+    0x0cce9212, 0x69ae, 0x11d9, 0xbe, 0xd3, 0x50, 0x50, 0x54, 0x50, 0x30, 0x30}
+DEFINE_GUID!{Audit_System_IPSecDriverEvents,
+    0x0cce9213, 0x69ae, 0x11d9, 0xbe, 0xd3, 0x50, 0x50, 0x54, 0x50, 0x30, 0x30}
+DEFINE_GUID!{Audit_System_Others,
+    0x0cce9214, 0x69ae, 0x11d9, 0xbe, 0xd3, 0x50, 0x50, 0x54, 0x50, 0x30, 0x30}
+DEFINE_GUID!{Audit_Logon_Logon,
+    0x0cce9215, 0x69ae, 0x11d9, 0xbe, 0xd3, 0x50, 0x50, 0x54, 0x50, 0x30, 0x30}
+DEFINE_GUID!{Audit_Logon_Logoff,
+    0x0cce9216, 0x69ae, 0x11d9, 0xbe, 0xd3, 0x50, 0x50, 0x54, 0x50, 0x30, 0x30}
+DEFINE_GUID!{Audit_Logon_AccountLockout,
+    0x0cce9217, 0x69ae, 0x11d9, 0xbe, 0xd3, 0x50, 0x50, 0x54, 0x50, 0x30, 0x30}
+DEFINE_GUID!{Audit_Logon_IPSecMainMode,
+    0x0cce9218, 0x69ae, 0x11d9, 0xbe, 0xd3, 0x50, 0x50, 0x54, 0x50, 0x30, 0x30}
+DEFINE_GUID!{Audit_Logon_IPSecQuickMode,
+    0x0cce9219, 0x69ae, 0x11d9, 0xbe, 0xd3, 0x50, 0x50, 0x54, 0x50, 0x30, 0x30}
+DEFINE_GUID!{Audit_Logon_IPSecUserMode,
+    0x0cce921a, 0x69ae, 0x11d9, 0xbe, 0xd3, 0x50, 0x50, 0x54, 0x50, 0x30, 0x30}
+DEFINE_GUID!{Audit_Logon_SpecialLogon,
+    0x0cce921b, 0x69ae, 0x11d9, 0xbe, 0xd3, 0x50, 0x50, 0x54, 0x50, 0x30, 0x30}
+DEFINE_GUID!{Audit_Logon_Others,
+    0x0cce921c, 0x69ae, 0x11d9, 0xbe, 0xd3, 0x50, 0x50, 0x54, 0x50, 0x30, 0x30}
+DEFINE_GUID!{Audit_ObjectAccess_FileSystem,
+    0x0cce921d, 0x69ae, 0x11d9, 0xbe, 0xd3, 0x50, 0x50, 0x54, 0x50, 0x30, 0x30}
+DEFINE_GUID!{Audit_ObjectAccess_Registry,
+    0x0cce921e, 0x69ae, 0x11d9, 0xbe, 0xd3, 0x50, 0x50, 0x54, 0x50, 0x30, 0x30}
+DEFINE_GUID!{Audit_ObjectAccess_Kernel,
+    0x0cce921f, 0x69ae, 0x11d9, 0xbe, 0xd3, 0x50, 0x50, 0x54, 0x50, 0x30, 0x30}
+DEFINE_GUID!{Audit_ObjectAccess_Sam,
+    0x0cce9220, 0x69ae, 0x11d9, 0xbe, 0xd3, 0x50, 0x50, 0x54, 0x50, 0x30, 0x30}
+DEFINE_GUID!{Audit_ObjectAccess_CertificationServices,
+    0x0cce9221, 0x69ae, 0x11d9, 0xbe, 0xd3, 0x50, 0x50, 0x54, 0x50, 0x30, 0x30}
+DEFINE_GUID!{Audit_ObjectAccess_ApplicationGenerated,
+    0x0cce9222, 0x69ae, 0x11d9, 0xbe, 0xd3, 0x50, 0x50, 0x54, 0x50, 0x30, 0x30}
+DEFINE_GUID!{Audit_ObjectAccess_Handle,
+    0x0cce9223, 0x69ae, 0x11d9, 0xbe, 0xd3, 0x50, 0x50, 0x54, 0x50, 0x30, 0x30}
+DEFINE_GUID!{Audit_ObjectAccess_Share,
+    0x0cce9224, 0x69ae, 0x11d9, 0xbe, 0xd3, 0x50, 0x50, 0x54, 0x50, 0x30, 0x30}
+DEFINE_GUID!{Audit_ObjectAccess_FirewallPacketDrops,
+    0x0cce9225, 0x69ae, 0x11d9, 0xbe, 0xd3, 0x50, 0x50, 0x54, 0x50, 0x30, 0x30}
+DEFINE_GUID!{Audit_ObjectAccess_FirewallConnection,
+    0x0cce9226, 0x69ae, 0x11d9, 0xbe, 0xd3, 0x50, 0x50, 0x54, 0x50, 0x30, 0x30}
+DEFINE_GUID!{Audit_ObjectAccess_Other,
+    0x0cce9227, 0x69ae, 0x11d9, 0xbe, 0xd3, 0x50, 0x50, 0x54, 0x50, 0x30, 0x30}
+DEFINE_GUID!{Audit_PrivilegeUse_Sensitive,
+    0x0cce9228, 0x69ae, 0x11d9, 0xbe, 0xd3, 0x50, 0x50, 0x54, 0x50, 0x30, 0x30}
+DEFINE_GUID!{Audit_PrivilegeUse_NonSensitive,
+    0x0cce9229, 0x69ae, 0x11d9, 0xbe, 0xd3, 0x50, 0x50, 0x54, 0x50, 0x30, 0x30}
+DEFINE_GUID!{Audit_PrivilegeUse_Others,
+    0x0cce922a, 0x69ae, 0x11d9, 0xbe, 0xd3, 0x50, 0x50, 0x54, 0x50, 0x30, 0x30}
+DEFINE_GUID!{Audit_DetailedTracking_ProcessCreation,
+    0x0cce922b, 0x69ae, 0x11d9, 0xbe, 0xd3, 0x50, 0x50, 0x54, 0x50, 0x30, 0x30}
+DEFINE_GUID!{Audit_DetailedTracking_ProcessTermination,
+    0x0cce922c, 0x69ae, 0x11d9, 0xbe, 0xd3, 0x50, 0x50, 0x54, 0x50, 0x30, 0x30}
+DEFINE_GUID!{Audit_DetailedTracking_DpapiActivity,
+    0x0cce922d, 0x69ae, 0x11d9, 0xbe, 0xd3, 0x50, 0x50, 0x54, 0x50, 0x30, 0x30}
+DEFINE_GUID!{Audit_DetailedTracking_RpcCall,
+    0x0cce922e, 0x69ae, 0x11d9, 0xbe, 0xd3, 0x50, 0x50, 0x54, 0x50, 0x30, 0x30}
+DEFINE_GUID!{Audit_PolicyChange_AuditPolicy,
+    0x0cce922f, 0x69ae, 0x11d9, 0xbe, 0xd3, 0x50, 0x50, 0x54, 0x50, 0x30, 0x30}
+DEFINE_GUID!{Audit_PolicyChange_AuthenticationPolicy,
+    0x0cce9230, 0x69ae, 0x11d9, 0xbe, 0xd3, 0x50, 0x50, 0x54, 0x50, 0x30, 0x30}
+DEFINE_GUID!{Audit_PolicyChange_AuthorizationPolicy,
+    0x0cce9231, 0x69ae, 0x11d9, 0xbe, 0xd3, 0x50, 0x50, 0x54, 0x50, 0x30, 0x30}
+DEFINE_GUID!{Audit_PolicyChange_MpsscvRulePolicy,
+    0x0cce9232, 0x69ae, 0x11d9, 0xbe, 0xd3, 0x50, 0x50, 0x54, 0x50, 0x30, 0x30}
+DEFINE_GUID!{Audit_PolicyChange_WfpIPSecPolicy,
+    0x0cce9233, 0x69ae, 0x11d9, 0xbe, 0xd3, 0x50, 0x50, 0x54, 0x50, 0x30, 0x30}
+DEFINE_GUID!{Audit_PolicyChange_Others,
+    0x0cce9234, 0x69ae, 0x11d9, 0xbe, 0xd3, 0x50, 0x50, 0x54, 0x50, 0x30, 0x30}
+DEFINE_GUID!{Audit_AccountManagement_UserAccount,
+    0x0cce9235, 0x69ae, 0x11d9, 0xbe, 0xd3, 0x50, 0x50, 0x54, 0x50, 0x30, 0x30}
+DEFINE_GUID!{Audit_AccountManagement_ComputerAccount,
+    0x0cce9236, 0x69ae, 0x11d9, 0xbe, 0xd3, 0x50, 0x50, 0x54, 0x50, 0x30, 0x30}
+DEFINE_GUID!{Audit_AccountManagement_SecurityGroup,
+    0x0cce9237, 0x69ae, 0x11d9, 0xbe, 0xd3, 0x50, 0x50, 0x54, 0x50, 0x30, 0x30}
+DEFINE_GUID!{Audit_AccountManagement_DistributionGroup,
+    0x0cce9238, 0x69ae, 0x11d9, 0xbe, 0xd3, 0x50, 0x50, 0x54, 0x50, 0x30, 0x30}
+DEFINE_GUID!{Audit_AccountManagement_ApplicationGroup,
+    0x0cce9239, 0x69ae, 0x11d9, 0xbe, 0xd3, 0x50, 0x50, 0x54, 0x50, 0x30, 0x30}
+DEFINE_GUID!{Audit_AccountManagement_Others,
+    0x0cce923a, 0x69ae, 0x11d9, 0xbe, 0xd3, 0x50, 0x50, 0x54, 0x50, 0x30, 0x30}
+DEFINE_GUID!{Audit_DSAccess_DSAccess,
+    0x0cce923b, 0x69ae, 0x11d9, 0xbe, 0xd3, 0x50, 0x50, 0x54, 0x50, 0x30, 0x30}
+DEFINE_GUID!{Audit_DsAccess_AdAuditChanges,
+    0x0cce923c, 0x69ae, 0x11d9, 0xbe, 0xd3, 0x50, 0x50, 0x54, 0x50, 0x30, 0x30}
+DEFINE_GUID!{Audit_Ds_Replication,
+    0x0cce923d, 0x69ae, 0x11d9, 0xbe, 0xd3, 0x50, 0x50, 0x54, 0x50, 0x30, 0x30}
+DEFINE_GUID!{Audit_Ds_DetailedReplication,
+    0x0cce923e, 0x69ae, 0x11d9, 0xbe, 0xd3, 0x50, 0x50, 0x54, 0x50, 0x30, 0x30}
+DEFINE_GUID!{Audit_AccountLogon_CredentialValidation,
+    0x0cce923f, 0x69ae, 0x11d9, 0xbe, 0xd3, 0x50, 0x50, 0x54, 0x50, 0x30, 0x30}
+DEFINE_GUID!{Audit_AccountLogon_Kerberos,
+    0x0cce9240, 0x69ae, 0x11d9, 0xbe, 0xd3, 0x50, 0x50, 0x54, 0x50, 0x30, 0x30}
+DEFINE_GUID!{Audit_AccountLogon_Others,
+    0x0cce9241, 0x69ae, 0x11d9, 0xbe, 0xd3, 0x50, 0x50, 0x54, 0x50, 0x30, 0x30}
+DEFINE_GUID!{Audit_AccountLogon_KerbCredentialValidation,
+    0x0cce9242, 0x69ae, 0x11d9, 0xbe, 0xd3, 0x50, 0x50, 0x54, 0x50, 0x30, 0x30}
+DEFINE_GUID!{Audit_Logon_NPS,
+    0x0cce9243, 0x69ae, 0x11d9, 0xbe, 0xd3, 0x50, 0x50, 0x54, 0x50, 0x30, 0x30}
+DEFINE_GUID!{Audit_ObjectAccess_DetailedFileShare,
+    0x0cce9244, 0x69ae, 0x11d9, 0xbe, 0xd3, 0x50, 0x50, 0x54, 0x50, 0x30, 0x30}
+DEFINE_GUID!{Audit_ObjectAccess_RemovableStorage,
+    0x0cce9245, 0x69ae, 0x11d9, 0xbe, 0xd3, 0x50, 0x50, 0x54, 0x50, 0x30, 0x30}
+DEFINE_GUID!{Audit_ObjectAccess_CbacStaging,
+    0x0cce9246, 0x69ae, 0x11d9, 0xbe, 0xd3, 0x50, 0x50, 0x54, 0x50, 0x30, 0x30}
+DEFINE_GUID!{Audit_Logon_Claims,
+    0x0cce9247, 0x69ae, 0x11d9, 0xbe, 0xd3, 0x50, 0x50, 0x54, 0x50, 0x30, 0x30}
+DEFINE_GUID!{Audit_System,
+    0x69979848, 0x797a, 0x11d9, 0xbe, 0xd3, 0x50, 0x50, 0x54, 0x50, 0x30, 0x30}
+DEFINE_GUID!{Audit_Logon,
+    0x69979849, 0x797a, 0x11d9, 0xbe, 0xd3, 0x50, 0x50, 0x54, 0x50, 0x30, 0x30}
+DEFINE_GUID!{Audit_ObjectAccess,
+    0x6997984a, 0x797a, 0x11d9, 0xbe, 0xd3, 0x50, 0x50, 0x54, 0x50, 0x30, 0x30}
+DEFINE_GUID!{Audit_PrivilegeUse,
+    0x6997984b, 0x797a, 0x11d9, 0xbe, 0xd3, 0x50, 0x50, 0x54, 0x50, 0x30, 0x30}
+DEFINE_GUID!{Audit_DetailedTracking,
+    0x6997984c, 0x797a, 0x11d9, 0xbe, 0xd3, 0x50, 0x50, 0x54, 0x50, 0x30, 0x30}
+DEFINE_GUID!{Audit_PolicyChange,
+    0x6997984d, 0x797a, 0x11d9, 0xbe, 0xd3, 0x50, 0x50, 0x54, 0x50, 0x30, 0x30}
+DEFINE_GUID!{Audit_AccountManagement,
+    0x6997984e, 0x797a, 0x11d9, 0xbe, 0xd3, 0x50, 0x50, 0x54, 0x50, 0x30, 0x30}
+DEFINE_GUID!{Audit_DirectoryServiceAccess,
+    0x6997984f, 0x797a, 0x11d9, 0xbe, 0xd3, 0x50, 0x50, 0x54, 0x50, 0x30, 0x30}
+DEFINE_GUID!{Audit_AccountLogon,
+    0x69979850, 0x797a, 0x11d9, 0xbe, 0xd3, 0x50, 0x50, 0x54, 0x50, 0x30, 0x30}
 ENUM!{enum POLICY_AUDIT_EVENT_TYPE {
     AuditCategorySystem = 0,
     AuditCategoryLogon,
@@ -1604,27 +1603,99 @@ pub const AUDIT_GENERIC_WRITE: ULONG = STANDARD_RIGHTS_WRITE | AUDIT_SET_USER_PO
     | AUDIT_SET_MISC_POLICY | AUDIT_SET_SYSTEM_POLICY;
 pub const AUDIT_GENERIC_EXECUTE: ULONG = STANDARD_RIGHTS_EXECUTE;
 extern "system" {
-    // pub fn AuditSetSystemPolicy();
-    // pub fn AuditSetPerUserPolicy();
-    // pub fn AuditQuerySystemPolicy();
-    // pub fn AuditQueryPerUserPolicy();
-    // pub fn AuditEnumeratePerUserPolicy();
-    // pub fn AuditComputeEffectivePolicyBySid();
-    // pub fn AuditComputeEffectivePolicyByToken();
-    // pub fn AuditEnumerateCategories();
-    // pub fn AuditEnumerateSubCategories();
-    // pub fn AuditLookupCategoryNameW();
-    // pub fn AuditLookupCategoryNameA();
-    // pub fn AuditLookupSubCategoryNameW();
-    // pub fn AuditLookupSubCategoryNameA();
-    // pub fn AuditLookupCategoryIdFromCategoryGuid();
-    // pub fn AuditLookupCategoryGuidFromCategoryId();
-    // pub fn AuditSetSecurity();
-    // pub fn AuditQuerySecurity();
-    // pub fn AuditSetGlobalSaclW();
-    // pub fn AuditSetGlobalSaclA();
-    // pub fn AuditQueryGlobalSaclW();
-    // pub fn AuditQueryGlobalSaclA();
+    pub fn AuditSetSystemPolicy(
+        pAuditPolicy: PCAUDIT_POLICY_INFORMATION,
+        PolicyCount: ULONG,
+    ) -> BOOLEAN;
+    pub fn AuditSetPerUserPolicy(
+        pSid: *const SID,
+        pAuditPolicy: PCAUDIT_POLICY_INFORMATION,
+        PolicyCount: ULONG,
+    ) -> BOOLEAN;
+    pub fn AuditQuerySystemPolicy(
+        pSubCategoryGuids: *const GUID,
+        PolicyCount: ULONG,
+        ppAuditPolicy: *mut PAUDIT_POLICY_INFORMATION,
+    ) -> BOOLEAN;
+    pub fn AuditQueryPerUserPolicy(
+        pSid: *const SID,
+        pSubCategoryGuids: *const GUID,
+        PolicyCount: ULONG,
+        ppAuditPolicy: *mut PAUDIT_POLICY_INFORMATION,
+    ) -> BOOLEAN;
+    pub fn AuditEnumeratePerUserPolicy(
+        ppAuditSidArray: *mut PPOLICY_AUDIT_SID_ARRAY
+    ) -> BOOLEAN;
+    pub fn AuditComputeEffectivePolicyBySid(
+        pSid: *const SID,
+        pSubCategoryGuids: *const GUID,
+        dwPolicyCount: ULONG,
+        ppAuditPolicy: *mut PAUDIT_POLICY_INFORMATION,
+    ) -> BOOLEAN;
+    pub fn AuditComputeEffectivePolicyByToken(
+        hTokenHandle: HANDLE,
+        pSubCategoryGuids: *const GUID,
+        dwPolicyCount: ULONG,
+        ppAuditPolicy: *mut PAUDIT_POLICY_INFORMATION,
+    ) -> BOOLEAN;
+    pub fn AuditEnumerateCategories(
+        ppAuditCategoriesArray: *mut *mut GUID,
+        pdwCountReturned: PULONG,
+    ) -> BOOLEAN;
+    pub fn AuditEnumerateSubCategories(
+        pAuditCategoryGuid: *const GUID,
+        bRetrieveAllSubCategories: BOOLEAN,
+        ppAuditSubCategoriesArray: *mut *mut GUID,
+        pdwCountReturned: PULONG,
+    ) -> BOOLEAN;
+    pub fn AuditLookupCategoryNameW(
+        pAuditCategoryGuid: *const GUID,
+        ppszCategoryName: *mut PWSTR,
+    ) -> BOOLEAN;
+    pub fn AuditLookupCategoryNameA(
+        pAuditCategoryGuid: *const GUID,
+        ppszCategoryName: *mut PSTR,
+    ) -> BOOLEAN;
+    pub fn AuditLookupSubCategoryNameW(
+        pAuditSubCategoryGuid: *const GUID,
+        ppszSubCategoryName: *mut PWSTR,
+    ) -> BOOLEAN;
+    pub fn AuditLookupSubCategoryNameA(
+        pAuditSubCategoryGuid: *const GUID,
+        ppszSubCategoryName: *mut PSTR,
+    ) -> BOOLEAN;
+    pub fn AuditLookupCategoryIdFromCategoryGuid(
+        pAuditCategoryGuid: *const GUID,
+        pAuditCategoryId: PPOLICY_AUDIT_EVENT_TYPE,
+    ) -> BOOLEAN;
+    pub fn AuditLookupCategoryGuidFromCategoryId(
+        AuditCategoryId: POLICY_AUDIT_EVENT_TYPE,
+        pAuditCategoryGuid: *mut GUID,
+    ) -> BOOLEAN;
+    pub fn AuditSetSecurity(
+        SecurityInformation: SECURITY_INFORMATION,
+        pSecurityDescriptor: PSECURITY_DESCRIPTOR,
+    ) -> BOOLEAN;
+    pub fn AuditQuerySecurity(
+        SecurityInformation: SECURITY_INFORMATION,
+        ppSecurityDescriptor: *mut PSECURITY_DESCRIPTOR,
+    ) -> BOOLEAN;
+    pub fn AuditSetGlobalSaclW(
+        ObjectTypeName: PCWSTR,
+        Acl: PACL,
+    ) -> BOOLEAN;
+    pub fn AuditSetGlobalSaclA(
+        ObjectTypeName: PCSTR,
+        Acl: PACL,
+    ) -> BOOLEAN;
+    pub fn AuditQueryGlobalSaclW(
+        ObjectTypeName: PCWSTR,
+        Acl: *mut PACL,
+    ) -> BOOLEAN;
+    pub fn AuditQueryGlobalSaclA(
+        ObjectTypeName: PCSTR,
+        Acl: *mut PACL
+    ) -> BOOLEAN;
     pub fn AuditFree(
         Buffer: PVOID,
     );
@@ -106,7 +106,7 @@ STRUCT!{struct PDH_BROWSE_DLG_CONFIG_A {
     dwDefaultDetailLevel: DWORD,
     szDialogBoxCaption: LPSTR,
 }}
-BITFIELD!(PDH_BROWSE_DLG_CONFIG_A flags: DWORD [
+BITFIELD!{PDH_BROWSE_DLG_CONFIG_A flags: DWORD [
     IncludeInstanceIndex set_IncludeInstanceIndex[0..1],
     SingleCounterPerAdd set_SingleCounterPerAdd[1..2],
     SingleCounterPerDialog set_SingleCounterPerDialog[2..3],
@@ -117,7 +117,7 @@ BITFIELD!(PDH_BROWSE_DLG_CONFIG_A flags: DWORD [
     DisableMachineSelection set_DisableMachineSelection[7..8],
     IncludeCostlyObjects set_IncludeCostlyObjects[8..9],
     ShowObjectBrowser set_ShowObjectBrowser[9..10],
-]);
+]}
 STRUCT!{struct PDH_BROWSE_DLG_CONFIG_W {
     flags: DWORD,
     hWndOwner: HWND,
@@ -130,7 +130,7 @@ STRUCT!{struct PDH_BROWSE_DLG_CONFIG_W {
     dwDefaultDetailLevel: DWORD,
     szDialogBoxCaption: LPWSTR,
 }}
-BITFIELD!(PDH_BROWSE_DLG_CONFIG_W flags: DWORD [
+BITFIELD!{PDH_BROWSE_DLG_CONFIG_W flags: DWORD [
     IncludeInstanceIndex set_IncludeInstanceIndex[0..1],
     SingleCounterPerAdd set_SingleCounterPerAdd[1..2],
     SingleCounterPerDialog set_SingleCounterPerDialog[2..3],
@@ -141,7 +141,7 @@ BITFIELD!(PDH_BROWSE_DLG_CONFIG_W flags: DWORD [
     DisableMachineSelection set_DisableMachineSelection[7..8],
     IncludeCostlyObjects set_IncludeCostlyObjects[8..9],
     ShowObjectBrowser set_ShowObjectBrowser[9..10],
-]);
+]}
 STRUCT!{struct PDH_COUNTER_PATH_ELEMENTS_A {
     szMachineName: LPSTR,
     szObjectName: LPSTR,
@@ -238,7 +238,7 @@ STRUCT!{struct PDH_BROWSE_DLG_CONFIG_HA {
     dwDefaultDetailLevel: DWORD,
     szDialogBoxCaption: LPSTR,
 }}
-BITFIELD!(PDH_BROWSE_DLG_CONFIG_HA flags: DWORD [
+BITFIELD!{PDH_BROWSE_DLG_CONFIG_HA flags: DWORD [
     IncludeInstanceIndex set_IncludeInstanceIndex[0..1],
     SingleCounterPerAdd set_SingleCounterPerAdd[1..2],
     SingleCounterPerDialog set_SingleCounterPerDialog[2..3],
@@ -249,7 +249,7 @@ BITFIELD!(PDH_BROWSE_DLG_CONFIG_HA flags: DWORD [
     DisableMachineSelection set_DisableMachineSelection[7..8],
     IncludeCostlyObjects set_IncludeCostlyObjects[8..9],
     ShowObjectBrowser set_ShowObjectBrowser[9..10],
-]);
+]}
 STRUCT!{struct PDH_BROWSE_DLG_CONFIG_HW {
     flags: DWORD,
     hWndOwner: HWND,
@@ -262,7 +262,7 @@ STRUCT!{struct PDH_BROWSE_DLG_CONFIG_HW {
     dwDefaultDetailLevel: DWORD,
     szDialogBoxCaption: LPWSTR,
 }}
-BITFIELD!(PDH_BROWSE_DLG_CONFIG_HW flags: DWORD [
+BITFIELD!{PDH_BROWSE_DLG_CONFIG_HW flags: DWORD [
     IncludeInstanceIndex set_IncludeInstanceIndex[0..1],
     SingleCounterPerAdd set_SingleCounterPerAdd[1..2],
     SingleCounterPerDialog set_SingleCounterPerDialog[2..3],
@@ -273,7 +273,7 @@ BITFIELD!(PDH_BROWSE_DLG_CONFIG_HW flags: DWORD [
     DisableMachineSelection set_DisableMachineSelection[7..8],
     IncludeCostlyObjects set_IncludeCostlyObjects[8..9],
     ShowObjectBrowser set_ShowObjectBrowser[9..10],
-]);
+]}
 extern "system" {
     pub fn PdhGetDllVersion(
         lpdwVersion: LPDWORD,
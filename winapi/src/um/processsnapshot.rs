@@ -29,7 +29,7 @@ ENUM!{enum PSS_CAPTURE_FLAGS {
     PSS_CREATE_FORCE_BREAKAWAY = 0x10000000,
     PSS_CREATE_USE_VM_ALLOCATIONS = 0x20000000,
     PSS_CREATE_MEASURE_PERFORMANCE = 0x40000000,
-    PSS_CREATE_RELEASE_SECTION = -2147483648i32 as u32,
+    PSS_CREATE_RELEASE_SECTION = 0x80000000,
 }}
 ENUM!{enum PSS_QUERY_INFORMATION_CLASS {
     PSS_QUERY_PROCESS_INFORMATION = 0,
@@ -51,8 +51,8 @@ ENUM!{enum PSS_DUPLICATE_FLAGS {
     PSS_DUPLICATE_NONE = 0x00,
     PSS_DUPLICATE_CLOSE_SOURCE = 0x01,
 }}
-DECLARE_HANDLE!(HPSS, HPSS__);
-DECLARE_HANDLE!(HPSSWALK, HPSSWALK__);
+DECLARE_HANDLE!{HPSS, HPSS__}
+DECLARE_HANDLE!{HPSSWALK, HPSSWALK__}
 FN!{stdcall pAllocRoutine(
     Context: *mut c_void,
     Size: DWORD,
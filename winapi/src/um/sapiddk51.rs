@@ -16,7 +16,7 @@ use um::unknwnbase::{IUnknown, IUnknownVtbl};
 use um::winnt::{HANDLE, HRESULT, LONG, LPCWSTR, LPWSTR, PVOID, ULONGLONG, WCHAR};
 pub const SPRECOEXTENSION: &'static str = "RecoExtension";
 pub const SPALTERNATESCLSID: &'static str = "AlternatesCLSID";
-RIDL!(#[uuid(0xf8e690f0, 0x39cb, 0x4843, 0xb8, 0xd7, 0xc8, 0x46, 0x96, 0xe1, 0x11, 0x9d)]
+RIDL!{#[uuid(0xf8e690f0, 0x39cb, 0x4843, 0xb8, 0xd7, 0xc8, 0x46, 0x96, 0xe1, 0x11, 0x9d)]
 interface ISpTokenUI(ISpTokenUIVtbl): IUnknown(IUnknownVtbl) {
     fn IsUISupported(
         pszTypeOfUI: LPCWSTR,
@@ -34,8 +34,8 @@ interface ISpTokenUI(ISpTokenUIVtbl): IUnknown(IUnknownVtbl) {
         pToken: *mut ISpObjectToken,
         punkObject: *mut IUnknown,
     ) -> HRESULT,
-});
-RIDL!(#[uuid(0x06b64f9f, 0x7fda, 0x11d2, 0xb4, 0xf2, 0x00, 0xc0, 0x4f, 0x79, 0x73, 0x96)]
+}}
+RIDL!{#[uuid(0x06b64f9f, 0x7fda, 0x11d2, 0xb4, 0xf2, 0x00, 0xc0, 0x4f, 0x79, 0x73, 0x96)]
 interface ISpObjectTokenEnumBuilder(ISpObjectTokenEnumBuilderVtbl):
     IEnumSpObjectTokens(IEnumSpObjectTokensVtbl) {
     fn SetAttribs(
@@ -57,15 +57,15 @@ interface ISpObjectTokenEnumBuilder(ISpObjectTokenEnumBuilderVtbl):
     fn Sort(
         pszTokenIdToListFirst: LPCWSTR,
     ) -> HRESULT,
-});
-DECLARE_HANDLE!(SPWORDHANDLE, SPWORDHANDLE__);
-DECLARE_HANDLE!(SPRULEHANDLE, SPRULEHANDLE__);
-DECLARE_HANDLE!(SPGRAMMARHANDLE, SPGRAMMARHANDLE__);
-DECLARE_HANDLE!(SPRECOCONTEXTHANDLE, SPRECOCONTEXTHANDLE__);
-DECLARE_HANDLE!(SPPHRASERULEHANDLE, SPPHRASERULEHANDLE__);
-DECLARE_HANDLE!(SPPHRASEPROPERTYHANDLE, SPPHRASEPROPERTYHANDLE__);
-DECLARE_HANDLE!(SPTRANSITIONID, SPTRANSITIONID__);
-RIDL!(#[uuid(0xf4711347, 0xe608, 0x11d2, 0xa0, 0x86, 0x00, 0xc0, 0x4f, 0x8e, 0xf9, 0xb5)]
+}}
+DECLARE_HANDLE!{SPWORDHANDLE, SPWORDHANDLE__}
+DECLARE_HANDLE!{SPRULEHANDLE, SPRULEHANDLE__}
+DECLARE_HANDLE!{SPGRAMMARHANDLE, SPGRAMMARHANDLE__}
+DECLARE_HANDLE!{SPRECOCONTEXTHANDLE, SPRECOCONTEXTHANDLE__}
+DECLARE_HANDLE!{SPPHRASERULEHANDLE, SPPHRASERULEHANDLE__}
+DECLARE_HANDLE!{SPPHRASEPROPERTYHANDLE, SPPHRASEPROPERTYHANDLE__}
+DECLARE_HANDLE!{SPTRANSITIONID, SPTRANSITIONID__}
+RIDL!{#[uuid(0xf4711347, 0xe608, 0x11d2, 0xa0, 0x86, 0x00, 0xc0, 0x4f, 0x8e, 0xf9, 0xb5)]
 interface ISpErrorLog(ISpErrorLogVtbl): IUnknown(IUnknownVtbl) {
     fn AddError(
         lLineNumber: c_long,
@@ -74,8 +74,8 @@ interface ISpErrorLog(ISpErrorLogVtbl): IUnknown(IUnknownVtbl) {
         pszHelpFile: LPCWSTR,
         dwHelpContext: DWORD,
     ) -> HRESULT,
-});
-RIDL!(#[uuid(0xb1e29d58, 0xa675, 0x11d2, 0x83, 0x02, 0x00, 0xc0, 0x4f, 0x8e, 0xe6, 0xc0)]
+}}
+RIDL!{#[uuid(0xb1e29d58, 0xa675, 0x11d2, 0x83, 0x02, 0x00, 0xc0, 0x4f, 0x8e, 0xe6, 0xc0)]
 interface ISpGrammarCompiler(ISpGrammarCompilerVtbl): IUnknown(IUnknownVtbl) {
     fn CompileStream(
         pSource: *mut IStream,
@@ -85,8 +85,8 @@ interface ISpGrammarCompiler(ISpGrammarCompilerVtbl): IUnknown(IUnknownVtbl) {
         pErrorLog: *mut ISpErrorLog,
         dwFlags: DWORD,
     ) -> HRESULT,
-});
-RIDL!(#[uuid(0x3ddca27c, 0x665c, 0x4786, 0x9f, 0x97, 0x8c, 0x90, 0xc3, 0x48, 0x8b, 0x61)]
+}}
+RIDL!{#[uuid(0x3ddca27c, 0x665c, 0x4786, 0x9f, 0x97, 0x8c, 0x90, 0xc3, 0x48, 0x8b, 0x61)]
 interface ISpGramCompBackend(ISpGramCompBackendVtbl): ISpGrammarBuilder(ISpGrammarBuilderVtbl) {
     fn SetSaveObjects(
         pStream: *mut IStream,
@@ -95,8 +95,8 @@ interface ISpGramCompBackend(ISpGramCompBackendVtbl): ISpGrammarBuilder(ISpGramm
     fn InitFromBinaryGrammar(
         pBinaryData: *const SPBINARYGRAMMAR,
     ) -> HRESULT,
-});
-RIDL!(#[uuid(0x12d7360f, 0xa1c9, 0x11d3, 0xbc, 0x90, 0x00, 0xc0, 0x4f, 0x72, 0xdf, 0x9f)]
+}}
+RIDL!{#[uuid(0x12d7360f, 0xa1c9, 0x11d3, 0xbc, 0x90, 0x00, 0xc0, 0x4f, 0x72, 0xdf, 0x9f)]
 interface ISpITNProcessor(ISpITNProcessorVtbl): IUnknown(IUnknownVtbl) {
     fn LoadITNGrammar(
         pszCLSID: LPWSTR,
@@ -104,8 +104,8 @@ interface ISpITNProcessor(ISpITNProcessorVtbl): IUnknown(IUnknownVtbl) {
     fn ITNPhrase(
         pPhrase: *mut ISpPhraseBuilder,
     ) -> HRESULT,
-});
-RIDL!(#[uuid(0x88a3342a, 0x0bed, 0x4834, 0x92, 0x2b, 0x88, 0xd4, 0x31, 0x73, 0x16, 0x2f)]
+}}
+RIDL!{#[uuid(0x88a3342a, 0x0bed, 0x4834, 0x92, 0x2b, 0x88, 0xd4, 0x31, 0x73, 0x16, 0x2f)]
 interface ISpPhraseBuilder(ISpPhraseBuilderVtbl): ISpPhrase(ISpPhraseVtbl) {
     fn InitFromPhrase(
         pPhrase: *const SPPHRASE,
@@ -131,10 +131,10 @@ interface ISpPhraseBuilder(ISpPhraseBuilderVtbl): ISpPhrase(ISpPhraseVtbl) {
         cReplacements: ULONG,
         pReplacements: *const SPPHRASEREPLACEMENT,
     ) -> HRESULT,
-});
+}}
 pub type ISpTask = *mut c_void;
 pub type ISpThreadTask = *mut c_void;
-RIDL!(#[uuid(0xa6be4d73, 0x4403, 0x4358, 0xb2, 0x2d, 0x03, 0x46, 0xe2, 0x3b, 0x17, 0x64)]
+RIDL!{#[uuid(0xa6be4d73, 0x4403, 0x4358, 0xb2, 0x2d, 0x03, 0x46, 0xe2, 0x3b, 0x17, 0x64)]
 interface ISpThreadControl(ISpThreadControlVtbl): ISpNotifySink(ISpNotifySinkVtbl) {
     fn StartThread(
         dwFlags: DWORD,
@@ -152,14 +152,14 @@ interface ISpThreadControl(ISpThreadControlVtbl): ISpNotifySink(ISpNotifySinkVtb
     fn WindowHandle() -> HWND,
     fn ThreadCompleteEvent() -> HANDLE,
     fn ExitThreadEvent() -> HANDLE,
-});
+}}
 STRUCT!{struct SPTMTHREADINFO {
     lPoolSize: c_long,
     lPriority: c_long,
     ulConcurrencyLimit: ULONG,
     ulMaxQuickAllocThreads: ULONG,
 }}
-RIDL!(#[uuid(0x2baeef81, 0x2ca3, 0x4331, 0x98, 0xf3, 0x26, 0xec, 0x5a, 0xbe, 0xfb, 0x03)]
+RIDL!{#[uuid(0x2baeef81, 0x2ca3, 0x4331, 0x98, 0xf3, 0x26, 0xec, 0x5a, 0xbe, 0xfb, 0x03)]
 interface ISpTaskManager(ISpTaskManagerVtbl): IUnknown(IUnknownVtbl) {
     fn SetThreadPoolInfo(
         pPoolInfo: *const SPTMTHREADINFO,
@@ -190,7 +190,7 @@ interface ISpTaskManager(ISpTaskManagerVtbl): IUnknown(IUnknownVtbl) {
         dwGroupId: DWORD,
         ulWaitPeriod: ULONG,
     ) -> HRESULT,
-});
+}}
 ENUM!{enum SPVSKIPTYPE {
     SPVST_SENTENCE = 1 << 0,
 }}
@@ -201,7 +201,7 @@ ENUM!{enum SPVESACTIONS {
     SPVES_RATE = 1 << 2,
     SPVES_VOLUME = 1 << 3,
 }}
-RIDL!(#[uuid(0x9880499b, 0xcce9, 0x11d2, 0xb5, 0x03, 0x00, 0xc0, 0x4f, 0x79, 0x73, 0x96)]
+RIDL!{#[uuid(0x9880499b, 0xcce9, 0x11d2, 0xb5, 0x03, 0x00, 0xc0, 0x4f, 0x79, 0x73, 0x96)]
 interface ISpTTSEngineSite(ISpTTSEngineSiteVtbl): ISpEventSink(ISpEventSinkVtbl) {
     fn GetActions() -> DWORD,
     fn Write(
@@ -221,7 +221,7 @@ interface ISpTTSEngineSite(ISpTTSEngineSiteVtbl): ISpEventSink(ISpEventSinkVtbl)
     fn CompleteSkip(
         ulNumSkipped: c_long,
     ) -> HRESULT,
-});
+}}
 STRUCT!{struct SPVTEXTFRAG {
     pNext: *mut SPVTEXTFRAG,
     State: SPVSTATE,
@@ -229,7 +229,7 @@ STRUCT!{struct SPVTEXTFRAG {
     ulTextLen: ULONG,
     ulTextSrcOffset: ULONG,
 }}
-RIDL!(#[uuid(0xa74d7c8e, 0x4cc5, 0x4f2f, 0xa6, 0xeb, 0x80, 0x4d, 0xee, 0x18, 0x50, 0x0e)]
+RIDL!{#[uuid(0xa74d7c8e, 0x4cc5, 0x4f2f, 0xa6, 0xeb, 0x80, 0x4d, 0xee, 0x18, 0x50, 0x0e)]
 interface ISpTTSEngine(ISpTTSEngineVtbl): IUnknown(IUnknownVtbl) {
     fn Speak(
         dwSpeakFlags: DWORD,
@@ -244,7 +244,7 @@ interface ISpTTSEngine(ISpTTSEngineVtbl): IUnknown(IUnknownVtbl) {
         pOutputFormatId: *mut GUID,
         ppCoMemOutputWaveFormatEx: *mut WAVEFORMATEX,
     ) -> HRESULT,
-});
+}}
 STRUCT!{struct SPWORDENTRY {
     hWord: SPWORDHANDLE,
     LangID: WORD,
@@ -310,7 +310,7 @@ STRUCT!{struct SPPATHENTRY {
     hTransition: SPTRANSITIONID,
     elem: SPPHRASEELEMENT,
 }}
-RIDL!(#[uuid(0x6a6ffad8, 0x78b6, 0x473d, 0xb8, 0x44, 0x98, 0x15, 0x2e, 0x4f, 0xb1, 0x6b)]
+RIDL!{#[uuid(0x6a6ffad8, 0x78b6, 0x473d, 0xb8, 0x44, 0x98, 0x15, 0x2e, 0x4f, 0xb1, 0x6b)]
 interface ISpCFGInterpreterSite(ISpCFGInterpreterSiteVtbl): IUnknown(IUnknownVtbl) {
     fn AddTextReplacement(
         pReplace: *mut SPPHRASEREPLACEMENT,
@@ -322,8 +322,8 @@ interface ISpCFGInterpreterSite(ISpCFGInterpreterSiteVtbl): IUnknown(IUnknownVtb
         pszResourceName: LPCWSTR,
         ppCoMemResource: *mut LPWSTR,
     ) -> HRESULT,
-});
-RIDL!(#[uuid(0xf3d3f926, 0x11fc, 0x11d3, 0xbb, 0x97, 0x00, 0xc0, 0x4f, 0x8e, 0xe6, 0xc0)]
+}}
+RIDL!{#[uuid(0xf3d3f926, 0x11fc, 0x11d3, 0xbb, 0x97, 0x00, 0xc0, 0x4f, 0x8e, 0xe6, 0xc0)]
 interface ISpCFGInterpreter(ISpCFGInterpreterVtbl): IUnknown(IUnknownVtbl) {
     fn InitGrammar(
         pszGrammarName: LPCWSTR,
@@ -335,7 +335,7 @@ interface ISpCFGInterpreter(ISpCFGInterpreterVtbl): IUnknown(IUnknownVtbl) {
         ulCountOfElements: *const ULONG,
         pSite: *mut ISpCFGInterpreterSite,
     ) -> HRESULT,
-});
+}}
 ENUM!{enum SPCFGNOTIFY {
     SPCFGN_ADD,
     SPCFGN_REMOVE,
@@ -390,7 +390,7 @@ STRUCT!{struct SPPARSEINFO {
     pSREnginePrivateData: *const BYTE,
     fHypothesis: BOOL,
 }}
-RIDL!(#[uuid(0x3b414aec, 0x720c, 0x4883, 0xb9, 0xef, 0x17, 0x8c, 0xd3, 0x94, 0xfb, 0x3a)]
+RIDL!{#[uuid(0x3b414aec, 0x720c, 0x4883, 0xb9, 0xef, 0x17, 0x8c, 0xd3, 0x94, 0xfb, 0x3a)]
 interface ISpSREngineSite(ISpSREngineSiteVtbl): IUnknown(IUnknownVtbl) {
     fn Read(
         pv: *mut c_void,
@@ -461,13 +461,13 @@ interface ISpSREngineSite(ISpSREngineSiteVtbl): IUnknown(IUnknownVtbl) {
     fn UpdateRecoPos(
         ullCurrentRecoPos: ULONGLONG,
     ) -> HRESULT,
-});
+}}
 ENUM!{enum SPPROPSRC {
     SPPROPSRC_RECO_INST,
     SPPROPSRC_RECO_CTX,
     SPPROPSRC_RECO_GRAMMAR,
 }}
-RIDL!(#[uuid(0x2f472991, 0x854b, 0x4465, 0xb6, 0x13, 0xfb, 0xaf, 0xb3, 0xad, 0x8e, 0xd8)]
+RIDL!{#[uuid(0x2f472991, 0x854b, 0x4465, 0xb6, 0x13, 0xfb, 0xaf, 0xb3, 0xad, 0x8e, 0xd8)]
 interface ISpSREngine(ISpSREngineVtbl): IUnknown(IUnknownVtbl) {
     fn SetSite(
         pSite: *mut ISpSREngineSite,
@@ -614,7 +614,7 @@ interface ISpSREngine(ISpSREngineVtbl): IUnknown(IUnknownVtbl) {
         pvEngineContext: *mut c_void,
         eContextState: SPCONTEXTSTATE,
     ) -> HRESULT,
-});
+}}
 STRUCT!{struct SPPHRASEALTREQUEST {
     ulStartElement: ULONG,
     cElements: ULONG,
@@ -624,7 +624,7 @@ STRUCT!{struct SPPHRASEALTREQUEST {
     pPhrase: *mut ISpPhrase,
     pRecoContext: *mut ISpRecoContext,
 }}
-RIDL!(#[uuid(0x8e7c791e, 0x4467, 0x11d3, 0x97, 0x23, 0x00, 0xc0, 0x4f, 0x72, 0xdb, 0x08)]
+RIDL!{#[uuid(0x8e7c791e, 0x4467, 0x11d3, 0x97, 0x23, 0x00, 0xc0, 0x4f, 0x72, 0xdb, 0x08)]
 interface _ISpPrivateEngineCall(_ISpPrivateEngineCallVtbl): IUnknown(IUnknownVtbl) {
     fn CallEngine(
         pCallFrame: *mut c_void,
@@ -636,10 +636,14 @@ interface _ISpPrivateEngineCall(_ISpPrivateEngineCallVtbl): IUnknown(IUnknownVtb
         ppCoMemOutFrame: *mut *mut c_void,
         pulOutFrameSize: *mut ULONG,
     ) -> HRESULT,
-});
+}}
 extern {
     pub static LIBID_SpeechDDKLib: IID;
     pub static CLSID_SpDataKey: CLSID;
+}
+RIDL!{#[uuid(0xd9f6ee60, 0x58c9, 0x458b, 0x88, 0xe1, 0x2f, 0x90, 0x8f, 0xd7, 0xf8, 0x7c)]
+class SpDataKey;}
+extern {
     pub static CLSID_SpObjectTokenEnum: CLSID;
     pub static CLSID_SpPhraseBuilder: CLSID;
     pub static CLSID_SpITNProcessor: CLSID;
@@ -9,7 +9,7 @@ use shared::guiddef::{REFGUID, REFIID};
 use um::unknwnbase::{IUnknown, IUnknownVtbl};
 use um::winnt::HRESULT;
 pub type LPSERVICEPROVIDER = *mut IServiceProvider;
-RIDL!(#[uuid(0x6d5140c1, 0x7436, 0x11ce, 0x80, 0x34, 0x00, 0xaa, 0x00, 0x60, 0x09, 0xfa)]
+RIDL!{#[uuid(0x6d5140c1, 0x7436, 0x11ce, 0x80, 0x34, 0x00, 0xaa, 0x00, 0x60, 0x09, 0xfa)]
 interface IServiceProvider(IServiceProviderVtbl): IUnknown(IUnknownVtbl) {
     fn QueryService(
         guidService: REFGUID,
@@ -21,4 +21,4 @@ interface IServiceProvider(IServiceProviderVtbl): IUnknown(IUnknownVtbl) {
         riid: REFIID,
         ppvObject: *mut *mut IUnknown,
     ) -> HRESULT,
-});
+}}
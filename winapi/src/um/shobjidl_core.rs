@@ -4,15 +4,15 @@
 // <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your option.
 // All files in the project carrying such notice may not be copied, modified, or distributed
 // except according to those terms.
-
 use ctypes::{c_int, c_void};
 use shared::guiddef::{REFGUID, REFIID};
-use shared::minwindef::ULONG;
+use shared::minwindef::{BOOL, ULONG};
 use shared::windef::HWND;
 use um::objidl::IBindCtx;
 use um::unknwnbase::{IUnknown, IUnknownVtbl};
 use um::winnt::{HRESULT, LPWSTR};
-
+DEFINE_GUID!{CLSID_TaskbarList,
+    0x56fdf344, 0xfd6d, 0x11d0, 0x95, 0x8a, 0x00, 0x60, 0x97, 0xc9, 0xa0, 0x90}
 //4498
 pub type SFGAOF = ULONG;
 //9466
@@ -34,8 +34,7 @@ ENUM!{enum SICHINTF {
     SICHINT_CANONICAL = 0x10000000,
     SICHINT_TEST_FILESYSPATH_IF_NOT_EQUAL = 0x20000000,
 }}
-RIDL!(
-#[uuid(0x43826d1e, 0xe718, 0x42ee, 0xbc, 0x55, 0xa1, 0xe2, 0x61, 0xc3, 0x7b, 0xfe)]
+RIDL!{#[uuid(0x43826d1e, 0xe718, 0x42ee, 0xbc, 0x55, 0xa1, 0xe2, 0x61, 0xc3, 0x7b, 0xfe)]
 interface IShellItem(IShellItemVtbl): IUnknown(IUnknownVtbl) {
     fn BindToHandler(
         pbc: *mut IBindCtx,
@@ -59,15 +58,37 @@ interface IShellItem(IShellItemVtbl): IUnknown(IUnknownVtbl) {
         hint: SICHINTF,
         piOrder: *mut c_int,
     ) -> HRESULT,
-});
+}}
 //20869
-RIDL!(
-#[uuid(0xb4db1657, 0x70d7, 0x485e, 0x8e, 0x3e, 0x6f, 0xcb, 0x5a, 0x5c, 0x18, 0x02)]
+RIDL!{#[uuid(0xb4db1657, 0x70d7, 0x485e, 0x8e, 0x3e, 0x6f, 0xcb, 0x5a, 0x5c, 0x18, 0x02)]
 interface IModalWindow(IModalWindowVtbl): IUnknown(IUnknownVtbl) {
     fn Show(
         hwndOwner: HWND,
     ) -> HRESULT,
-});
+}}
 //22307
 //27457
 pub type IShellItemFilter = IUnknown; // TODO
+RIDL!{#[uuid(0x56fdf342, 0xfd6d, 0x11d0, 0x95, 0x8a, 0x00, 0x60, 0x97, 0xc9, 0xa0, 0x90)]
+interface ITaskbarList(ITaskbarListVtbl): IUnknown(IUnknownVtbl) {
+    fn HrInit() -> HRESULT,
+    fn AddTab(
+        hwnd: HWND,
+    ) -> HRESULT,
+    fn DeleteTab(
+        hwnd: HWND,
+    ) -> HRESULT,
+    fn ActivateTab(
+        hwnd: HWND,
+    ) -> HRESULT,
+    fn SetActiveAlt(
+        hwnd: HWND,
+    ) -> HRESULT,
+}}
+RIDL!{#[uuid(0x602d4995, 0xb13a, 0x429b, 0xa6, 0x6e, 0x19, 0x35, 0xe4, 0x4f, 0x43, 0x17)]
+interface ITaskbarList2(ITaskbarList2Vtbl): ITaskbarList(ITaskbarListVtbl) {
+    fn MarkFullscreenWindow(
+        hwnd: HWND,
+        fFullscreen: BOOL,
+    ) -> HRESULT,
+}}
@@ -5,18 +5,16 @@
 // All files in the project carrying such notice may not be copied, modified, or distributed
 // except according to those terms.
 //! this ALWAYS GENERATED file contains the definitions for the interfaces
-
 use shared::guiddef::GUID;
 use shared::minwindef::{BYTE, USHORT};
 use um::winnt::LPCWSTR;
-
-STRUCT!{struct SHITEMID {
+STRUCT!{#[repr(packed)] struct SHITEMID {
     cb: USHORT,
-    abID: [BYTE; 0],
+    abID: [BYTE; 1],
 }}
 pub type LPSHITEMID = *mut SHITEMID;
 pub type LPCSHITEMID = *const SHITEMID;
-STRUCT!{struct ITEMIDLIST {
+STRUCT!{#[repr(packed)] struct ITEMIDLIST {
     mkid: SHITEMID,
 }}
 pub type ITEMIDLIST_RELATIVE = ITEMIDLIST;
@@ -3290,8 +3290,8 @@ pub const CMSG_CTRL_DECRYPT: DWORD = 2;
 pub const CMSG_CTRL_VERIFY_HASH: DWORD = 5;
 pub const CMSG_CTRL_ADD_SIGNER: DWORD = 6;
 pub const CMSG_CTRL_DEL_SIGNER: DWORD = 7;
-pub const CMSG_CTRL_ADD_SIGNER_UNAUTH_ATTR: DWORD =  8;
-pub const CMSG_CTRL_DEL_SIGNER_UNAUTH_ATTR: DWORD =  9;
+pub const CMSG_CTRL_ADD_SIGNER_UNAUTH_ATTR: DWORD = 8;
+pub const CMSG_CTRL_DEL_SIGNER_UNAUTH_ATTR: DWORD = 9;
 pub const CMSG_CTRL_ADD_CERT: DWORD = 10;
 pub const CMSG_CTRL_DEL_CERT: DWORD = 11;
 pub const CMSG_CTRL_ADD_CRL: DWORD = 12;
@@ -4686,7 +4686,7 @@ STRUCT!{struct CTL_FIND_USAGE_PARA {
 }}
 pub type PCTL_FIND_USAGE_PARA = *mut CTL_FIND_USAGE_PARA;
 pub const CTL_FIND_NO_LIST_ID_CBDATA: DWORD = 0xFFFFFFFF;
-pub const CTL_FIND_NO_SIGNER_PTR: PCERT_INFO = -1i32 as PCERT_INFO;
+pub const CTL_FIND_NO_SIGNER_PTR: PCERT_INFO = -1isize as PCERT_INFO;
 pub const CTL_FIND_SAME_USAGE_FLAG: DWORD = 0x1;
 STRUCT!{struct CTL_FIND_SUBJECT_PARA {
     cbSize: DWORD,
@@ -5581,6 +5581,8 @@ STRUCT!{struct CRYPT_SIGN_MESSAGE_PARA {
     rgUnauthAttr: PCRYPT_ATTRIBUTE,
     dwFlags: DWORD,
     dwInnerContentType: DWORD,
+    HashEncryptionAlgorithm: CRYPT_ALGORITHM_IDENTIFIER,
+    pvHashEncryptionAuxInfo: *mut c_void,
 }}
 pub type PCRYPT_SIGN_MESSAGE_PARA = *mut CRYPT_SIGN_MESSAGE_PARA;
 pub const CRYPT_MESSAGE_BARE_CONTENT_OUT_FLAG: DWORD = 0x00000001;
@@ -6817,7 +6819,6 @@ extern "system" {
 }
 pub const CRYPT_OID_VERIFY_CERTIFICATE_CHAIN_POLICY_FUNC: &'static str
     = "CertDllVerifyCertificateChainPolicy";
-
 pub const CERT_CHAIN_POLICY_BASE: LPCSTR = 1 as LPCSTR;
 pub const CERT_CHAIN_POLICY_AUTHENTICODE: LPCSTR = 2 as LPCSTR;
 pub const CERT_CHAIN_POLICY_AUTHENTICODE_TS: LPCSTR = 3 as LPCSTR;
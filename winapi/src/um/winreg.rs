@@ -6,11 +6,44 @@
 // except according to those terms.
 use shared::basetsd::DWORD_PTR;
 use shared::minwindef::{
-    BOOL, BYTE, DWORD, HKEY, LPBYTE, LPCVOID, LPDWORD, PBOOL, PFILETIME, PHKEY, ULONG
+    BOOL, BYTE, DWORD, HKEY, LPBYTE, LPCVOID, LPDWORD, PFILETIME, PHKEY, ULONG
 };
 use um::minwinbase::LPSECURITY_ATTRIBUTES;
 use um::winnt::{ACCESS_MASK, HANDLE, LONG, LPCSTR, LPCWSTR, LPSTR, LPWSTR, PVOID};
+pub type LSTATUS = LONG;
+pub const RRF_RT_REG_NONE: DWORD = 0x00000001;
+pub const RRF_RT_REG_SZ: DWORD = 0x00000002;
+pub const RRF_RT_REG_EXPAND_SZ: DWORD = 0x00000004;
+pub const RRF_RT_REG_BINARY: DWORD = 0x00000008;
+pub const RRF_RT_REG_DWORD: DWORD = 0x00000010;
+pub const RRF_RT_REG_MULTI_SZ: DWORD = 0x00000020;
+pub const RRF_RT_REG_QWORD: DWORD = 0x00000040;
+pub const RRF_RT_DWORD: DWORD = RRF_RT_REG_BINARY | RRF_RT_REG_DWORD;
+pub const RRF_RT_QWORD: DWORD = RRF_RT_REG_BINARY | RRF_RT_REG_QWORD;
+pub const RRF_RT_ANY: DWORD = 0x0000ffff;
+pub const RRF_SUBKEY_WOW6464KEY: DWORD = 0x00010000;
+pub const RRF_SUBKEY_WOW6432KEY: DWORD = 0x00020000;
+pub const RRF_WOW64_MASK: DWORD = 0x00030000;
+pub const RRF_NOEXPAND: DWORD = 0x10000000;
+pub const RRF_ZEROONFAILURE: DWORD = 0x20000000;
+pub const REG_PROCESS_APPKEY: DWORD = 0x00000001;
 pub type REGSAM = ACCESS_MASK;
+pub const HKEY_CLASSES_ROOT: HKEY = 0x80000000i32 as isize as HKEY;
+pub const HKEY_CURRENT_USER: HKEY = 0x80000001i32 as isize as HKEY;
+pub const HKEY_LOCAL_MACHINE: HKEY = 0x80000002i32 as isize as HKEY;
+pub const HKEY_USERS: HKEY = 0x80000003i32 as isize as HKEY;
+pub const HKEY_PERFORMANCE_DATA: HKEY = 0x80000004i32 as isize as HKEY;
+pub const HKEY_PERFORMANCE_TEXT: HKEY = 0x80000050i32 as isize as HKEY;
+pub const HKEY_PERFORMANCE_NLSTEXT: HKEY = 0x80000060i32 as isize as HKEY;
+pub const HKEY_CURRENT_CONFIG: HKEY = 0x80000005i32 as isize as HKEY;
+pub const HKEY_DYN_DATA: HKEY = 0x80000006i32 as isize as HKEY;
+pub const HKEY_CURRENT_USER_LOCAL_SETTINGS: HKEY = 0x80000007i32 as isize as HKEY;
+// PROVIDER_KEEPS_VALUE_LENGTH
+// val_context
+// PVALUEA
+// PVALUEW
+// QUERYHANDLER
+// REG_PROVIDER
 STRUCT!{struct VALENTA {
     ve_valuename: LPSTR,
     ve_valuelen: DWORD,
@@ -25,81 +58,61 @@ STRUCT!{struct VALENTW {
     ve_type: DWORD,
 }}
 pub type PVALENTW = *mut VALENTW;
-pub const HKEY_CLASSES_ROOT: HKEY = 0x80000000i32 as isize as HKEY;
-pub const HKEY_CURRENT_USER: HKEY = 0x80000001i32 as isize as HKEY;
-pub const HKEY_LOCAL_MACHINE: HKEY = 0x80000002i32 as isize as HKEY;
-pub const HKEY_USERS: HKEY = 0x80000003i32 as isize as HKEY;
-pub const HKEY_PERFORMANCE_DATA: HKEY = 0x80000004i32 as isize as HKEY;
-pub const HKEY_PERFORMANCE_TEXT: HKEY = 0x80000050i32 as isize as HKEY;
-pub const HKEY_PERFORMANCE_NLSTEXT: HKEY = 0x80000060i32 as isize as HKEY;
-pub const HKEY_CURRENT_CONFIG: HKEY = 0x80000005i32 as isize as HKEY;
-pub const HKEY_DYN_DATA: HKEY = 0x80000006i32 as isize as HKEY;
-pub const HKEY_CURRENT_USER_LOCAL_SETTINGS: HKEY = 0x80000007i32 as isize as HKEY;
+// WIN31_CLASS
 pub const REG_MUI_STRING_TRUNCATE: DWORD = 0x00000001;
-pub const RRF_RT_REG_NONE: DWORD = 0x00000001;
-pub const RRF_RT_REG_SZ: DWORD = 0x00000002;
-pub const RRF_RT_REG_EXPAND_SZ: DWORD = 0x00000004;
-pub const RRF_RT_REG_BINARY: DWORD = 0x00000008;
-pub const RRF_RT_REG_DWORD: DWORD = 0x00000010;
-pub const RRF_RT_REG_MULTI_SZ: DWORD = 0x00000020;
-pub const RRF_RT_REG_QWORD: DWORD = 0x00000040;
-pub const RRF_RT_DWORD: DWORD = RRF_RT_REG_BINARY | RRF_RT_REG_DWORD;
-pub const RRF_RT_QWORD: DWORD = RRF_RT_REG_BINARY | RRF_RT_REG_QWORD;
-pub const RRF_RT_ANY: DWORD = 0x0000ffff;
-pub const RRF_NOEXPAND: DWORD = 0x10000000;
-pub const RRF_ZEROONFAILURE: DWORD = 0x20000000;
+pub const REG_SECURE_CONNECTION: DWORD = 1;
 extern "system" {
     pub fn RegCloseKey(
         hKey: HKEY
-    ) -> LONG;
+    ) -> LSTATUS;
     pub fn RegOverridePredefKey(
         hKey: HKEY,
         hNewHKey: HKEY
-    ) -> LONG;
+    ) -> LSTATUS;
     pub fn RegOpenUserClassesRoot(
         hToken: HANDLE,
         dwOptions: DWORD,
         samDesired: REGSAM,
         phkResult: PHKEY,
-    ) -> LONG;
+    ) -> LSTATUS;
     pub fn RegOpenCurrentUser(
         samDesired: REGSAM,
         phkResult: PHKEY
-    ) -> LONG;
-    pub fn RegDisablePredefinedCache() -> LONG;
-    pub fn RegDisablePredefinedCacheEx() -> LONG;
+    ) -> LSTATUS;
+    pub fn RegDisablePredefinedCache() -> LSTATUS;
+    pub fn RegDisablePredefinedCacheEx() -> LSTATUS;
     pub fn RegConnectRegistryA(
         lpMachineName: LPCSTR,
         hKey: HKEY,
         phkResult: PHKEY
-    ) -> LONG;
+    ) -> LSTATUS;
     pub fn RegConnectRegistryW(
         lpMachineName: LPCWSTR,
         hKey: HKEY,
         phkResult: PHKEY
-    ) -> LONG;
+    ) -> LSTATUS;
     pub fn RegConnectRegistryExA(
         lpMachineName: LPCSTR,
         hKey: HKEY,
         flags: ULONG,
         phkResult: PHKEY
-    ) -> LONG;
+    ) -> LSTATUS;
     pub fn RegConnectRegistryExW(
         lpMachineName: LPCWSTR,
         hKey: HKEY,
         flags: ULONG,
         phkResult: PHKEY
-    ) -> LONG;
+    ) -> LSTATUS;
     pub fn RegCreateKeyA(
         hKey: HKEY,
         lpSubKey: LPCSTR,
         phkResult: PHKEY
-    );
+    ) -> LSTATUS;
     pub fn RegCreateKeyW(
         hKey: HKEY,
         lpSubKey: LPCWSTR,
         phkResult: PHKEY
-    );
+    ) -> LSTATUS;
     pub fn RegCreateKeyExA(
         hKey: HKEY,
         lpSubKey: LPCSTR,
@@ -110,7 +123,7 @@ extern "system" {
         lpSecurityAttributes: LPSECURITY_ATTRIBUTES,
         phkResult: PHKEY,
         lpdwDisposition: LPDWORD,
-    ) -> LONG;
+    ) -> LSTATUS;
     pub fn RegCreateKeyExW(
         hKey: HKEY,
         lpSubKey: LPCWSTR,
@@ -121,7 +134,7 @@ extern "system" {
         lpSecurityAttributes: LPSECURITY_ATTRIBUTES,
         phkResult: PHKEY,
         lpdwDisposition: LPDWORD,
-    ) -> LONG;
+    ) -> LSTATUS;
     pub fn RegCreateKeyTransactedA(
         hKey: HKEY,
         lpSubKey: LPCSTR,
@@ -134,7 +147,7 @@ extern "system" {
         lpdwDisposition: LPDWORD,
         hTransaction: HANDLE,
         pExtendedParemeter: PVOID,
-    ) -> LONG;
+    ) -> LSTATUS;
     pub fn RegCreateKeyTransactedW(
         hKey: HKEY,
         lpSubKey: LPCWSTR,
@@ -147,27 +160,27 @@ extern "system" {
         lpdwDisposition: LPDWORD,
         hTransaction: HANDLE,
         pExtendedParemeter: PVOID,
-    ) -> LONG;
+    ) -> LSTATUS;
     pub fn RegDeleteKeyA(
         hKey: HKEY,
         lpSubKey: LPCSTR
-    ) -> LONG;
+    ) -> LSTATUS;
     pub fn RegDeleteKeyW(
         hKey: HKEY,
         lpSubKey: LPCWSTR
-    ) -> LONG;
+    ) -> LSTATUS;
     pub fn RegDeleteKeyExA(
         hKey: HKEY,
         lpSubKey: LPCSTR,
         samDesired: REGSAM,
         Reserved: DWORD,
-    ) -> LONG;
+    ) -> LSTATUS;
     pub fn RegDeleteKeyExW(
         hKey: HKEY,
         lpSubKey: LPCWSTR,
         samDesired: REGSAM,
         Reserved: DWORD,
-    ) -> LONG;
+    ) -> LSTATUS;
     pub fn RegDeleteKeyTransactedA(
         hKey: HKEY,
         lpSubKey: LPCSTR,
@@ -175,7 +188,7 @@ extern "system" {
         Reserved: DWORD,
         hTransaction: HANDLE,
         pExtendedParemeter: PVOID,
-    ) -> LONG;
+    ) -> LSTATUS;
     pub fn RegDeleteKeyTransactedW(
         hKey: HKEY,
         lpSubKey: LPCWSTR,
@@ -183,7 +196,7 @@ extern "system" {
         Reserved: DWORD,
         hTransaction: HANDLE,
         pExtendedParemeter: PVOID,
-    ) -> LONG;
+    ) -> LSTATUS;
     pub fn RegDisableReflectionKey(
         hBase: HKEY
     ) -> LONG;
@@ -192,16 +205,16 @@ extern "system" {
     ) -> LONG;
     pub fn RegQueryReflectionKey(
         hBase: HKEY,
-        bIsReflectionDisabled: PBOOL
+        bIsReflectionDisabled: *mut BOOL
     ) -> LONG;
     pub fn RegDeleteValueA(
         hKey: HKEY,
         lpValueName: LPCSTR
-    ) -> LONG;
+    ) -> LSTATUS;
     pub fn RegDeleteValueW(
         hKey: HKEY,
         lpValueName: LPCWSTR
-    ) -> LONG;
+    ) -> LSTATUS;
     // pub fn RegEnumKeyA();
     // pub fn RegEnumKeyW();
     pub fn RegEnumKeyExA(
@@ -213,7 +226,7 @@ extern "system" {
         lpClass: LPSTR,
         lpcClass: LPDWORD,
         lpftLastWriteTime: PFILETIME,
-    ) -> LONG;
+    ) -> LSTATUS;
     pub fn RegEnumKeyExW(
         hKey: HKEY,
         dwIndex: DWORD,
@@ -223,7 +236,7 @@ extern "system" {
         lpClass: LPWSTR,
         lpcClass: LPDWORD,
         lpftLastWriteTime: PFILETIME,
-    ) -> LONG;
+    ) -> LSTATUS;
     pub fn RegEnumValueA(
         hKey: HKEY,
         dwIndex: DWORD,
@@ -233,7 +246,7 @@ extern "system" {
         lpType: LPDWORD,
         lpData: LPBYTE,
         lpcbData: LPDWORD,
-    ) -> LONG;
+    ) -> LSTATUS;
     pub fn RegEnumValueW(
         hKey: HKEY,
         dwIndex: DWORD,
@@ -243,10 +256,10 @@ extern "system" {
         lpType: LPDWORD,
         lpData: LPBYTE,
         lpcbData: LPDWORD,
-    ) -> LONG;
+    ) -> LSTATUS;
     pub fn RegFlushKey(
         hKey: HKEY
-    ) -> LONG;
+    ) -> LSTATUS;
     // pub fn RegGetKeySecurity();
     // pub fn RegLoadKeyA();
     // pub fn RegLoadKeyW();
@@ -256,7 +269,7 @@ extern "system" {
         dwNotifyFilter: DWORD,
         hEvent: HANDLE,
         fAsynchronous: BOOL,
-    ) -> LONG;
+    ) -> LSTATUS;
     // pub fn RegOpenKeyA();
     // pub fn RegOpenKeyW();
     pub fn RegOpenKeyExA(
@@ -265,14 +278,14 @@ extern "system" {
         ulOptions: DWORD,
         samDesired: REGSAM,
         phkResult: PHKEY,
-    ) -> LONG;
+    ) -> LSTATUS;
     pub fn RegOpenKeyExW(
         hKey: HKEY,
         lpSubKey: LPCWSTR,
         ulOptions: DWORD,
         samDesired: REGSAM,
         phkResult: PHKEY,
-    ) -> LONG;
+    ) -> LSTATUS;
     pub fn RegOpenKeyTransactedA(
         hKey: HKEY,
         lpSubKey: LPCSTR,
@@ -281,7 +294,7 @@ extern "system" {
         phkResult: PHKEY,
         hTransaction: HANDLE,
         pExtendedParemeter: PVOID,
-    ) -> LONG;
+    ) -> LSTATUS;
     pub fn RegOpenKeyTransactedW(
         hKey: HKEY,
         lpSubKey: LPCWSTR,
@@ -290,7 +303,7 @@ extern "system" {
         phkResult: PHKEY,
         hTransaction: HANDLE,
         pExtendedParemeter: PVOID,
-    ) -> LONG;
+    ) -> LSTATUS;
     pub fn RegQueryInfoKeyA(
         hKey: HKEY,
         lpClass: LPSTR,
@@ -304,7 +317,7 @@ extern "system" {
         lpcMaxValueLen: LPDWORD,
         lpcbSecurityDescriptor: LPDWORD,
         lpftLastWriteTime: PFILETIME,
-    ) -> LONG;
+    ) -> LSTATUS;
     pub fn RegQueryInfoKeyW(
         hKey: HKEY,
         lpClass: LPWSTR,
@@ -318,7 +331,7 @@ extern "system" {
         lpcMaxValueLen: LPDWORD,
         lpcbSecurityDescriptor: LPDWORD,
         lpftLastWriteTime: PFILETIME,
-    ) -> LONG;
+    ) -> LSTATUS;
     // pub fn RegQueryValueA();
     // pub fn RegQueryValueW();
     pub fn RegQueryMultipleValuesA(
@@ -327,14 +340,14 @@ extern "system" {
         num_vals: DWORD,
         lpValueBuf: LPSTR,
         ldwTotsize: LPDWORD,
-    ) -> LONG;
+    ) -> LSTATUS;
     pub fn RegQueryMultipleValuesW(
         hKey: HKEY,
         val_list: PVALENTW,
         num_vals: DWORD,
         lpValueBuf: LPWSTR,
         ldwTotsize: LPDWORD,
-    ) -> LONG;
+    ) -> LSTATUS;
     pub fn RegQueryValueExA(
         hKey: HKEY,
         lpValueName: LPCSTR,
@@ -342,7 +355,7 @@ extern "system" {
         lpType: LPDWORD,
         lpData: LPBYTE,
         lpcbData: LPDWORD,
-    ) -> LONG;
+    ) -> LSTATUS;
     pub fn RegQueryValueExW(
         hKey: HKEY,
         lpValueName: LPCWSTR,
@@ -350,7 +363,7 @@ extern "system" {
         lpType: LPDWORD,
         lpData: LPBYTE,
         lpcbData: LPDWORD,
-    ) -> LONG;
+    ) -> LSTATUS;
     // pub fn RegReplaceKeyA();
     // pub fn RegReplaceKeyW();
     // pub fn RegRestoreKeyA();
@@ -368,7 +381,7 @@ extern "system" {
         dwType: DWORD,
         lpData: *const BYTE,
         cbData: DWORD,
-    ) -> LONG;
+    ) -> LSTATUS;
     pub fn RegSetValueExW(
         hKey: HKEY,
         lpValueName: LPCWSTR,
@@ -376,19 +389,19 @@ extern "system" {
         dwType: DWORD,
         lpData: *const BYTE,
         cbData: DWORD,
-    ) -> LONG;
+    ) -> LSTATUS;
     // pub fn RegUnLoadKeyA();
     // pub fn RegUnLoadKeyW();
     pub fn RegDeleteKeyValueA(
         hKey: HKEY,
         lpSubKey: LPCSTR,
         lpValueName: LPCSTR
-    ) -> LONG;
+    ) -> LSTATUS;
     pub fn RegDeleteKeyValueW(
         hKey: HKEY,
         lpSubKey: LPCWSTR,
         lpValueName: LPCWSTR
-    ) -> LONG;
+    ) -> LSTATUS;
     pub fn RegSetKeyValueA(
         hKey: HKEY,
         lpSubKey: LPCSTR,
@@ -396,7 +409,7 @@ extern "system" {
         dwType: DWORD,
         lpData: LPCVOID,
         cbData: DWORD,
-    ) -> LONG;
+    ) -> LSTATUS;
     pub fn RegSetKeyValueW(
         hKey: HKEY,
         lpSubKey: LPCWSTR,
@@ -404,20 +417,20 @@ extern "system" {
         dwType: DWORD,
         lpData: LPCVOID,
         cbData: DWORD,
-    ) -> LONG;
+    ) -> LSTATUS;
     pub fn RegDeleteTreeA(
         hKey: HKEY,
         lpSubKey: LPCSTR
-    ) -> LONG;
+    ) -> LSTATUS;
     pub fn RegDeleteTreeW(
         hKey: HKEY,
         lpSubKey: LPCWSTR
-    ) -> LONG;
+    ) -> LSTATUS;
     pub fn RegCopyTreeA(
         hKeySrc: HKEY,
         lpSubKey: LPCSTR,
         hKeyDest: HKEY
-    ) -> LONG;
+    ) -> LSTATUS;
     pub fn RegGetValueA(
         hkey: HKEY,
         lpSubKey: LPCSTR,
@@ -426,7 +439,7 @@ extern "system" {
         pdwType: LPDWORD,
         pvData: PVOID,
         pcbData: LPDWORD,
-    ) -> LONG;
+    ) -> LSTATUS;
     pub fn RegGetValueW(
         hkey: HKEY,
         lpSubKey: LPCWSTR,
@@ -435,12 +448,12 @@ extern "system" {
         pdwType: LPDWORD,
         pvData: PVOID,
         pcbData: LPDWORD,
-    ) -> LONG;
+    ) -> LSTATUS;
     pub fn RegCopyTreeW(
         hKeySrc: HKEY,
         lpSubKey: LPCWSTR,
         hKeyDest: HKEY
-    ) -> LONG;
+    ) -> LSTATUS;
     // pub fn RegLoadMUIStringA();
     pub fn RegLoadMUIStringW(
         hKey: HKEY,
@@ -450,19 +463,26 @@ extern "system" {
         pcbData: LPDWORD,
         Flags: DWORD,
         pszDirectory: LPCWSTR,
-    ) -> LONG;
+    ) -> LSTATUS;
     // pub fn RegLoadAppKeyA();
     // pub fn RegLoadAppKeyW();
     // pub fn InitiateSystemShutdownA();
     // pub fn InitiateSystemShutdownW();
     pub fn AbortSystemShutdownA(
-        lpMachineName: LPCSTR
+        lpMachineName: LPSTR
     ) -> BOOL;
     pub fn AbortSystemShutdownW(
         lpMachineName: LPWSTR
     ) -> BOOL;
+}
+// REASON_*
+// MAX_SHUTDOWN_TIMEOUT
+extern "system" {
     // pub fn InitiateSystemShutdownExA();
     // pub fn InitiateSystemShutdownExW();
+}
+// SHUTDOWN_*
+extern "system" {
     // pub fn InitiateShutdownA();
     // pub fn InitiateShutdownW();
     // pub fn CheckForHiberboot();
@@ -3048,7 +3048,7 @@ extern "system" {
         cbSize: c_int,
     ) -> UINT;
 }
-DECLARE_HANDLE!(HTOUCHINPUT, HTOUCHINPUT__);
+DECLARE_HANDLE!{HTOUCHINPUT, HTOUCHINPUT__}
 STRUCT!{struct TOUCHINPUT {
     x: LONG,
     y: LONG,
@@ -6483,9 +6483,132 @@ extern "system" {
     pub fn IsWinEventHookInstalled(
         event: DWORD,
     ) -> BOOL;
+}
+pub const WINEVENT_OUTOFCONTEXT: UINT = 0x0000;
+pub const WINEVENT_SKIPOWNTHREAD: UINT = 0x0001;
+pub const WINEVENT_SKIPOWNPROCESS: UINT = 0x0002;
+pub const WINEVENT_INCONTEXT: UINT = 0x0004;
+extern "system" {
     pub fn UnhookWinEvent(
         hWinEventHook: HWINEVENTHOOK,
     ) -> BOOL;
+}
+pub const CHILDID_SELF: LONG = 0;
+pub const INDEXID_OBJECT: LONG = 0;
+pub const INDEXID_CONTAINER: LONG = 0;
+pub const OBJID_WINDOW: LONG = 0x0000;
+pub const OBJID_SYSMENU: LONG = 0xFFFFFFFF;
+pub const OBJID_TITLEBAR: LONG = 0xFFFFFFFE;
+pub const OBJID_MENU: LONG = 0xFFFFFFFD;
+pub const OBJID_CLIENT: LONG = 0xFFFFFFFC;
+pub const OBJID_VSCROLL: LONG = 0xFFFFFFFB;
+pub const OBJID_HSCROLL: LONG = 0xFFFFFFFA;
+pub const OBJID_SIZEGRIP: LONG = 0xFFFFFFF9;
+pub const OBJID_CARET: LONG = 0xFFFFFFF8;
+pub const OBJID_CURSOR: LONG = 0xFFFFFFF7;
+pub const OBJID_ALERT: LONG = 0xFFFFFFF6;
+pub const OBJID_SOUND: LONG = 0xFFFFFFF5;
+pub const OBJID_QUERYCLASSNAMEIDX: LONG = 0xFFFFFFF4;
+pub const OBJID_NATIVEOM: LONG = 0xFFFFFFF0;
+pub const EVENT_MIN: UINT = 0x0001;
+pub const EVENT_MAX: UINT = 0x7FFFFFFF;
+pub const EVENT_SYSTEM_SOUND: UINT = 0x0001;
+pub const EVENT_SYSTEM_ALERT: UINT = 0x0002;
+pub const EVENT_SYSTEM_FOREGROUND: UINT = 0x0003;
+pub const EVENT_SYSTEM_MENUSTART: UINT = 0x0004;
+pub const EVENT_SYSTEM_MENUEND: UINT = 0x0005;
+pub const EVENT_SYSTEM_MENUPOPUPSTART: UINT = 0x0006;
+pub const EVENT_SYSTEM_MENUPOPUPEND: UINT = 0x0007;
+pub const EVENT_SYSTEM_CAPTURESTART: UINT = 0x0008;
+pub const EVENT_SYSTEM_CAPTUREEND: UINT = 0x0009;
+pub const EVENT_SYSTEM_MOVESIZESTART: UINT = 0x000A;
+pub const EVENT_SYSTEM_MOVESIZEEND: UINT = 0x000B;
+pub const EVENT_SYSTEM_CONTEXTHELPSTART: UINT = 0x000C;
+pub const EVENT_SYSTEM_CONTEXTHELPEND: UINT = 0x000D;
+pub const EVENT_SYSTEM_DRAGDROPSTART: UINT = 0x000E;
+pub const EVENT_SYSTEM_DRAGDROPEND: UINT = 0x000F;
+pub const EVENT_SYSTEM_DIALOGSTART: UINT = 0x0010;
+pub const EVENT_SYSTEM_DIALOGEND: UINT = 0x0011;
+pub const EVENT_SYSTEM_SCROLLINGSTART: UINT = 0x0012;
+pub const EVENT_SYSTEM_SCROLLINGEND: UINT = 0x0013;
+pub const EVENT_SYSTEM_SWITCHSTART: UINT = 0x0014;
+pub const EVENT_SYSTEM_SWITCHEND: UINT = 0x0015;
+pub const EVENT_SYSTEM_MINIMIZESTART: UINT = 0x0016;
+pub const EVENT_SYSTEM_MINIMIZEEND: UINT = 0x0017;
+pub const EVENT_SYSTEM_DESKTOPSWITCH: UINT = 0x0020;
+pub const EVENT_SYSTEM_SWITCHER_APPGRABBED: UINT = 0x0024;
+pub const EVENT_SYSTEM_SWITCHER_APPOVERTARGET: UINT = 0x0025;
+pub const EVENT_SYSTEM_SWITCHER_APPDROPPED: UINT = 0x0026;
+pub const EVENT_SYSTEM_SWITCHER_CANCELLED: UINT = 0x0027;
+pub const EVENT_SYSTEM_IME_KEY_NOTIFICATION: UINT = 0x0029;
+pub const EVENT_SYSTEM_END: UINT = 0x00FF;
+pub const EVENT_OEM_DEFINED_START: UINT = 0x0101;
+pub const EVENT_OEM_DEFINED_END: UINT = 0x01FF;
+pub const EVENT_UIA_EVENTID_START: UINT = 0x4E00;
+pub const EVENT_UIA_EVENTID_END: UINT = 0x4EFF;
+pub const EVENT_UIA_PROPID_START: UINT = 0x7500;
+pub const EVENT_UIA_PROPID_END: UINT = 0x75FF;
+pub const EVENT_CONSOLE_CARET: UINT = 0x4001;
+pub const EVENT_CONSOLE_UPDATE_REGION: UINT = 0x4002;
+pub const EVENT_CONSOLE_UPDATE_SIMPLE: UINT = 0x4003;
+pub const EVENT_CONSOLE_UPDATE_SCROLL: UINT = 0x4004;
+pub const EVENT_CONSOLE_LAYOUT: UINT = 0x4005;
+pub const EVENT_CONSOLE_START_APPLICATION: UINT = 0x4006;
+pub const EVENT_CONSOLE_END_APPLICATION: UINT = 0x4007;
+#[cfg(target_arch = "x86_64")]
+pub const CONSOLE_APPLICATION_16BIT: LONG = 0x0000;
+#[cfg(target_arch = "x86")]
+pub const CONSOLE_APPLICATION_16BIT: LONG = 0x0001;
+pub const CONSOLE_CARET_SELECTION: LONG = 0x0001;
+pub const CONSOLE_CARET_VISIBLE: LONG = 0x0002;
+pub const EVENT_CONSOLE_END: UINT = 0x40FF;
+pub const EVENT_OBJECT_CREATE: UINT = 0x8000;
+pub const EVENT_OBJECT_DESTROY: UINT = 0x8001;
+pub const EVENT_OBJECT_SHOW: UINT = 0x8002;
+pub const EVENT_OBJECT_HIDE: UINT = 0x8003;
+pub const EVENT_OBJECT_REORDER: UINT = 0x8004;
+pub const EVENT_OBJECT_FOCUS: UINT = 0x8005;
+pub const EVENT_OBJECT_SELECTION: UINT = 0x8006;
+pub const EVENT_OBJECT_SELECTIONADD: UINT = 0x8007;
+pub const EVENT_OBJECT_SELECTIONREMOVE: UINT = 0x8008;
+pub const EVENT_OBJECT_SELECTIONWITHIN: UINT = 0x8009;
+pub const EVENT_OBJECT_STATECHANGE: UINT = 0x800A;
+pub const EVENT_OBJECT_LOCATIONCHANGE: UINT = 0x800B;
+pub const EVENT_OBJECT_NAMECHANGE: UINT = 0x800C;
+pub const EVENT_OBJECT_DESCRIPTIONCHANGE: UINT = 0x800D;
+pub const EVENT_OBJECT_VALUECHANGE: UINT = 0x800E;
+pub const EVENT_OBJECT_PARENTCHANGE: UINT = 0x800F;
+pub const EVENT_OBJECT_HELPCHANGE: UINT = 0x8010;
+pub const EVENT_OBJECT_DEFACTIONCHANGE: UINT = 0x8011;
+pub const EVENT_OBJECT_ACCELERATORCHANGE: UINT = 0x8012;
+pub const EVENT_OBJECT_INVOKED: UINT = 0x8013;
+pub const EVENT_OBJECT_TEXTSELECTIONCHANGED: UINT = 0x8014;
+pub const EVENT_OBJECT_CONTENTSCROLLED: UINT = 0x8015;
+pub const EVENT_SYSTEM_ARRANGMENTPREVIEW: UINT = 0x8016;
+pub const EVENT_OBJECT_CLOAKED: UINT = 0x8017;
+pub const EVENT_OBJECT_UNCLOAKED: UINT = 0x8018;
+pub const EVENT_OBJECT_LIVEREGIONCHANGED: UINT = 0x8019;
+pub const EVENT_OBJECT_HOSTEDOBJECTSINVALIDATED: UINT = 0x8020;
+pub const EVENT_OBJECT_DRAGSTART: UINT = 0x8021;
+pub const EVENT_OBJECT_DRAGCANCEL: UINT = 0x8022;
+pub const EVENT_OBJECT_DRAGCOMPLETE: UINT = 0x8023;
+pub const EVENT_OBJECT_DRAGENTER: UINT = 0x8024;
+pub const EVENT_OBJECT_DRAGLEAVE: UINT = 0x8025;
+pub const EVENT_OBJECT_DRAGDROPPED: UINT = 0x8026;
+pub const EVENT_OBJECT_IME_SHOW: UINT = 0x8027;
+pub const EVENT_OBJECT_IME_HIDE: UINT = 0x8028;
+pub const EVENT_OBJECT_IME_CHANGE: UINT = 0x8029;
+pub const EVENT_OBJECT_TEXTEDIT_CONVERSIONTARGETCHANGED: UINT = 0x8030;
+pub const EVENT_OBJECT_END: UINT = 0x80FF;
+pub const EVENT_AIA_START: UINT = 0xA000;
+pub const EVENT_AIA_END: UINT = 0xAFFF;
+pub const ALERT_SYSTEM_INFORMATIONAL: LONG = 1;
+pub const ALERT_SYSTEM_WARNING: LONG = 2;
+pub const ALERT_SYSTEM_ERROR: LONG = 3;
+pub const ALERT_SYSTEM_QUERY: LONG = 4;
+pub const ALERT_SYSTEM_CRITICAL: LONG = 5;
+pub const CALERT_SYSTEM: LONG = 6;
+extern "system" {
 //14098
     pub fn BlockInput(
         fBlockIt: BOOL,
@@ -6526,7 +6649,7 @@ extern "system" {
         bGrant: BOOL,
     ) -> BOOL;
 }
-DECLARE_HANDLE!(HRAWINPUT, HRAWINPUT__);
+DECLARE_HANDLE!{HRAWINPUT, HRAWINPUT__}
 #[inline]
 pub fn GET_RAWINPUT_CODE_WPARAM(wParam: WPARAM) -> WPARAM { wParam & 0xff }
 pub const RIM_INPUT: WPARAM = 0;
@@ -6596,7 +6719,7 @@ pub const RI_KEY_TERMSRV_SHADOW: DWORD = 0x10;
 STRUCT!{struct RAWHID {
     dwSizeHid: DWORD,
     dwCount: DWORD,
-    bRawData: [BYTE; 0],
+    bRawData: [BYTE; 1],
 }}
 pub type PRAWHID = *mut RAWHID;
 pub type LPRAWHID = *mut RAWHID;
@@ -4,5 +4,5 @@
 // <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your option.
 // All files in the project carrying such notice may not be copied, modified, or distributed
 // except according to those terms.
-use ctypes::{c_uint};
+use ctypes::c_uint;
 pub const UINT_MAX: c_uint = 0xffffffff;
@@ -4,7 +4,7 @@
 // <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your option.
 // All files in the project carrying such notice may not be copied, modified, or distributed
 // except according to those terms.
-use um::winnt::{HRESULT};
+use um::winnt::HRESULT;
 use winrt::inspectable::{IInspectable, IInspectableVtbl};
 RIDL!{#[uuid(0x00000035, 0x0000, 0x0000, 0xc0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x46)]
 interface IActivationFactory(IActivationFactoryVtbl): IInspectable(IInspectableVtbl) {